@@ -3,13 +3,18 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 //! Assorted functions to help with date calculations.
+//!
+//! Only [`iso_weekday`] and [`iso_date_to_epoch_days`] are public; the remaining functions are used
+//! internally by the formatter.
 
-use crate::date::{Era, IsoWeekday, Year};
+use crate::date::{DayOfYearInfo, Era, IsoWeekday, WeekOfMonth, WeekOfYear, Year};
 use crate::pattern::{Pattern, TimeGranularity};
+use crate::provider::week_data::WeekDataV1;
 
+use std::convert::TryFrom;
 use tinystr::tinystr8;
 
-pub fn iso_year_to_gregorian(iso_year: i32) -> Year {
+pub(crate) fn iso_year_to_gregorian(iso_year: i32) -> Year {
     if iso_year > 0 {
         Year {
             era: Era(tinystr8!("ce")),
@@ -61,31 +66,433 @@ fn test_iso_year_to_gregorian() {
     );
 }
 
-/// Temporary simplified function to get the day of the week
-/// month and day are both zero-indexed.
+/// Returns the year of the Thai Buddhist calendar that corresponds to the ISO year `iso_year`.
+/// Buddhist years are counted from 543 BC, and share their months and days with the Gregorian
+/// calendar.
+pub(crate) fn iso_year_to_buddhist(iso_year: i32) -> Year {
+    Year {
+        era: Era(tinystr8!("be")),
+        number: iso_year + 543,
+        related_iso: iso_year,
+    }
+}
+
+#[test]
+fn test_iso_year_to_buddhist() {
+    assert_eq!(
+        iso_year_to_buddhist(2020),
+        Year {
+            era: Era(tinystr8!("be")),
+            number: 2563,
+            related_iso: 2020,
+        }
+    );
+    assert_eq!(
+        iso_year_to_buddhist(-542),
+        Year {
+            era: Era(tinystr8!("be")),
+            number: 1,
+            related_iso: -542,
+        }
+    );
+}
+
+/// Returns the year of the Japanese imperial calendar that contains a date in the proleptic
+/// Gregorian calendar. `month` and `day` are both zero-indexed.
+///
+/// The year number counts from 1 at the start of each era, so the year in which an era starts
+/// is also the last year of the previous era. Dates before the Meiji era are given in the
+/// Gregorian calendar.
+pub(crate) fn iso_date_to_japanese_year(iso_year: i32, month: u32, day: u32) -> Year {
+    let date = (iso_year, month, day);
+    let (era, first_iso_year) = if date >= (2019, 4, 0) {
+        (tinystr8!("reiwa"), 2019)
+    } else if date >= (1989, 0, 7) {
+        (tinystr8!("heisei"), 1989)
+    } else if date >= (1926, 11, 24) {
+        (tinystr8!("showa"), 1926)
+    } else if date >= (1912, 6, 29) {
+        (tinystr8!("taisho"), 1912)
+    } else if date >= (1868, 8, 7) {
+        (tinystr8!("meiji"), 1868)
+    } else {
+        return iso_year_to_gregorian(iso_year);
+    };
+    Year {
+        era: Era(era),
+        number: iso_year - first_iso_year + 1,
+        related_iso: iso_year,
+    }
+}
+
+#[test]
+fn test_iso_date_to_japanese_year() {
+    let japanese_year = |year, month, day| {
+        let year = iso_date_to_japanese_year(year, month, day);
+        (year.era.0.to_string(), year.number)
+    };
+    // 2020-01-01
+    assert_eq!(japanese_year(2020, 0, 0), ("reiwa".to_string(), 2));
+    // 2019-05-01 and the day before
+    assert_eq!(japanese_year(2019, 4, 0), ("reiwa".to_string(), 1));
+    assert_eq!(japanese_year(2019, 3, 29), ("heisei".to_string(), 31));
+    // 1989-01-08 and the day before
+    assert_eq!(japanese_year(1989, 0, 7), ("heisei".to_string(), 1));
+    assert_eq!(japanese_year(1989, 0, 6), ("showa".to_string(), 64));
+    // 1926-12-25 and the day before
+    assert_eq!(japanese_year(1926, 11, 24), ("showa".to_string(), 1));
+    assert_eq!(japanese_year(1926, 11, 23), ("taisho".to_string(), 15));
+    // 1912-07-30 and the day before
+    assert_eq!(japanese_year(1912, 6, 29), ("taisho".to_string(), 1));
+    assert_eq!(japanese_year(1912, 6, 28), ("meiji".to_string(), 45));
+    // 1868-09-08 and the day before
+    assert_eq!(japanese_year(1868, 8, 7), ("meiji".to_string(), 1));
+    assert_eq!(japanese_year(1868, 8, 6), ("ce".to_string(), 1868));
+}
+
+/// Returns the day of the week of a date in the proleptic Gregorian calendar.
+///
+/// Like the fields of [`MockDateTime`](crate::mock::datetime::MockDateTime), `month` and `day`
+/// are both zero-indexed. This is the weekday used by the formatter for the `E` field.
 ///
 /// The caller should guarantee that `month` is between 0 and 11.
-pub fn iso_date_to_weekday(year: i32, month: usize, day: usize) -> IsoWeekday {
+///
+/// # Examples
+///
+/// ```
+/// use icu::datetime::arithmetic::iso_weekday;
+/// use icu::datetime::date::IsoWeekday;
+///
+/// // 2021-03-03
+/// assert_eq!(iso_weekday(2021, 2, 2), IsoWeekday::Wednesday);
+/// ```
+pub fn iso_weekday(year: i32, month: u32, day: u32) -> IsoWeekday {
     debug_assert!(month < 12);
     let t = &[0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
-    let year = (if month < 2 { year - 1 } else { year }).rem_euclid(400) as usize;
-    let result = (year + year / 4 - year / 100 + year / 400 + t[month] + day + 1) % 7;
-    result.into()
+    let year = (if month < 2 { year - 1 } else { year }).rem_euclid(400) as u32;
+    let result = (year + year / 4 - year / 100 + year / 400 + t[month as usize] + day + 1) % 7;
+    (result as usize).into()
+}
+
+#[test]
+fn test_iso_weekday() {
+    assert_eq!(IsoWeekday::Saturday, iso_weekday(2000, 0, 0));
+    assert_eq!(IsoWeekday::Wednesday, iso_weekday(2021, 1, 2));
+    assert_eq!(IsoWeekday::Saturday, iso_weekday(-400, 0, 0));
+    assert_eq!(IsoWeekday::Wednesday, iso_weekday(-379, 1, 2));
+    assert_eq!(IsoWeekday::Monday, iso_weekday(1900, 0, 0));
+    assert_eq!(IsoWeekday::Thursday, iso_weekday(1970, 0, 0));
+    assert_eq!(IsoWeekday::Friday, iso_weekday(2021, 11, 30));
+}
+
+/// Returns [`true`] if `year` is a leap year in the proleptic Gregorian calendar.
+pub(crate) fn is_iso_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Returns the number of days in the ISO `year`.
+pub(crate) fn iso_days_in_year(year: i32) -> u32 {
+    if is_iso_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+/// Returns the number of days in the zero-indexed `month` of the ISO `year`.
+///
+/// The caller should guarantee that `month` is between 0 and 11.
+pub(crate) fn iso_days_in_month(year: i32, month: u32) -> u32 {
+    debug_assert!(month < 12);
+    match month {
+        1 if is_iso_leap_year(year) => 29,
+        1 => 28,
+        3 | 5 | 8 | 10 => 30,
+        _ => 31,
+    }
+}
+
+#[test]
+fn test_iso_days_in_month() {
+    assert_eq!(31, iso_days_in_month(2021, 0));
+    assert_eq!(28, iso_days_in_month(2021, 1));
+    assert_eq!(29, iso_days_in_month(2020, 1));
+    assert_eq!(29, iso_days_in_month(2000, 1));
+    assert_eq!(28, iso_days_in_month(1900, 1));
+    assert_eq!(30, iso_days_in_month(2021, 10));
+    assert_eq!(31, iso_days_in_month(2021, 11));
+}
+
+/// Returns the number of days from 1970-01-01 to a date in the proleptic Gregorian calendar,
+/// which is negative for earlier dates. `month` and `day` are both zero-indexed.
+///
+/// The caller should guarantee that `month` is between 0 and 11. A `day` past the end of the
+/// month counts into the following months.
+///
+/// # Examples
+///
+/// ```
+/// use icu::datetime::arithmetic::iso_date_to_epoch_days;
+///
+/// // 1969-12-31
+/// assert_eq!(iso_date_to_epoch_days(1969, 11, 30), -1);
+/// // 2021-03-03
+/// assert_eq!(iso_date_to_epoch_days(2021, 2, 2), 18689);
+/// ```
+pub fn iso_date_to_epoch_days(year: i32, month: u32, day: u32) -> i64 {
+    debug_assert!(month < 12);
+    // Count years from March, so that the leap day is the last day of the year.
+    let year = i64::from(year) - if month < 2 { 1 } else { 0 };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * ((i64::from(month) + 10) % 12) + 2) / 5 + i64::from(day);
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    // 0000-03-01 is 719468 days before 1970-01-01.
+    era * 146097 + day_of_era - 719468
+}
+
+/// Returns the date in the proleptic Gregorian calendar that is `days` days after 1970-01-01, as
+/// a year and a zero-indexed month and day. This is the inverse of [`iso_date_to_epoch_days`].
+///
+/// # Panics
+///
+/// Panics if the year does not fit in an [`i32`].
+pub(crate) fn iso_epoch_days_to_date(days: i64) -> (i32, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (year_of_era * 365 + year_of_era / 4 - year_of_era / 100);
+    // The month, counted from March.
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5;
+    let month = (month + 2) % 12;
+    let year = year_of_era + era * 400 + if month < 2 { 1 } else { 0 };
+    let year = i32::try_from(year).expect("Year out of range.");
+    (year, month as u32, day as u32)
 }
 
 #[test]
-fn test_iso_date_to_weekday() {
-    assert_eq!(IsoWeekday::Saturday, iso_date_to_weekday(2000, 0, 0));
-    assert_eq!(IsoWeekday::Wednesday, iso_date_to_weekday(2021, 1, 2));
-    assert_eq!(IsoWeekday::Saturday, iso_date_to_weekday(-400, 0, 0));
-    assert_eq!(IsoWeekday::Wednesday, iso_date_to_weekday(-379, 1, 2));
+fn test_iso_epoch_days() {
+    assert_eq!(0, iso_date_to_epoch_days(1970, 0, 0));
+    assert_eq!(-1, iso_date_to_epoch_days(1969, 11, 30));
+    assert_eq!(18321, iso_date_to_epoch_days(2020, 1, 28));
+    assert_eq!(10957, iso_date_to_epoch_days(2000, 0, 0));
+    assert_eq!(-719528, iso_date_to_epoch_days(0, 0, 0));
+    // A day past the end of the month.
+    assert_eq!(31, iso_date_to_epoch_days(1970, 0, 31));
+
+    assert_eq!((1970, 0, 0), iso_epoch_days_to_date(0));
+    assert_eq!((1969, 11, 30), iso_epoch_days_to_date(-1));
+    assert_eq!((2020, 1, 28), iso_epoch_days_to_date(18321));
+    assert_eq!((0, 0, 0), iso_epoch_days_to_date(-719528));
+
+    for days in -800_000..800_000 {
+        let (year, month, day) = iso_epoch_days_to_date(days);
+        assert!(day < iso_days_in_month(year, month));
+        assert_eq!(days, iso_date_to_epoch_days(year, month, day));
+    }
+}
+
+/// Returns the 1-based day of the year.
+/// month and day are both zero-indexed.
+///
+/// The caller should guarantee that `month` is between 0 and 11.
+pub(crate) fn iso_day_of_year(year: i32, month: usize, day: u32) -> u32 {
+    debug_assert!(month < 12);
+    const DAYS_BEFORE_MONTH: [u32; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+    let leap_day = if month >= 2 && is_iso_leap_year(year) {
+        1
+    } else {
+        0
+    };
+    DAYS_BEFORE_MONTH[month] + leap_day + day + 1
+}
+
+#[test]
+fn test_iso_day_of_year() {
+    assert_eq!(1, iso_day_of_year(2021, 0, 0));
+    assert_eq!(60, iso_day_of_year(2021, 2, 0));
+    assert_eq!(61, iso_day_of_year(2020, 2, 0));
+    assert_eq!(365, iso_day_of_year(2021, 11, 30));
+    assert_eq!(366, iso_day_of_year(2020, 11, 30));
+    assert_eq!(366, iso_day_of_year(2000, 11, 30));
+    assert_eq!(365, iso_day_of_year(1900, 11, 30));
+}
+
+/// Returns the position of `weekday` within a week that starts on the locale's first weekday,
+/// from 0 to 6.
+fn relative_weekday(week_data: &WeekDataV1, weekday: IsoWeekday) -> u32 {
+    (weekday as i32 - week_data.first_weekday as i32).rem_euclid(7) as u32
+}
+
+/// Returns the week number of the 1-based `day` within a period of days, such as a month or a
+/// year. `weekday` is the day of the week of `day`.
+///
+/// The week containing the first day of the period is week 1 if at least
+/// [`min_week_days`](WeekDataV1::min_week_days) of its days fall into the period. Otherwise it
+/// is week 0, since it is counted as part of the preceding period.
+pub(crate) fn week_of_period(week_data: &WeekDataV1, day: u32, weekday: IsoWeekday) -> u32 {
+    debug_assert!(day > 0);
+    // The position of the first day of the period within its week.
+    let first_day_offset =
+        (relative_weekday(week_data, weekday) as i64 - (day as i64 - 1)).rem_euclid(7) as u32;
+    let days_in_first_week = 7 - first_day_offset;
+    let first_week = if days_in_first_week >= u32::from(week_data.min_week_days) {
+        1
+    } else {
+        0
+    };
+    (day - 1 + first_day_offset) / 7 + first_week
+}
+
+/// Returns the week of the month for the 1-based `day_of_month`.
+pub(crate) fn week_of_month(
+    week_data: &WeekDataV1,
+    day_of_month: u32,
+    weekday: IsoWeekday,
+) -> WeekOfMonth {
+    WeekOfMonth(week_of_period(week_data, day_of_month, weekday))
+}
+
+/// Returns the week-numbering year and the week of that year of a day.
+///
+/// The days at the start of a year can belong to the last week of the previous year, and the
+/// days at the end of a year can belong to the first week of the next year.
+pub(crate) fn week_of_year(
+    week_data: &WeekDataV1,
+    year: Year,
+    info: &DayOfYearInfo,
+    weekday: IsoWeekday,
+) -> (Year, WeekOfYear) {
+    let week = week_of_period(week_data, info.day_of_year, weekday);
+    if week == 0 {
+        // The day is in the last week of the previous year.
+        let day_of_prev_year = info.day_of_year + info.days_in_prev_year;
+        let week = week_of_period(week_data, day_of_prev_year, weekday);
+        return (info.prev_year.clone(), WeekOfYear(week));
+    }
+
+    // The week is the first week of the next year if enough of its days fall into that year.
+    let last_day_of_week = info.day_of_year + 6 - relative_weekday(week_data, weekday);
+    let days_in_next_year = last_day_of_week.saturating_sub(info.days_in_year);
+    if days_in_next_year > 0 && days_in_next_year >= u32::from(week_data.min_week_days) {
+        return (info.next_year.clone(), WeekOfYear(1));
+    }
+    (year, WeekOfYear(week))
+}
+
+/// Returns the number of `weekday` within a week that starts on the locale's first weekday,
+/// from 1 to 7. This is the number formatted for the numeric `e` and `c` fields.
+pub(crate) fn local_day_of_week(week_data: &WeekDataV1, weekday: IsoWeekday) -> u32 {
+    relative_weekday(week_data, weekday) + 1
+}
+
+/// Returns the day-of-week-in-month (`F`) for the 1-based `day_of_month`, i.e. 2 for the
+/// second Wednesday of a month. This does not depend on the locale.
+pub(crate) fn day_of_week_in_month(day_of_month: u32) -> u32 {
+    (day_of_month - 1) / 7 + 1
+}
+
+#[cfg(test)]
+mod week_tests {
+    use super::*;
+
+    const ISO: WeekDataV1 = WeekDataV1 {
+        first_weekday: IsoWeekday::Monday,
+        min_week_days: 4,
+    };
+
+    const US: WeekDataV1 = WeekDataV1 {
+        first_weekday: IsoWeekday::Sunday,
+        min_week_days: 1,
+    };
+
+    fn day_of_year_info(year: i32, month: usize, day: u32) -> DayOfYearInfo {
+        DayOfYearInfo {
+            day_of_year: iso_day_of_year(year, month, day),
+            days_in_year: iso_days_in_year(year),
+            days_in_prev_year: iso_days_in_year(year - 1),
+            prev_year: iso_year_to_gregorian(year - 1),
+            next_year: iso_year_to_gregorian(year + 1),
+        }
+    }
+
+    /// Returns the week-numbering year and the week of the year, for a 0-based month and day.
+    fn week(week_data: &WeekDataV1, year: i32, month: usize, day: u32) -> (i32, u32) {
+        let (year, week) = week_of_year(
+            week_data,
+            iso_year_to_gregorian(year),
+            &day_of_year_info(year, month, day),
+            iso_weekday(year, month as u32, day),
+        );
+        (year.number, week.0)
+    }
+
+    #[test]
+    fn test_week_of_year_iso() {
+        // 2020-01-01 is a Wednesday, so the first week of 2020 starts on 2019-12-30.
+        assert_eq!((2020, 1), week(&ISO, 2020, 0, 0));
+        assert_eq!((2020, 1), week(&ISO, 2019, 11, 29));
+        assert_eq!((2019, 52), week(&ISO, 2019, 11, 28));
+        // 2021-01-01 is a Friday, so 2021-01-01 to 2021-01-03 are in the last week of 2020.
+        assert_eq!((2020, 53), week(&ISO, 2021, 0, 0));
+        assert_eq!((2020, 53), week(&ISO, 2021, 0, 2));
+        assert_eq!((2021, 1), week(&ISO, 2021, 0, 3));
+        assert_eq!((2020, 53), week(&ISO, 2020, 11, 30));
+        assert_eq!((2021, 10), week(&ISO, 2021, 2, 10));
+    }
+
+    #[test]
+    fn test_week_of_year_us() {
+        // With a minimum of one day, the week containing January 1 is always the first week.
+        assert_eq!((2020, 1), week(&US, 2020, 0, 0));
+        assert_eq!((2020, 1), week(&US, 2020, 0, 3));
+        assert_eq!((2020, 2), week(&US, 2020, 0, 4));
+        assert_eq!((2021, 1), week(&US, 2021, 0, 0));
+        assert_eq!((2021, 1), week(&US, 2020, 11, 30));
+        assert_eq!((2021, 1), week(&US, 2020, 11, 26));
+        assert_eq!((2020, 52), week(&US, 2020, 11, 25));
+    }
+
+    #[test]
+    fn test_local_day_of_week() {
+        assert_eq!(1, local_day_of_week(&ISO, IsoWeekday::Monday));
+        assert_eq!(7, local_day_of_week(&ISO, IsoWeekday::Sunday));
+        assert_eq!(1, local_day_of_week(&US, IsoWeekday::Sunday));
+        assert_eq!(2, local_day_of_week(&US, IsoWeekday::Monday));
+        assert_eq!(7, local_day_of_week(&US, IsoWeekday::Saturday));
+    }
+
+    #[test]
+    fn test_week_of_month() {
+        // 2021-05-01 is a Saturday.
+        let saturday = iso_weekday(2021, 4, 0);
+        let sunday = iso_weekday(2021, 4, 1);
+        let monday = iso_weekday(2021, 4, 2);
+        assert_eq!(WeekOfMonth(0), week_of_month(&ISO, 1, saturday));
+        assert_eq!(WeekOfMonth(0), week_of_month(&ISO, 2, sunday));
+        assert_eq!(WeekOfMonth(1), week_of_month(&ISO, 3, monday));
+        assert_eq!(WeekOfMonth(1), week_of_month(&US, 1, saturday));
+        assert_eq!(WeekOfMonth(2), week_of_month(&US, 2, sunday));
+        assert_eq!(WeekOfMonth(2), week_of_month(&US, 3, monday));
+    }
+
+    #[test]
+    fn test_day_of_week_in_month() {
+        assert_eq!(1, day_of_week_in_month(1));
+        assert_eq!(1, day_of_week_in_month(7));
+        assert_eq!(2, day_of_week_in_month(8));
+        assert_eq!(5, day_of_week_in_month(31));
+    }
 }
 
 /// Returns [`true`] if the most granular time being displayed will align with
 /// the top of the hour, otherwise returns [`false`].
 /// e.g. `12:00:00` is at the top of the hour for any display granularity.
 /// e.g. `12:00:05` is only at the top of the hour if the seconds are not displayed.
-pub fn is_top_of_hour(pattern: &Pattern, minute: u8, second: u8) -> bool {
+pub(crate) fn is_top_of_hour(pattern: &Pattern, minute: u8, second: u8) -> bool {
     match pattern.most_granular_time() {
         None | Some(TimeGranularity::Hours) => true,
         Some(TimeGranularity::Minutes) => minute == 0,
@@ -2,6 +2,9 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use crate::arithmetic;
+use crate::options::preferences::Calendar;
+use crate::provider::week_data::WeekDataV1;
 use displaydoc::Display;
 use icu_locid::Locale;
 use std::convert::TryFrom;
@@ -13,6 +16,22 @@ use tinystr::TinyStr8;
 pub enum DateTimeError {
     #[displaydoc("{0}")]
     Parse(std::num::ParseIntError),
+    /// A part of the input string does not match the expected format.
+    #[displaydoc("Failed to parse {input:?}, expected {expected}")]
+    InvalidFormat {
+        /// The offending part of the input.
+        input: String,
+        /// The expected format, e.g. `YYYY-MM-DDThh:mm:ss`.
+        expected: &'static str,
+    },
+    /// A component of the input string is out of range.
+    #[displaydoc("{field}={value} is out of range, must be between {min}-{max}")]
+    OutOfRange {
+        field: &'static str,
+        value: u32,
+        min: u32,
+        max: u32,
+    },
     #[displaydoc("{field} must be between 0-{max}")]
     Overflow { field: &'static str, max: usize },
     #[displaydoc("{field} must be between {min}-0")]
@@ -21,7 +40,14 @@ pub enum DateTimeError {
     InvalidTimeZoneOffset,
 }
 
-impl std::error::Error for DateTimeError {}
+impl std::error::Error for DateTimeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Parse(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 impl From<std::num::ParseIntError> for DateTimeError {
     fn from(e: std::num::ParseIntError) -> Self {
@@ -110,55 +136,135 @@ pub trait LocalizedDateTimeInput<T: DateTimeInput> {
     /// A reference to this instance's [`DateTimeInput`].
     fn datetime(&self) -> &T;
 
+    /// The year in the calendar of the locale, which is selected by its `ca` keyword.
+    ///
+    /// For example, 2020 is the year 2563 of the Buddhist calendar.
+    fn year(&self) -> Option<Year>;
+
     /// The year number according to week numbering.
     ///
     /// For example, December 31, 2020 is part of the first week of 2021.
-    fn year_week(&self) -> Year;
+    fn year_week(&self) -> Option<Year>;
 
     /// The week of the month according to UTS 35.
-    fn week_of_month(&self) -> WeekOfMonth;
+    fn week_of_month(&self) -> Option<WeekOfMonth>;
 
     /// The week number of the year.
     ///
     /// For example, December 31, 2020 is part of the first week of 2021.
-    fn week_of_year(&self) -> WeekOfYear;
+    fn week_of_year(&self) -> Option<WeekOfYear>;
+
+    /// The number of the day of the week, counted from 1 for the first day of the week of the
+    /// locale.
+    ///
+    /// For example, Monday is day 2 in the United States, where weeks start on Sunday.
+    fn local_day_of_week(&self) -> Option<u32>;
 
     /// TODO(#487): Implement flexible day periods.
     fn flexible_day_period(&self);
 }
 
+/// Computes the week-numbering year and the week of the year of `datetime`, or [`None`] if
+/// one of the required input fields is missing.
+fn week_of_year<T: DateInput>(datetime: &T, week_data: &WeekDataV1) -> Option<(Year, WeekOfYear)> {
+    Some(arithmetic::week_of_year(
+        week_data,
+        datetime.year()?,
+        &datetime.day_of_year_info()?,
+        datetime.iso_weekday()?,
+    ))
+}
+
+/// Computes the year of `datetime` in `calendar`, or [`None`] if one of the required input
+/// fields is missing. The input is expected to be in the Gregorian calendar.
+fn calendar_year<T: DateInput>(datetime: &T, calendar: Calendar) -> Option<Year> {
+    let year = datetime.year()?;
+    Some(match calendar {
+        Calendar::Gregorian => year,
+        Calendar::Buddhist => arithmetic::iso_year_to_buddhist(year.related_iso),
+        Calendar::Japanese => arithmetic::iso_date_to_japanese_year(
+            year.related_iso,
+            datetime.month()?.number - 1,
+            datetime.day_of_month()?.0 - 1,
+        ),
+    })
+}
+
+/// Computes the week-numbering year of `datetime` in `calendar`, or [`None`] if one of the
+/// required input fields is missing. Outside of the Gregorian calendar, the week-numbering year
+/// keeps the era of the calendar year, and only its number differs.
+fn year_week<T: DateInput>(
+    datetime: &T,
+    week_data: &WeekDataV1,
+    calendar: Calendar,
+) -> Option<Year> {
+    let (week_year, _) = week_of_year(datetime, week_data)?;
+    if calendar == Calendar::Gregorian {
+        return Some(week_year);
+    }
+    let mut year = calendar_year(datetime, calendar)?;
+    year.number += week_year.related_iso - year.related_iso;
+    year.related_iso = week_year.related_iso;
+    Some(year)
+}
+
+/// Computes the week of the month of `datetime`, or [`None`] if one of the required input
+/// fields is missing.
+fn week_of_month<T: DateInput>(datetime: &T, week_data: &WeekDataV1) -> Option<WeekOfMonth> {
+    Some(arithmetic::week_of_month(
+        week_data,
+        datetime.day_of_month()?.0,
+        datetime.iso_weekday()?,
+    ))
+}
+
+/// Computes the number of the day of the week of `datetime` within the local week, or [`None`]
+/// if the weekday is missing.
+fn local_day_of_week<T: DateInput>(datetime: &T, week_data: &WeekDataV1) -> Option<u32> {
+    Some(arithmetic::local_day_of_week(
+        week_data,
+        datetime.iso_weekday()?,
+    ))
+}
+
 pub(crate) struct DateTimeInputWithLocale<'s, T: DateTimeInput> {
     data: &'s T,
-    _first_weekday: u8,
-    _anchor_weekday: u8,
+    week_data: Option<&'s WeekDataV1>,
+    calendar: Calendar,
 }
 
 impl<'s, T: DateTimeInput> DateTimeInputWithLocale<'s, T> {
-    pub fn new(data: &'s T, _locale: &Locale) -> Self {
+    pub fn new(data: &'s T, week_data: Option<&'s WeekDataV1>, locale: &Locale) -> Self {
         Self {
             data,
-            // TODO(#488): Implement week calculations.
-            _first_weekday: 1,
-            _anchor_weekday: 4,
+            week_data,
+            calendar: Calendar::from_locale(locale).unwrap_or(Calendar::Gregorian),
         }
     }
+
+    fn week_data(&self) -> &WeekDataV1 {
+        self.week_data.expect("Expect week data to be present")
+    }
 }
 
 pub(crate) struct ZonedDateTimeInputWithLocale<'s, T: ZonedDateTimeInput> {
     data: &'s T,
-    _first_weekday: u8,
-    _anchor_weekday: u8,
+    week_data: Option<&'s WeekDataV1>,
+    calendar: Calendar,
 }
 
 impl<'s, T: ZonedDateTimeInput> ZonedDateTimeInputWithLocale<'s, T> {
-    pub fn new(data: &'s T, _locale: &Locale) -> Self {
+    pub fn new(data: &'s T, week_data: Option<&'s WeekDataV1>, locale: &Locale) -> Self {
         Self {
             data,
-            // TODO(#488): Implement week calculations.
-            _first_weekday: 1,
-            _anchor_weekday: 4,
+            week_data,
+            calendar: Calendar::from_locale(locale).unwrap_or(Calendar::Gregorian),
         }
     }
+
+    fn week_data(&self) -> &WeekDataV1 {
+        self.week_data.expect("Expect week data to be present")
+    }
 }
 
 impl<'s, T: DateTimeInput> LocalizedDateTimeInput<T> for DateTimeInputWithLocale<'s, T> {
@@ -166,16 +272,24 @@ impl<'s, T: DateTimeInput> LocalizedDateTimeInput<T> for DateTimeInputWithLocale
         self.data
     }
 
-    fn year_week(&self) -> Year {
-        todo!("#488")
+    fn year(&self) -> Option<Year> {
+        calendar_year(self.data, self.calendar)
     }
 
-    fn week_of_month(&self) -> WeekOfMonth {
-        todo!("#488")
+    fn year_week(&self) -> Option<Year> {
+        year_week(self.data, self.week_data(), self.calendar)
     }
 
-    fn week_of_year(&self) -> WeekOfYear {
-        todo!("#488")
+    fn week_of_month(&self) -> Option<WeekOfMonth> {
+        week_of_month(self.data, self.week_data())
+    }
+
+    fn week_of_year(&self) -> Option<WeekOfYear> {
+        week_of_year(self.data, self.week_data()).map(|(_, week)| week)
+    }
+
+    fn local_day_of_week(&self) -> Option<u32> {
+        local_day_of_week(self.data, self.week_data())
     }
 
     fn flexible_day_period(&self) {
@@ -188,16 +302,24 @@ impl<'s, T: ZonedDateTimeInput> LocalizedDateTimeInput<T> for ZonedDateTimeInput
         self.data
     }
 
-    fn year_week(&self) -> Year {
-        todo!("#488")
+    fn year(&self) -> Option<Year> {
+        calendar_year(self.data, self.calendar)
+    }
+
+    fn year_week(&self) -> Option<Year> {
+        year_week(self.data, self.week_data(), self.calendar)
+    }
+
+    fn week_of_month(&self) -> Option<WeekOfMonth> {
+        week_of_month(self.data, self.week_data())
     }
 
-    fn week_of_month(&self) -> WeekOfMonth {
-        todo!("#488")
+    fn week_of_year(&self) -> Option<WeekOfYear> {
+        week_of_year(self.data, self.week_data()).map(|(_, week)| week)
     }
 
-    fn week_of_year(&self) -> WeekOfYear {
-        todo!("#488")
+    fn local_day_of_week(&self) -> Option<u32> {
+        local_day_of_week(self.data, self.week_data())
     }
 
     fn flexible_day_period(&self) {
@@ -248,8 +370,10 @@ pub struct Month {
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct DayOfYearInfo {
+    /// The 1-based day of the year.
     pub day_of_year: u32,
     pub days_in_year: u32,
+    pub days_in_prev_year: u32,
     pub prev_year: Year,
     pub next_year: Year,
 }
@@ -267,6 +391,10 @@ pub struct DayOfYearInfo {
 /// assert_eq!(7, IsoWeekday::Sunday as usize);
 /// ```
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
 #[repr(i8)]
 pub enum IsoWeekday {
     Monday = 1,
@@ -333,7 +461,7 @@ macro_rules! dt_unit {
                 let val: u8 = input.parse()?;
                 if val > $value {
                     Err(DateTimeError::Overflow {
-                        field: "$name",
+                        field: stringify!($name),
                         max: $value,
                     })
                 } else {
@@ -348,7 +476,7 @@ macro_rules! dt_unit {
             fn try_from(input: u8) -> Result<Self, Self::Error> {
                 if input > $value {
                     Err(DateTimeError::Overflow {
-                        field: "$name",
+                        field: stringify!($name),
                         max: $value,
                     })
                 } else {
@@ -363,7 +491,7 @@ macro_rules! dt_unit {
             fn try_from(input: usize) -> Result<Self, Self::Error> {
                 if input > $value {
                     Err(DateTimeError::Overflow {
-                        field: "$name",
+                        field: stringify!($name),
                         max: $value,
                     })
                 } else {
@@ -455,12 +583,12 @@ impl GmtOffset {
 
     /// Returns `true` if the [`GmtOffset`] has non-zero minutes, otherwise `false`.
     pub fn has_minutes(&self) -> bool {
-        self.0 % 3600 / 60 > 0
+        self.0 % 3600 / 60 != 0
     }
 
     /// Returns `true` if the [`GmtOffset`] has non-zero seconds, otherwise `false`.
     pub fn has_seconds(&self) -> bool {
-        self.0 % 3600 % 60 > 0
+        self.0 % 3600 % 60 != 0
     }
 }
 
@@ -6,15 +6,18 @@ use crate::{
     format::datetime,
     options::DateTimeFormatOptions,
     provider::{
+        eras::ErasV1Marker,
         gregory::{DatePatternsV1Marker, DateSymbolsV1Marker},
         helpers::DateTimePatterns,
+        week_data::WeekDataV1Marker,
     },
 };
 use icu_locid::Locale;
 use icu_provider::prelude::*;
 
 use crate::{
-    date::DateTimeInput, pattern::Pattern, provider, DateTimeFormatError, FormattedDateTime,
+    date::DateTimeInput, pattern::Pattern, provider, DateTimeFormatError, DateTimeParts,
+    FormattedDateTime, PartsWrite,
 };
 
 /// [`DateTimeFormat`] is the main structure of the [`icu_datetime`] component.
@@ -61,6 +64,8 @@ pub struct DateTimeFormat<'d> {
     pub(super) locale: Locale,
     pub(super) pattern: Pattern,
     pub(super) symbols: Option<DataPayload<'d, 'd, DateSymbolsV1Marker>>,
+    pub(super) eras: Option<DataPayload<'d, 'd, ErasV1Marker>>,
+    pub(super) week_data: Option<DataPayload<'d, 'd, WeekDataV1Marker>>,
 }
 
 impl<'d> DateTimeFormat<'d> {
@@ -86,17 +91,35 @@ impl<'d> DateTimeFormat<'d> {
     ///
     /// assert_eq!(dtf.is_ok(), true);
     /// ```
+    ///
+    /// Dates are formatted in the calendar of the [`preferences::Bag`] of the options if there is
+    /// one, or else in the calendar of the `ca` keyword of the locale, such as
+    /// `th-u-ca-buddhist`. The Gregorian calendar is used by default and in place of unsupported
+    /// calendars.
+    ///
+    /// Options that request no field, such as a [`length::Bag`] without a date or a time length,
+    /// result in [`DateTimeFormatError::EmptyOptions`]. Options that request fields that cannot
+    /// be displayed together, such as a [`components::Bag`] with an hour and a second but no
+    /// minute, result in [`DateTimeFormatError::InvalidOptions`].
+    ///
+    /// [`preferences::Bag`]: crate::options::preferences::Bag
+    /// [`length::Bag`]: crate::options::length::Bag
+    /// [`components::Bag`]: crate::options::components::Bag
     pub fn try_new<
         T: Into<Locale>,
         D: DataProvider<'d, 'd, DateSymbolsV1Marker>
             + DataProvider<'d, 'd, DatePatternsV1Marker>
+            + DataProvider<'d, 'd, ErasV1Marker>
+            + DataProvider<'d, 'd, WeekDataV1Marker>
             + ?Sized,
     >(
         locale: T,
         data_provider: &D,
         options: &DateTimeFormatOptions,
     ) -> Result<Self, DateTimeFormatError> {
-        let locale = locale.into();
+        options.validate()?;
+        let mut locale = locale.into();
+        let calendar = options.resolve_calendar(&mut locale);
 
         let patterns_data: icu_provider::DataPayload<
             '_,
@@ -107,7 +130,7 @@ impl<'d> DateTimeFormat<'d> {
                 resource_path: ResourcePath {
                     key: provider::key::GREGORY_DATE_PATTERNS_V1,
                     options: ResourceOptions {
-                        variant: None,
+                        variant: calendar.patterns_variant(),
                         langid: Some(locale.clone().into()),
                     },
                 },
@@ -119,10 +142,10 @@ impl<'d> DateTimeFormat<'d> {
             .get_pattern_for_options(options)?
             .unwrap_or_default();
 
-        let requires_data = datetime::analyze_pattern(&pattern, false)
+        let required = datetime::analyze_pattern(&pattern, false)
             .map_err(|field| DateTimeFormatError::UnsupportedField(field.symbol))?;
 
-        let symbols_data = if requires_data {
+        let symbols_data = if required.symbols {
             Some(
                 data_provider
                     .load_payload(&DataRequest {
@@ -140,7 +163,49 @@ impl<'d> DateTimeFormat<'d> {
             None
         };
 
-        Ok(Self::new(locale, pattern, symbols_data))
+        let eras_data = if required.eras {
+            Some(
+                data_provider
+                    .load_payload(&DataRequest {
+                        resource_path: ResourcePath {
+                            key: provider::key::ERAS_V1,
+                            options: ResourceOptions {
+                                variant: Some(calendar.as_str().into()),
+                                langid: Some(locale.clone().into()),
+                            },
+                        },
+                    })?
+                    .take_payload()?,
+            )
+        } else {
+            None
+        };
+
+        let week_data = if required.week_data {
+            Some(
+                data_provider
+                    .load_payload(&DataRequest {
+                        resource_path: ResourcePath {
+                            key: provider::key::WEEK_DATA_V1,
+                            options: ResourceOptions {
+                                variant: None,
+                                langid: Some(locale.clone().into()),
+                            },
+                        },
+                    })?
+                    .take_payload()?,
+            )
+        } else {
+            None
+        };
+
+        Ok(Self::new(
+            locale,
+            pattern,
+            symbols_data,
+            eras_data,
+            week_data,
+        ))
     }
 
     /// Creates a new [`DateTimeFormat`] regardless of whether there are time-zone symbols in the pattern.
@@ -160,6 +225,8 @@ impl<'d> DateTimeFormat<'d> {
         locale: T,
         pattern: Pattern,
         symbols: Option<DataPayload<'d, 'd, DateSymbolsV1Marker>>,
+        eras: Option<DataPayload<'d, 'd, ErasV1Marker>>,
+        week_data: Option<DataPayload<'d, 'd, WeekDataV1Marker>>,
     ) -> Self {
         let locale = locale.into();
 
@@ -167,6 +234,8 @@ impl<'d> DateTimeFormat<'d> {
             locale,
             pattern,
             symbols,
+            eras,
+            week_data,
         }
     }
 
@@ -205,6 +274,8 @@ impl<'d> DateTimeFormat<'d> {
         FormattedDateTime {
             pattern: &self.pattern,
             symbols: self.symbols.as_ref().map(|s| s.get()),
+            eras: self.eras.as_ref().map(|e| e.get()),
+            week_data: self.week_data.as_ref().map(|w| w.get()),
             datetime: value,
             locale: &self.locale,
         }
@@ -244,6 +315,81 @@ impl<'d> DateTimeFormat<'d> {
         datetime::write_pattern(
             &self.pattern,
             self.symbols.as_ref().map(|s| s.get()),
+            self.eras.as_ref().map(|e| e.get()),
+            self.week_data.as_ref().map(|w| w.get()),
+            value,
+            &self.locale,
+            w,
+        )
+        .map_err(|_| std::fmt::Error)
+    }
+
+    /// Takes a mutable reference to anything that implements the [`PartsWrite`] trait
+    /// and a [`DateTimeInput`] implementer and populates the buffer with a formatted value,
+    /// reporting each field and literal of the pattern as a separate [`DateTimePart`](crate::DateTimePart).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu::datetime::{DateTimeFormat, DateTimePart, PartsWrite, options::length};
+    /// use icu::datetime::mock::datetime::MockDateTime;
+    /// use std::fmt;
+    ///
+    /// /// Wraps every field in brackets, leaving literals as they are.
+    /// struct Annotated(String);
+    ///
+    /// impl fmt::Write for Annotated {
+    ///     fn write_str(&mut self, s: &str) -> fmt::Result {
+    ///         self.0.write_str(s)
+    ///     }
+    /// }
+    ///
+    /// impl PartsWrite for Annotated {
+    ///     fn with_part<F>(&mut self, part: DateTimePart, f: F) -> fmt::Result
+    ///     where
+    ///         F: FnOnce(&mut Self) -> fmt::Result,
+    ///     {
+    ///         if part == DateTimePart::Literal {
+    ///             return f(self);
+    ///         }
+    ///         self.0.push('[');
+    ///         f(self)?;
+    ///         self.0.push(']');
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let locale: Locale = langid!("en").into();
+    /// let options = length::Bag {
+    ///     date: None,
+    ///     time: Some(length::Time::Short),
+    ///     ..Default::default()
+    /// };
+    /// let dtf = DateTimeFormat::try_new(locale, &provider, &options.into())
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// let datetime = MockDateTime::try_new(2020, 9, 1, 12, 34, 28)
+    ///     .expect("Failed to construct DateTime.");
+    ///
+    /// let mut sink = Annotated(String::new());
+    /// dtf.format_to_parts(&mut sink, &datetime)
+    ///     .expect("Failed to write to a buffer.");
+    ///
+    /// assert_eq!(sink.0, "[12]:[34] [PM]");
+    /// ```
+    pub fn format_to_parts(
+        &self,
+        w: &mut impl PartsWrite,
+        value: &impl DateTimeInput,
+    ) -> std::fmt::Result {
+        datetime::write_pattern_parts(
+            &self.pattern,
+            self.symbols.as_ref().map(|s| s.get()),
+            self.eras.as_ref().map(|e| e.get()),
+            self.week_data.as_ref().map(|w| w.get()),
             value,
             &self.locale,
             w,
@@ -251,6 +397,50 @@ impl<'d> DateTimeFormat<'d> {
         .map_err(|_| std::fmt::Error)
     }
 
+    /// Takes a [`DateTimeInput`] implementer and returns it formatted as a string, together with
+    /// the byte range of each field and literal of the pattern in that string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu::datetime::{DateTimeFormat, DateTimePart, options::length};
+    /// use icu::datetime::mock::datetime::MockDateTime;
+    /// # let provider = icu_testdata::get_provider();
+    /// # let locale: Locale = langid!("en").into();
+    /// let options = length::Bag {
+    ///     date: Some(length::Date::Medium),
+    ///     time: None,
+    ///     ..Default::default()
+    /// };
+    /// let dtf = DateTimeFormat::try_new(locale, &provider, &options.into())
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// let datetime: MockDateTime = "2020-09-01T12:34:28".parse()
+    ///     .expect("Failed to parse date.");
+    ///
+    /// let parts = dtf.format_parts(&datetime);
+    ///
+    /// assert_eq!(parts.as_str(), "Sep 1, 2020");
+    /// assert_eq!(
+    ///     parts.parts(),
+    ///     &[
+    ///         (DateTimePart::Month, 0..3),
+    ///         (DateTimePart::Literal, 3..4),
+    ///         (DateTimePart::Day, 4..5),
+    ///         (DateTimePart::Literal, 5..7),
+    ///         (DateTimePart::Year, 7..11),
+    ///     ]
+    /// );
+    /// ```
+    pub fn format_parts(&self, value: &impl DateTimeInput) -> DateTimeParts {
+        let mut parts = DateTimeParts::default();
+        self.format_to_parts(&mut parts, value)
+            .expect("Failed to write to a String.");
+        parts
+    }
+
     /// Takes a [`DateTimeInput`] implementer and returns it formatted as a string.
     ///
     /// # Examples
@@ -278,4 +468,30 @@ impl<'d> DateTimeFormat<'d> {
             .expect("Failed to write to a String.");
         s
     }
+
+    /// Returns the pattern that was selected for the options and locale of the formatter. Its
+    /// string form is the UTS 35 pattern, which is useful for debugging and for testing the
+    /// pattern selection without formatting a date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu::datetime::{DateTimeFormat, options::length};
+    /// # let provider = icu_testdata::get_provider();
+    /// let locale: Locale = langid!("en").into();
+    /// let options = length::Bag {
+    ///     date: Some(length::Date::Medium),
+    ///     time: Some(length::Time::Short),
+    ///     ..Default::default()
+    /// };
+    /// let dtf = DateTimeFormat::try_new(locale, &provider, &options.into())
+    ///     .expect("Failed to create DateTimeFormat instance.");
+    ///
+    /// assert_eq!(dtf.resolved_pattern().to_string(), "MMM d, y, h:mm a");
+    /// ```
+    pub fn resolved_pattern(&self) -> &Pattern {
+        &self.pattern
+    }
 }
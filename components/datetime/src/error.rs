@@ -30,9 +30,27 @@ pub enum DateTimeFormatError {
     /// An error originating from an unsupported field in a datetime format.
     #[displaydoc("Unsupported field: {0:?}")]
     UnsupportedField(FieldSymbol),
+    /// An error originating from options that request no field, such as a
+    /// [`length::Bag`](crate::options::length::Bag) without a date or a time length.
+    #[displaydoc("The options request no field")]
+    EmptyOptions,
+    /// An error originating from options that request fields that cannot be displayed together,
+    /// such as an hour and a second without a minute.
+    #[displaydoc("Invalid options: {0}")]
+    InvalidOptions(&'static str),
 }
 
-impl std::error::Error for DateTimeFormatError {}
+impl std::error::Error for DateTimeFormatError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Pattern(err) => Some(err),
+            Self::Format(err) => Some(err),
+            Self::DataProvider(err) => Some(err),
+            Self::Skeleton(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 impl From<pattern::Error> for DateTimeFormatError {
     fn from(e: pattern::Error) -> Self {
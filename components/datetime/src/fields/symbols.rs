@@ -33,6 +33,9 @@ pub enum FieldSymbol {
     Minute,
     Second(Second),
     TimeZone(TimeZone),
+    Week(Week),
+    // Listed last to keep the serialized indices of the other symbols stable.
+    Era,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
@@ -58,34 +61,37 @@ impl FieldSymbol {
     /// ordered most significant to least significant.
     fn get_canonical_order(&self) -> u8 {
         match self {
-            Self::Year(Year::Calendar) => 0,
-            Self::Year(Year::WeekOf) => 1,
-            Self::Month(Month::Format) => 2,
-            Self::Month(Month::StandAlone) => 3,
-            Self::Day(Day::DayOfMonth) => 4,
-            Self::Day(Day::DayOfYear) => 5,
-            Self::Day(Day::DayOfWeekInMonth) => 6,
-            Self::Day(Day::ModifiedJulianDay) => 7,
-            Self::Weekday(Weekday::Format) => 8,
-            Self::Weekday(Weekday::Local) => 9,
-            Self::Weekday(Weekday::StandAlone) => 10,
-            Self::DayPeriod(DayPeriod::AmPm) => 11,
-            Self::DayPeriod(DayPeriod::NoonMidnight) => 12,
-            Self::Hour(Hour::H11) => 13,
-            Self::Hour(Hour::H12) => 14,
-            Self::Hour(Hour::H23) => 15,
-            Self::Hour(Hour::H24) => 16,
-            Self::Minute => 17,
-            Self::Second(Second::Second) => 18,
-            Self::Second(Second::FractionalSecond) => 19,
-            Self::Second(Second::Millisecond) => 20,
-            Self::TimeZone(TimeZone::LowerZ) => 21,
-            Self::TimeZone(TimeZone::UpperZ) => 22,
-            Self::TimeZone(TimeZone::UpperO) => 23,
-            Self::TimeZone(TimeZone::LowerV) => 24,
-            Self::TimeZone(TimeZone::UpperV) => 25,
-            Self::TimeZone(TimeZone::LowerX) => 26,
-            Self::TimeZone(TimeZone::UpperX) => 27,
+            Self::Era => 0,
+            Self::Year(Year::Calendar) => 1,
+            Self::Year(Year::WeekOf) => 2,
+            Self::Month(Month::Format) => 3,
+            Self::Month(Month::StandAlone) => 4,
+            Self::Week(Week::WeekOfYear) => 5,
+            Self::Week(Week::WeekOfMonth) => 6,
+            Self::Day(Day::DayOfMonth) => 7,
+            Self::Day(Day::DayOfYear) => 8,
+            Self::Day(Day::DayOfWeekInMonth) => 9,
+            Self::Day(Day::ModifiedJulianDay) => 10,
+            Self::Weekday(Weekday::Format) => 11,
+            Self::Weekday(Weekday::Local) => 12,
+            Self::Weekday(Weekday::StandAlone) => 13,
+            Self::DayPeriod(DayPeriod::AmPm) => 14,
+            Self::DayPeriod(DayPeriod::NoonMidnight) => 15,
+            Self::Hour(Hour::H11) => 16,
+            Self::Hour(Hour::H12) => 17,
+            Self::Hour(Hour::H23) => 18,
+            Self::Hour(Hour::H24) => 19,
+            Self::Minute => 20,
+            Self::Second(Second::Second) => 21,
+            Self::Second(Second::FractionalSecond) => 22,
+            Self::Second(Second::Millisecond) => 23,
+            Self::TimeZone(TimeZone::LowerZ) => 24,
+            Self::TimeZone(TimeZone::UpperZ) => 25,
+            Self::TimeZone(TimeZone::UpperO) => 26,
+            Self::TimeZone(TimeZone::LowerV) => 27,
+            Self::TimeZone(TimeZone::UpperV) => 28,
+            Self::TimeZone(TimeZone::LowerX) => 29,
+            Self::TimeZone(TimeZone::UpperX) => 30,
         }
     }
 }
@@ -94,10 +100,12 @@ impl TryFrom<u8> for FieldSymbol {
     type Error = SymbolError;
     fn try_from(b: u8) -> Result<Self, Self::Error> {
         match b {
+            b'G' => Ok(Self::Era),
             b'm' => Ok(Self::Minute),
             _ => Year::try_from(b)
                 .map(Self::Year)
                 .or_else(|_| Month::try_from(b).map(Self::Month))
+                .or_else(|_| Week::try_from(b).map(Self::Week))
                 .or_else(|_| Day::try_from(b).map(Self::Day))
                 .or_else(|_| Weekday::try_from(b).map(Self::Weekday))
                 .or_else(|_| DayPeriod::try_from(b).map(Self::DayPeriod))
@@ -123,6 +131,7 @@ impl TryFrom<char> for FieldSymbol {
 impl From<FieldSymbol> for char {
     fn from(symbol: FieldSymbol) -> Self {
         match symbol {
+            FieldSymbol::Era => 'G',
             FieldSymbol::Year(year) => match year {
                 Year::Calendar => 'y',
                 Year::WeekOf => 'Y',
@@ -131,6 +140,10 @@ impl From<FieldSymbol> for char {
                 Month::Format => 'M',
                 Month::StandAlone => 'L',
             },
+            FieldSymbol::Week(week) => match week {
+                Week::WeekOfYear => 'w',
+                Week::WeekOfMonth => 'W',
+            },
             FieldSymbol::Day(day) => match day {
                 Day::DayOfMonth => 'd',
                 Day::DayOfYear => 'D',
@@ -256,6 +269,39 @@ impl From<Month> for FieldSymbol {
     }
 }
 
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub enum Week {
+    WeekOfYear,
+    WeekOfMonth,
+}
+
+impl LengthType for Week {
+    fn get_length_type(&self, _length: FieldLength) -> TextOrNumeric {
+        TextOrNumeric::Numeric
+    }
+}
+
+impl TryFrom<u8> for Week {
+    type Error = SymbolError;
+    fn try_from(b: u8) -> Result<Self, Self::Error> {
+        match b {
+            b'w' => Ok(Self::WeekOfYear),
+            b'W' => Ok(Self::WeekOfMonth),
+            b => Err(SymbolError::Unknown(b)),
+        }
+    }
+}
+
+impl From<Week> for FieldSymbol {
+    fn from(input: Week) -> Self {
+        Self::Week(input)
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 #[cfg_attr(
     feature = "provider_serde",
@@ -380,9 +426,11 @@ impl LengthType for Weekday {
     fn get_length_type(&self, length: FieldLength) -> TextOrNumeric {
         match self {
             Self::Format => TextOrNumeric::Text,
+            // The one- and two-letter forms of `e` and `c` are the number of the day in the
+            // local week.
             Self::Local | Self::StandAlone => match length {
-                FieldLength::One | FieldLength::TwoDigit => TextOrNumeric::Text,
-                _ => TextOrNumeric::Numeric,
+                FieldLength::One | FieldLength::TwoDigit => TextOrNumeric::Numeric,
+                _ => TextOrNumeric::Text,
             },
         }
     }
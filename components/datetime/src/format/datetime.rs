@@ -3,13 +3,19 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::arithmetic;
-use crate::date::{DateTimeInput, DateTimeInputWithLocale, LocalizedDateTimeInput};
+use crate::date::{
+    DateTimeInput, DateTimeInputWithLocale, FractionalSecond, LocalizedDateTimeInput,
+};
 use crate::error::DateTimeFormatError as Error;
-use crate::fields::{self, Field, FieldLength, FieldSymbol};
+use crate::fields::{self, Field, FieldLength, FieldSymbol, TextOrNumeric};
+use crate::format::parts::{DateTimePart, PartsWrite};
 use crate::pattern::{Pattern, PatternItem};
 use crate::provider;
+use crate::provider::eras::ErasV1;
 use crate::provider::helpers::DateTimeSymbols;
+use crate::provider::week_data::WeekDataV1;
 use icu_locid::Locale;
+use icu_provider::prelude::DataError;
 use std::fmt;
 use writeable::Writeable;
 
@@ -47,6 +53,8 @@ where
 {
     pub(crate) pattern: &'l Pattern,
     pub(crate) symbols: Option<&'l provider::gregory::DateSymbolsV1>,
+    pub(crate) eras: Option<&'l ErasV1>,
+    pub(crate) week_data: Option<&'l WeekDataV1>,
     pub(crate) datetime: &'l T,
     pub(crate) locale: &'l Locale,
 }
@@ -56,8 +64,16 @@ where
     T: DateTimeInput,
 {
     fn write_to<W: fmt::Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
-        write_pattern(self.pattern, self.symbols, self.datetime, self.locale, sink)
-            .map_err(|_| std::fmt::Error)
+        write_pattern(
+            self.pattern,
+            self.symbols,
+            self.eras,
+            self.week_data,
+            self.datetime,
+            self.locale,
+            sink,
+        )
+        .map_err(|_| std::fmt::Error)
     }
 
     // TODO(#489): Implement write_len
@@ -68,8 +84,16 @@ where
     T: DateTimeInput,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write_pattern(self.pattern, self.symbols, self.datetime, self.locale, f)
-            .map_err(|_| std::fmt::Error)
+        write_pattern(
+            self.pattern,
+            self.symbols,
+            self.eras,
+            self.week_data,
+            self.datetime,
+            self.locale,
+            f,
+        )
+        .map_err(|_| std::fmt::Error)
     }
 }
 
@@ -93,9 +117,37 @@ where
     }
 }
 
+/// Writes the digits of `fraction` that follow the decimal point, as many as the `length` of the
+/// field. As specified by UTS 35, further digits are truncated rather than rounded, which could
+/// otherwise carry into the seconds, and missing digits are filled with zeros.
+fn write_fraction<W>(
+    result: &mut W,
+    fraction: &FractionalSecond,
+    length: FieldLength,
+) -> Result<(), std::fmt::Error>
+where
+    W: fmt::Write + ?Sized,
+{
+    let digits = match fraction {
+        FractionalSecond::Millisecond(value) => format!("{:03}", value),
+        FractionalSecond::Microsecond(value) => format!("{:06}", value),
+        FractionalSecond::Nanosecond(value) => format!("{:09}", value),
+    };
+    for digit in digits
+        .chars()
+        .chain(std::iter::repeat('0'))
+        .take(length as usize)
+    {
+        result.write_char(digit)?;
+    }
+    Ok(())
+}
+
 pub fn write_pattern<T, W>(
     pattern: &crate::pattern::Pattern,
     symbols: Option<&provider::gregory::DateSymbolsV1>,
+    eras: Option<&ErasV1>,
+    week_data: Option<&WeekDataV1>,
     datetime: &T,
     locale: &Locale,
     w: &mut W,
@@ -104,25 +156,60 @@ where
     T: DateTimeInput,
     W: fmt::Write + ?Sized,
 {
-    let loc_datetime = DateTimeInputWithLocale::new(datetime, locale);
+    let loc_datetime = DateTimeInputWithLocale::new(datetime, week_data, locale);
     for item in pattern.items() {
         match item {
-            PatternItem::Field(field) => write_field(pattern, field, symbols, &loc_datetime, w)?,
+            PatternItem::Field(field) => {
+                write_field(pattern, field, symbols, eras, &loc_datetime, w)?
+            }
             PatternItem::Literal(l) => w.write_str(l)?,
         }
     }
     Ok(())
 }
 
+pub fn write_pattern_parts<T, W>(
+    pattern: &crate::pattern::Pattern,
+    symbols: Option<&provider::gregory::DateSymbolsV1>,
+    eras: Option<&ErasV1>,
+    week_data: Option<&WeekDataV1>,
+    datetime: &T,
+    locale: &Locale,
+    w: &mut W,
+) -> Result<(), Error>
+where
+    T: DateTimeInput,
+    W: PartsWrite,
+{
+    let loc_datetime = DateTimeInputWithLocale::new(datetime, week_data, locale);
+    for item in pattern.items() {
+        match item {
+            PatternItem::Field(field) => {
+                // `with_part` only carries `fmt::Error`, so keep the original error around.
+                let mut result = Ok(());
+                let written = w.with_part(DateTimePart::from(field.symbol), |w| {
+                    result = write_field(pattern, field, symbols, eras, &loc_datetime, w);
+                    result.as_ref().map(|_| ()).map_err(|_| fmt::Error)
+                });
+                result?;
+                written?
+            }
+            PatternItem::Literal(l) => w.with_part(DateTimePart::Literal, |w| w.write_str(l))?,
+        }
+    }
+    Ok(())
+}
+
 // This function assumes that the correct decision has been
-// made regarding availability of symbols in the caller.
+// made regarding availability of symbols and week data in the caller.
 //
-// When modifying the list of fields using symbols,
+// When modifying the list of fields using symbols, eras, or week data,
 // update the matching query in `analyze_pattern` function.
 pub(super) fn write_field<T, W>(
     pattern: &crate::pattern::Pattern,
     field: &fields::Field,
     symbols: Option<&crate::provider::gregory::DateSymbolsV1>,
+    eras: Option<&ErasV1>,
     datetime: &impl LocalizedDateTimeInput<T>,
     w: &mut W,
 ) -> Result<(), Error>
@@ -131,13 +218,25 @@ where
     W: fmt::Write + ?Sized,
 {
     match field.symbol {
-        FieldSymbol::Year(..) => format_number(
+        FieldSymbol::Era => {
+            let era = datetime.year().ok_or(Error::MissingInputField)?.era;
+            let code: &str = &era.0;
+            // Eras without a name in the data, such as those of a calendar that the locale
+            // does not translate, are written as their codes.
+            let symbol = eras
+                .ok_or(Error::DataProvider(DataError::MissingPayload))?
+                .get_symbol_for_era(field.length, code)
+                .unwrap_or(code);
+            w.write_str(symbol)?
+        }
+        FieldSymbol::Year(fields::Year::Calendar) => format_number(
             w,
-            datetime
-                .datetime()
-                .year()
-                .ok_or(Error::MissingInputField)?
-                .number as isize,
+            datetime.year().ok_or(Error::MissingInputField)?.number as isize,
+            field.length,
+        )?,
+        FieldSymbol::Year(fields::Year::WeekOf) => format_number(
+            w,
+            datetime.year_week().ok_or(Error::MissingInputField)?.number as isize,
             field.length,
         )?,
         FieldSymbol::Month(month) => match field.length {
@@ -151,6 +250,10 @@ where
                 field.length,
             )?,
             length => {
+                let month = match month {
+                    fields::Month::Format if is_standalone(pattern) => fields::Month::StandAlone,
+                    month => month,
+                };
                 let symbol = symbols
                     .expect("Expect symbols to be present")
                     .get_symbol_for_month(
@@ -166,25 +269,63 @@ where
                 w.write_str(symbol)?
             }
         },
+        FieldSymbol::Weekday(_) if field.get_length_type() == TextOrNumeric::Numeric => {
+            format_number(
+                w,
+                datetime
+                    .local_day_of_week()
+                    .ok_or(Error::MissingInputField)? as isize,
+                field.length,
+            )?
+        }
         FieldSymbol::Weekday(weekday) => {
             let dow = datetime
                 .datetime()
                 .iso_weekday()
                 .ok_or(Error::MissingInputField)?;
+            let weekday = match weekday {
+                fields::Weekday::Format | fields::Weekday::Local if is_standalone(pattern) => {
+                    fields::Weekday::StandAlone
+                }
+                weekday => weekday,
+            };
             let symbol = symbols
                 .expect("Expect symbols to be present")
                 .get_symbol_for_weekday(weekday, field.length, dow);
             w.write_str(symbol)?
         }
-        FieldSymbol::Day(..) => format_number(
-            w,
-            datetime
-                .datetime()
-                .day_of_month()
-                .ok_or(Error::MissingInputField)?
-                .0 as isize,
-            field.length,
-        )?,
+        FieldSymbol::Week(week) => {
+            let number = match week {
+                fields::Week::WeekOfYear => datetime.week_of_year().map(|w| w.0),
+                fields::Week::WeekOfMonth => datetime.week_of_month().map(|w| w.0),
+            };
+            format_number(
+                w,
+                number.ok_or(Error::MissingInputField)? as isize,
+                field.length,
+            )?
+        }
+        FieldSymbol::Day(day) => {
+            let number = match day {
+                fields::Day::DayOfMonth => datetime.datetime().day_of_month().map(|d| d.0),
+                fields::Day::DayOfYear => datetime
+                    .datetime()
+                    .day_of_year_info()
+                    .map(|info| info.day_of_year),
+                fields::Day::DayOfWeekInMonth => datetime
+                    .datetime()
+                    .day_of_month()
+                    .map(|d| arithmetic::day_of_week_in_month(d.0)),
+                fields::Day::ModifiedJulianDay => {
+                    return Err(Error::UnsupportedField(field.symbol))
+                }
+            };
+            format_number(
+                w,
+                number.ok_or(Error::MissingInputField)? as isize,
+                field.length,
+            )?
+        }
         FieldSymbol::Hour(hour) => {
             let h =
                 usize::from(datetime.datetime().hour().ok_or(Error::MissingInputField)?) as isize;
@@ -219,6 +360,13 @@ where
             ) as isize,
             field.length,
         )?,
+        FieldSymbol::Second(fields::Second::FractionalSecond) => {
+            let fraction = datetime
+                .datetime()
+                .fraction()
+                .ok_or(Error::MissingInputField)?;
+            write_fraction(w, &fraction, field.length)?
+        }
         FieldSymbol::Second(..) => format_number(
             w,
             usize::from(
@@ -249,41 +397,67 @@ where
     Ok(())
 }
 
-// This function determins whether the struct will load symbols data.
-// Keep it in sync with the `write_field` use of symbols.
-pub fn analyze_pattern(pattern: &Pattern, supports_time_zones: bool) -> Result<bool, &Field> {
+/// Returns whether `pattern` has a single field, such as "MMMM" or "EEEE", whose value is then
+/// displayed by itself rather than within a date. Month and weekday names in such patterns use
+/// their stand-alone forms, which differ from the format forms in languages such as Russian,
+/// where month names are inflected within a date.
+fn is_standalone(pattern: &Pattern) -> bool {
+    pattern
+        .items()
+        .iter()
+        .filter(|item| matches!(item, PatternItem::Field(_)))
+        .count()
+        == 1
+}
+
+/// The data that needs to be loaded to format a pattern.
+#[derive(Debug, Default, PartialEq)]
+pub struct RequiredData {
+    pub symbols: bool,
+    pub eras: bool,
+    pub week_data: bool,
+}
+
+// This function determins whether the struct will load symbols, eras, and week data.
+// Keep it in sync with the `write_field` use of symbols, eras, and week data.
+pub fn analyze_pattern(
+    pattern: &Pattern,
+    supports_time_zones: bool,
+) -> Result<RequiredData, &Field> {
     let fields = pattern.items().iter().filter_map(|p| match p {
         PatternItem::Field(field) => Some(field),
         _ => None,
     });
 
-    let mut requires_symbols = false;
+    let mut required = RequiredData::default();
 
     for field in fields {
-        if !requires_symbols {
-            requires_symbols = match field.symbol {
-                FieldSymbol::Month(_) => {
-                    !matches!(field.length, FieldLength::One | FieldLength::TwoDigit)
+        match field.symbol {
+            FieldSymbol::Month(_) => {
+                if !matches!(field.length, FieldLength::One | FieldLength::TwoDigit) {
+                    required.symbols = true;
                 }
-                FieldSymbol::Weekday(_) | FieldSymbol::DayPeriod(_) => true,
-                _ => false,
             }
-        }
-
-        if supports_time_zones {
-            if requires_symbols {
-                // If we require time zones, and symbols, we know all
-                // we need to return already.
-                break;
+            FieldSymbol::Weekday(_) => {
+                if field.get_length_type() == TextOrNumeric::Numeric {
+                    required.week_data = true;
+                } else {
+                    required.symbols = true;
+                }
+            }
+            FieldSymbol::DayPeriod(_) => required.symbols = true,
+            FieldSymbol::Era => required.eras = true,
+            FieldSymbol::Year(fields::Year::WeekOf) | FieldSymbol::Week(_) => {
+                required.week_data = true
             }
-        } else if matches!(field.symbol, FieldSymbol::TimeZone(_)) {
             // If we don't support time zones, and encountered a time zone
             // field, error out.
-            return Err(field);
+            FieldSymbol::TimeZone(_) if !supports_time_zones => return Err(field),
+            _ => {}
         }
     }
 
-    Ok(requires_symbols)
+    Ok(required)
 }
 
 #[cfg(test)]
@@ -317,6 +491,8 @@ mod tests {
         write_pattern(
             &pattern,
             Some(&data.get()),
+            None,
+            None,
             &datetime,
             &"und".parse().unwrap(),
             &mut sink,
@@ -325,6 +501,310 @@ mod tests {
         println!("{}", sink);
     }
 
+    #[test]
+    #[cfg(feature = "provider_serde")]
+    fn test_standalone_names() {
+        use crate::mock::datetime::MockDateTime;
+        use crate::provider::gregory::DateSymbolsV1Marker;
+        use icu_provider::prelude::*;
+
+        let provider = icu_testdata::get_provider();
+        let data: DataPayload<DateSymbolsV1Marker> = provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: provider::key::GREGORY_DATE_SYMBOLS_V1,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some("ru".parse().unwrap()),
+                    },
+                },
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        // A Sunday.
+        let datetime: MockDateTime = "2021-01-03T00:00:00".parse().unwrap();
+        let format = |pattern: &str| {
+            let pattern = crate::pattern::Pattern::from_bytes(pattern).unwrap();
+            let mut sink = String::new();
+            write_pattern(
+                &pattern,
+                Some(data.get()),
+                None,
+                None,
+                &datetime,
+                &"ru".parse().unwrap(),
+                &mut sink,
+            )
+            .unwrap();
+            sink
+        };
+
+        // Russian month names are inflected within a date.
+        assert_eq!(format("d MMMM"), "3 января");
+        assert_eq!(format("LLLL y"), "январь 2021");
+        assert_eq!(format("MMMM"), "январь");
+        assert_eq!(format("LLLL"), "январь");
+
+        assert_eq!(format("EEEEE, d"), "вс, 3");
+        assert_eq!(format("ccccc, d"), "В, 3");
+        assert_eq!(format("EEEEE"), "В");
+        // Stand-alone names that are missing from the data fall back to the format names.
+        assert_eq!(format("cccc"), "воскресенье");
+    }
+
+    #[test]
+    fn test_write_pattern_parts() {
+        use crate::mock::datetime::MockDateTime;
+
+        struct Parts(Vec<(DateTimePart, String)>);
+
+        impl fmt::Write for Parts {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0
+                    .last_mut()
+                    .expect("Text is only written inside a part")
+                    .1
+                    .push_str(s);
+                Ok(())
+            }
+        }
+
+        impl PartsWrite for Parts {
+            fn with_part<F>(&mut self, part: DateTimePart, f: F) -> fmt::Result
+            where
+                F: FnOnce(&mut Self) -> fmt::Result,
+            {
+                self.0.push((part, String::new()));
+                f(self)
+            }
+        }
+
+        let pattern = crate::pattern::Pattern::from_bytes("y-MM-dd HH:mm").unwrap();
+        let datetime = MockDateTime::try_new(2020, 8, 1, 9, 4, 28).unwrap();
+        let mut sink = Parts(vec![]);
+        write_pattern_parts(
+            &pattern,
+            None,
+            None,
+            None,
+            &datetime,
+            &"und".parse().unwrap(),
+            &mut sink,
+        )
+        .unwrap();
+
+        let expected = vec![
+            (DateTimePart::Year, "2020"),
+            (DateTimePart::Literal, "-"),
+            (DateTimePart::Month, "09"),
+            (DateTimePart::Literal, "-"),
+            (DateTimePart::Day, "02"),
+            (DateTimePart::Literal, " "),
+            (DateTimePart::Hour, "09"),
+            (DateTimePart::Literal, ":"),
+            (DateTimePart::Minute, "04"),
+        ];
+        let actual: Vec<_> = sink.0.iter().map(|(p, s)| (*p, s.as_str())).collect();
+        assert_eq!(actual, expected);
+
+        // Errors of the fields are preserved.
+        let pattern = crate::pattern::Pattern::from_bytes("HH z").unwrap();
+        let mut sink = Parts(vec![]);
+        assert!(matches!(
+            write_pattern_parts(
+                &pattern,
+                None,
+                None,
+                None,
+                &datetime,
+                &"und".parse().unwrap(),
+                &mut sink,
+            ),
+            Err(Error::UnsupportedField(_))
+        ));
+    }
+
+    #[test]
+    fn test_week_fields() {
+        use crate::date::IsoWeekday;
+        use crate::mock::datetime::MockDateTime;
+
+        let iso = WeekDataV1 {
+            first_weekday: IsoWeekday::Monday,
+            min_week_days: 4,
+        };
+        let us = WeekDataV1 {
+            first_weekday: IsoWeekday::Sunday,
+            min_week_days: 1,
+        };
+        let format = |pattern: &str, week_data: &WeekDataV1, datetime: &MockDateTime| {
+            let pattern = crate::pattern::Pattern::from_bytes(pattern).unwrap();
+            let mut sink = String::new();
+            write_pattern(
+                &pattern,
+                None,
+                None,
+                Some(week_data),
+                datetime,
+                &"und".parse().unwrap(),
+                &mut sink,
+            )
+            .unwrap();
+            sink
+        };
+
+        let new_years_day: MockDateTime = "2021-01-01T00:00:00".parse().unwrap();
+        assert_eq!(format("Y-'W'ww", &iso, &new_years_day), "2020-W53");
+        assert_eq!(format("Y-'W'ww", &us, &new_years_day), "2021-W01");
+        assert_eq!(format("y-'W'ww", &iso, &new_years_day), "2021-W53");
+
+        let datetime: MockDateTime = "2020-01-01T00:00:00".parse().unwrap();
+        assert_eq!(format("Y-'W'ww", &iso, &datetime), "2020-W01");
+
+        // 2021-05-01 is a Saturday, so the 10th is the second Monday of the month.
+        let datetime: MockDateTime = "2021-05-10T00:00:00".parse().unwrap();
+        assert_eq!(format("W F D", &iso, &datetime), "2 2 130");
+        assert_eq!(format("W F D", &us, &datetime), "3 2 130");
+        // The numeric local weekday counts from the first day of the locale's week.
+        assert_eq!(format("e ee c", &iso, &datetime), "1 01 1");
+        assert_eq!(format("e ee c", &us, &datetime), "2 02 2");
+    }
+
+    #[test]
+    fn test_eras() {
+        use crate::mock::datetime::MockDateTime;
+
+        let mut eras = ErasV1::default();
+        eras.abbr.insert("be".into(), "BE".into());
+        eras.names.insert("reiwa".into(), "Reiwa".into());
+        let format = |pattern: &str, locale: &str, datetime: &MockDateTime| {
+            let pattern = crate::pattern::Pattern::from_bytes(pattern).unwrap();
+            let mut sink = String::new();
+            write_pattern(
+                &pattern,
+                None,
+                Some(&eras),
+                None,
+                datetime,
+                &locale.parse().unwrap(),
+                &mut sink,
+            )
+            .unwrap();
+            sink
+        };
+
+        let datetime: MockDateTime = "2020-09-01T00:00:00".parse().unwrap();
+        assert_eq!(format("G y", "und-u-ca-buddhist", &datetime), "BE 2563");
+        assert_eq!(format("GGGG y", "und-u-ca-japanese", &datetime), "Reiwa 2");
+        // Wide names fall back to abbreviated names.
+        assert_eq!(format("GGGG y", "und-u-ca-buddhist", &datetime), "BE 2563");
+        // Eras without a name are written as their codes.
+        assert_eq!(format("G y", "und-u-ca-japanese", &datetime), "reiwa 2");
+        assert_eq!(format("G y", "und", &datetime), "ce 2020");
+
+        let datetime: MockDateTime = "2019-04-30T00:00:00".parse().unwrap();
+        assert_eq!(format("G y", "und-u-ca-japanese", &datetime), "heisei 31");
+    }
+
+    #[test]
+    fn test_analyze_pattern() {
+        let required = |pattern: &str| {
+            analyze_pattern(
+                &crate::pattern::Pattern::from_bytes(pattern).unwrap(),
+                false,
+            )
+            .unwrap()
+        };
+        assert_eq!(required("y-MM-dd"), RequiredData::default());
+        assert_eq!(
+            required("EEE w"),
+            RequiredData {
+                symbols: true,
+                eras: false,
+                week_data: true,
+            }
+        );
+        assert_eq!(
+            required("Y"),
+            RequiredData {
+                symbols: false,
+                eras: false,
+                week_data: true,
+            }
+        );
+        assert_eq!(
+            required("e"),
+            RequiredData {
+                symbols: false,
+                eras: false,
+                week_data: true,
+            }
+        );
+        assert_eq!(
+            required("eee"),
+            RequiredData {
+                symbols: true,
+                eras: false,
+                week_data: false,
+            }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "provider_serde")]
+    fn test_format_parts_cover_string() {
+        use crate::mock::datetime::MockDateTime;
+        use crate::options::length;
+        use crate::DateTimeFormat;
+
+        let provider = icu_testdata::get_provider();
+        let options = length::Bag {
+            date: Some(length::Date::Full),
+            time: Some(length::Time::Medium),
+            ..Default::default()
+        };
+        let datetime: MockDateTime = "2020-09-01T12:34:28".parse().unwrap();
+
+        for locale in &["en", "ja", "ru", "ar"] {
+            let dtf = DateTimeFormat::try_new(
+                locale.parse::<icu_locid::Locale>().unwrap(),
+                &provider,
+                &options.clone().into(),
+            )
+            .unwrap();
+            let parts = dtf.format_parts(&datetime);
+            assert_eq!(
+                parts.as_str(),
+                dtf.format_to_string(&datetime),
+                "{}",
+                locale
+            );
+
+            // The parts are non-empty, contiguous, and cover the whole string.
+            let mut end = 0;
+            for (part, range) in parts.parts() {
+                assert_eq!(range.start, end, "{}: {:?}", locale, part);
+                assert!(range.start < range.end, "{}: {:?}", locale, part);
+                end = range.end;
+            }
+            assert_eq!(end, parts.as_str().len(), "{}", locale);
+
+            for part in &[
+                DateTimePart::Year,
+                DateTimePart::Hour,
+                DateTimePart::Literal,
+            ] {
+                assert!(
+                    parts.parts().iter().any(|(p, _)| p == part),
+                    "{}: {:?}",
+                    locale,
+                    part
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_format_number() {
         let values = &[2, 20, 201, 2017, 20173];
@@ -345,4 +825,73 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_write_fraction() {
+        let samples = &[
+            (FractionalSecond::Millisecond(123), FieldLength::One, "1"),
+            (
+                FractionalSecond::Millisecond(123),
+                FieldLength::Abbreviated,
+                "123",
+            ),
+            (
+                FractionalSecond::Millisecond(5),
+                FieldLength::Abbreviated,
+                "005",
+            ),
+            (
+                FractionalSecond::Millisecond(120),
+                FieldLength::Six,
+                "120000",
+            ),
+            (
+                FractionalSecond::Microsecond(999_600),
+                FieldLength::Abbreviated,
+                "999",
+            ),
+            (
+                FractionalSecond::Nanosecond(987_654_321),
+                FieldLength::Six,
+                "987654",
+            ),
+            (FractionalSecond::Nanosecond(1), FieldLength::TwoDigit, "00"),
+        ];
+        for (fraction, length, expected) in samples {
+            let mut s = String::new();
+            write_fraction(&mut s, fraction, *length).unwrap();
+            assert_eq!(s, *expected, "{:?} {:?}", fraction, length);
+        }
+    }
+
+    #[test]
+    fn test_write_pattern_fraction() {
+        use crate::mock::datetime::MockDateTime;
+
+        let pattern = crate::pattern::Pattern::from_bytes("HH:mm:ss.SSS").unwrap();
+        let write = |datetime: &MockDateTime| {
+            let mut sink = String::new();
+            write_pattern(
+                &pattern,
+                None,
+                None,
+                None,
+                datetime,
+                &"und".parse().unwrap(),
+                &mut sink,
+            )
+            .map(|_| sink)
+        };
+
+        let datetime: MockDateTime = "2020-08-01T12:35:00.123".parse().unwrap();
+        assert_eq!(write(&datetime).unwrap(), "12:35:00.123");
+        // The fraction is truncated, so the seconds are never rounded up.
+        let datetime: MockDateTime = "2020-08-01T12:35:59.99996".parse().unwrap();
+        assert_eq!(write(&datetime).unwrap(), "12:35:59.999");
+        let datetime: MockDateTime = "2020-08-01T12:35:00.5".parse().unwrap();
+        assert_eq!(write(&datetime).unwrap(), "12:35:00.500");
+
+        let datetime = MockDateTime::try_new(2020, 7, 0, 12, 35, 0).unwrap();
+        assert!(matches!(write(&datetime), Err(Error::MissingInputField)));
+    }
 }
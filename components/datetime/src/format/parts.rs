@@ -0,0 +1,109 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::fields::FieldSymbol;
+use std::fmt;
+use std::ops::Range;
+
+/// The kind of a part of a formatted datetime, as reported to
+/// [`PartsWrite::with_part`].
+///
+/// Every pattern field maps to the part of the same name, while the text between fields
+/// is reported as [`DateTimePart::Literal`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTimePart {
+    /// Literal text of the pattern, such as separators and quoted text.
+    Literal,
+    Era,
+    Year,
+    Month,
+    Week,
+    Day,
+    Weekday,
+    DayPeriod,
+    Hour,
+    Minute,
+    Second,
+    TimeZone,
+}
+
+impl From<FieldSymbol> for DateTimePart {
+    fn from(symbol: FieldSymbol) -> Self {
+        match symbol {
+            FieldSymbol::Era => Self::Era,
+            FieldSymbol::Year(_) => Self::Year,
+            FieldSymbol::Month(_) => Self::Month,
+            FieldSymbol::Week(_) => Self::Week,
+            FieldSymbol::Day(_) => Self::Day,
+            FieldSymbol::Weekday(_) => Self::Weekday,
+            FieldSymbol::DayPeriod(_) => Self::DayPeriod,
+            FieldSymbol::Hour(_) => Self::Hour,
+            FieldSymbol::Minute => Self::Minute,
+            FieldSymbol::Second(_) => Self::Second,
+            FieldSymbol::TimeZone(_) => Self::TimeZone,
+        }
+    }
+}
+
+/// A [`Write`](fmt::Write) sink that is notified of the parts of a formatted datetime.
+///
+/// See [`DateTimeFormat::format_to_parts`](crate::DateTimeFormat::format_to_parts).
+pub trait PartsWrite: fmt::Write {
+    /// Writes a single part of the formatted value. `f` writes the text of the part
+    /// to the sink passed to it.
+    fn with_part<F>(&mut self, part: DateTimePart, f: F) -> fmt::Result
+    where
+        F: FnOnce(&mut Self) -> fmt::Result;
+}
+
+/// A formatted datetime together with the byte ranges of its parts, as returned by
+/// [`DateTimeFormat::format_parts`](crate::DateTimeFormat::format_parts).
+///
+/// The parts are in the order of the pattern, do not overlap, and together cover the whole
+/// string, including the literal text between fields.
+///
+/// [`DateTimeParts`] is itself a [`PartsWrite`] sink, so it can also collect the parts of any
+/// other formatter that writes parts.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DateTimeParts {
+    string: String,
+    parts: Vec<(DateTimePart, Range<usize>)>,
+}
+
+impl DateTimeParts {
+    /// Returns the formatted string.
+    pub fn as_str(&self) -> &str {
+        &self.string
+    }
+
+    /// Returns the parts of the formatted string, each with its byte range in
+    /// [`DateTimeParts::as_str()`].
+    pub fn parts(&self) -> &[(DateTimePart, Range<usize>)] {
+        &self.parts
+    }
+}
+
+impl fmt::Display for DateTimeParts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.string)
+    }
+}
+
+impl fmt::Write for DateTimeParts {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.string.write_str(s)
+    }
+}
+
+impl PartsWrite for DateTimeParts {
+    fn with_part<F>(&mut self, part: DateTimePart, f: F) -> fmt::Result
+    where
+        F: FnOnce(&mut Self) -> fmt::Result,
+    {
+        let start = self.string.len();
+        f(self)?;
+        self.parts.push((part, start..self.string.len()));
+        Ok(())
+    }
+}
@@ -9,7 +9,7 @@ use crate::fields::{self, FieldSymbol};
 use crate::pattern::{Error as PatternError, PatternItem};
 use crate::{
     date::TimeZoneInput,
-    time_zone::{IsoFormat, IsoMinutes, IsoSeconds, TimeZoneFormat},
+    time_zone::{IsoFormat, IsoMinutes, IsoSeconds, NameFormat, TimeZoneFormat},
 };
 use writeable::Writeable;
 
@@ -41,7 +41,6 @@ where
     }
 }
 
-// TODO(#622) Make this public once TimeZoneFormat is public.
 pub(crate) fn write_pattern<T, W>(
     time_zone_format: &TimeZoneFormat,
     time_zone: &T,
@@ -76,12 +75,16 @@ where
     if let FieldSymbol::TimeZone(zone_symbol) = field.symbol {
         match zone_symbol {
             fields::TimeZone::LowerZ => match u8::from(field.length) {
-                1..=3 => time_zone_format
-                    .short_specific_non_location_format(w, time_zone)
-                    .or_else(|_| time_zone_format.localized_gmt_format(w, time_zone))?,
-                4 => time_zone_format
-                    .long_specific_non_location_format(w, time_zone)
-                    .or_else(|_| time_zone_format.localized_gmt_format(w, time_zone))?,
+                1..=3 => time_zone_format.format_with_fallback(
+                    w,
+                    time_zone,
+                    &[NameFormat::ShortSpecificNonLocation],
+                )?,
+                4 => time_zone_format.format_with_fallback(
+                    w,
+                    time_zone,
+                    &[NameFormat::LongSpecificNonLocation],
+                )?,
                 _ => {
                     return Err(Error::Pattern(PatternError::FieldLengthInvalid(
                         FieldSymbol::TimeZone(zone_symbol),
@@ -111,7 +114,8 @@ where
                 }
             },
             fields::TimeZone::UpperO => match u8::from(field.length) {
-                1..=4 => time_zone_format.localized_gmt_format(w, time_zone)?,
+                1 => time_zone_format.short_localized_gmt_format(w, time_zone)?,
+                4 => time_zone_format.localized_gmt_format(w, time_zone)?,
                 _ => {
                     return Err(Error::Pattern(PatternError::FieldLengthInvalid(
                         FieldSymbol::TimeZone(zone_symbol),
@@ -119,14 +123,22 @@ where
                 }
             },
             fields::TimeZone::LowerV => match u8::from(field.length) {
-                1 => time_zone_format
-                    .short_generic_non_location_format(w, time_zone)
-                    .or_else(|_| time_zone_format.generic_location_format(w, time_zone))
-                    .or_else(|_| time_zone_format.localized_gmt_format(w, time_zone))?,
-                4 => time_zone_format
-                    .long_generic_non_location_format(w, time_zone)
-                    .or_else(|_| time_zone_format.generic_location_format(w, time_zone))
-                    .or_else(|_| time_zone_format.localized_gmt_format(w, time_zone))?,
+                1 => time_zone_format.format_with_fallback(
+                    w,
+                    time_zone,
+                    &[
+                        NameFormat::ShortGenericNonLocation,
+                        NameFormat::GenericLocation,
+                    ],
+                )?,
+                4 => time_zone_format.format_with_fallback(
+                    w,
+                    time_zone,
+                    &[
+                        NameFormat::LongGenericNonLocation,
+                        NameFormat::GenericLocation,
+                    ],
+                )?,
                 _ => {
                     return Err(Error::Pattern(PatternError::FieldLengthInvalid(
                         FieldSymbol::TimeZone(zone_symbol),
@@ -136,12 +148,15 @@ where
             fields::TimeZone::UpperV => match u8::from(field.length) {
                 1 => todo!("#606 (BCP-47 identifiers)"),
                 2 => todo!("#606 (BCP-47 identifiers)"),
-                3 => time_zone_format
-                    .exemplar_city(w, time_zone)
-                    .or_else(|_| time_zone_format.unknown_city(w))?,
-                4 => time_zone_format
-                    .generic_location_format(w, time_zone)
-                    .or_else(|_| time_zone_format.localized_gmt_format(w, time_zone))?,
+                3 => match time_zone_format.exemplar_city(time_zone) {
+                    Some(city) => w.write_str(city)?,
+                    None => time_zone_format.unknown_city(w)?,
+                },
+                4 => time_zone_format.format_with_fallback(
+                    w,
+                    time_zone,
+                    &[NameFormat::GenericLocation],
+                )?,
                 _ => {
                     return Err(Error::Pattern(PatternError::FieldLengthInvalid(
                         FieldSymbol::TimeZone(zone_symbol),
@@ -152,35 +167,35 @@ where
                 1 => time_zone_format.iso8601_format(
                     w,
                     time_zone,
-                    IsoFormat::UtcBasic,
+                    IsoFormat::Basic,
                     IsoMinutes::Optional,
                     IsoSeconds::Never,
                 )?,
                 2 => time_zone_format.iso8601_format(
                     w,
                     time_zone,
-                    IsoFormat::UtcBasic,
+                    IsoFormat::Basic,
                     IsoMinutes::Required,
                     IsoSeconds::Never,
                 )?,
                 3 => time_zone_format.iso8601_format(
                     w,
                     time_zone,
-                    IsoFormat::UtcExtended,
+                    IsoFormat::Extended,
                     IsoMinutes::Required,
                     IsoSeconds::Never,
                 )?,
                 4 => time_zone_format.iso8601_format(
                     w,
                     time_zone,
-                    IsoFormat::UtcBasic,
+                    IsoFormat::Basic,
                     IsoMinutes::Required,
                     IsoSeconds::Optional,
                 )?,
                 5 => time_zone_format.iso8601_format(
                     w,
                     time_zone,
-                    IsoFormat::UtcExtended,
+                    IsoFormat::Extended,
                     IsoMinutes::Required,
                     IsoSeconds::Optional,
                 )?,
@@ -194,35 +209,35 @@ where
                 1 => time_zone_format.iso8601_format(
                     w,
                     time_zone,
-                    IsoFormat::Basic,
+                    IsoFormat::UtcBasic,
                     IsoMinutes::Optional,
                     IsoSeconds::Never,
                 )?,
                 2 => time_zone_format.iso8601_format(
                     w,
                     time_zone,
-                    IsoFormat::Basic,
+                    IsoFormat::UtcBasic,
                     IsoMinutes::Required,
                     IsoSeconds::Never,
                 )?,
                 3 => time_zone_format.iso8601_format(
                     w,
                     time_zone,
-                    IsoFormat::Extended,
+                    IsoFormat::UtcExtended,
                     IsoMinutes::Required,
                     IsoSeconds::Never,
                 )?,
                 4 => time_zone_format.iso8601_format(
                     w,
                     time_zone,
-                    IsoFormat::Basic,
+                    IsoFormat::UtcBasic,
                     IsoMinutes::Required,
                     IsoSeconds::Optional,
                 )?,
                 5 => time_zone_format.iso8601_format(
                     w,
                     time_zone,
-                    IsoFormat::Extended,
+                    IsoFormat::UtcExtended,
                     IsoMinutes::Required,
                     IsoSeconds::Optional,
                 )?,
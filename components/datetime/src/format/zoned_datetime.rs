@@ -54,7 +54,12 @@ where
 {
     let locale = &zoned_datetime_format.datetime_format.locale;
     let pattern = &zoned_datetime_format.datetime_format.pattern;
-    let loc_datetime = ZonedDateTimeInputWithLocale::new(zoned_datetime, locale);
+    let week_data = zoned_datetime_format
+        .datetime_format
+        .week_data
+        .as_ref()
+        .map(|w| w.get());
+    let loc_datetime = ZonedDateTimeInputWithLocale::new(zoned_datetime, week_data, locale);
     for item in pattern.items() {
         match item {
             PatternItem::Field(field) => {
@@ -82,6 +87,11 @@ where
         .symbols
         .as_ref()
         .map(|s| s.get());
+    let eras = zoned_datetime_format
+        .datetime_format
+        .eras
+        .as_ref()
+        .map(|e| e.get());
 
     match field.symbol {
         FieldSymbol::TimeZone(_time_zone) => time_zone::write_field(
@@ -90,7 +100,7 @@ where
             loc_datetime.datetime(),
             w,
         )?,
-        _ => datetime::write_field(pattern, field, symbols, loc_datetime, w)?,
+        _ => datetime::write_field(pattern, field, symbols, eras, loc_datetime, w)?,
     }
     Ok(())
 }
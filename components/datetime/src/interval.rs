@@ -0,0 +1,570 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::{
+    date::DateTimeInput,
+    datetime::DateTimeFormat,
+    fields::{Field, FieldSymbol},
+    format::datetime,
+    options::DateTimeFormatOptions,
+    pattern::{Pattern, PatternItem},
+    provider::{
+        self,
+        eras::ErasV1Marker,
+        gregory::{
+            intervals::IntervalPatternsV1, patterns::PatternV1, DateIntervalPatternsV1Marker,
+            DatePatternsV1Marker, DateSymbolsV1Marker,
+        },
+        week_data::WeekDataV1Marker,
+    },
+    skeleton::{self, Skeleton},
+    DateTimeFormatError,
+};
+use icu_locid::Locale;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::fmt;
+
+/// The largest calendar field in which the start and the end of an interval differ, from the
+/// largest to the smallest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum GreatestDifference {
+    Year,
+    Month,
+    Day,
+    /// The times are in different halves of the day, which is a larger difference than the hour
+    /// for patterns that have a day period.
+    AmPm,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl GreatestDifference {
+    /// Returns the greatest difference between `start` and `end`, or [`None`] if they are equal
+    /// down to the second.
+    fn between(start: &impl DateTimeInput, end: &impl DateTimeInput) -> Option<Self> {
+        if start.year().map(|y| y.number) != end.year().map(|y| y.number) {
+            Some(Self::Year)
+        } else if start.month().map(|m| m.number) != end.month().map(|m| m.number) {
+            Some(Self::Month)
+        } else if start.day_of_month().map(|d| d.0) != end.day_of_month().map(|d| d.0) {
+            Some(Self::Day)
+        } else {
+            let start_hour = start.hour().map(u8::from);
+            let end_hour = end.hour().map(u8::from);
+            if start_hour.map(|h| h < 12) != end_hour.map(|h| h < 12) {
+                Some(Self::AmPm)
+            } else if start_hour != end_hour {
+                Some(Self::Hour)
+            } else if start.minute().map(u8::from) != end.minute().map(u8::from) {
+                Some(Self::Minute)
+            } else if start.second().map(u8::from) != end.second().map(u8::from) {
+                Some(Self::Second)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Returns the calendar field that changes the value of a field with the given symbol.
+    fn for_symbol(symbol: FieldSymbol) -> Option<Self> {
+        match symbol {
+            FieldSymbol::Era | FieldSymbol::Year(_) => Some(Self::Year),
+            FieldSymbol::Month(_) => Some(Self::Month),
+            FieldSymbol::Day(_) | FieldSymbol::Weekday(_) | FieldSymbol::Week(_) => Some(Self::Day),
+            FieldSymbol::DayPeriod(_) => Some(Self::AmPm),
+            FieldSymbol::Hour(_) => Some(Self::Hour),
+            FieldSymbol::Minute => Some(Self::Minute),
+            FieldSymbol::Second(_) => Some(Self::Second),
+            FieldSymbol::TimeZone(_) => None,
+        }
+    }
+}
+
+/// An interval pattern, split into the part that is formatted with the start of the interval
+/// and the part that is formatted with its end.
+struct IntervalPattern {
+    start: Pattern,
+    end: Pattern,
+}
+
+impl IntervalPattern {
+    /// Splits `pattern` before its first repeated field. For example, `MMM d – d, y` is split
+    /// into `MMM d – ` and `d, y`.
+    fn split(pattern: &Pattern) -> Self {
+        let items = pattern.items();
+        let mut symbols: Vec<FieldSymbol> = Vec::new();
+        let mut split = items.len();
+        for (index, item) in items.iter().enumerate() {
+            if let PatternItem::Field(field) = item {
+                if symbols
+                    .iter()
+                    .any(|symbol| skeleton::is_same_field_type(*symbol, field.symbol))
+                {
+                    split = index;
+                    break;
+                }
+                symbols.push(field.symbol);
+            }
+        }
+        Self {
+            start: Pattern::from(items[..split].to_vec()),
+            end: Pattern::from(items[split..].to_vec()),
+        }
+    }
+}
+
+/// [`DateTimeIntervalFormat`] formats the interval between two datetimes, such as
+/// "Sep 12 – 14, 2020", where the fields that the two datetimes have in common are only
+/// written once.
+///
+/// The datetimes are formatted with the pattern that [`DateTimeFormat`] uses for the same
+/// [`DateTimeFormatOptions`]. The interval pattern is chosen from the interval patterns of the
+/// locale for the skeleton with the same fields as that pattern, according to the largest
+/// calendar field in which the datetimes differ. The pattern is split before its first repeated
+/// field: the part before is formatted with the start of the interval, and the rest with its
+/// end.
+///
+/// If the two datetimes are equal in all of the fields of the pattern, the start is formatted as
+/// a single datetime. If there is no interval pattern for the fields of the pattern, or for the
+/// largest difference between the datetimes, both are formatted in full and joined by the
+/// fallback pattern of the locale, such as `{0} – {1}`. The same applies to patterns that
+/// combine a date and a time, as the locale data only has interval patterns for dates or for
+/// times.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::Locale;
+/// use icu::locid::macros::langid;
+/// use icu::datetime::{DateTimeIntervalFormat, options::length};
+/// use icu::datetime::mock::datetime::MockDateTime;
+///
+/// let provider = icu_testdata::get_provider();
+/// let locale: Locale = langid!("en").into();
+///
+/// let options = length::Bag {
+///     date: Some(length::Date::Medium),
+///     time: None,
+///     ..Default::default()
+/// };
+/// let dtif = DateTimeIntervalFormat::try_new(locale, &provider, &options.into())
+///     .expect("Failed to create DateTimeIntervalFormat instance.");
+///
+/// let start: MockDateTime = "2020-09-12T12:35:00".parse()
+///     .expect("Failed to parse date.");
+/// let end: MockDateTime = "2020-09-14T09:00:00".parse()
+///     .expect("Failed to parse date.");
+///
+/// assert_eq!(dtif.format_to_string(&start, &end), "Sep 12 – 14, 2020");
+/// ```
+pub struct DateTimeIntervalFormat<'d> {
+    datetime_format: DateTimeFormat<'d>,
+    /// The interval patterns for the pattern of `datetime_format`, by the greatest difference
+    /// that they apply to.
+    interval_patterns: Vec<(GreatestDifference, IntervalPattern)>,
+    fallback: Cow<'static, str>,
+}
+
+impl<'d> DateTimeIntervalFormat<'d> {
+    /// Constructor that takes a selected [`Locale`], reference to a [`DataProvider`] and
+    /// a list of options, then collects all data necessary to format intervals between date and
+    /// time values into the given locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu::datetime::{DateTimeIntervalFormat, DateTimeFormatOptions};
+    /// use icu_provider::inv::InvariantDataProvider;
+    ///
+    /// let locale: Locale = langid!("en").into();
+    ///
+    /// let provider = InvariantDataProvider;
+    ///
+    /// let options = DateTimeFormatOptions::default();
+    ///
+    /// let dtif = DateTimeIntervalFormat::try_new(locale, &provider, &options);
+    ///
+    /// assert_eq!(dtif.is_ok(), true);
+    /// ```
+    pub fn try_new<
+        T: Into<Locale>,
+        D: DataProvider<'d, 'd, DateSymbolsV1Marker>
+            + DataProvider<'d, 'd, DatePatternsV1Marker>
+            + DataProvider<'d, 'd, ErasV1Marker>
+            + DataProvider<'d, 'd, WeekDataV1Marker>
+            + DataProvider<'d, 'd, DateIntervalPatternsV1Marker>
+            + ?Sized,
+    >(
+        locale: T,
+        data_provider: &D,
+        options: &DateTimeFormatOptions,
+    ) -> Result<Self, DateTimeFormatError> {
+        let locale = locale.into();
+
+        let interval_data: DataPayload<'_, '_, DateIntervalPatternsV1Marker> = data_provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: provider::key::GREGORY_INTERVAL_PATTERNS_V1,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(locale.clone().into()),
+                    },
+                },
+            })?
+            .take_payload()?;
+
+        let datetime_format = DateTimeFormat::try_new(locale, data_provider, options)?;
+
+        let interval_patterns = match Self::get_interval_patterns(
+            &interval_data.get().skeletons.0,
+            &datetime_format.pattern,
+        ) {
+            Some(patterns) => [
+                (GreatestDifference::Year, &patterns.year),
+                (GreatestDifference::Month, &patterns.month),
+                (GreatestDifference::Day, &patterns.day),
+                (GreatestDifference::AmPm, &patterns.am_pm),
+                (GreatestDifference::Hour, &patterns.hour),
+                (GreatestDifference::Minute, &patterns.minute),
+            ]
+            .iter()
+            .filter_map(|(difference, pattern)| {
+                let pattern = Self::adjust_interval_pattern(pattern.as_ref()?, &datetime_format)?;
+                Some((*difference, IntervalPattern::split(&pattern)))
+            })
+            .collect(),
+            None => Vec::new(),
+        };
+
+        Ok(Self {
+            datetime_format,
+            interval_patterns,
+            fallback: interval_data.get().fallback.clone(),
+        })
+    }
+
+    /// Returns the interval patterns of the skeleton with the same fields as `pattern`.
+    fn get_interval_patterns<'a>(
+        skeletons: &'a litemap::LiteMap<
+            provider::gregory::patterns::SkeletonV1,
+            IntervalPatternsV1,
+        >,
+        pattern: &Pattern,
+    ) -> Option<&'a IntervalPatternsV1> {
+        let available: Vec<Skeleton> = skeletons
+            .iter_keys()
+            .map(|skeleton| skeleton.0.clone())
+            .collect();
+        let index =
+            skeleton::best_match_with_same_fields(&Skeleton::from_pattern(pattern), &available)?;
+        skeletons.iter_values().nth(index)
+    }
+
+    /// Adjusts the lengths of the fields of an interval pattern to those of the pattern of
+    /// `datetime_format`. Returns [`None`] if the interval pattern needs data that was not loaded
+    /// for the pattern of `datetime_format`.
+    fn adjust_interval_pattern(
+        interval_pattern: &PatternV1,
+        datetime_format: &DateTimeFormat,
+    ) -> Option<Pattern> {
+        let fields: Vec<Field> = datetime_format
+            .pattern
+            .items()
+            .iter()
+            .filter_map(|item| match item {
+                PatternItem::Field(field) => Some(*field),
+                PatternItem::Literal(_) => None,
+            })
+            .collect();
+        let pattern = skeleton::adjust_field_lengths(&interval_pattern.0, &fields).pattern;
+        let required = datetime::analyze_pattern(&pattern, false).ok()?;
+        if (required.symbols && datetime_format.symbols.is_none())
+            || (required.eras && datetime_format.eras.is_none())
+            || (required.week_data && datetime_format.week_data.is_none())
+        {
+            return None;
+        }
+        Some(pattern)
+    }
+
+    /// Takes a mutable reference to anything that implements [`Write`](std::fmt::Write) trait
+    /// and two [`DateTimeInput`] implementers, and populates the buffer with the formatted
+    /// interval between them. The interval is formatted from the earlier to the later datetime,
+    /// regardless of the order of the arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu::datetime::{DateTimeIntervalFormat, options::length};
+    /// use icu::datetime::mock::datetime::MockDateTime;
+    /// # let provider = icu_testdata::get_provider();
+    /// # let locale: Locale = langid!("en").into();
+    /// # let options = length::Bag {
+    /// #     date: None,
+    /// #     time: Some(length::Time::Short),
+    /// #     ..Default::default()
+    /// # };
+    /// let dtif = DateTimeIntervalFormat::try_new(locale, &provider, &options.into())
+    ///     .expect("Failed to create DateTimeIntervalFormat instance.");
+    ///
+    /// let start = MockDateTime::try_new(2020, 9, 1, 10, 30, 0)
+    ///     .expect("Failed to construct DateTime.");
+    /// let end = MockDateTime::try_new(2020, 9, 1, 11, 45, 0)
+    ///     .expect("Failed to construct DateTime.");
+    ///
+    /// let mut buffer = String::new();
+    /// dtif.format_to_write(&mut buffer, &end, &start)
+    ///     .expect("Failed to write to a buffer.");
+    ///
+    /// assert_eq!(buffer, "10:30 – 11:45 AM");
+    /// ```
+    pub fn format_to_write<T: DateTimeInput>(
+        &self,
+        w: &mut impl std::fmt::Write,
+        start: &T,
+        end: &T,
+    ) -> std::fmt::Result {
+        let (start, end) = if Self::sort_key(end) < Self::sort_key(start) {
+            (end, start)
+        } else {
+            (start, end)
+        };
+
+        let difference = match GreatestDifference::between(start, end) {
+            Some(difference) => difference,
+            None => return self.datetime_format.format_to_write(w, start),
+        };
+
+        // The datetimes are formatted as a single one if they only differ in calendar fields
+        // that are smaller than the smallest field of the pattern.
+        let smallest_field = self
+            .datetime_format
+            .pattern
+            .items()
+            .iter()
+            .filter_map(|item| match item {
+                PatternItem::Field(field) => GreatestDifference::for_symbol(field.symbol),
+                PatternItem::Literal(_) => None,
+            })
+            .max();
+        if smallest_field.map_or(true, |smallest_field| difference > smallest_field) {
+            return self.datetime_format.format_to_write(w, start);
+        }
+
+        let find = |difference| {
+            self.interval_patterns
+                .iter()
+                .find(|(d, _)| *d == difference)
+                .map(|(_, pattern)| pattern)
+        };
+        let interval_pattern = match difference {
+            // Patterns without a day period have no pattern for a different half of the day.
+            GreatestDifference::AmPm => {
+                find(GreatestDifference::AmPm).or_else(|| find(GreatestDifference::Hour))
+            }
+            difference => find(difference),
+        };
+
+        match interval_pattern {
+            Some(interval_pattern) => {
+                self.write_pattern(w, &interval_pattern.start, start)?;
+                self.write_pattern(w, &interval_pattern.end, end)
+            }
+            None => self.write_fallback(w, start, end),
+        }
+    }
+
+    /// Takes two [`DateTimeInput`] implementers and returns the interval between them formatted
+    /// as a string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::Locale;
+    /// use icu::locid::macros::langid;
+    /// use icu::datetime::{DateTimeIntervalFormat, mock::datetime::MockDateTime, skeleton::Skeleton};
+    /// use std::convert::TryFrom;
+    /// # let provider = icu_testdata::get_provider();
+    /// # let locale: Locale = langid!("en").into();
+    /// let options = Skeleton::try_from("yMMMd")
+    ///     .expect("Failed to parse skeleton.")
+    ///     .into();
+    ///
+    /// let dtif = DateTimeIntervalFormat::try_new(locale, &provider, &options)
+    ///     .expect("Failed to create DateTimeIntervalFormat instance.");
+    ///
+    /// let start: MockDateTime = "2020-09-12T12:35:00".parse()
+    ///     .expect("Failed to parse date.");
+    /// let end: MockDateTime = "2021-01-05T12:35:00".parse()
+    ///     .expect("Failed to parse date.");
+    ///
+    /// assert_eq!(dtif.format_to_string(&start, &end), "Sep 12, 2020 – Jan 5, 2021");
+    /// ```
+    pub fn format_to_string<T: DateTimeInput>(&self, start: &T, end: &T) -> String {
+        let mut s = String::new();
+        self.format_to_write(&mut s, start, end)
+            .expect("Failed to write to a String.");
+        s
+    }
+
+    fn write_pattern(
+        &self,
+        w: &mut impl std::fmt::Write,
+        pattern: &Pattern,
+        value: &impl DateTimeInput,
+    ) -> fmt::Result {
+        datetime::write_pattern(
+            pattern,
+            self.datetime_format.symbols.as_ref().map(|s| s.get()),
+            self.datetime_format.eras.as_ref().map(|e| e.get()),
+            self.datetime_format.week_data.as_ref().map(|w| w.get()),
+            value,
+            &self.datetime_format.locale,
+            w,
+        )
+        .map_err(|_| std::fmt::Error)
+    }
+
+    /// Writes both datetimes in full, in place of the `{0}` and `{1}` placeholders of the
+    /// fallback pattern.
+    fn write_fallback(
+        &self,
+        w: &mut impl std::fmt::Write,
+        start: &impl DateTimeInput,
+        end: &impl DateTimeInput,
+    ) -> fmt::Result {
+        let mut rest = &*self.fallback;
+        while let Some(index) = rest.find('{') {
+            w.write_str(&rest[..index])?;
+            rest = &rest[index..];
+            if rest.starts_with("{0}") {
+                self.datetime_format.format_to_write(w, start)?;
+            } else if rest.starts_with("{1}") {
+                self.datetime_format.format_to_write(w, end)?;
+            } else {
+                w.write_char('{')?;
+                rest = &rest[1..];
+                continue;
+            }
+            rest = &rest[3..];
+        }
+        w.write_str(rest)
+    }
+
+    /// Returns a key that orders datetimes chronologically.
+    fn sort_key(value: &impl DateTimeInput) -> impl Ord {
+        (
+            value.year().map(|y| y.number),
+            value.month().map(|m| m.number),
+            value.day_of_month().map(|d| d.0),
+            value.hour().map(u8::from),
+            value.minute().map(u8::from),
+            value.second().map(u8::from),
+        )
+    }
+}
+
+#[cfg(all(test, feature = "provider_serde"))]
+mod test {
+    use super::*;
+    use crate::mock::datetime::MockDateTime;
+    use crate::options::length;
+    use icu_locid_macros::langid;
+
+    fn format(options: &length::Bag, start: &str, end: &str) -> String {
+        let provider = icu_testdata::get_provider();
+        let locale: Locale = langid!("en").into();
+        let dtif =
+            DateTimeIntervalFormat::try_new(locale, &provider, &options.clone().into()).unwrap();
+        let start: MockDateTime = start.parse().unwrap();
+        let end: MockDateTime = end.parse().unwrap();
+        dtif.format_to_string(&start, &end)
+    }
+
+    #[test]
+    fn test_greatest_difference() {
+        let date = length::Bag {
+            date: Some(length::Date::Medium),
+            time: None,
+            ..Default::default()
+        };
+        assert_eq!(
+            format(&date, "2020-09-12T12:35:00", "2021-01-05T12:35:00"),
+            "Sep 12, 2020 – Jan 5, 2021"
+        );
+        assert_eq!(
+            format(&date, "2020-09-12T12:35:00", "2020-10-03T12:35:00"),
+            "Sep 12 – Oct 3, 2020"
+        );
+        assert_eq!(
+            format(&date, "2020-09-12T12:35:00", "2020-09-14T12:35:00"),
+            "Sep 12 – 14, 2020"
+        );
+
+        let time = length::Bag {
+            date: None,
+            time: Some(length::Time::Short),
+            ..Default::default()
+        };
+        assert_eq!(
+            format(&time, "2020-09-12T10:30:00", "2020-09-12T14:15:00"),
+            "10:30 AM – 2:15 PM"
+        );
+        assert_eq!(
+            format(&time, "2020-09-12T10:30:00", "2020-09-12T10:45:00"),
+            "10:30 – 10:45 AM"
+        );
+    }
+
+    #[test]
+    fn test_single_datetime() {
+        let date = length::Bag {
+            date: Some(length::Date::Medium),
+            time: None,
+            ..Default::default()
+        };
+        assert_eq!(
+            format(&date, "2020-09-12T12:35:00", "2020-09-12T12:35:00"),
+            "Sep 12, 2020"
+        );
+        // The times are not part of the pattern.
+        assert_eq!(
+            format(&date, "2020-09-12T08:00:00", "2020-09-12T20:00:00"),
+            "Sep 12, 2020"
+        );
+    }
+
+    #[test]
+    fn test_reversed() {
+        let date = length::Bag {
+            date: Some(length::Date::Medium),
+            time: None,
+            ..Default::default()
+        };
+        assert_eq!(
+            format(&date, "2020-09-14T12:35:00", "2020-09-12T12:35:00"),
+            "Sep 12 – 14, 2020"
+        );
+    }
+
+    #[test]
+    fn test_fallback() {
+        // There are no interval patterns for a date combined with a time.
+        let datetime = length::Bag {
+            date: Some(length::Date::Medium),
+            time: Some(length::Time::Short),
+            ..Default::default()
+        };
+        assert_eq!(
+            format(&datetime, "2020-09-12T12:35:00", "2020-09-14T09:00:00"),
+            "Sep 12, 2020, 12:35 PM – Sep 14, 2020, 9:00 AM"
+        );
+    }
+}
@@ -57,8 +57,28 @@
 //! let dtf = DateTimeFormat::try_new(locale, &provider, &options);
 //! ```
 //!
-//! At the moment, the crate provides only options using the [`Length`] bag, but in the future,
-//! we expect to add more ways to customize the output, like skeletons, and components.
+//! The output can also be customized with a [`Skeleton`], which is matched against the skeletons
+//! available for the locale.
+//!
+//! ```
+//! use icu::locid::Locale;
+//! use icu::locid::macros::langid;
+//! use icu::datetime::{DateTimeFormat, mock::datetime::MockDateTime, skeleton::Skeleton};
+//! use std::convert::TryFrom;
+//! # let provider = icu_testdata::get_provider();
+//! # let locale: Locale = langid!("en").into();
+//! let options = Skeleton::try_from("yMMMMd")
+//!     .expect("Failed to parse skeleton.")
+//!     .into();
+//!
+//! let dtf = DateTimeFormat::try_new(locale, &provider, &options)
+//!     .expect("Failed to create DateTimeFormat instance.");
+//!
+//! let date: MockDateTime = "2020-09-12T12:35:00".parse()
+//!     .expect("Failed to parse date.");
+//!
+//! assert_eq!(dtf.format(&date).to_string(), "September 12, 2020");
+//! ```
 //!
 //! *Notice:* Rust at the moment does not have a canonical way to represent date and time. We are introducing
 //! [`MockDateTime`] as an example of the data necessary for ICU [`DateTimeFormat`] to work, and
@@ -67,14 +87,15 @@
 //!
 //! [`DataProvider`]: icu_provider::DataProvider
 //! [`ICU4X`]: ../icu/index.html
-//! [`Length`]: options::length
+//! [`Skeleton`]: skeleton::Skeleton
 //! [`MockDateTime`]: mock::datetime::MockDateTime
-mod arithmetic;
+pub mod arithmetic;
 pub mod date;
 pub mod datetime;
 mod error;
 mod fields;
 mod format;
+pub mod interval;
 pub mod mock;
 pub mod options;
 #[doc(hidden)]
@@ -82,14 +103,16 @@ pub mod pattern;
 pub mod provider;
 #[doc(hidden)]
 pub mod skeleton;
-// TODO(#622) make the time_zone module public once TimeZoneFormat is public.
-pub(crate) mod time_zone;
+pub mod time_zone;
 pub mod zoned_datetime;
 
 pub use datetime::DateTimeFormat;
 pub use error::DateTimeFormatError;
 pub use format::datetime::FormattedDateTime;
+pub use format::parts::{DateTimePart, DateTimeParts, PartsWrite};
+pub use format::time_zone::FormattedTimeZone;
 pub use format::zoned_datetime::FormattedZonedDateTime;
+pub use interval::DateTimeIntervalFormat;
 pub use options::DateTimeFormatOptions;
+pub use time_zone::TimeZoneFormat;
 pub use zoned_datetime::ZonedDateTimeFormat;
-// TODO(#622) re-export TimeZoneFormat once it is made public.
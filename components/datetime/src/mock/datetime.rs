@@ -4,7 +4,9 @@
 
 use crate::arithmetic;
 use crate::date::*;
-use std::convert::TryInto;
+use crate::mock::duration::MockDuration;
+use std::convert::{TryFrom, TryInto};
+use std::ops::Range;
 use std::str::FromStr;
 use tinystr::tinystr8;
 
@@ -30,7 +32,7 @@ use tinystr::tinystr8;
 ///     .expect("Failed to parse a datetime.");
 /// ```
 /// [`DateTimeFormat`]: crate::datetime::DateTimeFormat
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct MockDateTime {
     /// ISO-8601 year (proleptic Gregorian).
     pub year: i32,
@@ -49,6 +51,9 @@ pub struct MockDateTime {
 
     /// 0-based second.
     pub second: IsoSecond,
+
+    /// Fraction of the second, if any.
+    pub fraction: Option<FractionalSecond>,
 }
 
 impl MockDateTime {
@@ -68,6 +73,7 @@ impl MockDateTime {
             hour,
             minute,
             second,
+            fraction: None,
         }
     }
 
@@ -99,8 +105,246 @@ impl MockDateTime {
             hour: hour.try_into()?,
             minute: minute.try_into()?,
             second: second.try_into()?,
+            fraction: None,
         })
     }
+
+    /// Creates a [`MockDateTime`] from a Unix timestamp: the number of seconds since
+    /// 1970-01-01T00:00:00 UTC, which is negative for earlier datetimes.
+    ///
+    /// The fields are those of the proleptic Gregorian calendar at UTC. Leap seconds are not
+    /// taken into account: every day has 86400 seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::mock::datetime::MockDateTime;
+    ///
+    /// let dt = MockDateTime::from_unix_seconds(1234567890);
+    /// assert_eq!(dt, "2009-02-13T23:31:30".parse().unwrap());
+    ///
+    /// let dt = MockDateTime::from_unix_seconds(-1);
+    /// assert_eq!(dt, "1969-12-31T23:59:59".parse().unwrap());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the year does not fit in an [`i32`].
+    pub fn from_unix_seconds(seconds: i64) -> Self {
+        const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+        let (year, month, day) =
+            arithmetic::iso_epoch_days_to_date(seconds.div_euclid(SECONDS_PER_DAY));
+        let seconds = seconds.rem_euclid(SECONDS_PER_DAY);
+        Self::new(
+            year,
+            month,
+            day,
+            IsoHour::new_unchecked((seconds / 3600) as u8),
+            IsoMinute::new_unchecked((seconds / 60 % 60) as u8),
+            IsoSecond::new_unchecked((seconds % 60) as u8),
+        )
+    }
+
+    /// Creates a [`MockDateTime`] from the number of milliseconds since 1970-01-01T00:00:00
+    /// UTC, which is negative for earlier datetimes. The milliseconds are kept as the
+    /// [`fraction`](Self::fraction) of the second.
+    ///
+    /// See [`from_unix_seconds()`](Self::from_unix_seconds()) for how the fields are computed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::mock::datetime::MockDateTime;
+    ///
+    /// let dt = MockDateTime::from_unix_millis(-1);
+    /// assert_eq!(dt, "1969-12-31T23:59:59.999".parse().unwrap());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the year does not fit in an [`i32`].
+    pub fn from_unix_millis(millis: i64) -> Self {
+        let mut result = Self::from_unix_seconds(millis.div_euclid(1000));
+        result.fraction = Some(FractionalSecond::Millisecond(millis.rem_euclid(1000) as u16));
+        result
+    }
+
+    /// Moves this [`MockDateTime`] by a number of days, which can be negative. The time of the
+    /// day is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::mock::datetime::MockDateTime;
+    ///
+    /// let mut dt: MockDateTime = "2020-02-28T13:21:00".parse()
+    ///     .expect("Failed to parse a datetime.");
+    ///
+    /// dt.add_days(1);
+    /// assert_eq!(dt, "2020-02-29T13:21:00".parse().unwrap());
+    /// dt.add_days(1);
+    /// assert_eq!(dt, "2020-03-01T13:21:00".parse().unwrap());
+    /// dt.add_days(-366);
+    /// assert_eq!(dt, "2019-03-01T13:21:00".parse().unwrap());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting year does not fit in an [`i32`].
+    pub fn add_days(&mut self, days: i64) {
+        let days = arithmetic::iso_date_to_epoch_days(self.year, self.month, self.day) + days;
+        let (year, month, day) = arithmetic::iso_epoch_days_to_date(days);
+        self.year = year;
+        self.month = month;
+        self.day = day;
+    }
+
+    /// Moves this [`MockDateTime`] by a number of seconds, which can be negative. The date
+    /// changes when the time passes midnight.
+    ///
+    /// Leap seconds are not taken into account: every day has 86400 seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::mock::datetime::MockDateTime;
+    ///
+    /// let mut dt: MockDateTime = "2020-12-31T23:59:30".parse()
+    ///     .expect("Failed to parse a datetime.");
+    ///
+    /// dt.add_seconds(45);
+    /// assert_eq!(dt, "2021-01-01T00:00:15".parse().unwrap());
+    /// dt.add_seconds(-3600);
+    /// assert_eq!(dt, "2020-12-31T23:00:15".parse().unwrap());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting year does not fit in an [`i32`].
+    pub fn add_seconds(&mut self, seconds: i64) {
+        const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+        let seconds = i64::from(u8::from(self.hour)) * 3600
+            + i64::from(u8::from(self.minute)) * 60
+            + i64::from(u8::from(self.second))
+            + seconds;
+        self.add_days(seconds.div_euclid(SECONDS_PER_DAY));
+        let seconds = seconds.rem_euclid(SECONDS_PER_DAY);
+        self.hour = IsoHour::new_unchecked((seconds / 3600) as u8);
+        self.minute = IsoMinute::new_unchecked((seconds / 60 % 60) as u8);
+        self.second = IsoSecond::new_unchecked((seconds % 60) as u8);
+    }
+
+    /// Moves this [`MockDateTime`] by a number of hours, which can be negative. See
+    /// [`add_seconds()`](Self::add_seconds()).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::mock::datetime::MockDateTime;
+    ///
+    /// let mut dt: MockDateTime = "2020-02-28T13:21:00".parse()
+    ///     .expect("Failed to parse a datetime.");
+    ///
+    /// dt.add_hours(36);
+    /// assert_eq!(dt, "2020-03-01T01:21:00".parse().unwrap());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting year does not fit in an [`i32`].
+    pub fn add_hours(&mut self, hours: i64) {
+        self.add_seconds(hours * 3600);
+    }
+
+    /// Moves this [`MockDateTime`] by a number of minutes, which can be negative. See
+    /// [`add_seconds()`](Self::add_seconds()).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::mock::datetime::MockDateTime;
+    ///
+    /// let mut dt: MockDateTime = "2020-10-14T13:21:00".parse()
+    ///     .expect("Failed to parse a datetime.");
+    ///
+    /// dt.add_minutes(90);
+    /// assert_eq!(dt, "2020-10-14T14:51:00".parse().unwrap());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting year does not fit in an [`i32`].
+    pub fn add_minutes(&mut self, minutes: i64) {
+        self.add_seconds(minutes * 60);
+    }
+
+    /// Moves this [`MockDateTime`] by a number of months, which can be negative. The day of
+    /// the month and the time of the day are unchanged.
+    ///
+    /// If the day does not exist in the resulting month, it is clamped to the last day of that
+    /// month. For example, January 31 plus one month is February 28, or February 29 in a leap
+    /// year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::mock::datetime::MockDateTime;
+    ///
+    /// let mut dt: MockDateTime = "2020-01-31T13:21:00".parse()
+    ///     .expect("Failed to parse a datetime.");
+    ///
+    /// dt.add_months(1);
+    /// assert_eq!(dt, "2020-02-29T13:21:00".parse().unwrap());
+    /// dt.add_months(-14);
+    /// assert_eq!(dt, "2018-12-29T13:21:00".parse().unwrap());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting year does not fit in an [`i32`].
+    pub fn add_months(&mut self, months: i64) {
+        let months = i64::from(self.year) * 12 + i64::from(self.month) + months;
+        self.year = i32::try_from(months.div_euclid(12)).expect("Year out of range.");
+        self.month = months.rem_euclid(12) as u32;
+        self.day = self
+            .day
+            .min(arithmetic::iso_days_in_month(self.year, self.month) - 1);
+    }
+
+    /// Moves this [`MockDateTime`] by a [`MockDuration`].
+    ///
+    /// The fields of the duration are applied from the largest to the smallest unit: first
+    /// the years and months with [`add_months()`](Self::add_months()), which clamps the day to
+    /// the last day of the resulting month, then the days with
+    /// [`add_days()`](Self::add_days()), and then the time with
+    /// [`add_seconds()`](Self::add_seconds()).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::mock::datetime::MockDateTime;
+    /// use icu::datetime::mock::duration::MockDuration;
+    ///
+    /// let mut dt: MockDateTime = "2019-01-31T22:00:00".parse()
+    ///     .expect("Failed to parse a datetime.");
+    ///
+    /// dt.add_duration(&MockDuration {
+    ///     years: 1,
+    ///     months: 1,
+    ///     hours: 3,
+    ///     ..Default::default()
+    /// });
+    /// assert_eq!(dt, "2020-03-01T01:00:00".parse().unwrap());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting year does not fit in an [`i32`].
+    pub fn add_duration(&mut self, duration: &MockDuration) {
+        self.add_months(duration.years * 12 + duration.months);
+        self.add_days(duration.days);
+        self.add_seconds(duration.hours * 3600 + duration.minutes * 60 + duration.seconds);
+    }
 }
 
 impl FromStr for MockDateTime {
@@ -109,32 +353,122 @@ impl FromStr for MockDateTime {
     /// Parse a [`MockDateTime`] from a string.
     ///
     /// This utility is for easily creating dates, not a complete robust solution. The
-    /// string must take a specific form of the ISO-8601 format: `YYYY-MM-DDThh:mm:ss`.
+    /// string must take a specific form of the ISO-8601 format: `YYYY-MM-DDThh:mm:ss`,
+    /// optionally followed by a fraction of the second of up to nine digits.
     ///
     /// ```
+    /// use icu::datetime::date::FractionalSecond;
     /// use icu::datetime::mock::datetime::MockDateTime;
     ///
     /// let date: MockDateTime = "2020-10-14T13:21:00".parse()
     ///     .expect("Failed to parse a datetime.");
+    /// assert_eq!(date.fraction, None);
+    ///
+    /// let date: MockDateTime = "2020-10-14T13:21:00.12".parse()
+    ///     .expect("Failed to parse a datetime.");
+    /// assert_eq!(date.fraction, Some(FractionalSecond::Millisecond(120)));
+    /// ```
+    ///
+    /// Components that don't fit the format or are out of range are reported in the error.
+    ///
+    /// ```
+    /// use icu::datetime::mock::datetime::MockDateTime;
+    ///
+    /// let err = "2020-13-40".parse::<MockDateTime>()
+    ///     .expect_err("Month 13 is out of range.");
+    /// assert_eq!(err.to_string(), "month=13 is out of range, must be between 1-12");
+    ///
+    /// let err = "2020-10-14T1x:21:00".parse::<MockDateTime>()
+    ///     .expect_err("Hour is not a number.");
+    /// assert_eq!(err.to_string(), "Failed to parse \"1x\", expected YYYY-MM-DDThh:mm:ss");
     /// ```
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let year: i32 = input[0..4].parse()?;
-        let month: u32 = input[5..7].parse()?;
-        let day: u32 = input[8..10].parse()?;
-        let hour: IsoHour = input[11..13].parse()?;
-        let minute: IsoMinute = input[14..16].parse()?;
-        let second: IsoSecond = input[17..19].parse()?;
+        let year: i32 = input
+            .get(0..4)
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| invalid_format(input, 0..4))?;
+        let month = parse_component(input, 5..7, "month", 1, 12)?;
+        let day = parse_component(input, 8..10, "day", 1, 31)?;
+        let hour = parse_component(input, 11..13, "hour", 0, 24)?;
+        let minute = parse_component(input, 14..16, "minute", 0, 60)?;
+        let second = parse_component(input, 17..19, "second", 0, 61)?;
+        let fraction = parse_fraction(input)?;
         Ok(Self {
             year,
             month: month - 1,
             day: day - 1,
-            hour,
-            minute,
-            second,
+            hour: IsoHour::new_unchecked(hour as u8),
+            minute: IsoMinute::new_unchecked(minute as u8),
+            second: IsoSecond::new_unchecked(second as u8),
+            fraction,
         })
     }
 }
 
+const EXPECTED_FORMAT: &str = "YYYY-MM-DDThh:mm:ss";
+
+fn invalid_format(input: &str, range: Range<usize>) -> DateTimeError {
+    DateTimeError::InvalidFormat {
+        input: input.get(range).unwrap_or(input).to_string(),
+        expected: EXPECTED_FORMAT,
+    }
+}
+
+/// Parses the unsigned number at `range` of `input`, and checks that it is between `min`
+/// and `max`, inclusive.
+fn parse_component(
+    input: &str,
+    range: Range<usize>,
+    field: &'static str,
+    min: u32,
+    max: u32,
+) -> Result<u32, DateTimeError> {
+    let value: u32 = input
+        .get(range.clone())
+        .filter(|s| s.bytes().all(|b| b.is_ascii_digit()))
+        .and_then(|s| s.parse().ok())
+        .ok_or_else(|| invalid_format(input, range))?;
+    if value < min || value > max {
+        return Err(DateTimeError::OutOfRange {
+            field,
+            value,
+            min,
+            max,
+        });
+    }
+    Ok(value)
+}
+
+/// Parses the fraction of the second that follows a `.` after the seconds of `input`, if any.
+/// The fraction has the precision of the next of milli-, micro-, or nanoseconds that fits its
+/// digits.
+fn parse_fraction(input: &str) -> Result<Option<FractionalSecond>, DateTimeError> {
+    let digits = match input.get(19..) {
+        Some(rest) if rest.starts_with('.') => {
+            let rest = &rest[1..];
+            &rest[..rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or_else(|| rest.len())]
+        }
+        _ => return Ok(None),
+    };
+    let range = 20..20 + digits.len();
+    let precision = match digits.len() {
+        1..=3 => 3,
+        4..=6 => 6,
+        7..=9 => 9,
+        _ => return Err(invalid_format(input, range)),
+    };
+    let value = format!("{:0<width$}", digits, width = precision)
+        .parse()
+        .map_err(|_| invalid_format(input, range))?;
+    Ok(Some(match precision {
+        3 => FractionalSecond::Millisecond(value as u16),
+        6 => FractionalSecond::Microsecond(value),
+        _ => FractionalSecond::Nanosecond(value),
+    }))
+}
+
 impl DateInput for MockDateTime {
     fn year(&self) -> Option<Year> {
         Some(arithmetic::iso_year_to_gregorian(self.year))
@@ -153,15 +487,17 @@ impl DateInput for MockDateTime {
     }
 
     fn iso_weekday(&self) -> Option<IsoWeekday> {
-        Some(arithmetic::iso_date_to_weekday(
-            self.year,
-            self.month as usize,
-            self.day as usize,
-        ))
+        Some(arithmetic::iso_weekday(self.year, self.month, self.day))
     }
 
     fn day_of_year_info(&self) -> Option<DayOfYearInfo> {
-        unimplemented!()
+        Some(DayOfYearInfo {
+            day_of_year: arithmetic::iso_day_of_year(self.year, self.month as usize, self.day),
+            days_in_year: arithmetic::iso_days_in_year(self.year),
+            days_in_prev_year: arithmetic::iso_days_in_year(self.year - 1),
+            prev_year: arithmetic::iso_year_to_gregorian(self.year - 1),
+            next_year: arithmetic::iso_year_to_gregorian(self.year + 1),
+        })
     }
 }
 
@@ -179,6 +515,82 @@ impl IsoTimeInput for MockDateTime {
     }
 
     fn fraction(&self) -> Option<FractionalSecond> {
-        None
+        self.fraction.clone()
+    }
+}
+
+#[test]
+fn test_from_unix_seconds() {
+    for (seconds, expected) in &[
+        (0, "1970-01-01T00:00:00"),
+        (-1, "1969-12-31T23:59:59"),
+        (-86400, "1969-12-31T00:00:00"),
+        (951782399, "2000-02-28T23:59:59"),
+        (951782400, "2000-02-29T00:00:00"),
+        (951868800, "2000-03-01T00:00:00"),
+        (1582977600, "2020-02-29T12:00:00"),
+        // 1900 is not a leap year.
+        (-2203891201, "1900-02-28T23:59:59"),
+        (-2203891200, "1900-03-01T00:00:00"),
+        (-62135596800, "0001-01-01T00:00:00"),
+        (253402300799, "9999-12-31T23:59:59"),
+    ] {
+        assert_eq!(
+            MockDateTime::from_unix_seconds(*seconds),
+            expected.parse().unwrap(),
+            "{}",
+            seconds
+        );
     }
 }
+
+#[test]
+fn test_from_unix_millis() {
+    for (millis, expected) in &[
+        (0, "1970-01-01T00:00:00.000"),
+        (-1, "1969-12-31T23:59:59.999"),
+        (-1000, "1969-12-31T23:59:59.000"),
+        (1234567890123, "2009-02-13T23:31:30.123"),
+        (951868799999, "2000-02-29T23:59:59.999"),
+    ] {
+        assert_eq!(
+            MockDateTime::from_unix_millis(*millis),
+            expected.parse().unwrap(),
+            "{}",
+            millis
+        );
+    }
+}
+
+#[test]
+fn test_add_days() {
+    for (start, days, expected) in &[
+        ("2021-01-31T10:00:00", 1, "2021-02-01T10:00:00"),
+        ("2021-03-01T10:00:00", -1, "2021-02-28T10:00:00"),
+        ("2020-03-01T10:00:00", -1, "2020-02-29T10:00:00"),
+        ("2020-02-28T10:00:00", 2, "2020-03-01T10:00:00"),
+        ("2021-12-20T10:00:00", 40, "2022-01-29T10:00:00"),
+        ("2022-01-29T10:00:00", -40, "2021-12-20T10:00:00"),
+        ("2019-03-01T10:00:00", 366, "2020-03-01T10:00:00"),
+        ("1900-02-28T10:00:00", 1, "1900-03-01T10:00:00"),
+    ] {
+        let mut dt: MockDateTime = start.parse().unwrap();
+        dt.add_days(*days);
+        assert_eq!(dt, expected.parse().unwrap(), "{} + {} days", start, days);
+    }
+}
+
+#[test]
+fn test_add_time() {
+    let mut dt: MockDateTime = "2020-02-28T23:30:00".parse().unwrap();
+    dt.add_minutes(90);
+    assert_eq!(dt, "2020-02-29T01:00:00".parse().unwrap());
+    dt.add_hours(23);
+    assert_eq!(dt, "2020-03-01T00:00:00".parse().unwrap());
+    dt.add_minutes(-1);
+    assert_eq!(dt, "2020-02-29T23:59:00".parse().unwrap());
+    dt.add_hours(-24 * 60);
+    assert_eq!(dt, "2019-12-31T23:59:00".parse().unwrap());
+    dt.add_seconds(60);
+    assert_eq!(dt, "2020-01-01T00:00:00".parse().unwrap());
+}
@@ -0,0 +1,42 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+/// A temporary struct that represents an amount of time to move a
+/// [`MockDateTime`](crate::mock::datetime::MockDateTime) by, for use with
+/// [`MockDateTime::add_duration()`](crate::mock::datetime::MockDateTime::add_duration()).
+///
+/// Each field can be negative, in which case the date is moved backwards by that amount.
+///
+/// # Examples
+///
+/// ```
+/// use icu::datetime::mock::duration::MockDuration;
+///
+/// // One month and a half minute.
+/// let duration = MockDuration {
+///     months: 1,
+///     seconds: 30,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct MockDuration {
+    /// Number of years.
+    pub years: i64,
+
+    /// Number of months.
+    pub months: i64,
+
+    /// Number of days.
+    pub days: i64,
+
+    /// Number of hours.
+    pub hours: i64,
+
+    /// Number of minutes.
+    pub minutes: i64,
+
+    /// Number of seconds.
+    pub seconds: i64,
+}
@@ -3,5 +3,6 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 pub mod datetime;
+pub mod duration;
 pub mod time_zone;
 pub mod zoned_datetime;
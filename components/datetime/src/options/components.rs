@@ -9,8 +9,8 @@
 //! | Algorithm step | Status |
 //! |----------------|--------|
 //! | Match skeleton fields according to a ranking             | Implemented |
-//! | Adjust the matched pattern to have certain widths        | Not yet implemented. See [issue #584](https://github.com/unicode-org/icu4x/issues/584) |
-//! | Match date and times separately, and them combine them   | Not yet implemented. See [issue #585](https://github.com/unicode-org/icu4x/issues/585) |
+//! | Adjust the matched pattern to have certain widths        | Implemented |
+//! | Match date and times separately, and them combine them   | Implemented |
 //! | Use appendItems to fill in a pattern with missing fields | Not yet, and may not be fully implemented. See [issue #586](https://github.com/unicode-org/icu4x/issues/586) |
 //!
 //! # Description
@@ -37,6 +37,10 @@
 //! certain situations, and component combinations, fields will not have a match, or the match will
 //! have a different type of presentation for a given locale.
 //!
+//! When no pattern has exactly the requested widths, the closest one is used and the widths of
+//! its fields are adjusted. For instance, a `long` month with a numeric day and a `long` weekday
+//! can be formatted from the `E, MMM d` pattern as `EEEE, MMMM d`. A field is never changed from
+//! numeric to text or vice versa, and the widths of hour, minute, and second fields are kept.
 //!
 //! # Examples
 //!
@@ -72,6 +76,7 @@
 //! *Note*: The exact result returned from [`DateTimeFormat`](crate::DateTimeFormat) is a subject to change over
 //! time. Formatted result should be treated as opaque and displayed to the user as-is,
 //! and it is strongly recommended to never write tests that expect a particular formatted output.
+use crate::error::DateTimeFormatError;
 use crate::fields::{self, Field, FieldLength, FieldSymbol};
 
 use super::preferences;
@@ -98,14 +103,58 @@ pub struct Bag {
 }
 
 impl Bag {
+    /// Returns an error if the bag requests no field, a field that cannot be matched against
+    /// skeletons yet, or fields that leave a gap in the date or in the time, such as a year and
+    /// a day without a month.
+    pub(crate) fn validate(&self) -> Result<(), DateTimeFormatError> {
+        let Self {
+            era,
+            year,
+            month,
+            day,
+            weekday,
+            hour,
+            minute,
+            second,
+            time_zone_name,
+            preferences: _,
+        } = self;
+        if era.is_none()
+            && year.is_none()
+            && month.is_none()
+            && day.is_none()
+            && weekday.is_none()
+            && hour.is_none()
+            && minute.is_none()
+            && second.is_none()
+            && time_zone_name.is_none()
+        {
+            return Err(DateTimeFormatError::EmptyOptions);
+        }
+        if era.is_some() {
+            // TODO(#486) - Eras are formatted in patterns, but not yet matched in skeletons.
+            return Err(DateTimeFormatError::UnsupportedField(FieldSymbol::Era));
+        }
+        if year.is_some() && day.is_some() && month.is_none() {
+            return Err(DateTimeFormatError::InvalidOptions(
+                "A year and a day can only be displayed with a month",
+            ));
+        }
+        if hour.is_some() && second.is_some() && minute.is_none() {
+            return Err(DateTimeFormatError::InvalidOptions(
+                "An hour and a second can only be displayed with a minute",
+            ));
+        }
+        Ok(())
+    }
+
     /// Converts the components::Bag into a Vec<Field>. The fields will be ordered in from most
     /// significant field to least significant. This is the order the fields are listed in
     /// the UTS 35 table - https://unicode.org/reports/tr35/tr35-dates.html#Date_Field_Symbol_Table
     pub(crate) fn to_vec_fields(&self) -> Vec<Field> {
         let mut fields = Vec::new();
-        if let Some(_era) = self.era {
-            unimplemented!("FieldSymbol::Era is needed. See issue #486.")
-        }
+        // TODO(#486) - The era is not converted, as Bag::validate rejects it until eras can be
+        // matched in skeletons.
 
         if let Some(year) = self.year {
             // Unimplemented year fields:
@@ -211,6 +260,7 @@ impl Bag {
                 symbol: FieldSymbol::Hour(match self.preferences {
                     Some(preferences::Bag {
                         hour_cycle: Some(hour_cycle),
+                        ..
                     }) => match hour_cycle {
                         // k - symbol
                         preferences::HourCycle::H24 => fields::Hour::H24,
@@ -392,4 +442,16 @@ mod test {
             ]
         );
     }
+    #[test]
+    fn test_component_bag_validate_era() {
+        let bag = Bag {
+            era: Some(Text::Short),
+            year: Some(Numeric::Numeric),
+            ..Default::default()
+        };
+        assert!(matches!(
+            bag.validate(),
+            Err(DateTimeFormatError::UnsupportedField(Symbol::Era))
+        ));
+    }
 }
@@ -82,7 +82,9 @@ use serde::{Deserialize, Serialize};
 pub struct Bag {
     pub date: Option<Date>,
     pub time: Option<Time>,
-    #[cfg_attr(feature = "serde", serde(skip_serializing, skip_deserializing))]
+    /// User preferences that override those of the locale. A preferred hour cycle changes the
+    /// hours of the time pattern, and adds or removes its day period when switching between
+    /// 12-hour and 24-hour time. A preferred calendar changes the year and era of the date.
     pub preferences: Option<preferences::Bag>,
 }
 
@@ -32,6 +32,10 @@
 pub mod components;
 pub mod length;
 pub mod preferences;
+
+use crate::error::DateTimeFormatError;
+use crate::skeleton;
+use icu_locid::Locale;
 /// A bag of options which, together with [`Locale`](icu_locid::Locale), defines how
 /// dates will be formatted with a [`DateTimeFormat`](crate::DateTimeFormat) instance.
 ///
@@ -61,6 +65,10 @@ pub enum DateTimeFormatOptions {
     Length(length::Bag),
     /// Bag of components describing which fields and how should be displayed.
     Components(components::Bag),
+    /// Skeleton, such as `yMMMd`, describing which fields and how should be displayed. The
+    /// pattern of the closest available skeleton is used, with its field lengths adjusted to
+    /// the requested ones.
+    Skeleton(skeleton::Skeleton),
 }
 
 impl Default for DateTimeFormatOptions {
@@ -69,6 +77,45 @@ impl Default for DateTimeFormatOptions {
     }
 }
 
+impl DateTimeFormatOptions {
+    /// Returns the user preferences of the options, if any.
+    pub(crate) fn preferences(&self) -> Option<&preferences::Bag> {
+        match self {
+            Self::Length(bag) => bag.preferences.as_ref(),
+            Self::Components(bag) => bag.preferences.as_ref(),
+            Self::Skeleton(_) => None,
+        }
+    }
+
+    /// Returns an error if the options request no field, or fields that cannot be displayed
+    /// together.
+    pub(crate) fn validate(&self) -> Result<(), DateTimeFormatError> {
+        match self {
+            Self::Length(length::Bag {
+                date: None,
+                time: None,
+                ..
+            }) => Err(DateTimeFormatError::EmptyOptions),
+            Self::Length(_) => Ok(()),
+            Self::Components(bag) => bag.validate(),
+            Self::Skeleton(skeleton) if skeleton.fields_len() == 0 => {
+                Err(DateTimeFormatError::EmptyOptions)
+            }
+            Self::Skeleton(_) => Ok(()),
+        }
+    }
+
+    /// Applies the calendar preference of the options to the `ca` keyword of `locale`, and
+    /// returns the calendar in which dates are formatted for the resulting locale. Calendars
+    /// that are not supported fall back to the Gregorian calendar.
+    pub(crate) fn resolve_calendar(&self, locale: &mut Locale) -> preferences::Calendar {
+        if let Some(calendar) = self.preferences().and_then(|prefs| prefs.calendar) {
+            calendar.set_on_locale(locale);
+        }
+        preferences::Calendar::from_locale(locale).unwrap_or(preferences::Calendar::Gregorian)
+    }
+}
+
 impl From<length::Bag> for DateTimeFormatOptions {
     fn from(input: length::Bag) -> Self {
         Self::Length(input)
@@ -80,3 +127,9 @@ impl From<components::Bag> for DateTimeFormatOptions {
         Self::Components(input)
     }
 }
+
+impl From<skeleton::Skeleton> for DateTimeFormatOptions {
+    fn from(input: skeleton::Skeleton) -> Self {
+        Self::Skeleton(input)
+    }
+}
@@ -21,10 +21,15 @@
 //! use icu::datetime::options::preferences;
 //!
 //! let prefs = preferences::Bag {
-//!     hour_cycle: Some(preferences::HourCycle::H23)
+//!     hour_cycle: Some(preferences::HourCycle::H23),
+//!     calendar: None,
 //! };
 //! ```
 use crate::fields;
+use icu_locid::extensions::unicode::{Key, Keywords, Value};
+use icu_locid::Locale;
+use std::borrow::Cow;
+use tinystr::tinystr4;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -37,7 +42,8 @@ use serde::{Deserialize, Serialize};
 /// use icu::datetime::options::preferences;
 ///
 /// let prefs = preferences::Bag {
-///     hour_cycle: Some(preferences::HourCycle::H23)
+///     hour_cycle: Some(preferences::HourCycle::H23),
+///     calendar: Some(preferences::Calendar::Buddhist),
 /// };
 /// ```
 #[derive(Debug, Clone, PartialEq)]
@@ -45,6 +51,9 @@ use serde::{Deserialize, Serialize};
 pub struct Bag {
     #[cfg_attr(feature = "serde", serde(rename = "hourCycle"))]
     pub hour_cycle: Option<HourCycle>,
+    /// The calendar in which dates are formatted. This takes precedence over the `ca` keyword
+    /// of the locale.
+    pub calendar: Option<Calendar>,
 }
 
 /// A user preference for adjusting how the hour component is displayed.
@@ -111,3 +120,97 @@ impl HourCycle {
         }
     }
 }
+
+/// A user preference for the calendar system in which dates are formatted.
+///
+/// The input of the formatter is always given in the Gregorian calendar, and its year and era
+/// are converted to the selected calendar. Months and days are shared with the Gregorian
+/// calendar by all of the supported calendars.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Calendar {
+    /// The Gregorian calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// "Sep 1, 2020";
+    /// ```
+    #[cfg_attr(feature = "serde", serde(rename = "gregory"))]
+    Gregorian,
+    /// The Thai Buddhist calendar, whose years are counted from 543 BC.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// "Sep 1, 2563 BE";
+    /// ```
+    #[cfg_attr(feature = "serde", serde(rename = "buddhist"))]
+    Buddhist,
+    /// The Japanese imperial calendar, whose years are counted from the start of the era of
+    /// the reigning emperor. Dates before the Meiji era are formatted in the Gregorian calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// "Sep 1, 2 Reiwa";
+    /// ```
+    #[cfg_attr(feature = "serde", serde(rename = "japanese"))]
+    Japanese,
+}
+
+impl Calendar {
+    /// Returns the BCP-47 identifier of the calendar, as used in the `ca` keyword of a locale
+    /// and as the variant of the calendar-specific data.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Gregorian => "gregory",
+            Self::Buddhist => "buddhist",
+            Self::Japanese => "japanese",
+        }
+    }
+
+    /// Returns the variant of the date patterns of the calendar. The date patterns of the
+    /// Gregorian calendar have no variant.
+    pub(crate) fn patterns_variant(self) -> Option<Cow<'static, str>> {
+        match self {
+            Self::Gregorian => None,
+            _ => Some(Cow::Borrowed(self.as_str())),
+        }
+    }
+
+    /// Returns the calendar selected by the `ca` keyword of `locale`, or [`None`] if the keyword
+    /// is absent or names an unsupported calendar.
+    pub(crate) fn from_locale(locale: &Locale) -> Option<Self> {
+        let value = locale.extensions.unicode.keywords.get(&calendar_key())?;
+        match value.to_string().as_str() {
+            "gregory" => Some(Self::Gregorian),
+            "buddhist" => Some(Self::Buddhist),
+            "japanese" => Some(Self::Japanese),
+            _ => None,
+        }
+    }
+
+    /// Sets the `ca` keyword of `locale` to this calendar, replacing any calendar it selects.
+    pub(crate) fn set_on_locale(self, locale: &mut Locale) {
+        let key = calendar_key();
+        let value: Value = self
+            .as_str()
+            .parse()
+            .expect("Calendar identifiers are valid keyword values");
+        let keywords = &mut locale.extensions.unicode.keywords;
+        if let Some(existing) = keywords.get_mut(&key) {
+            *existing = value;
+        } else {
+            let mut list = keywords.to_vec();
+            list.push((key, value));
+            list.sort_by_key(|(key, _)| *key);
+            *keywords = Keywords::from_vec_unchecked(list);
+        }
+    }
+}
+
+/// The key of the Unicode extension keyword that selects a calendar.
+fn calendar_key() -> Key {
+    Key::from_tinystr4_unchecked(tinystr4!("ca"))
+}
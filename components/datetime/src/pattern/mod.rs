@@ -140,16 +140,10 @@ impl fmt::Display for Pattern {
                     }
                 }
                 PatternItem::Literal(literal) => {
-                    // Determine if the literal contains any characters that would need to be escaped.
-                    let mut needs_escaping = false;
-                    for ch in literal.chars() {
-                        if ch.is_ascii_alphabetic() || ch == '\'' {
-                            needs_escaping = true;
-                            break;
-                        }
-                    }
+                    // Determine if the literal contains any characters that would need to be quoted.
+                    let needs_quoting = literal.chars().any(|ch| ch.is_ascii_alphabetic());
 
-                    if needs_escaping {
+                    if needs_quoting {
                         let mut ch_iter = literal.trim_end().chars().peekable();
 
                         // Do not escape the leading whitespace.
@@ -165,11 +159,7 @@ impl fmt::Display for Pattern {
                         // Wrap in "'" and escape "'".
                         formatter.write_char('\'')?;
                         for ch in ch_iter {
-                            if ch == '\'' {
-                                // Escape a single quote.
-                                formatter.write_char('\\')?;
-                            }
-                            formatter.write_char(ch)?;
+                            write_literal_char(formatter, ch)?;
                         }
                         formatter.write_char('\'')?;
 
@@ -182,7 +172,9 @@ impl fmt::Display for Pattern {
                             }
                         }
                     } else {
-                        formatter.write_str(literal)?;
+                        for ch in literal.chars() {
+                            write_literal_char(formatter, ch)?;
+                        }
                     }
                 }
             }
@@ -191,6 +183,16 @@ impl fmt::Display for Pattern {
     }
 }
 
+/// Writes a character of a literal, escaping a single quote as two single quotes, which is how
+/// UTS 35 spells it both inside and outside of quoted text.
+fn write_literal_char(formatter: &mut fmt::Formatter, ch: char) -> fmt::Result {
+    if ch == '\'' {
+        formatter.write_str("''")
+    } else {
+        formatter.write_char(ch)
+    }
+}
+
 impl FromIterator<PatternItem> for Pattern {
     fn from_iter<I: IntoIterator<Item = PatternItem>>(iter: I) -> Self {
         Self::from(iter.into_iter().collect::<Vec<_>>())
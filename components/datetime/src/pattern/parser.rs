@@ -266,13 +266,32 @@ mod tests {
             );
         }
 
-        let broken = vec![(" 'foo ", Error::UnclosedLiteral)];
+        let broken = vec![
+            (" 'foo ", Error::UnclosedLiteral),
+            ("h 'o''clock", Error::UnclosedLiteral),
+        ];
 
         for (string, error) in broken {
             assert_eq!(Parser::new(string).parse(), Err(error),);
         }
     }
 
+    #[test]
+    fn pattern_display_roundtrip() {
+        let samples = vec![
+            "h 'o''clock'",
+            " 'o''clock' ",
+            "hh''a",
+            "''",
+            "'week' d 'of' MMMM",
+        ];
+
+        for string in samples {
+            let pattern = Pattern::from_bytes(string).expect("Parsing pattern failed.");
+            assert_eq!(pattern.to_string(), string);
+        }
+    }
+
     #[test]
     fn pattern_parse_symbols() {
         let samples = vec![
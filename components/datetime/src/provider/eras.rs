@@ -0,0 +1,47 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::fields::FieldLength;
+use icu_provider::yoke::{self, *};
+use litemap::LiteMap;
+use std::borrow::Cow;
+
+/// The names of the eras of a calendar, keyed by era code, such as `ce` or `heisei`.
+/// See CLDR-JSON ca-gregorian.json `eras` for more context.
+///
+/// The calendar is selected by the variant of the data request, which is the BCP-47 identifier
+/// of the calendar, such as `gregory`, `buddhist`, or `japanese`.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct ErasV1 {
+    /// The wide era names, such as "Anno Domini".
+    pub names: LiteMap<Cow<'static, str>, Cow<'static, str>>,
+
+    /// The abbreviated era names, such as "AD".
+    pub abbr: LiteMap<Cow<'static, str>, Cow<'static, str>>,
+
+    /// The narrow era names, such as "A".
+    pub narrow: LiteMap<Cow<'static, str>, Cow<'static, str>>,
+}
+
+impl ErasV1 {
+    /// Returns the name of the era with the code `era` for a field of the given length, or
+    /// [`None`] if the era has no name. Wide and narrow names fall back to the abbreviated name.
+    pub(crate) fn get_symbol_for_era(&self, length: FieldLength, era: &str) -> Option<&str> {
+        let names = match length {
+            FieldLength::Wide => &self.names,
+            FieldLength::Narrow => &self.narrow,
+            _ => &self.abbr,
+        };
+        names
+            .get(era)
+            .or_else(|| self.abbr.get(era))
+            .map(|name| &**name)
+    }
+}
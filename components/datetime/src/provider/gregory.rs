@@ -34,6 +34,33 @@ pub struct DatePatternsV1 {
 
     pub datetime: patterns::DateTimeFormatsV1,
 }
+
+/// The patterns for formatting the interval between two datetimes, such as "Sep 12 – 14, 2020".
+/// See CLDR-JSON ca-gregorian.json `intervalFormats` for more context.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct DateIntervalPatternsV1 {
+    /// The pattern that joins two formatted datetimes when there is no interval pattern for
+    /// them, such as `{0} – {1}`.
+    pub fallback: Cow<'static, str>,
+
+    pub skeletons: intervals::IntervalSkeletonsV1,
+}
+
+/// The default joins the two datetimes with the fallback pattern of the CLDR root locale.
+impl Default for DateIntervalPatternsV1 {
+    fn default() -> Self {
+        Self {
+            fallback: Cow::Borrowed("{0} – {1}"),
+            skeletons: Default::default(),
+        }
+    }
+}
 macro_rules! symbols {
         ($name: ident, $expr: ty) => {
             pub mod $name {
@@ -226,3 +253,34 @@ pub mod patterns {
         pub skeletons: SkeletonsV1,
     }
 }
+
+pub mod intervals {
+    use super::patterns::{PatternV1, SkeletonV1};
+    use litemap::LiteMap;
+
+    /// The interval patterns of a skeleton, by the greatest difference between the two
+    /// datetimes. Each pattern contains the fields of the skeleton twice: the fields up to the
+    /// first repeated one are formatted with the start of the interval, and the rest with its end.
+    #[derive(Debug, PartialEq, Clone, Default)]
+    #[cfg_attr(
+        feature = "provider_serde",
+        derive(serde::Serialize, serde::Deserialize)
+    )]
+    pub struct IntervalPatternsV1 {
+        pub year: Option<PatternV1>,
+        pub month: Option<PatternV1>,
+        pub day: Option<PatternV1>,
+        /// The pattern for times in different halves of the day.
+        pub am_pm: Option<PatternV1>,
+        /// The pattern for times with different hours in the same half of the day.
+        pub hour: Option<PatternV1>,
+        pub minute: Option<PatternV1>,
+    }
+
+    #[derive(Debug, PartialEq, Clone, Default)]
+    #[cfg_attr(
+        feature = "provider_serde",
+        derive(serde::Serialize, serde::Deserialize)
+    )]
+    pub struct IntervalSkeletonsV1(pub LiteMap<SkeletonV1, IntervalPatternsV1>);
+}
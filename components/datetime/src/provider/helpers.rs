@@ -5,7 +5,7 @@
 use crate::date;
 use crate::error::DateTimeFormatError;
 use crate::fields;
-use crate::options::{components, length, DateTimeFormatOptions};
+use crate::options::{components, length, preferences, DateTimeFormatOptions};
 use crate::pattern::Pattern;
 use crate::provider;
 use crate::skeleton;
@@ -20,6 +20,7 @@ pub trait DateTimePatterns {
         components: &components::Bag,
     ) -> Result<Option<Pattern>>;
     fn get_pattern_for_length_bag(&self, length: &length::Bag) -> Result<Option<Pattern>>;
+    fn get_pattern_for_skeleton(&self, skeleton: &skeleton::Skeleton) -> Result<Option<Pattern>>;
     fn get_pattern_for_date_length(&self, length: length::Date) -> Result<Pattern>;
     fn get_pattern_for_time_length(&self, length: length::Time) -> Result<Pattern>;
     fn get_pattern_for_datetime_length(
@@ -57,6 +58,7 @@ impl DateTimePatterns for provider::gregory::DatePatternsV1 {
         match options {
             DateTimeFormatOptions::Length(bag) => self.get_pattern_for_length_bag(bag),
             DateTimeFormatOptions::Components(bag) => self.get_pattern_for_components_bag(bag),
+            DateTimeFormatOptions::Skeleton(skeleton) => self.get_pattern_for_skeleton(skeleton),
         }
     }
 
@@ -73,19 +75,35 @@ impl DateTimePatterns for provider::gregory::DatePatternsV1 {
                 &requested_fields,
             ) {
                 skeleton::BestSkeleton::AllFieldsMatch(pattern)
-                | skeleton::BestSkeleton::MissingOrExtraFields(pattern) => Some(pattern),
+                | skeleton::BestSkeleton::MissingOrExtraFields(pattern) => {
+                    Some(skeleton::adjust_field_lengths(&pattern, &requested_fields).pattern)
+                }
                 skeleton::BestSkeleton::NoMatch => None,
             },
         )
     }
 
     fn get_pattern_for_length_bag(&self, length: &length::Bag) -> Result<Option<Pattern>> {
+        let get_time_pattern = |time_length| -> Result<Pattern> {
+            let pattern = self.get_pattern_for_time_length(time_length)?;
+            Ok(match length.preferences {
+                Some(preferences::Bag {
+                    hour_cycle: Some(hour_cycle),
+                    ..
+                }) => skeleton::apply_hour_cycle(
+                    &self.datetime.skeletons,
+                    pattern,
+                    hour_cycle.field(),
+                ),
+                _ => pattern,
+            })
+        };
         match (length.date, length.time) {
             (None, None) => Ok(None),
-            (None, Some(time_length)) => self.get_pattern_for_time_length(time_length).map(Some),
+            (None, Some(time_length)) => get_time_pattern(time_length).map(Some),
             (Some(date_length), None) => self.get_pattern_for_date_length(date_length).map(Some),
             (Some(date_length), Some(time_length)) => {
-                let time = self.get_pattern_for_time_length(time_length)?;
+                let time = get_time_pattern(time_length)?;
                 let date = self.get_pattern_for_date_length(date_length)?;
 
                 self.get_pattern_for_datetime_length(date_length, date, time)
@@ -94,6 +112,15 @@ impl DateTimePatterns for provider::gregory::DatePatternsV1 {
         }
     }
 
+    fn get_pattern_for_skeleton(&self, skeleton: &skeleton::Skeleton) -> Result<Option<Pattern>> {
+        Ok(skeleton::create_best_pattern_for_skeleton(
+            &self.datetime.skeletons,
+            &self.datetime.length_patterns,
+            skeleton,
+        )?
+        .map(|skeleton_match| skeleton_match.pattern))
+    }
+
     fn get_pattern_for_date_length(&self, length: length::Date) -> Result<Pattern> {
         let date = &self.date;
         let s = match length {
@@ -141,7 +168,8 @@ impl DateTimeSymbols for provider::gregory::DateSymbolsV1 {
         day: date::IsoWeekday,
     ) -> &Cow<str> {
         let widths = match weekday {
-            fields::Weekday::Format => &self.weekdays.format,
+            // The text forms of the local weekday are the same as those of the format weekday.
+            fields::Weekday::Format | fields::Weekday::Local => &self.weekdays.format,
             fields::Weekday::StandAlone => {
                 if let Some(ref widths) = self.weekdays.stand_alone {
                     let symbols = match length {
@@ -162,7 +190,6 @@ impl DateTimeSymbols for provider::gregory::DateSymbolsV1 {
                     return self.get_symbol_for_weekday(fields::Weekday::Format, length, day);
                 }
             }
-            fields::Weekday::Local => unimplemented!(),
         };
         let symbols = match length {
             fields::FieldLength::Wide => &widths.wide,
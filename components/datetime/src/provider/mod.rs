@@ -6,15 +6,21 @@
 //!
 //! Read more about data providers: [`icu_provider`]
 
+pub mod eras;
 pub mod gregory;
 pub(crate) mod helpers;
 pub mod time_zones;
+pub mod week_data;
 
 pub mod key {
     use icu_provider::{resource_key, ResourceKey};
     pub const GREGORY_DATE_PATTERNS_V1: ResourceKey =
         resource_key!(DateTime, "gregory_patterns", 1);
     pub const GREGORY_DATE_SYMBOLS_V1: ResourceKey = resource_key!(DateTime, "gregory_symbols", 1);
+    pub const GREGORY_INTERVAL_PATTERNS_V1: ResourceKey =
+        resource_key!(DateTime, "gregory_interval", 1);
+    pub const WEEK_DATA_V1: ResourceKey = resource_key!(DateTime, "week_data", 1);
+    pub const ERAS_V1: ResourceKey = resource_key!(DateTime, "eras", 1);
     pub const TIMEZONE_FORMATS_V1: ResourceKey = resource_key!(TimeZone, "formats", 1);
     pub const TIMEZONE_EXEMPLAR_CITIES_V1: ResourceKey =
         resource_key!(TimeZone, "exemplar_cities", 1);
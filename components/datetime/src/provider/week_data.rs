@@ -0,0 +1,35 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::date::IsoWeekday;
+use icu_provider::yoke::{self, *};
+
+/// An ICU4X mapping to the CLDR weekData, which describes how weeks are numbered.
+/// See CLDR-JSON weekData.json for more context.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct WeekDataV1 {
+    /// The first day of a week.
+    pub first_weekday: IsoWeekday,
+
+    /// The minimal number of days of a week that must fall into a year or a month for the
+    /// week to be counted as the first week of that year or month.
+    pub min_week_days: u8,
+}
+
+/// The default is the CLDR week data for the world (`001`): weeks start on Monday, and the
+/// first week of a year or a month needs to contain at least one day of it.
+impl Default for WeekDataV1 {
+    fn default() -> Self {
+        Self {
+            first_weekday: IsoWeekday::Monday,
+            min_week_days: 1,
+        }
+    }
+}
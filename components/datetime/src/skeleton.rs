@@ -11,7 +11,7 @@ use std::convert::TryFrom;
 use crate::{
     fields::{self, Field, FieldLength, FieldSymbol},
     options::length,
-    pattern::Pattern,
+    pattern::{Pattern, PatternItem},
     provider::gregory::patterns::{LengthPatternsV1, PatternV1, SkeletonV1, SkeletonsV1},
 };
 
@@ -45,9 +45,35 @@ impl Skeleton {
         self.0.iter()
     }
 
-    fn fields_len(&self) -> usize {
+    pub(crate) fn fields_len(&self) -> usize {
         self.0.len()
     }
+
+    /// Returns the skeleton of the fields of `pattern`, for matching the pattern against other
+    /// skeletons. Skeletons do not contain day periods, nor stand-alone months and weekdays, so
+    /// these are dropped or replaced by their format variants.
+    pub(crate) fn from_pattern(pattern: &Pattern) -> Self {
+        let mut fields: SmallVec<[fields::Field; 5]> = SmallVec::new();
+        for item in pattern.items() {
+            let field = match item {
+                PatternItem::Field(field) => field,
+                PatternItem::Literal(_) => continue,
+            };
+            let symbol = match field.symbol {
+                FieldSymbol::DayPeriod(_) => continue,
+                FieldSymbol::Month(_) => FieldSymbol::Month(fields::Month::Format),
+                FieldSymbol::Weekday(_) => FieldSymbol::Weekday(fields::Weekday::Format),
+                symbol => symbol,
+            };
+            let field = Field::from((symbol, field.length));
+            if fields.iter().any(|f| f.symbol == field.symbol) {
+                continue;
+            }
+            let pos = fields.binary_search(&field).unwrap_or_else(|pos| pos);
+            fields.insert(pos, field);
+        }
+        Self(fields)
+    }
 }
 
 /// This is an implementation of the serde deserialization visitor pattern.
@@ -171,7 +197,11 @@ impl TryFrom<&str> for Skeleton {
         let mut iter = skeleton_string.bytes().peekable();
         while let Some(byte) = iter.next() {
             // Convert the byte to a valid field symbol.
-            let field_symbol = FieldSymbol::try_from(byte)?;
+            let field_symbol = match FieldSymbol::try_from(byte)? {
+                // TODO(#486) - Eras are formatted in patterns, but not yet matched in skeletons.
+                FieldSymbol::Era => return Err(SkeletonError::SymbolUnimplemented(byte.into())),
+                field_symbol => field_symbol,
+            };
 
             // Go through the bytes to count how often it's repeated.
             let mut field_length: u8 = 1;
@@ -263,14 +293,8 @@ impl From<fields::SymbolError> for SkeletonError {
                 match byte {
                     // TODO(#487) - Flexible day periods
                     b'B'
-                    // TODO(#486) - Era
-                    | b'G'
-                    // TODO(#502) - Week of month
-                    | b'W'
                     // TODO(#501) - Quarters
                     | b'Q'
-                    // TODO (#488) - Week of year
-                    | b'w'
                     => Self::SymbolUnimplemented(byte.into()),
                     _ => Self::SymbolUnknown(byte.into()),
                 }
@@ -338,6 +362,13 @@ const SKELETON_EXTRA_SYMBOL: u32 = 1000;
 // the stored skeletons. There cannot be any cases higher than this one.
 const REQUESTED_SYMBOL_MISSING: u32 = 10000;
 
+/// The distance between a requested [`Skeleton`] and an available one, as computed by
+/// [`best_match()`]. The lower the distance, the better the fit. Field width and
+/// text vs. numeric differences add small penalties, while extra and missing fields add
+/// penalties that are orders of magnitude larger.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Distance(pub u32);
+
 /// According to the [UTS 35 skeleton matching algorithm](https://unicode.org/reports/tr35/tr35-dates.html#Matching_Skeletons)
 /// there will be a guaranteed match for a skeleton. However, with this initial implementation,
 /// there is no attempt to add on missing fields. This enum encodes the variants for the current
@@ -467,6 +498,263 @@ pub fn create_best_pattern_for_fields<'a>(
     }
 }
 
+/// A pattern matched to a requested [`Skeleton`] by [`create_best_pattern_for_skeleton()`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct SkeletonMatch {
+    /// The pattern of the closest available skeleton, with its field lengths adjusted to the
+    /// requested ones.
+    pub pattern: Pattern,
+    /// The fields of the pattern whose length was adjusted, as pairs of the field in the
+    /// available pattern and the adjusted field.
+    pub adjusted_fields: Vec<(Field, Field)>,
+    /// The requested fields that are not in the pattern, because no available skeleton has them.
+    pub missing_fields: Vec<Field>,
+}
+
+/// Finds the pattern that best fits a requested [`Skeleton`], such as `yMMMd`, among the
+/// available skeletons, and adjusts the lengths of its fields to the requested ones. For example,
+/// requesting `yMMMMd` when only `yMMMd` is available changes the pattern `MMM d, y` to
+/// `MMMM d, y`.
+///
+/// If no skeleton has all of the requested date and time fields, the date and time are matched
+/// separately and combined with one of the `length_patterns`, as for [`components::Bag`].
+/// Returns [`None`] if no skeleton has any of the requested fields.
+///
+/// Fields that are not date or time fields, such as time zones, are only supported if a skeleton
+/// has all of the requested fields; otherwise [`SkeletonError::UnimplementedField`] is returned.
+///
+/// [`components::Bag`]: crate::options::components::Bag
+pub fn create_best_pattern_for_skeleton(
+    skeletons: &SkeletonsV1,
+    length_patterns: &LengthPatternsV1,
+    skeleton: &Skeleton,
+) -> Result<Option<SkeletonMatch>, SkeletonError> {
+    if skeletons.0.is_empty() {
+        return Ok(None);
+    }
+    let fields = &skeleton.0[..];
+    if !matches!(
+        get_best_available_format_pattern(skeletons, fields),
+        BestSkeleton::AllFieldsMatch(_)
+    ) {
+        // These require "append items" support, see #586.
+        if let Some(field) = group_fields_by_type(fields).other.first() {
+            return Err(SkeletonError::UnimplementedField(field.symbol.into()));
+        }
+    }
+    Ok(
+        match create_best_pattern_for_fields(skeletons, length_patterns, fields) {
+            BestSkeleton::AllFieldsMatch(pattern) | BestSkeleton::MissingOrExtraFields(pattern) => {
+                Some(adjust_field_lengths(&pattern, fields))
+            }
+            BestSkeleton::NoMatch => None,
+        },
+    )
+}
+
+/// Whether two field symbols represent the same kind of field, and differ at most in a way that
+/// the skeleton matching considers to have no distance, such as `M` and `L` for months, or `H`
+/// and `h` for hours.
+pub(crate) fn is_same_field_type(a: FieldSymbol, b: FieldSymbol) -> bool {
+    match (a, b) {
+        (FieldSymbol::Month(_), FieldSymbol::Month(_))
+        | (FieldSymbol::Weekday(_), FieldSymbol::Weekday(_))
+        | (FieldSymbol::DayPeriod(_), FieldSymbol::DayPeriod(_))
+        | (FieldSymbol::Hour(_), FieldSymbol::Hour(_)) => true,
+        _ => a == b,
+    }
+}
+
+/// Adjusts the lengths of the fields in `pattern` to the lengths of the `requested` fields of the
+/// same type, per the [UTS 35 skeleton matching algorithm](https://unicode.org/reports/tr35/tr35-dates.html#Matching_Skeletons).
+///
+/// Fields are not changed from numeric to text or vice versa. As in ICU, the lengths of hour,
+/// minute, and second fields are kept, so that a request for `Hms` still yields `HH:mm:ss`.
+pub(crate) fn adjust_field_lengths(pattern: &Pattern, requested: &[Field]) -> SkeletonMatch {
+    let mut items = Vec::with_capacity(pattern.items().len());
+    let mut adjusted_fields = Vec::new();
+    for item in pattern.items() {
+        let field = match item {
+            PatternItem::Field(field) => field,
+            PatternItem::Literal(_) => {
+                items.push(item.clone());
+                continue;
+            }
+        };
+        let requested_field = requested
+            .iter()
+            .find(|requested_field| is_same_field_type(requested_field.symbol, field.symbol));
+        let adjusted = match (requested_field, field.symbol) {
+            (_, FieldSymbol::Hour(_)) | (_, FieldSymbol::Minute) | (_, FieldSymbol::Second(_)) => {
+                None
+            }
+            (Some(requested_field), _) if requested_field.length != field.length => {
+                let adjusted = Field::from((field.symbol, requested_field.length));
+                if adjusted.get_length_type() == field.get_length_type() {
+                    Some(adjusted)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+        match adjusted {
+            Some(adjusted) => {
+                adjusted_fields.push((*field, adjusted));
+                items.push(PatternItem::Field(adjusted));
+            }
+            None => items.push(item.clone()),
+        }
+    }
+
+    let missing_fields = requested
+        .iter()
+        .filter(|requested_field| {
+            !pattern.items().iter().any(|item| match item {
+                PatternItem::Field(field) => {
+                    is_same_field_type(requested_field.symbol, field.symbol)
+                }
+                PatternItem::Literal(_) => false,
+            })
+        })
+        .copied()
+        .collect();
+
+    SkeletonMatch {
+        pattern: Pattern::from(items),
+        adjusted_fields,
+        missing_fields,
+    }
+}
+
+/// Changes the hour fields of `pattern` to the given `hour`, such as for a user preference of
+/// the hour cycle.
+///
+/// Switching between a 12-hour and a 24-hour cycle also adds or removes the day period. If the
+/// locale has an available format with the same fields in the other cycle, its pattern is used,
+/// so that `h:mm a` becomes `HH:mm` in English. Otherwise the day period of `pattern` is removed
+/// together with the whitespace separating it, or added after the time.
+pub(crate) fn apply_hour_cycle(
+    skeletons: &SkeletonsV1,
+    pattern: Pattern,
+    hour: fields::Hour,
+) -> Pattern {
+    fn is_12_hour(hour: fields::Hour) -> bool {
+        matches!(hour, fields::Hour::H11 | fields::Hour::H12)
+    }
+
+    let current = pattern.items().iter().find_map(|item| match item {
+        PatternItem::Field(Field {
+            symbol: FieldSymbol::Hour(hour),
+            ..
+        }) => Some(*hour),
+        _ => None,
+    });
+    let current = match current {
+        Some(current) => current,
+        None => return pattern,
+    };
+
+    let mut items: Vec<PatternItem> = if is_12_hour(current) == is_12_hour(hour) {
+        pattern.items().to_vec()
+    } else {
+        // Available formats only use `h` and `H`.
+        let matching_hour = if is_12_hour(hour) {
+            fields::Hour::H12
+        } else {
+            fields::Hour::H23
+        };
+        let mut fields = Skeleton::from_pattern(&pattern).0;
+        for field in fields.iter_mut() {
+            if let FieldSymbol::Hour(_) = field.symbol {
+                field.symbol = FieldSymbol::Hour(matching_hour);
+            }
+        }
+        match get_best_available_format_pattern(skeletons, &fields) {
+            BestSkeleton::AllFieldsMatch(available) => available.items().to_vec(),
+            _ if is_12_hour(hour) => add_day_period(pattern.items()),
+            _ => remove_day_periods(pattern.items()),
+        }
+    };
+
+    for item in items.iter_mut() {
+        if let PatternItem::Field(field) = item {
+            if let FieldSymbol::Hour(_) = field.symbol {
+                field.symbol = FieldSymbol::Hour(hour);
+            }
+        }
+    }
+    Pattern::from(items)
+}
+
+/// Adds an `a` day period after the last hour, minute, or second field, unless there already is
+/// a day period.
+fn add_day_period(items: &[PatternItem]) -> Vec<PatternItem> {
+    let mut items = items.to_vec();
+    let has_day_period = items.iter().any(|item| {
+        matches!(
+            item,
+            PatternItem::Field(Field {
+                symbol: FieldSymbol::DayPeriod(_),
+                ..
+            })
+        )
+    });
+    let last_time_field = items.iter().rposition(|item| match item {
+        PatternItem::Field(field) => matches!(
+            field.symbol,
+            FieldSymbol::Hour(_) | FieldSymbol::Minute | FieldSymbol::Second(_)
+        ),
+        PatternItem::Literal(_) => false,
+    });
+    if let (false, Some(index)) = (has_day_period, last_time_field) {
+        items.insert(index + 1, PatternItem::Literal(String::from(" ")));
+        items.insert(
+            index + 2,
+            PatternItem::from((
+                FieldSymbol::DayPeriod(fields::DayPeriod::AmPm),
+                FieldLength::One,
+            )),
+        );
+    }
+    items
+}
+
+/// Removes the day periods, and the whitespace that separates them from the rest of the pattern.
+fn remove_day_periods(items: &[PatternItem]) -> Vec<PatternItem> {
+    let mut result: Vec<PatternItem> = Vec::with_capacity(items.len());
+    let mut trim_next = false;
+    for item in items {
+        match item {
+            PatternItem::Field(Field {
+                symbol: FieldSymbol::DayPeriod(_),
+                ..
+            }) => match result.last_mut() {
+                Some(PatternItem::Literal(literal)) => {
+                    let trimmed = literal.trim_end().len();
+                    literal.truncate(trimmed);
+                    if literal.is_empty() {
+                        result.pop();
+                    }
+                }
+                _ => trim_next = true,
+            },
+            PatternItem::Literal(literal) if trim_next => {
+                trim_next = false;
+                let literal = literal.trim_start();
+                if !literal.is_empty() {
+                    result.push(PatternItem::Literal(String::from(literal)));
+                }
+            }
+            item => {
+                trim_next = false;
+                result.push(item.clone());
+            }
+        }
+    }
+    result
+}
+
 struct FieldsByType {
     pub date: Vec<Field>,
     pub time: Vec<Field>,
@@ -487,6 +775,7 @@ fn group_fields_by_type(fields: &[Field]) -> FieldsByType {
             //  - Solo example: "E"
             FieldSymbol::Year(_)
             | FieldSymbol::Month(_)
+            | FieldSymbol::Week(_)
             | FieldSymbol::Day(_)
             | FieldSymbol::Weekday(_) => date.push(*field),
 
@@ -497,9 +786,7 @@ fn group_fields_by_type(fields: &[Field]) -> FieldsByType {
             | FieldSymbol::Second(_) => time.push(*field),
 
             // Other components
-            FieldSymbol::TimeZone(_) => other.push(*field),
-            // TODO(#486)
-            // FieldSymbol::Era(_) => other.push(*field),
+            FieldSymbol::TimeZone(_) | FieldSymbol::Era => other.push(*field),
             // Plus others...
         };
     }
@@ -514,15 +801,20 @@ fn group_fields_by_type(fields: &[Field]) -> FieldsByType {
 ///  * Compute a score based on the best possible match for the given fields.
 ///  * Select the skeleton with highest score.
 ///
-/// The following is not implemented:
+/// The following is implemented by the callers of this function:
 ///
 ///  * 2.6.2.1 Matching Skeletons
-///    - TODO(#584) - Modify the resulting pattern to have fields of the same length. For example requesting
-///      a skeleton "yMMMMd" can have a best match of ["yMMMd", "d MMM y"]. This pattern should
-///      then be modified to use the requested length to produce a pattern "d MMMM y".
-///      However, fields should not be changed from numeric to text.
+///    - Modify the resulting pattern to have fields of the same length. For example requesting
+///      a skeleton "yMMMMd" can have a best match of ["yMMMd", "d MMM y"]. This pattern is
+///      then modified to use the requested length to produce a pattern "d MMMM y".
+///      See [`create_best_pattern_for_skeleton()`].
+///  * 2.6.2.2 Missing Skeleton Fields
+///    - The mechanism to combine a date pattern and a time pattern. See
+///      [`create_best_pattern_for_fields()`].
+///
+/// The following is not implemented:
+///
 ///  * 2.6.2.2 Missing Skeleton Fields
-///    - TODO(#585) - The mechanism to combine a date pattern and a time pattern.
 ///    - TODO(#586) - Using the CLDR appendItems field. Note: There is not agreement yet on how
 ///      much of this step to implement. See the issue for more information.
 pub fn get_best_available_format_pattern<'a>(
@@ -534,76 +826,8 @@ pub fn get_best_available_format_pattern<'a>(
     let mut closest_missing_fields = 0;
 
     for available_format_pattern in get_available_format_patterns(skeletons) {
-        let skeleton = &available_format_pattern.skeleton;
-        debug_assert!(
-            skeleton.fields_len() <= MAX_SKELETON_FIELDS as usize,
-            "The distance mechanism assumes skeletons are less than MAX_SKELETON_FIELDS in length."
-        );
-        let mut missing_fields = 0;
-        let mut distance: u32 = 0;
-        // The distance should fit into a u32.
-
-        let mut requested_fields = fields.iter().peekable();
-        let mut skeleton_fields = skeleton.fields_iter().peekable();
-        loop {
-            let next = (requested_fields.peek(), skeleton_fields.peek());
-
-            // Try to find matching symbols.
-            match next {
-                (Some(requested_field), Some(skeleton_field)) => {
-                    debug_assert!(
-                        // As of the time of this writing, stand-alone months are not in the CLDR
-                        // skeleton data. The components::Bag could produce stand-alone month fields,
-                        // but since the CLDR does not have them, only Month::Format symbols are
-                        // used for matching.
-                        skeleton_field.symbol != FieldSymbol::Month(fields::Month::StandAlone)
-                    );
-
-                    if skeleton_field.symbol > requested_field.symbol {
-                        // Keep searching for a matching skeleton field.
-                        skeleton_fields.next();
-                        distance += SKELETON_EXTRA_SYMBOL;
-                        continue;
-                    }
-
-                    if skeleton_field.symbol < requested_field.symbol {
-                        // The requested field symbol is missing from the skeleton.
-                        distance += REQUESTED_SYMBOL_MISSING;
-                        missing_fields += 1;
-                        requested_fields.next();
-                        continue;
-                    }
-
-                    distance += if requested_field == skeleton_field {
-                        NO_DISTANCE
-                    } else if requested_field.symbol != skeleton_field.symbol {
-                        SUBSTANTIAL_DIFFERENCES_DISTANCE
-                    } else if requested_field.get_length_type() != skeleton_field.get_length_type()
-                    {
-                        TEXT_VS_NUMERIC_DISTANCE
-                    } else {
-                        WIDTH_MISMATCH_DISTANCE
-                    };
-
-                    requested_fields.next();
-                    skeleton_fields.next();
-                }
-                (None, Some(_)) => {
-                    // The skeleton has additional fields that we are not matching.
-                    distance += SKELETON_EXTRA_SYMBOL;
-                    skeleton_fields.next();
-                }
-                (Some(_), None) => {
-                    // The skeleton is missing requested fields.
-                    distance += REQUESTED_SYMBOL_MISSING;
-                    requested_fields.next();
-                    missing_fields += 1;
-                }
-                (None, None) => {
-                    break;
-                }
-            }
-        }
+        let (distance, missing_fields) =
+            get_skeleton_distance(&available_format_pattern.skeleton, fields);
 
         if distance < closest_distance {
             closest_format_pattern = Some(available_format_pattern.pattern);
@@ -624,6 +848,124 @@ pub fn get_best_available_format_pattern<'a>(
     BestSkeleton::AllFieldsMatch(closest_format_pattern)
 }
 
+/// Finds the [`Skeleton`] in `available` that is the closest fit for `requested`, according
+/// to the same distance scoring used by [`get_best_available_format_pattern()`]. Returns the
+/// index of the best skeleton and its [`Distance`], or [`None`] if `available` is empty. Ties
+/// are resolved in favor of the first skeleton.
+///
+/// # Examples
+///
+/// ```
+/// use icu::datetime::skeleton::{best_match, Skeleton};
+/// use std::convert::TryFrom;
+///
+/// let requested = Skeleton::try_from("Hm").expect("Failed to parse skeleton");
+/// let available = [
+///     Skeleton::try_from("hm").expect("Failed to parse skeleton"),
+///     Skeleton::try_from("Hms").expect("Failed to parse skeleton"),
+/// ];
+///
+/// let (index, _distance) = best_match(&requested, &available).expect("No skeletons available");
+/// assert_eq!(index, 1);
+/// ```
+pub fn best_match(requested: &Skeleton, available: &[Skeleton]) -> Option<(usize, Distance)> {
+    let mut best: Option<(usize, Distance)> = None;
+    for (index, skeleton) in available.iter().enumerate() {
+        let distance = Distance(get_skeleton_distance(skeleton, &requested.0).0);
+        if best.map_or(true, |(_, closest)| distance < closest) {
+            best = Some((index, distance));
+        }
+    }
+    best
+}
+
+/// Like [`best_match()`], but only considers the skeletons in `available` that have a field of
+/// the same symbol as each field of `requested`, and no other fields. The fields may differ in
+/// their widths, such as `MMM` and `MMMM`.
+pub(crate) fn best_match_with_same_fields(
+    requested: &Skeleton,
+    available: &[Skeleton],
+) -> Option<usize> {
+    best_match(requested, available)
+        .filter(|(_, distance)| distance.0 < SKELETON_EXTRA_SYMBOL)
+        .map(|(index, _)| index)
+}
+
+/// Computes the distance between the requested fields and a skeleton, per the distance values
+/// above, along with the number of requested fields that are missing from the skeleton.
+fn get_skeleton_distance(skeleton: &Skeleton, fields: &[Field]) -> (u32, usize) {
+    debug_assert!(
+        skeleton.fields_len() <= MAX_SKELETON_FIELDS as usize,
+        "The distance mechanism assumes skeletons are less than MAX_SKELETON_FIELDS in length."
+    );
+    let mut missing_fields = 0;
+    let mut distance: u32 = 0;
+    // The distance should fit into a u32.
+
+    let mut requested_fields = fields.iter().peekable();
+    let mut skeleton_fields = skeleton.fields_iter().peekable();
+    loop {
+        let next = (requested_fields.peek(), skeleton_fields.peek());
+
+        // Try to find matching symbols.
+        match next {
+            (Some(requested_field), Some(skeleton_field)) => {
+                debug_assert!(
+                    // As of the time of this writing, stand-alone months are not in the CLDR
+                    // skeleton data. The components::Bag could produce stand-alone month fields,
+                    // but since the CLDR does not have them, only Month::Format symbols are
+                    // used for matching.
+                    skeleton_field.symbol != FieldSymbol::Month(fields::Month::StandAlone)
+                );
+
+                if skeleton_field.symbol > requested_field.symbol {
+                    // Keep searching for a matching skeleton field.
+                    skeleton_fields.next();
+                    distance += SKELETON_EXTRA_SYMBOL;
+                    continue;
+                }
+
+                if skeleton_field.symbol < requested_field.symbol {
+                    // The requested field symbol is missing from the skeleton.
+                    distance += REQUESTED_SYMBOL_MISSING;
+                    missing_fields += 1;
+                    requested_fields.next();
+                    continue;
+                }
+
+                distance += if requested_field == skeleton_field {
+                    NO_DISTANCE
+                } else if requested_field.symbol != skeleton_field.symbol {
+                    SUBSTANTIAL_DIFFERENCES_DISTANCE
+                } else if requested_field.get_length_type() != skeleton_field.get_length_type() {
+                    TEXT_VS_NUMERIC_DISTANCE
+                } else {
+                    WIDTH_MISMATCH_DISTANCE
+                };
+
+                requested_fields.next();
+                skeleton_fields.next();
+            }
+            (None, Some(_)) => {
+                // The skeleton has additional fields that we are not matching.
+                distance += SKELETON_EXTRA_SYMBOL;
+                skeleton_fields.next();
+            }
+            (Some(_), None) => {
+                // The skeleton is missing requested fields.
+                distance += REQUESTED_SYMBOL_MISSING;
+                requested_fields.next();
+                missing_fields += 1;
+            }
+            (None, None) => {
+                break;
+            }
+        }
+    }
+
+    (distance, missing_fields)
+}
+
 pub fn get_available_format_patterns<'a>(
     skeletons: &'a SkeletonsV1,
 ) -> impl Iterator<Item = AvailableFormatPattern> + 'a {
@@ -785,17 +1127,180 @@ mod test {
         );
     }
 
+    fn get_skeleton_match(skeleton: &str) -> Option<SkeletonMatch> {
+        let data_provider = get_data_payload();
+        create_best_pattern_for_skeleton(
+            &data_provider.get().datetime.skeletons,
+            &data_provider.get().datetime.length_patterns,
+            &Skeleton::try_from(skeleton).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_skeleton_exact_matches() {
+        for (skeleton, pattern) in &[("Hms", "HH:mm:ss"), ("yMMMd", "MMM d, y")] {
+            assert_eq!(
+                get_skeleton_match(skeleton),
+                Some(SkeletonMatch {
+                    pattern: Pattern::from_bytes(pattern).unwrap(),
+                    adjusted_fields: vec![],
+                    missing_fields: vec![],
+                }),
+                "{}",
+                skeleton
+            );
+        }
+    }
+
+    #[test]
+    fn test_skeleton_field_length_adjustment() {
+        let month = |length| Field::from((FieldSymbol::Month(Month::Format), length));
+
+        // Only yMMMd is available, so MMM is adjusted to MMMM.
+        assert_eq!(
+            get_skeleton_match("yMMMMd"),
+            Some(SkeletonMatch {
+                pattern: Pattern::from_bytes("MMMM d, y").unwrap(),
+                adjusted_fields: vec![(month(FieldLength::Abbreviated), month(FieldLength::Wide))],
+                missing_fields: vec![],
+            })
+        );
+
+        assert_eq!(
+            get_skeleton_match("MMMMEEEEd").unwrap().pattern,
+            Pattern::from_bytes("EEEE, MMMM d").unwrap()
+        );
+        assert_eq!(
+            get_skeleton_match("yyMMMd").unwrap().pattern,
+            Pattern::from_bytes("MMM d, yy").unwrap()
+        );
+
+        // Hours, minutes, and seconds keep the lengths of the pattern.
+        let skeleton_match = get_skeleton_match("Hmmss").unwrap();
+        assert_eq!(
+            skeleton_match.pattern,
+            Pattern::from_bytes("HH:mm:ss").unwrap()
+        );
+        assert!(skeleton_match.adjusted_fields.is_empty());
+
+        // Numeric fields are not changed to text.
+        let skeleton_match = adjust_field_lengths(
+            &Pattern::from_bytes("L").unwrap(),
+            &Skeleton::try_from("MMMM").unwrap().0,
+        );
+        assert_eq!(skeleton_match.pattern, Pattern::from_bytes("L").unwrap());
+        assert!(skeleton_match.adjusted_fields.is_empty());
+    }
+
+    #[test]
+    fn test_skeleton_missing_field() {
+        // No skeleton has a day of the year.
+        let skeleton_match = get_skeleton_match("yMMMdD").unwrap();
+        assert_eq!(
+            skeleton_match.pattern,
+            Pattern::from_bytes("MMM d, y").unwrap()
+        );
+        assert_eq!(
+            skeleton_match.missing_fields,
+            vec![Field::from((
+                FieldSymbol::Day(Day::DayOfYear),
+                FieldLength::One
+            ))]
+        );
+
+        // Time zones are only supported in exact matches.
+        let data_provider = get_data_payload();
+        assert!(matches!(
+            create_best_pattern_for_skeleton(
+                &data_provider.get().datetime.skeletons,
+                &data_provider.get().datetime.length_patterns,
+                &Skeleton::try_from("yMMMdv").unwrap(),
+            ),
+            Err(SkeletonError::UnimplementedField('v'))
+        ));
+        assert!(get_skeleton_match("Hmv").is_some());
+    }
+
+    #[test]
+    fn test_best_match() {
+        let requested = Skeleton::try_from("Hm").unwrap();
+        let hm = Skeleton::try_from("hm").unwrap();
+        let hms = Skeleton::try_from("Hms").unwrap();
+
+        // The same hour cycle with an extra field is preferred over a different hour cycle.
+        assert_eq!(
+            best_match(&requested, &[hm.clone(), hms.clone()]),
+            Some((1, Distance(SKELETON_EXTRA_SYMBOL)))
+        );
+        assert_eq!(
+            best_match(&requested, &[hms, hm]),
+            Some((0, Distance(SKELETON_EXTRA_SYMBOL)))
+        );
+
+        assert_eq!(
+            best_match(&requested, &[requested.clone()]),
+            Some((0, Distance(NO_DISTANCE)))
+        );
+        assert_eq!(best_match(&requested, &[]), None);
+    }
+
+    #[test]
+    fn test_apply_hour_cycle() {
+        let data_provider = get_data_payload();
+        let skeletons = &data_provider.get().datetime.skeletons;
+        let apply = |pattern: &str, hour| {
+            apply_hour_cycle(skeletons, Pattern::from_bytes(pattern).unwrap(), hour)
+        };
+
+        // The available format of the other hour cycle is used.
+        assert_eq!(
+            apply("h:mm a", fields::Hour::H23),
+            Pattern::from_bytes("HH:mm").unwrap()
+        );
+        assert_eq!(
+            apply("HH:mm:ss", fields::Hour::H11),
+            Pattern::from_bytes("K:mm:ss a").unwrap()
+        );
+        // Within the same hour cycle only the symbol changes.
+        assert_eq!(
+            apply("h:mm a", fields::Hour::H11),
+            Pattern::from_bytes("K:mm a").unwrap()
+        );
+        // There is no available format with a specific time zone name, so the day period is
+        // removed from or added to the pattern itself.
+        assert_eq!(
+            apply("h:mm:ss a zzzz", fields::Hour::H23),
+            Pattern::from_bytes("H:mm:ss zzzz").unwrap()
+        );
+        assert_eq!(
+            apply("HH:mm:ss zzzz", fields::Hour::H12),
+            Pattern::from_bytes("hh:mm:ss a zzzz").unwrap()
+        );
+        assert_eq!(
+            apply("a h:mm z", fields::Hour::H23),
+            Pattern::from_bytes("H:mm z").unwrap()
+        );
+        // Patterns without hours are unchanged.
+        assert_eq!(
+            apply("MMM d, y", fields::Hour::H23),
+            Pattern::from_bytes("MMM d, y").unwrap()
+        );
+    }
+
     // These were all of the skeletons from the "available formats" in the CLDR as of 2021-01
     // Generated with:
     // https://gist.github.com/gregtatum/1d76bbdb87132f71a969a10f0c1d2d9c
 
     #[rustfmt::skip]
-    const SUPPORTED_STRING_SKELETONS: [&str; 60] = [
+    const SUPPORTED_STRING_SKELETONS: [&str; 62] = [
         "E", "dEEEE", "EHm", "EHms", "dE", "Ehm", "Ehms", "H", "HHmm", "HHmmss", "Hm", "Hms", "M",
         "MdEEEE", "MdE", "MMM", "MMMdEEEE", "MMMdE", "MMMM", "MMMMdEEEE", "MMMMdE", "MMMMd",
         "MMMMdd", "MMMd", "MMMdd", "MMd", "MMdd", "Md", "Mdd", "d", "h", "hm", "hms", "mmss", "ms",
         "y", "yM", "yMdEEEE", "yMdE", "yMM", "yMMM", "yMMMdEEEE", "yMMMdE", "yMMMM", "yMMMMdEEEE",
         "yMMMMdE", "yMMMMdcccc", "yMMMMd", "yMMMd", "yMMdd", "yMd",
+        // Weeks
+        "MMMMW", "yw",
         // Timezones
         "HHmmZ", "Hmsv", "Hmsvvvv", "Hmv", "Hmvvvv", "hmsv", "hmsvvvv", "hmv", "hmvvvv",
     ];
@@ -805,17 +1310,13 @@ mod test {
     //       and then regenerate the test data.
     //       https://github.com/unicode-org/icu4x/blob/main/provider/testdata/README.md
     #[rustfmt::skip]
-    const UNSUPPORTED_STRING_SKELETONS: [&str; 19] = [
+    const UNSUPPORTED_STRING_SKELETONS: [&str; 17] = [
         // TODO(#487) - Flexible day periods
         "Bh", "Bhm", "Bhms", "EBhm", "EBhms",
         // TODO(#486) - Era
         "Gy", "GyM", "GyMMM", "GyMMMdEEEE", "GyMMMdE", "GyMMMM", "GyMMMMdE", "GyMMMMd", "GyMMMd",
-        // TODO(#502) - Week of month
-        "MMMMW",
         // TODO(#501) - Quarters
         "yQ", "yQQQ", "yQQQQ",
-        // TODO (#488) - Week of year
-        "yw"
     ];
 
     #[test]
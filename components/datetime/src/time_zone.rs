@@ -58,33 +58,27 @@ where
 ///
 /// # Examples
 ///
-/// // TODO(#622) Uncomment and update example once TimeZoneFormat is public.
-/// // ```
-/// // use icu_locid::Locale;
-/// // use icu_locid::macros::langid;
-/// // use icu_datetime::TimeZoneFormat;
-/// // use icu_datetime::date::GmtOffset;
-/// // use icu_datetime::mock::time_zone::MockTimeZone;
-/// // use icu_provider::inv::InvariantDataProvider;
+/// ```
+/// use icu_locid::Locale;
+/// use icu_locid_macros::langid;
+/// use icu_datetime::TimeZoneFormat;
+/// use icu_datetime::pattern::Pattern;
+/// use icu_datetime::date::GmtOffset;
+/// use icu_datetime::mock::time_zone::MockTimeZone;
 ///
-/// // let locale: Locale = langid!("en").into();
-/// // let pattern = std::iter::empty().collect();
-/// // let provider = InvariantDataProvider;
+/// let locale: Locale = langid!("en").into();
+/// let pattern = Pattern::from_bytes("z").unwrap();
+/// let provider = icu_testdata::get_provider();
 ///
-/// // let tzf = TimeZoneFormat::try_new(locale, pattern, &provider)
-/// //     .expect("Failed to create TimeZoneFormat");
+/// let tzf = TimeZoneFormat::try_new(locale, pattern, &provider)
+///     .expect("Failed to create TimeZoneFormat");
 ///
-/// // let time_zone = MockTimeZone::new(
-/// //        GmtOffset::default(),
-/// //        None,
-/// //        None,
-/// //        None,
-/// // );
+/// let time_zone = MockTimeZone::new(GmtOffset::default(), None, None, None);
 ///
-/// // let value = tzf.format_to_string(&time_zone);
-/// // ```
-// TODO(#622) Make TimeZoneFormat public once we have a clean way to provide it options.
-pub(super) struct TimeZoneFormat<'d> {
+/// let value = tzf.format_to_string(&time_zone);
+/// assert_eq!(value, "GMT");
+/// ```
+pub struct TimeZoneFormat<'d> {
     /// The pattern to format.
     pub(super) pattern: Pattern,
     /// The data that contains meta information about how to display content.
@@ -112,24 +106,21 @@ impl<'d> TimeZoneFormat<'d> {
     ///
     /// # Examples
     ///
-    /// // TODO(#622) Uncomment and update example once TimeZoneFormat is public.
-    /// // ```
-    /// // use icu_locid::Locale;
-    /// // use icu_locid::macros::langid;
-    /// // use icu_datetime::TimeZoneFormat;
-    /// // use icu_datetime::mock::time_zone::MockTimeZone;
-    /// // use icu_provider::inv::InvariantDataProvider;
+    /// ```
+    /// use icu_locid::Locale;
+    /// use icu_locid_macros::langid;
+    /// use icu_datetime::TimeZoneFormat;
+    /// use icu_datetime::pattern::Pattern;
     ///
-    /// // let locale: Locale = langid!("en").into();
-    /// // let pattern = std::iter::empty().collect();
-    /// // let provider = InvariantDataProvider;
+    /// let locale: Locale = langid!("en").into();
+    /// let pattern = Pattern::from_bytes("z").unwrap();
+    /// let provider = icu_testdata::get_provider();
     ///
-    /// // let tzf = TimeZoneFormat::try_new(locale, pattern, &provider);
+    /// let tzf = TimeZoneFormat::try_new(locale, pattern, &provider);
     ///
-    /// // assert!(tzf.is_ok());
-    /// // ```
-    // TODO(#622) Make this public once TimeZoneFormat is public.
-    pub(super) fn try_new<L, ZP>(
+    /// assert!(tzf.is_ok());
+    /// ```
+    pub fn try_new<L, ZP>(
         locale: L,
         pattern: Pattern,
         zone_provider: &ZP,
@@ -252,8 +243,16 @@ impl<'d> TimeZoneFormat<'d> {
                         ))
                     }
                 },
+                TimeZone::UpperO => match length {
+                    1 | 4 => (), // Localized GMT formats, CLDR data is required by default.
+                    _ => {
+                        return Err(DateTimeFormatError::Pattern(
+                            PatternError::FieldLengthInvalid(FieldSymbol::TimeZone(symbol)),
+                        ))
+                    }
+                },
                 // ISO-8601 or localized GMT formats. CLDR data is either unneeded or required by default.
-                TimeZone::LowerX | TimeZone::UpperX | TimeZone::UpperZ | TimeZone::UpperO => (),
+                TimeZone::LowerX | TimeZone::UpperX | TimeZone::UpperZ => (),
             }
         }
 
@@ -265,35 +264,26 @@ impl<'d> TimeZoneFormat<'d> {
     ///
     /// # Examples
     ///
-    /// // TODO(#622) Uncomment and update example once TimeZoneFormat is public.
-    /// // ```
-    /// // use icu_locid::Locale;
-    /// // use icu_locid::macros::langid;
-    /// // use icu_datetime::TimeZoneFormat;
-    /// // use icu_datetime::date::GmtOffset;
-    /// // use icu_datetime::mock::time_zone::MockTimeZone;
-    /// // use icu_provider::inv::InvariantDataProvider;
-    ///
-    /// // # let locale: Locale = langid!("en").into();
-    /// // # let pattern = std::iter::empty().collect();
-    /// // # let provider = InvariantDataProvider;
-    ///
-    /// // let tzf = TimeZoneFormat::try_new(locale, pattern, &provider)
-    /// //     .expect("Failed to create TimeZoneFormat");
-    ///
-    /// // let time_zone = MockTimeZone::new(
-    /// //        GmtOffset::default(),
-    /// //        None,
-    /// //        None,
-    /// //        None,
-    /// // );
-    ///
-    /// // let _ = tzf.format(&time_zone);
-    /// // ```
-    // TODO(#622) Make this public once TimeZoneFormat is public.
-    //           And remove #[allow(unused)]
-    #[allow(unused)]
-    pub(super) fn format<'l: 'd, T>(&'l self, value: &'l T) -> FormattedTimeZone<'l, T>
+    /// ```
+    /// use icu_locid::Locale;
+    /// use icu_locid_macros::langid;
+    /// use icu_datetime::TimeZoneFormat;
+    /// use icu_datetime::pattern::Pattern;
+    /// use icu_datetime::date::GmtOffset;
+    /// use icu_datetime::mock::time_zone::MockTimeZone;
+    ///
+    /// # let locale: Locale = langid!("en").into();
+    /// # let pattern = Pattern::from_bytes("z").unwrap();
+    /// # let provider = icu_testdata::get_provider();
+    ///
+    /// let tzf = TimeZoneFormat::try_new(locale, pattern, &provider)
+    ///     .expect("Failed to create TimeZoneFormat");
+    ///
+    /// let time_zone = MockTimeZone::new(GmtOffset::default(), None, None, None);
+    ///
+    /// assert_eq!(tzf.format(&time_zone).to_string(), "GMT");
+    /// ```
+    pub fn format<'l: 'd, T>(&'l self, value: &'l T) -> FormattedTimeZone<'l, T>
     where
         T: TimeZoneInput,
     {
@@ -308,39 +298,30 @@ impl<'d> TimeZoneFormat<'d> {
     ///
     /// # Examples
     ///
-    /// // TODO(#622) Uncomment and update example once TimeZoneFormat is public.
-    /// // ```
-    /// // use icu_locid::Locale;
-    /// // use icu_locid::macros::langid;
-    /// // use icu_datetime::TimeZoneFormat;
-    /// // use icu_datetime::date::GmtOffset;
-    /// // use icu_datetime::mock::time_zone::MockTimeZone;
-    /// // use icu_provider::inv::InvariantDataProvider;
-    ///
-    /// // # let locale: Locale = langid!("en").into();
-    /// // # let pattern = std::iter::empty().collect();
-    /// // # let provider = InvariantDataProvider;
-    ///
-    /// // let tzf = TimeZoneFormat::try_new(locale, pattern, &provider)
-    /// //     .expect("Failed to create TimeZoneFormat");
-    ///
-    /// // let time_zone = MockTimeZone::new(
-    /// //        GmtOffset::default(),
-    /// //        None,
-    /// //        None,
-    /// //        None,
-    /// // );
-    ///
-    /// // let mut buffer = String::new();
-    /// // tzf.format_to_write(&mut buffer, &time_zone)
-    /// //     .expect("Failed to write to a buffer.");
-    ///
-    /// // let _ = format!("Time Zone: {}", buffer);
-    /// // ```
-    // TODO(#622) Make this public once TimeZoneFormat is public.
-    //            And remove #[allow(unused)]
-    #[allow(unused)]
-    pub(super) fn format_to_write(
+    /// ```
+    /// use icu_locid::Locale;
+    /// use icu_locid_macros::langid;
+    /// use icu_datetime::TimeZoneFormat;
+    /// use icu_datetime::pattern::Pattern;
+    /// use icu_datetime::date::GmtOffset;
+    /// use icu_datetime::mock::time_zone::MockTimeZone;
+    ///
+    /// # let locale: Locale = langid!("en").into();
+    /// # let pattern = Pattern::from_bytes("z").unwrap();
+    /// # let provider = icu_testdata::get_provider();
+    ///
+    /// let tzf = TimeZoneFormat::try_new(locale, pattern, &provider)
+    ///     .expect("Failed to create TimeZoneFormat");
+    ///
+    /// let time_zone = MockTimeZone::new(GmtOffset::default(), None, None, None);
+    ///
+    /// let mut buffer = String::new();
+    /// tzf.format_to_write(&mut buffer, &time_zone)
+    ///     .expect("Failed to write to a buffer.");
+    ///
+    /// assert_eq!(buffer, "GMT");
+    /// ```
+    pub fn format_to_write(
         &self,
         w: &mut impl std::fmt::Write,
         value: &impl TimeZoneInput,
@@ -352,152 +333,183 @@ impl<'d> TimeZoneFormat<'d> {
     ///
     /// # Examples
     ///
-    /// // TODO(#622) Uncomment and update example once TimeZoneFormat is public.
-    /// // ```
-    /// // use icu_locid::Locale;
-    /// // use icu_locid::macros::langid;
-    /// // use icu_datetime::TimeZoneFormat;
-    /// // use icu_datetime::date::GmtOffset;
-    /// // use icu_datetime::mock::time_zone::MockTimeZone;
-    /// // use icu_provider::inv::InvariantDataProvider;
-    ///
-    /// // # let locale: Locale = langid!("en").into();
-    /// // # let pattern = std::iter::empty().collect();
-    /// // # let provider = InvariantDataProvider;
-    ///
-    /// // let tzf = TimeZoneFormat::try_new(locale, pattern, &provider)
-    /// //     .expect("Failed to create TimeZoneFormat");
-    ///
-    /// // let time_zone = MockTimeZone::new(
-    /// //        GmtOffset::default(),
-    /// //        None,
-    /// //        None,
-    /// //        None,
-    /// // );
-    ///
-    /// // let _ = tzf.format_to_string(&time_zone);
-    /// // ```
-    // TODO(#622) Make this public once TimeZoneFormat is public.
-    //           And remove #[allow(unused)]
-    #[allow(unused)]
-    pub(super) fn format_to_string(&self, value: &impl TimeZoneInput) -> String {
+    /// ```
+    /// use icu_locid::Locale;
+    /// use icu_locid_macros::langid;
+    /// use icu_datetime::TimeZoneFormat;
+    /// use icu_datetime::pattern::Pattern;
+    /// use icu_datetime::date::GmtOffset;
+    /// use icu_datetime::mock::time_zone::MockTimeZone;
+    ///
+    /// # let locale: Locale = langid!("en").into();
+    /// # let pattern = Pattern::from_bytes("z").unwrap();
+    /// # let provider = icu_testdata::get_provider();
+    ///
+    /// let tzf = TimeZoneFormat::try_new(locale, pattern, &provider)
+    ///     .expect("Failed to create TimeZoneFormat");
+    ///
+    /// let time_zone = MockTimeZone::new(GmtOffset::default(), None, None, None);
+    ///
+    /// assert_eq!(tzf.format_to_string(&time_zone), "GMT");
+    /// ```
+    pub fn format_to_string(&self, value: &impl TimeZoneInput) -> String {
         let mut s = String::new();
         self.format_to_write(&mut s, value)
             .expect("Failed to write to a String.");
         s
     }
 
-    /// Writes the time zone in generic location format as defined by the UTS-35 spec.
-    /// e.g. France Time
-    /// https://unicode.org/reports/tr35/tr35-dates.html#Time_Zone_Format_Terminology
-    pub(super) fn generic_location_format<W: fmt::Write + ?Sized>(
+    /// Writes the time zone using the first of `formats` for which data is available, falling
+    /// back to the localized GMT format if there is none, as described in UTS-35:
+    /// https://unicode.org/reports/tr35/tr35-dates.html#Using_Time_Zone_Names
+    ///
+    /// A format is skipped if its data was not loaded, or if the time zone lacks the metazone,
+    /// time zone id, or time variant that it requires. Since the localized GMT format only
+    /// requires the GMT offset, this only fails if writing to the sink fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_datetime::mock::time_zone::MockTimeZone;
+    /// use icu_datetime::pattern::Pattern;
+    /// use icu_datetime::time_zone::NameFormat;
+    /// use icu_datetime::TimeZoneFormat;
+    /// use icu_locid_macros::langid;
+    /// use tinystr::tinystr8;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let pattern = Pattern::from_bytes("zzzz").unwrap();
+    /// let tzf = TimeZoneFormat::try_new(langid!("en"), pattern, &provider)
+    ///     .expect("Failed to create TimeZoneFormat");
+    ///
+    /// let formats = [
+    ///     NameFormat::LongSpecificNonLocation,
+    ///     NameFormat::LongGenericNonLocation,
+    /// ];
+    ///
+    /// let mut time_zone = MockTimeZone::new(
+    ///     "-07:00".parse().unwrap(),
+    ///     Some("America/Los_Angeles".to_string()),
+    ///     Some("America_Pacific".to_string()),
+    ///     Some(tinystr8!("daylight")),
+    /// );
+    /// let mut buffer = String::new();
+    /// tzf.format_with_fallback(&mut buffer, &time_zone, &formats)
+    ///     .expect("Failed to write to a buffer.");
+    /// assert_eq!(buffer, "Pacific Daylight Time");
+    ///
+    /// // Without a metazone, none of the names apply.
+    /// time_zone.metazone_id = None;
+    /// let mut buffer = String::new();
+    /// tzf.format_with_fallback(&mut buffer, &time_zone, &formats)
+    ///     .expect("Failed to write to a buffer.");
+    /// assert_eq!(buffer, "GMT-07:00");
+    /// ```
+    pub fn format_with_fallback<W: fmt::Write + ?Sized>(
         &self,
         sink: &mut W,
         time_zone: &impl TimeZoneInput,
+        formats: &[NameFormat],
     ) -> Result<(), DateTimeFormatError> {
+        match formats
+            .iter()
+            .find_map(|&format| self.name(format, time_zone))
+        {
+            Some(name) => sink.write_str(&name).map_err(DateTimeFormatError::from),
+            None => self.localized_gmt_format(sink, time_zone),
+        }
+    }
+
+    /// Returns the name of the time zone in the given format, or `None` if the data for it is
+    /// not available.
+    fn name(&self, format: NameFormat, time_zone: &impl TimeZoneInput) -> Option<Cow<'_, str>> {
+        match format {
+            NameFormat::LongSpecificNonLocation => self
+                .long_specific_non_location_name(time_zone)
+                .map(Cow::Borrowed),
+            NameFormat::ShortSpecificNonLocation => self
+                .short_specific_non_location_name(time_zone)
+                .map(Cow::Borrowed),
+            NameFormat::LongGenericNonLocation => self
+                .long_generic_non_location_name(time_zone)
+                .map(Cow::Borrowed),
+            NameFormat::ShortGenericNonLocation => self
+                .short_generic_non_location_name(time_zone)
+                .map(Cow::Borrowed),
+            NameFormat::GenericLocation => self.generic_location_name(time_zone).map(Cow::Owned),
+        }
+    }
+
+    /// Returns the time zone in generic location format as defined by the UTS-35 spec.
+    /// e.g. France Time
+    /// https://unicode.org/reports/tr35/tr35-dates.html#Time_Zone_Format_Terminology
+    fn generic_location_name(&self, time_zone: &impl TimeZoneInput) -> Option<String> {
         // TODO(blocked on #277) Use formatter utility instead of replacing "{0}".
-        sink.write_str(
-            &self
-                .exemplar_cities
-                .as_ref()
-                .map(|p| p.get())
-                .and_then(|cities| time_zone.time_zone_id().and_then(|id| cities.get(id)))
-                .map(|location| {
-                    self.zone_formats
-                        .get()
-                        .region_format
-                        .replace("{0}", location)
-                })
-                .ok_or(fmt::Error)?,
-        )
-        .map_err(DateTimeFormatError::from)
+        self.exemplar_city(time_zone).map(|location| {
+            self.zone_formats
+                .get()
+                .region_format
+                .replace("{0}", location)
+        })
     }
 
-    /// Writes the time zone in short generic non-location format as defined by the UTS-35 spec.
+    /// Returns the time zone in short generic non-location format as defined by the UTS-35 spec.
     /// e.g. PT
     /// https://unicode.org/reports/tr35/tr35-dates.html#Time_Zone_Format_Terminology
-    pub(super) fn short_generic_non_location_format<W: fmt::Write + ?Sized>(
-        &self,
-        sink: &mut W,
-        time_zone: &impl TimeZoneInput,
-    ) -> Result<(), DateTimeFormatError> {
-        sink.write_str(
-            self.mz_generic_short
-                .as_ref()
-                .map(|p| p.get())
-                .and_then(|metazones| time_zone.metazone_id().and_then(|mz| metazones.get(mz)))
-                .ok_or(fmt::Error)?,
-        )
-        .map_err(DateTimeFormatError::from)
+    fn short_generic_non_location_name(&self, time_zone: &impl TimeZoneInput) -> Option<&str> {
+        self.mz_generic_short
+            .as_ref()
+            .map(|p| p.get())
+            .and_then(|metazones| time_zone.metazone_id().and_then(|mz| metazones.get(mz)))
+            .map(|name| &**name)
     }
 
-    /// Writes the time zone in long generic non-location format as defined by the UTS-35 spec.
+    /// Returns the time zone in long generic non-location format as defined by the UTS-35 spec.
     /// e.g. Pacific Time
     /// https://unicode.org/reports/tr35/tr35-dates.html#Time_Zone_Format_Terminology
-    pub(super) fn long_generic_non_location_format<W: fmt::Write + ?Sized>(
-        &self,
-        sink: &mut W,
-        time_zone: &impl TimeZoneInput,
-    ) -> Result<(), DateTimeFormatError> {
-        sink.write_str(
-            self.mz_generic_long
-                .as_ref()
-                .map(|p| p.get())
-                .and_then(|metazones| time_zone.metazone_id().and_then(|mz| metazones.get(mz)))
-                .ok_or(fmt::Error)?,
-        )
-        .map_err(DateTimeFormatError::from)
+    fn long_generic_non_location_name(&self, time_zone: &impl TimeZoneInput) -> Option<&str> {
+        self.mz_generic_long
+            .as_ref()
+            .map(|p| p.get())
+            .and_then(|metazones| time_zone.metazone_id().and_then(|mz| metazones.get(mz)))
+            .map(|name| &**name)
     }
 
-    /// Writes the time zone in short specific non-location format as defined by the UTS-35 spec.
+    /// Returns the time zone in short specific non-location format as defined by the UTS-35 spec.
     /// e.g. PDT
     /// https://unicode.org/reports/tr35/tr35-dates.html#Time_Zone_Format_Terminology
-    pub(super) fn short_specific_non_location_format<W: fmt::Write + ?Sized>(
-        &self,
-        sink: &mut W,
-        time_zone: &impl TimeZoneInput,
-    ) -> Result<(), DateTimeFormatError> {
-        sink.write_str(
-            self.mz_specific_short
-                .as_ref()
-                .map(|p| p.get())
-                .and_then(|metazones| time_zone.metazone_id().and_then(|mz| metazones.get(mz)))
-                .and_then(|specific_names| {
-                    time_zone
-                        .time_variant()
-                        .and_then(|variant| specific_names.get(variant))
-                })
-                .ok_or_else(|| DateTimeFormatError::from(fmt::Error))?,
-        )
-        .map_err(DateTimeFormatError::from)
+    fn short_specific_non_location_name(&self, time_zone: &impl TimeZoneInput) -> Option<&str> {
+        self.mz_specific_short
+            .as_ref()
+            .map(|p| p.get())
+            .and_then(|metazones| time_zone.metazone_id().and_then(|mz| metazones.get(mz)))
+            .and_then(|specific_names| {
+                time_zone
+                    .time_variant()
+                    .and_then(|variant| specific_names.get(variant))
+            })
+            .map(|name| &**name)
     }
 
-    /// Writes the time zone in long specific non-location format as defined by the UTS-35 spec.
+    /// Returns the time zone in long specific non-location format as defined by the UTS-35 spec.
     /// e.g. Pacific Daylight Time
     /// https://unicode.org/reports/tr35/tr35-dates.html#Time_Zone_Format_Terminology
-    pub(super) fn long_specific_non_location_format<W: fmt::Write + ?Sized>(
-        &self,
-        sink: &mut W,
-        time_zone: &impl TimeZoneInput,
-    ) -> Result<(), DateTimeFormatError> {
-        sink.write_str(
-            self.mz_specific_long
-                .as_ref()
-                .map(|p| p.get())
-                .and_then(|metazones| time_zone.metazone_id().and_then(|mz| metazones.get(mz)))
-                .and_then(|specific_names| {
-                    time_zone
-                        .time_variant()
-                        .and_then(|variant| specific_names.get(variant))
-                })
-                .ok_or(fmt::Error)?,
-        )
-        .map_err(DateTimeFormatError::from)
+    fn long_specific_non_location_name(&self, time_zone: &impl TimeZoneInput) -> Option<&str> {
+        self.mz_specific_long
+            .as_ref()
+            .map(|p| p.get())
+            .and_then(|metazones| time_zone.metazone_id().and_then(|mz| metazones.get(mz)))
+            .and_then(|specific_names| {
+                time_zone
+                    .time_variant()
+                    .and_then(|variant| specific_names.get(variant))
+            })
+            .map(|name| &**name)
     }
 
-    /// Writes the time zone in localized GMT format according to the CLDR localized hour format.
-    /// This goes explicitly against the UTS-35 spec, which specifies long or short localized
-    /// GMT formats regardless of locale.
+    /// Writes the time zone in the long localized GMT format according to the CLDR localized hour
+    /// format, e.g. "GMT-07:00". This goes explicitly against the UTS-35 spec, which specifies
+    /// the long localized GMT format regardless of locale.
     ///
     /// You can see more information about our decision to resolve this conflict here:
     /// https://docs.google.com/document/d/16GAqaDRS6hzL8jNYjus5MglSevGBflISM-BrIS7bd4A/edit?usp=sharing
@@ -534,20 +546,66 @@ impl<'d> TimeZoneFormat<'d> {
         }
     }
 
-    /// Writes the exemplar city associated with this time zone.
-    pub(super) fn exemplar_city<W: fmt::Write + ?Sized>(
+    /// Writes the time zone in the short localized GMT format, e.g. "GMT-7" or "GMT+5:45".
+    ///
+    /// The short format is derived from the CLDR localized hour format: the hours are not
+    /// zero-padded, and the minutes are only written when the offset has any.
+    pub(super) fn short_localized_gmt_format<W: fmt::Write + ?Sized>(
         &self,
         sink: &mut W,
         time_zone: &impl TimeZoneInput,
     ) -> Result<(), DateTimeFormatError> {
-        sink.write_str(
-            self.exemplar_cities
-                .as_ref()
-                .map(|p| p.get())
-                .and_then(|cities| time_zone.time_zone_id().and_then(|id| cities.get(id)))
-                .ok_or(fmt::Error)?,
-        )
-        .map_err(DateTimeFormatError::from)
+        let gmt_offset = time_zone.gmt_offset();
+        if gmt_offset.is_zero() {
+            sink.write_str(&self.zone_formats.get().gmt_zero_format.clone())
+                .map_err(DateTimeFormatError::from)
+        } else {
+            let hour_format = if gmt_offset.is_positive() {
+                &self.zone_formats.get().hour_format.0
+            } else {
+                &self.zone_formats.get().hour_format.1
+            };
+            let hour_format = if gmt_offset.has_minutes() {
+                hour_format.to_string()
+            } else {
+                Self::remove_minutes(hour_format)
+            };
+            let hours = self.format_offset_hours(time_zone, ZeroPadding::Off);
+            // TODO(blocked on #277) Use formatter utility instead of replacing "{0}".
+            sink.write_str(
+                &self
+                    .zone_formats
+                    .get()
+                    .gmt_format
+                    .replace("{0}", &hour_format)
+                    // support all combos of "(HH|H):mm" by replacing longest patterns first.
+                    .replace("HH", &hours)
+                    .replace("mm", &self.format_offset_minutes(time_zone))
+                    .replace("H", &hours),
+            )
+            .map_err(DateTimeFormatError::from)
+        }
+    }
+
+    /// Removes the minutes and the separator that precedes them from a localized hour format,
+    /// e.g. "+HH:mm" becomes "+HH".
+    fn remove_minutes(hour_format: &str) -> String {
+        match (hour_format.rfind('H'), hour_format.find("mm")) {
+            (Some(hours), Some(minutes)) if hours < minutes => {
+                format!("{}{}", &hour_format[..=hours], &hour_format[minutes + 2..])
+            }
+            _ => hour_format.to_string(),
+        }
+    }
+
+    /// Returns the exemplar city associated with this time zone, or `None` if the data for it
+    /// is not available.
+    pub(super) fn exemplar_city(&self, time_zone: &impl TimeZoneInput) -> Option<&str> {
+        self.exemplar_cities
+            .as_ref()
+            .map(|p| p.get())
+            .and_then(|cities| time_zone.time_zone_id().and_then(|id| cities.get(id)))
+            .map(|city| &**city)
     }
 
     /// Writes the unknown city "Etc/Unknown" for the current locale.
@@ -556,7 +614,7 @@ impl<'d> TimeZoneFormat<'d> {
     /// returns the "Etc/Uknown" value of the `und` locale as a hard-coded string.
     ///
     /// This can be used as a fallback if [`exemplar_city()`](TimeZoneFormat::exemplar_city())
-    /// is unable to produce a localized form of the time zone's exemplar city in the current locale.
+    /// is unable to find a localized form of the time zone's exemplar city in the current locale.
     pub(super) fn unknown_city<W: fmt::Write + ?Sized>(
         &self,
         sink: &mut W,
@@ -666,6 +724,22 @@ impl<'d> TimeZoneFormat<'d> {
     }
 }
 
+/// The time zone formats that require locale data, listed in the order in which UTS-35
+/// falls back from one to the next.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NameFormat {
+    /// e.g. Pacific Daylight Time
+    LongSpecificNonLocation,
+    /// e.g. PDT
+    ShortSpecificNonLocation,
+    /// e.g. Pacific Time
+    LongGenericNonLocation,
+    /// e.g. PT
+    ShortGenericNonLocation,
+    /// e.g. Los Angeles Time
+    GenericLocation,
+}
+
 /// Determines which ISO-8601 format should be used to format a [`GmtOffset`](crate::date::GmtOffset).
 pub(super) enum IsoFormat {
     /// ISO-8601 Basic Format.
@@ -715,3 +789,192 @@ pub(super) enum ZeroPadding {
     /// Do not add zero-padding.
     Off,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "provider_serde")]
+    fn test_format_with_fallback() {
+        use crate::mock::time_zone::MockTimeZone;
+        use icu_locid_macros::langid;
+        use tinystr::tinystr8;
+
+        let provider = icu_testdata::get_provider();
+        let pattern = Pattern::from_bytes("zzzz vvvv").unwrap();
+        let tzf = TimeZoneFormat::try_new(langid!("en"), pattern, &provider).unwrap();
+
+        let offset = "-07:00".parse().unwrap();
+        let samples = vec![
+            (
+                MockTimeZone::new(
+                    offset,
+                    Some("America/Los_Angeles".to_string()),
+                    Some("America_Pacific".to_string()),
+                    Some(tinystr8!("daylight")),
+                ),
+                "Pacific Daylight Time",
+            ),
+            (
+                MockTimeZone::new(
+                    offset,
+                    Some("America/Los_Angeles".to_string()),
+                    Some("America_Pacific".to_string()),
+                    None,
+                ),
+                "Pacific Time",
+            ),
+            (
+                MockTimeZone::new(offset, Some("Asia/Tokyo".to_string()), None, None),
+                "Tokyo Time",
+            ),
+            (MockTimeZone::new(offset, None, None, None), "GMT-07:00"),
+            ("Z".parse().unwrap(), "GMT"),
+        ];
+        for (time_zone, expected) in samples {
+            let mut sink = String::new();
+            tzf.format_with_fallback(
+                &mut sink,
+                &time_zone,
+                &[
+                    NameFormat::LongSpecificNonLocation,
+                    NameFormat::ShortSpecificNonLocation,
+                    NameFormat::LongGenericNonLocation,
+                    NameFormat::ShortGenericNonLocation,
+                    NameFormat::GenericLocation,
+                ],
+            )
+            .unwrap();
+            assert_eq!(sink, expected);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "provider_serde")]
+    fn test_format_with_fallback_write_error() {
+        use crate::mock::time_zone::MockTimeZone;
+        use icu_locid_macros::langid;
+        use tinystr::tinystr8;
+
+        /// A sink that rejects the first write and accepts the following ones.
+        struct FailFirstWrite(Option<String>);
+        impl fmt::Write for FailFirstWrite {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                match self.0 {
+                    Some(ref mut buffer) => {
+                        buffer.push_str(s);
+                        Ok(())
+                    }
+                    None => {
+                        self.0 = Some(String::new());
+                        Err(fmt::Error)
+                    }
+                }
+            }
+        }
+
+        let provider = icu_testdata::get_provider();
+        let pattern = Pattern::from_bytes("zzzz").unwrap();
+        let tzf = TimeZoneFormat::try_new(langid!("en"), pattern, &provider).unwrap();
+        let time_zone = MockTimeZone::new(
+            "-07:00".parse().unwrap(),
+            Some("America/Los_Angeles".to_string()),
+            Some("America_Pacific".to_string()),
+            Some(tinystr8!("daylight")),
+        );
+
+        // The write error is returned instead of falling back to the localized GMT format.
+        let mut sink = FailFirstWrite(None);
+        let result = tzf.format_with_fallback(
+            &mut sink,
+            &time_zone,
+            &[NameFormat::LongSpecificNonLocation],
+        );
+        assert!(matches!(result, Err(DateTimeFormatError::Format(_))));
+        assert_eq!(sink.0.as_deref(), Some(""));
+    }
+
+    #[test]
+    #[cfg(feature = "provider_serde")]
+    fn test_localized_gmt_format() {
+        use crate::mock::time_zone::MockTimeZone;
+        use icu_locid_macros::langid;
+
+        let provider = icu_testdata::get_provider();
+        let tzf = TimeZoneFormat::try_new(langid!("en"), Pattern::default(), &provider).unwrap();
+
+        let samples = vec![
+            ("Z", "GMT", "GMT"),
+            ("-07:00", "GMT-7", "GMT-07:00"),
+            // India
+            ("+05:30", "GMT+5:30", "GMT+05:30"),
+            // Nepal
+            ("+05:45", "GMT+5:45", "GMT+05:45"),
+            ("-09:30", "GMT-9:30", "GMT-09:30"),
+        ];
+        for (offset, expected_short, expected_long) in samples {
+            let time_zone = MockTimeZone::new(offset.parse().unwrap(), None, None, None);
+
+            let mut sink = String::new();
+            tzf.short_localized_gmt_format(&mut sink, &time_zone)
+                .unwrap();
+            assert_eq!(sink, expected_short);
+
+            let mut sink = String::new();
+            tzf.localized_gmt_format(&mut sink, &time_zone).unwrap();
+            assert_eq!(sink, expected_long);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "provider_serde")]
+    fn test_iso8601_format() {
+        use crate::mock::time_zone::MockTimeZone;
+        use icu_locid_macros::langid;
+
+        let provider = icu_testdata::get_provider();
+        let samples = vec![
+            // The "x" family formats the zero offset numerically.
+            ("x", "Z", "+00"),
+            ("xx", "Z", "+0000"),
+            ("xxx", "Z", "+00:00"),
+            ("xxxx", "Z", "+0000"),
+            ("xxxxx", "Z", "+00:00"),
+            ("x", "+05:30", "+0530"),
+            ("xx", "+05:30", "+0530"),
+            ("xxx", "+05:30", "+05:30"),
+            ("x", "-07:00", "-07"),
+            ("xxx", "-07:00", "-07:00"),
+            // The "X" family formats the zero offset with the UTC indicator.
+            ("X", "Z", "Z"),
+            ("XX", "Z", "Z"),
+            ("XXX", "Z", "Z"),
+            ("XXXX", "Z", "Z"),
+            ("XXXXX", "Z", "Z"),
+            ("X", "+05:45", "+0545"),
+            ("XX", "+05:45", "+0545"),
+            ("XXX", "+05:45", "+05:45"),
+            ("X", "-07:00", "-07"),
+            ("XXXXX", "-07:00", "-07:00"),
+        ];
+        for (pattern, offset, expected) in samples {
+            let pattern = Pattern::from_bytes(pattern).unwrap();
+            let tzf = TimeZoneFormat::try_new(langid!("en"), pattern, &provider).unwrap();
+            let time_zone = MockTimeZone::new(offset.parse().unwrap(), None, None, None);
+            assert_eq!(tzf.format_to_string(&time_zone), expected);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "provider_serde")]
+    fn test_localized_gmt_format_invalid_length() {
+        use icu_locid_macros::langid;
+
+        let provider = icu_testdata::get_provider();
+        for pattern in &["OO", "OOO", "OOOOO"] {
+            let pattern = Pattern::from_bytes(pattern).unwrap();
+            assert!(TimeZoneFormat::try_new(langid!("en"), pattern, &provider).is_err());
+        }
+    }
+}
@@ -18,20 +18,27 @@ use crate::{
     DateTimeFormatError,
 };
 
-// TODO(#622) link [`TimeZoneFormat`] once it is public.
-/// The composition of [`DateTimeFormat`] and `TimeZoneFormat`.
+/// The composition of [`DateTimeFormat`] and [`TimeZoneFormat`].
 ///
 /// [`ZonedDateTimeFormat`] uses data from the [`DataProvider`]s, the selected [`Locale`], and the
 /// provided pattern to collect all data necessary to format a datetime with time zones into that locale.
 ///
 /// The various pattern symbols specified in UTS-35 require different sets of data for formatting.
-/// As such, `TimeZoneFormat` will pull in only the resources it needs to format that pattern
+/// As such, [`TimeZoneFormat`] will pull in only the resources it needs to format that pattern
 /// that is derived from the provided [`DateTimeFormatOptions`].
 ///
 /// For that reason, one should think of the process of formatting a zoned datetime in two steps:
 /// first, a computationally heavy construction of [`ZonedDateTimeFormat`], and then fast formatting
 /// of the data using the instance.
 ///
+/// The time zone is written in place of the time zone fields (`z`, `v`, `O`, `V`, etc.) of the
+/// resolved pattern, in the format they specify. For example, the `en` pattern for
+/// [`length::Time::Long`](crate::options::length::Time::Long) ends in `z` (e.g. "PDT"), while the
+/// one for [`length::Time::Full`](crate::options::length::Time::Full) ends in `zzzz`
+/// (e.g. "Pacific Daylight Time"). If the pattern has no time zone field, such as for
+/// [`length::Time::Medium`](crate::options::length::Time::Medium) in `en`, the time zone is not
+/// written at all.
+///
 /// # Examples
 ///
 /// ```
@@ -101,6 +108,8 @@ impl<'d> ZonedDateTimeFormat<'d> {
         L: Into<Locale>,
         DP: DataProvider<'d, 'd, provider::gregory::DatePatternsV1Marker>
             + DataProvider<'d, 'd, provider::gregory::DateSymbolsV1Marker>
+            + DataProvider<'d, 'd, provider::eras::ErasV1Marker>
+            + DataProvider<'d, 'd, provider::week_data::WeekDataV1Marker>
             + ?Sized,
         ZP: DataProvider<'d, 'd, provider::time_zones::TimeZoneFormatsV1Marker>
             + DataProvider<'d, 'd, provider::time_zones::ExemplarCitiesV1Marker>
@@ -110,7 +119,9 @@ impl<'d> ZonedDateTimeFormat<'d> {
             + DataProvider<'d, 'd, provider::time_zones::MetaZoneSpecificNamesShortV1Marker>
             + ?Sized,
     {
-        let locale = locale.into();
+        options.validate()?;
+        let mut locale = locale.into();
+        let calendar = options.resolve_calendar(&mut locale);
         let pattern_data: icu_provider::DataPayload<
             '_,
             '_,
@@ -120,7 +131,7 @@ impl<'d> ZonedDateTimeFormat<'d> {
                 resource_path: ResourcePath {
                     key: provider::key::GREGORY_DATE_PATTERNS_V1,
                     options: ResourceOptions {
-                        variant: None,
+                        variant: calendar.patterns_variant(),
                         langid: Some(locale.clone().into()),
                     },
                 },
@@ -132,10 +143,10 @@ impl<'d> ZonedDateTimeFormat<'d> {
             .get_pattern_for_options(options)?
             .unwrap_or_default();
 
-        let requires_data = datetime::analyze_pattern(&pattern, true)
+        let required = datetime::analyze_pattern(&pattern, true)
             .map_err(|field| DateTimeFormatError::UnsupportedField(field.symbol))?;
 
-        let symbols_data = if requires_data {
+        let symbols_data = if required.symbols {
             Some(
                 date_provider
                     .load_payload(&DataRequest {
@@ -153,7 +164,44 @@ impl<'d> ZonedDateTimeFormat<'d> {
             None
         };
 
-        let datetime_format = DateTimeFormat::new(locale, pattern, symbols_data);
+        let eras_data = if required.eras {
+            Some(
+                date_provider
+                    .load_payload(&DataRequest {
+                        resource_path: ResourcePath {
+                            key: provider::key::ERAS_V1,
+                            options: ResourceOptions {
+                                variant: Some(calendar.as_str().into()),
+                                langid: Some(locale.clone().into()),
+                            },
+                        },
+                    })?
+                    .take_payload()?,
+            )
+        } else {
+            None
+        };
+
+        let week_data = if required.week_data {
+            Some(
+                date_provider
+                    .load_payload(&DataRequest {
+                        resource_path: ResourcePath {
+                            key: provider::key::WEEK_DATA_V1,
+                            options: ResourceOptions {
+                                variant: None,
+                                langid: Some(locale.clone().into()),
+                            },
+                        },
+                    })?
+                    .take_payload()?,
+            )
+        } else {
+            None
+        };
+
+        let datetime_format =
+            DateTimeFormat::new(locale, pattern, symbols_data, eras_data, week_data);
         let time_zone_format = TimeZoneFormat::try_new(
             datetime_format.locale.clone(),
             datetime_format.pattern.clone(),
@@ -13,8 +13,10 @@ use icu_datetime::{
 };
 use icu_datetime::{
     provider::{
+        eras::ErasV1Marker,
         gregory::{DatePatternsV1, DatePatternsV1Marker, DateSymbolsV1, DateSymbolsV1Marker},
         key::{GREGORY_DATE_PATTERNS_V1, GREGORY_DATE_SYMBOLS_V1},
+        week_data::WeekDataV1Marker,
     },
     DateTimeFormat,
 };
@@ -55,6 +57,28 @@ impl<'d, 's> DataProvider<'d, 's, DatePatternsV1Marker> for MultiKeyStructProvid
     }
 }
 
+impl<'d, 's> DataProvider<'d, 's, WeekDataV1Marker> for MultiKeyStructProvider<'s> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'d, 's, WeekDataV1Marker>, icu_provider::DataError> {
+        Err(icu_provider::DataError::UnsupportedResourceKey(
+            req.resource_path.key,
+        ))
+    }
+}
+
+impl<'d, 's> DataProvider<'d, 's, ErasV1Marker> for MultiKeyStructProvider<'s> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'d, 's, ErasV1Marker>, icu_provider::DataError> {
+        Err(icu_provider::DataError::UnsupportedResourceKey(
+            req.resource_path.key,
+        ))
+    }
+}
+
 fn test_fixture(fixture_name: &str) {
     let provider = icu_testdata::get_provider();
 
@@ -302,6 +326,21 @@ fn test_length_fixtures() {
     );
 }
 
+/// Tests length::Bag configurations with a preferred hour cycle.
+#[test]
+fn test_length_hour_cycle() {
+    // components/datetime/tests/fixtures/tests/lengths-hour-cycle.json
+    test_fixture("lengths-hour-cycle");
+}
+
+/// Tests length::Bag configurations with a preferred calendar, and locales that select a
+/// calendar with the `ca` keyword.
+#[test]
+fn test_length_calendars() {
+    // components/datetime/tests/fixtures/tests/lengths-calendars.json
+    test_fixture("lengths-calendars");
+}
+
 /// Tests component::Bag configurations that have exact matches to CLDR skeletons.
 #[test]
 fn test_components_exact_matches() {
@@ -310,11 +349,9 @@ fn test_components_exact_matches() {
 }
 
 /// Tests that component::Bags can adjust for width differences in the final pattern.
-/// TODO(584) - This is unimplemented and will panic.
 #[test]
-#[should_panic]
 fn test_components_width_differences() {
-    // components/datetime/tests/fixtures/tests/components-exact-matches.json
+    // components/datetime/tests/fixtures/tests/components-width-differences.json
     test_fixture("components-width-differences");
 }
 
@@ -325,6 +362,13 @@ fn test_components_combine_datetime() {
     test_fixture("components-combine-date-time");
 }
 
+/// Tests skeleton options, which can adjust for width differences in the final pattern.
+#[test]
+fn test_skeletons_options() {
+    // components/datetime/tests/fixtures/tests/skeletons-options.json
+    test_fixture("skeletons-options");
+}
+
 #[test]
 fn constructing_datetime_format_with_time_zone_pattern_symbols_is_err() {
     use icu_datetime::{
@@ -346,3 +390,96 @@ fn constructing_datetime_format_with_time_zone_pattern_symbols_is_err() {
 
     assert!(result.is_err());
 }
+
+#[test]
+fn constructing_datetime_format_with_empty_options_is_err() {
+    use icu_datetime::{
+        options::{components, length},
+        DateTimeFormatError, DateTimeFormatOptions,
+    };
+    use icu_locid::Locale;
+    use icu_locid_macros::langid;
+
+    let locale: Locale = langid!("en").into();
+    let provider = icu_testdata::get_provider();
+
+    let options = DateTimeFormatOptions::Length(length::Bag {
+        date: None,
+        time: None,
+        preferences: None,
+    });
+    let result = DateTimeFormat::try_new(locale.clone(), &provider, &options);
+    assert!(matches!(result, Err(DateTimeFormatError::EmptyOptions)));
+
+    let options = DateTimeFormatOptions::Components(components::Bag::default());
+    let result = DateTimeFormat::try_new(locale.clone(), &provider, &options);
+    assert!(matches!(result, Err(DateTimeFormatError::EmptyOptions)));
+
+    let options = DateTimeFormatOptions::Components(components::Bag {
+        hour: Some(components::Numeric::Numeric),
+        second: Some(components::Numeric::Numeric),
+        ..Default::default()
+    });
+    let result = DateTimeFormat::try_new(locale, &provider, &options);
+    assert!(matches!(
+        result,
+        Err(DateTimeFormatError::InvalidOptions(_))
+    ));
+}
+
+#[test]
+fn constructing_datetime_format_with_era_components_is_err() {
+    use icu_datetime::{options::components, DateTimeFormatError, DateTimeFormatOptions};
+    use icu_locid::Locale;
+    use icu_locid_macros::langid;
+
+    let locale: Locale = langid!("en").into();
+    let provider = icu_testdata::get_provider();
+
+    let options = DateTimeFormatOptions::Components(components::Bag {
+        era: Some(components::Text::Short),
+        year: Some(components::Numeric::Numeric),
+        month: Some(components::Month::Short),
+        day: Some(components::Numeric::Numeric),
+        ..Default::default()
+    });
+    let result = DateTimeFormat::try_new(locale, &provider, &options);
+    assert!(matches!(
+        result,
+        Err(DateTimeFormatError::UnsupportedField(_))
+    ));
+}
+
+#[test]
+fn test_resolved_pattern() {
+    use icu_datetime::options::{length, preferences};
+    use icu_locid_macros::langid;
+
+    let provider = icu_testdata::get_provider();
+    let resolve = |locale: LanguageIdentifier, options: length::Bag| {
+        DateTimeFormat::try_new(locale, &provider, &options.into())
+            .unwrap()
+            .resolved_pattern()
+            .to_string()
+    };
+
+    let options = length::Bag {
+        date: Some(length::Date::Medium),
+        time: Some(length::Time::Short),
+        preferences: None,
+    };
+    assert_eq!(resolve(langid!("en"), options.clone()), "MMM d, y, h:mm a");
+    assert_eq!(
+        resolve(
+            langid!("en"),
+            length::Bag {
+                preferences: Some(preferences::Bag {
+                    hour_cycle: Some(preferences::HourCycle::H23),
+                    calendar: None,
+                }),
+                ..options
+            }
+        ),
+        "MMM d, y, HH:mm"
+    );
+}
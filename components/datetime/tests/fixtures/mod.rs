@@ -21,5 +21,6 @@ pub fn get_options(input: &structs::TestOptions) -> DateTimeFormatOptions {
     match input {
         structs::TestOptions::Length(bag) => (*bag).clone().into(),
         structs::TestOptions::Components(bag) => (*bag).clone().into(),
+        structs::TestOptions::Skeleton(skeleton) => (*skeleton).clone().into(),
     }
 }
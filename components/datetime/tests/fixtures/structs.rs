@@ -8,6 +8,7 @@
 //! components/datetime/tests/fixtures/tests
 
 use icu_datetime::options::{components, length};
+use icu_datetime::skeleton::Skeleton;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -33,6 +34,8 @@ pub enum TestOptions {
     Length(length::Bag),
     #[serde(rename = "components")]
     Components(components::Bag),
+    #[serde(rename = "skeleton")]
+    Skeleton(Skeleton),
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -346,6 +346,33 @@ pub mod plurals {
     pub use icu_plurals::*;
 }
 
+pub mod relativetime {
+    //! Relative time formatting operations
+    //!
+    //! This API provides functionality to format relative times, such as "yesterday" or
+    //! "in 3 days".
+    //!
+    //! [`RelativeTimeFormat`] is the main structure of the component. It formats an offset in a
+    //! [`RelativeTimeUnit`], or the offset between two datetimes, to a
+    //! [`FormattedRelativeTime`]. It uses a phrase of the locale when there is one for the
+    //! offset, and formats the number of units otherwise.
+    //!
+    //! # Examples
+    //!
+    //! ```
+    //! use icu::locid::Locale;
+    //! use icu::relativetime::{RelativeTimeFormat, RelativeTimeUnit};
+    //!
+    //! let provider = icu_provider::inv::InvariantDataProvider;
+    //! let rtf = RelativeTimeFormat::try_new(Locale::und(), &provider, Default::default())
+    //!     .expect("Data should load successfully");
+    //!
+    //! assert_eq!(rtf.format(3, RelativeTimeUnit::Day).to_string(), "+3 d");
+    //! assert_eq!(rtf.format(-2, RelativeTimeUnit::Week).to_string(), "-2 w");
+    //! ```
+    pub use icu_relativetime::*;
+}
+
 pub mod uniset {
     //! Unicode Set operations
     //!
@@ -83,6 +83,12 @@ fn maximize_bench(c: &mut Criterion) {
         })
     });
 
+    group.bench_function("maximize_all", |b| {
+        b.iter(|| {
+            lc.maximize_all(&mut locales.clone());
+        })
+    });
+
     group.finish();
 }
 
@@ -17,6 +17,12 @@
 //! This minimize method returns a new Locale that is the result of running the
 //! 'Remove Likely Subtags' algorithm from [`UTS #35: Unicode LDML 3. Likely Subtags`].
 //!
+//! The canonicalize method potentially updates a passed in locale in place
+//! by applying the language, script, region and variant aliases from CLDR, as
+//! described in [`UTS #35: Unicode LDML 3.2.1 Canonical Unicode Locale Identifiers`].
+//! For example, `iw` becomes `he`, `sh` becomes `sr-Latn` and the region `BU`
+//! becomes `MM`.
+//!
 //! # Examples
 //!
 //! ```
@@ -74,8 +80,9 @@
 //! [`ICU4X`]: ../icu/index.html
 //! [`CLDR`]: http://cldr.unicode.org/
 //! [`UTS #35: Unicode LDML 3. Likely Subtags`]: https://www.unicode.org/reports/tr35/#Likely_Subtags.
+//! [`UTS #35: Unicode LDML 3.2.1 Canonical Unicode Locale Identifiers`]: https://www.unicode.org/reports/tr35/#Canonical_Unicode_Locale_Identifiers
 
 pub mod locale_canonicalizer;
 pub mod provider;
 
-pub use locale_canonicalizer::{CanonicalizationResult, LocaleCanonicalizer};
+pub use locale_canonicalizer::{CanonicalizationResult, LocaleCanonicalizer, ModifiedSubtags};
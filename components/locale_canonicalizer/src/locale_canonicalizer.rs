@@ -4,7 +4,10 @@
 
 use crate::provider::*;
 use icu_locid::{
-    extensions::unicode::{Key, Value},
+    extensions::{
+        transform::Fields,
+        unicode::{Attributes, Key, Keywords, Value},
+    },
     subtags, LanguageIdentifier, Locale,
 };
 use icu_provider::prelude::*;
@@ -17,6 +20,35 @@ pub enum CanonicalizationResult {
     Unmodified,
 }
 
+/// The subtags that were modified by [`LocaleCanonicalizer::maximize_with_details`] or
+/// [`LocaleCanonicalizer::minimize_with_details`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ModifiedSubtags {
+    /// Whether the language subtag was added or changed.
+    pub language: bool,
+    /// Whether the script subtag was added, changed, or removed.
+    pub script: bool,
+    /// Whether the region subtag was added, changed, or removed.
+    pub region: bool,
+}
+
+impl ModifiedSubtags {
+    /// Returns whether no subtag was modified.
+    pub fn is_empty(&self) -> bool {
+        !(self.language || self.script || self.region)
+    }
+}
+
+impl From<ModifiedSubtags> for CanonicalizationResult {
+    fn from(modified: ModifiedSubtags) -> Self {
+        if modified.is_empty() {
+            CanonicalizationResult::Unmodified
+        } else {
+            CanonicalizationResult::Modified
+        }
+    }
+}
+
 pub struct LocaleCanonicalizer<'d, 's> {
     aliases: DataPayload<'d, 's, AliasesV1Marker>,
     likely_subtags: DataPayload<'d, 's, LikelySubtagsV1Marker>,
@@ -95,17 +127,56 @@ fn uts35_check_language_rules(
     CanonicalizationResult::Unmodified
 }
 
+/// Sorts the attributes, keywords and transform fields of the locale and removes duplicates,
+/// keeping the first occurrence of a duplicate key, as required by the canonical form of
+/// extensions in UTS #35.
+fn canonicalize_extensions(locale: &mut Locale) -> CanonicalizationResult {
+    let mut result = CanonicalizationResult::Unmodified;
+
+    let mut attributes = locale.extensions.unicode.attributes.to_vec();
+    attributes.sort();
+    attributes.dedup();
+    if attributes[..] != locale.extensions.unicode.attributes[..] {
+        locale.extensions.unicode.attributes = Attributes::from_vec_unchecked(attributes);
+        result = CanonicalizationResult::Modified;
+    }
+
+    // The sorts are stable, so only the first occurrence of a duplicate key is kept.
+    let mut keywords = locale.extensions.unicode.keywords.to_vec();
+    keywords.sort_by_key(|(key, _)| *key);
+    keywords.dedup_by_key(|(key, _)| *key);
+    if keywords[..] != locale.extensions.unicode.keywords[..] {
+        locale.extensions.unicode.keywords = Keywords::from_vec_unchecked(keywords);
+        result = CanonicalizationResult::Modified;
+    }
+
+    let mut fields = locale.extensions.transform.fields.to_vec();
+    fields.sort_by_key(|(key, _)| *key);
+    fields.dedup_by_key(|(key, _)| *key);
+    if fields[..] != locale.extensions.transform.fields[..] {
+        locale.extensions.transform.fields = Fields::from_vec_unchecked(fields);
+        result = CanonicalizationResult::Modified;
+    }
+
+    result
+}
+
 #[inline]
-fn update_langid(
-    entry: &LanguageIdentifier,
-    langid: &mut LanguageIdentifier,
-) -> CanonicalizationResult {
-    if langid.language.is_empty() {
+fn update_langid(entry: &LanguageIdentifier, langid: &mut LanguageIdentifier) -> ModifiedSubtags {
+    let mut modified = ModifiedSubtags::default();
+    if langid.language.is_empty() && !entry.language.is_empty() {
         langid.language = entry.language;
+        modified.language = true;
+    }
+    if langid.script.is_none() && entry.script.is_some() {
+        langid.script = entry.script;
+        modified.script = true;
     }
-    langid.script = langid.script.or(entry.script);
-    langid.region = langid.region.or(entry.region);
-    CanonicalizationResult::Modified
+    if langid.region.is_none() && entry.region.is_some() {
+        langid.region = entry.region;
+        modified.region = true;
+    }
+    modified
 }
 
 macro_rules! maximize_locale {
@@ -156,6 +227,9 @@ impl<'d, 's> LocaleCanonicalizer<'d, 's> {
     /// depending up the results of running the canonicalization algorithm
     /// from http://unicode.org/reports/tr35/#LocaleId_Canonicalization
     ///
+    /// The attributes, keywords and transform fields of the extensions are sorted, and only
+    /// the first occurrence of a duplicate key is kept.
+    ///
     /// Some BCP47 canonicalization data is not part of the CLDR json package. Because
     /// of this, some canonicalizations are not performed, e.g. the canonicalization of
     /// `und-u-ca-islamicc` to `und-u-ca-islamic-civil`. This will be fixed in a future
@@ -358,6 +432,10 @@ impl<'d, 's> LocaleCanonicalizer<'d, 's> {
 
         // Handle Locale extensions in their own loops, because these rules do not interact
         // with each other.
+        if canonicalize_extensions(locale) == CanonicalizationResult::Modified {
+            result = CanonicalizationResult::Modified;
+        }
+
         if let Some(lang) = &locale.extensions.transform.lang {
             let mut tlang: Locale = lang.clone().into();
             let mut matched = false;
@@ -411,6 +489,9 @@ impl<'d, 's> LocaleCanonicalizer<'d, 's> {
     /// returns [`CanonicalizationResult::Unmodified`] and the locale argument is
     /// unchanged.
     ///
+    /// Only the language, script, and region subtags are modified. Variants and
+    /// extensions are carried through unchanged.
+    ///
     /// # Examples
     ///
     /// ```
@@ -430,32 +511,81 @@ impl<'d, 's> LocaleCanonicalizer<'d, 's> {
     ///     .expect("parse failed");
     /// assert_eq!(lc.maximize(&mut locale), CanonicalizationResult::Unmodified);
     /// assert_eq!(locale.to_string(), "zh-Hant-TW");
+    ///
+    /// let mut locale : Locale = "en-u-ca-gregory".parse()
+    ///     .expect("parse failed");
+    /// assert_eq!(lc.maximize(&mut locale), CanonicalizationResult::Modified);
+    /// assert_eq!(locale.to_string(), "en-Latn-US-u-ca-gregory");
     /// ```
-    pub fn maximize<T: AsMut<LanguageIdentifier>>(&self, mut langid: T) -> CanonicalizationResult {
-        let langid = langid.as_mut();
-        let data = self.likely_subtags.get();
+    pub fn maximize<T: AsMut<LanguageIdentifier>>(&self, langid: T) -> CanonicalizationResult {
+        self.maximize_with_details(langid).into()
+    }
 
-        if !langid.language.is_empty() && langid.script.is_some() && langid.region.is_some() {
-            return CanonicalizationResult::Unmodified;
-        }
+    /// Like [`LocaleCanonicalizer::maximize`], but reports which subtags were modified.
+    ///
+    /// Since maximization only adds missing subtags, subtags present in the input are never
+    /// reported as modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locale_canonicalizer::{LocaleCanonicalizer, ModifiedSubtags};
+    /// use icu_locid::Locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let lc = LocaleCanonicalizer::new(&provider)
+    ///     .expect("create failed");
+    ///
+    /// let mut locale : Locale = "zh-CN".parse()
+    ///     .expect("parse failed");
+    /// assert_eq!(
+    ///     lc.maximize_with_details(&mut locale),
+    ///     ModifiedSubtags { language: false, script: true, region: false }
+    /// );
+    /// assert_eq!(locale.to_string(), "zh-Hans-CN");
+    /// ```
+    pub fn maximize_with_details<T: AsMut<LanguageIdentifier>>(
+        &self,
+        mut langid: T,
+    ) -> ModifiedSubtags {
+        maximize_langid(self.likely_subtags.get(), langid.as_mut())
+    }
 
-        if let Some(language) = langid.language.into() {
-            if let Some(region) = langid.region {
-                maximize_locale!(langid, data.language_region, language, region.into());
-            }
-            if let Some(script) = langid.script {
-                maximize_locale!(langid, data.language_script, language, script.into());
-            }
-            maximize_locale!(langid, data.language, language);
-        } else if let Some(script) = langid.script {
-            if let Some(region) = langid.region {
-                maximize_locale!(langid, data.script_region, script.into(), region.into());
-            }
-            maximize_locale!(langid, data.script, script.into());
-        } else if let Some(region) = langid.region {
-            maximize_locale!(langid, data.region, region.into());
-        }
-        update_langid(&data.und, langid)
+    /// Runs [`LocaleCanonicalizer::maximize`] on every locale of a batch, and returns the
+    /// number of locales that were modified.
+    ///
+    /// The likely subtags data is only fetched once for the whole batch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locale_canonicalizer::LocaleCanonicalizer;
+    /// use icu_locid::Locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let lc = LocaleCanonicalizer::new(&provider)
+    ///     .expect("create failed");
+    ///
+    /// let mut locales: Vec<Locale> = ["zh-CN", "zh-Hant-TW", "en"]
+    ///     .iter()
+    ///     .map(|s| s.parse().expect("parse failed"))
+    ///     .collect();
+    /// assert_eq!(lc.maximize_all(&mut locales), 2);
+    /// assert_eq!(locales[0].to_string(), "zh-Hans-CN");
+    /// assert_eq!(locales[1].to_string(), "zh-Hant-TW");
+    /// assert_eq!(locales[2].to_string(), "en-Latn-US");
+    /// ```
+    pub fn maximize_all<I>(&self, langids: I) -> usize
+    where
+        I: IntoIterator,
+        I::Item: AsMut<LanguageIdentifier>,
+    {
+        let data = self.likely_subtags.get();
+        langids
+            .into_iter()
+            .map(|mut langid| maximize_langid(data, langid.as_mut()))
+            .filter(|modified| !modified.is_empty())
+            .count()
     }
 
     /// This returns a new Locale that is the result of running the
@@ -468,6 +598,9 @@ impl<'d, 's> LocaleCanonicalizer<'d, 's> {
     /// returns [`CanonicalizationResult::Unmodified`] and the locale argument is
     /// unchanged.
     ///
+    /// Only the language, script, and region subtags are modified. Variants and
+    /// extensions are carried through unchanged.
+    ///
     /// # Examples
     ///
     /// ```
@@ -488,61 +621,133 @@ impl<'d, 's> LocaleCanonicalizer<'d, 's> {
     /// assert_eq!(lc.minimize(&mut locale), CanonicalizationResult::Unmodified);
     /// assert_eq!(locale.to_string(), "zh");
     /// ```
-    pub fn minimize<T: AsMut<LanguageIdentifier>>(&self, mut langid: T) -> CanonicalizationResult {
-        let langid = langid.as_mut();
-
-        let mut max = langid.clone();
-        self.maximize(&mut max);
-        max.variants.clear();
-        let mut trial = max.clone();
-
-        trial.script = None;
-        trial.region = None;
-        self.maximize(&mut trial);
-        if trial == max {
-            if langid.script.is_some() || langid.script.is_some() {
-                langid.script = None;
-                langid.region = None;
-                return CanonicalizationResult::Modified;
-            } else {
-                return CanonicalizationResult::Unmodified;
-            }
-        }
+    pub fn minimize<T: AsMut<LanguageIdentifier>>(&self, langid: T) -> CanonicalizationResult {
+        self.minimize_with_details(langid).into()
+    }
 
-        trial.script = None;
-        trial.region = max.region;
-        self.maximize(&mut trial);
-        if trial == max {
-            if langid.script.is_some() || langid.region != max.region {
-                langid.script = None;
-                langid.region = max.region;
-                return CanonicalizationResult::Modified;
-            } else {
-                return CanonicalizationResult::Unmodified;
-            }
-        }
+    /// Like [`LocaleCanonicalizer::minimize`], but reports which subtags were modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locale_canonicalizer::{LocaleCanonicalizer, ModifiedSubtags};
+    /// use icu_locid::Locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let lc = LocaleCanonicalizer::new(&provider)
+    ///     .expect("creation failed");
+    ///
+    /// let mut locale : Locale = "zh-Hant-TW".parse()
+    ///     .expect("parse failed");
+    /// assert_eq!(
+    ///     lc.minimize_with_details(&mut locale),
+    ///     ModifiedSubtags { language: false, script: true, region: false }
+    /// );
+    /// assert_eq!(locale.to_string(), "zh-TW");
+    /// ```
+    pub fn minimize_with_details<T: AsMut<LanguageIdentifier>>(
+        &self,
+        mut langid: T,
+    ) -> ModifiedSubtags {
+        minimize_langid(self.likely_subtags.get(), langid.as_mut())
+    }
 
-        trial.script = max.script;
-        trial.region = None;
-        self.maximize(&mut trial);
-        if trial == max {
-            if langid.script != max.script || langid.region.is_some() {
-                langid.script = max.script;
-                langid.region = None;
-                return CanonicalizationResult::Modified;
-            } else {
-                return CanonicalizationResult::Unmodified;
-            }
-        }
+    /// Runs [`LocaleCanonicalizer::minimize`] on every locale of a batch, and returns the
+    /// number of locales that were modified.
+    ///
+    /// The likely subtags data is only fetched once for the whole batch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_locale_canonicalizer::LocaleCanonicalizer;
+    /// use icu_locid::Locale;
+    ///
+    /// let provider = icu_testdata::get_provider();
+    /// let lc = LocaleCanonicalizer::new(&provider)
+    ///     .expect("create failed");
+    ///
+    /// let mut locales: Vec<Locale> = ["zh-Hans-CN", "zh-TW", "en-Latn-US"]
+    ///     .iter()
+    ///     .map(|s| s.parse().expect("parse failed"))
+    ///     .collect();
+    /// assert_eq!(lc.minimize_all(&mut locales), 2);
+    /// assert_eq!(locales[0].to_string(), "zh");
+    /// assert_eq!(locales[1].to_string(), "zh-TW");
+    /// assert_eq!(locales[2].to_string(), "en");
+    /// ```
+    pub fn minimize_all<I>(&self, langids: I) -> usize
+    where
+        I: IntoIterator,
+        I::Item: AsMut<LanguageIdentifier>,
+    {
+        let data = self.likely_subtags.get();
+        langids
+            .into_iter()
+            .map(|mut langid| minimize_langid(data, langid.as_mut()))
+            .filter(|modified| !modified.is_empty())
+            .count()
+    }
+}
 
-        if langid.script != max.script || langid.region != max.region {
-            langid.script = max.script;
-            langid.region = max.region;
-            CanonicalizationResult::Modified
-        } else {
-            CanonicalizationResult::Unmodified
+/// Runs the 'Add Likely Subtags' algorithm on `langid` using the likely subtags `data`.
+fn maximize_langid(data: &LikelySubtagsV1, langid: &mut LanguageIdentifier) -> ModifiedSubtags {
+    if !langid.language.is_empty() && langid.script.is_some() && langid.region.is_some() {
+        return ModifiedSubtags::default();
+    }
+
+    if let Some(language) = langid.language.into() {
+        if let Some(region) = langid.region {
+            maximize_locale!(langid, data.language_region, language, region.into());
+        }
+        if let Some(script) = langid.script {
+            maximize_locale!(langid, data.language_script, language, script.into());
         }
+        maximize_locale!(langid, data.language, language);
+    } else if let Some(script) = langid.script {
+        if let Some(region) = langid.region {
+            maximize_locale!(langid, data.script_region, script.into(), region.into());
+        }
+        maximize_locale!(langid, data.script, script.into());
+    } else if let Some(region) = langid.region {
+        maximize_locale!(langid, data.region, region.into());
     }
+    update_langid(&data.und, langid)
+}
+
+/// Runs the 'Remove Likely Subtags' algorithm on `langid` using the likely subtags `data`.
+fn minimize_langid(data: &LikelySubtagsV1, langid: &mut LanguageIdentifier) -> ModifiedSubtags {
+    let mut max = langid.clone();
+    maximize_langid(data, &mut max);
+    max.variants.clear();
+
+    // Find the shortest combination of script and region that maximizes back to `max`,
+    // trying the script-less forms first.
+    let candidates = [(None, None), (None, max.region), (max.script, None)];
+    let (script, region) = candidates
+        .iter()
+        .copied()
+        .find(|&(script, region)| {
+            let mut trial = LanguageIdentifier {
+                language: max.language,
+                script,
+                region,
+                variants: subtags::Variants::default(),
+            };
+            maximize_langid(data, &mut trial);
+            trial == max
+        })
+        .unwrap_or((max.script, max.region));
+
+    let modified = ModifiedSubtags {
+        language: langid.language != max.language,
+        script: langid.script != script,
+        region: langid.region != region,
+    };
+    langid.language = max.language;
+    langid.script = script;
+    langid.region = region;
+    modified
 }
 
 #[test]
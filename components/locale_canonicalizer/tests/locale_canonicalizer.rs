@@ -5,7 +5,8 @@
 mod fixtures;
 mod helpers;
 
-use icu_locale_canonicalizer::{CanonicalizationResult, LocaleCanonicalizer};
+use icu_locale_canonicalizer::{CanonicalizationResult, LocaleCanonicalizer, ModifiedSubtags};
+use icu_locid::extensions::unicode::{Key, Keywords, Value};
 use icu_locid::Locale;
 
 #[test]
@@ -33,6 +34,105 @@ fn test_maximize() {
     }
 }
 
+#[test]
+fn test_maximize_all_and_minimize_all() {
+    let provider = icu_testdata::get_provider();
+    let lc = LocaleCanonicalizer::new(&provider).unwrap();
+
+    for (path, maximize) in &[
+        ("./tests/fixtures/maximize.json", true),
+        ("./tests/fixtures/minimize.json", false),
+    ] {
+        let testcases: Vec<fixtures::CanonicalizationTest> =
+            helpers::read_fixture(path).expect("Failed to read a fixture");
+        let testcases: Vec<_> = testcases
+            .into_iter()
+            .filter(|case| case.disabled != Some(true))
+            .collect();
+
+        let mut locales: Vec<Locale> = testcases
+            .iter()
+            .map(|case| case.input.parse().unwrap())
+            .collect();
+        let expected_count = locales
+            .iter()
+            .filter(|locale| {
+                let mut locale = (*locale).clone();
+                let result = if *maximize {
+                    lc.maximize(&mut locale)
+                } else {
+                    lc.minimize(&mut locale)
+                };
+                result == CanonicalizationResult::Modified
+            })
+            .count();
+
+        let count = if *maximize {
+            lc.maximize_all(&mut locales)
+        } else {
+            lc.minimize_all(&mut locales)
+        };
+        assert_eq!(count, expected_count, "{}", path);
+        for (locale, case) in locales.iter().zip(testcases.iter()) {
+            assert_eq!(locale.to_string(), case.output);
+        }
+    }
+}
+
+#[test]
+fn test_modified_subtags() {
+    let provider = icu_testdata::get_provider();
+    let lc = LocaleCanonicalizer::new(&provider).unwrap();
+
+    let cases = [
+        ("en", "en-Latn-US", (false, true, true)),
+        ("en-GB", "en-Latn-GB", (false, true, false)),
+        ("und-Latn", "en-Latn-US", (true, false, true)),
+        ("en-Latn-US", "en-Latn-US", (false, false, false)),
+    ];
+    for (input, output, (language, script, region)) in cases.iter() {
+        let mut locale: Locale = input.parse().unwrap();
+        let modified = lc.maximize_with_details(&mut locale);
+        assert_eq!(locale.to_string(), *output);
+        assert_eq!(
+            modified,
+            ModifiedSubtags {
+                language: *language,
+                script: *script,
+                region: *region,
+            },
+            "{}",
+            input
+        );
+        assert_eq!(
+            CanonicalizationResult::from(modified) == CanonicalizationResult::Modified,
+            input != output
+        );
+    }
+
+    let cases = [
+        ("en-Latn-US", "en", (false, true, true)),
+        ("en-Latn-GB", "en-GB", (false, true, false)),
+        ("und-Latn-US", "en", (true, true, true)),
+        ("en", "en", (false, false, false)),
+    ];
+    for (input, output, (language, script, region)) in cases.iter() {
+        let mut locale: Locale = input.parse().unwrap();
+        let modified = lc.minimize_with_details(&mut locale);
+        assert_eq!(locale.to_string(), *output);
+        assert_eq!(
+            modified,
+            ModifiedSubtags {
+                language: *language,
+                script: *script,
+                region: *region,
+            },
+            "{}",
+            input
+        );
+    }
+}
+
 #[test]
 fn test_minimize() {
     let provider = icu_testdata::get_provider();
@@ -82,3 +182,31 @@ fn test_canonicalize() {
         }
     }
 }
+
+#[test]
+fn test_canonicalize_extensions() {
+    let provider = icu_testdata::get_provider();
+    let lc = LocaleCanonicalizer::new(&provider).unwrap();
+
+    let keyword =
+        |key: &str, value: &str| -> (Key, Value) { (key.parse().unwrap(), value.parse().unwrap()) };
+
+    // Keywords that were not created by the parser may be unsorted or contain duplicate keys.
+    let mut locale: Locale = "en".parse().unwrap();
+    locale.extensions.unicode.keywords = Keywords::from_vec_unchecked(vec![
+        keyword("nu", "latn"),
+        keyword("ca", "gregory"),
+        keyword("nu", "arab"),
+    ]);
+    assert_eq!(
+        lc.canonicalize(&mut locale),
+        CanonicalizationResult::Modified
+    );
+    assert_eq!(locale.to_string(), "en-u-ca-gregory-nu-latn");
+
+    assert_eq!(
+        lc.canonicalize(&mut locale),
+        CanonicalizationResult::Unmodified
+    );
+    assert_eq!(locale.to_string(), "en-u-ca-gregory-nu-latn");
+}
@@ -41,6 +41,9 @@ use crate::parser::ParserError;
 /// Those extensions are treated as a pass-through, and no Unicode related
 /// behavior depends on them.
 ///
+/// When parsing, the `x` singleton marks the start of the private-use subtags,
+/// and every subtag after it is a [`Key`], in its original order.
+///
 /// # Examples
 ///
 /// ```
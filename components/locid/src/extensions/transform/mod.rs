@@ -168,7 +168,9 @@ impl Transform {
             ));
         }
 
+        // The sort is stable, so only the first occurrence of a duplicate key is kept.
         tfields.sort_by_key(|f| f.0);
+        tfields.dedup_by_key(|f| f.0);
 
         Ok(Self {
             lang: tlang,
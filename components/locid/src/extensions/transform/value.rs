@@ -57,7 +57,9 @@ impl Value {
                 return Err(ParserError::InvalidExtension);
             }
             has_value = true;
-            let val = TinyStr8::from_bytes(subtag).map_err(|_| ParserError::InvalidExtension)?;
+            let val = TinyStr8::from_bytes(subtag)
+                .map_err(|_| ParserError::InvalidExtension)?
+                .to_ascii_lowercase();
             if val != TRUE_TVALUE {
                 v.push(val);
             }
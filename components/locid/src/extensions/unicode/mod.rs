@@ -154,7 +154,12 @@ impl Unicode {
             keywords.push((kw, Value::from_vec_unchecked(current_type)));
         }
 
+        attributes.sort();
+        attributes.dedup();
+
+        // The sort is stable, so only the first occurrence of a duplicate key is kept.
         keywords.sort_by_key(|i| i.0);
+        keywords.dedup_by_key(|i| i.0);
 
         Ok(Self {
             keywords: Keywords::from_vec_unchecked(keywords),
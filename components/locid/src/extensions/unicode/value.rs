@@ -56,8 +56,9 @@ impl Value {
                 if !Self::is_type_subtag(subtag) {
                     return Err(ParserError::InvalidExtension);
                 }
-                let val =
-                    TinyStr8::from_bytes(subtag).map_err(|_| ParserError::InvalidExtension)?;
+                let val = TinyStr8::from_bytes(subtag)
+                    .map_err(|_| ParserError::InvalidExtension)?
+                    .to_ascii_lowercase();
                 if val != TRUE_VALUE {
                     v.push(val);
                 }
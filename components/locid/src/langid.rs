@@ -4,7 +4,10 @@
 
 use std::str::FromStr;
 
-use crate::parser::{get_subtag_iterator, parse_language_identifier, ParserError, ParserMode};
+use crate::parser::{
+    get_subtag_iterator, parse_language_identifier, parse_language_identifier_with_position,
+    ParserError, ParserMode,
+};
 use crate::subtags;
 
 /// A core struct representing a [`Unicode BCP47 Language Identifier`].
@@ -103,6 +106,32 @@ impl LanguageIdentifier {
         parse_language_identifier(v, ParserMode::Locale)
     }
 
+    /// A constructor which takes a utf8 slice, parses it and
+    /// produces a well-formed [`LanguageIdentifier`], or an error together with
+    /// the byte offset of the subtag that failed to parse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::{LanguageIdentifier, ParserError};
+    ///
+    /// let li = LanguageIdentifier::try_from_bytes_with_position(b"en-US")
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(li.to_string(), "en-US");
+    ///
+    /// assert_eq!(
+    ///     LanguageIdentifier::try_from_bytes_with_position(b"en-US-$%"),
+    ///     Err((ParserError::InvalidSubtag, 6))
+    /// );
+    /// assert_eq!(
+    ///     LanguageIdentifier::try_from_bytes_with_position(b"1234-US"),
+    ///     Err((ParserError::InvalidLanguage, 0))
+    /// );
+    /// ```
+    pub fn try_from_bytes_with_position(v: &[u8]) -> Result<Self, (ParserError, usize)> {
+        parse_language_identifier_with_position(v, ParserMode::LanguageIdentifier)
+    }
+
     /// Returns the default undefined language "und". Same as [`default()`](Default::default()), but is `const`.
     ///
     /// # Examples
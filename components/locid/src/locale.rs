@@ -148,6 +148,33 @@ impl Locale {
     ) -> Option<&extensions::unicode::Value> {
         self.extensions.unicode.keywords.get(key)
     }
+
+    /// Returns the [`Private Use Extensions`] of the [`Locale`], in the order in which
+    /// they were specified.
+    ///
+    /// All subtags following the `x` singleton are private-use subtags, including ones
+    /// that look like other singletons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::Locale;
+    ///
+    /// let loc: Locale = "en-x-Foo-bar-u-ca".parse()
+    ///     .expect("Parsing failed.");
+    /// let private: Vec<&str> = loc.private_use()
+    ///     .iter()
+    ///     .map(|key| key.as_str())
+    ///     .collect();
+    /// assert_eq!(private, vec!["foo", "bar", "u", "ca"]);
+    /// assert!(loc.extensions.unicode.is_empty());
+    /// assert_eq!(loc.to_string(), "en-x-foo-bar-u-ca");
+    /// ```
+    ///
+    /// [`Private Use Extensions`]: extensions::private
+    pub fn private_use(&self) -> &extensions::Private {
+        &self.extensions.private
+    }
 }
 
 impl FromStr for Locale {
@@ -27,20 +27,29 @@ pub fn parse_language_identifier_from_iter<'a>(
     iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
     mode: ParserMode,
 ) -> Result<LanguageIdentifier, ParserError> {
+    parse_language_identifier_from_iter_with_subtag(iter, mode).map_err(|(err, _)| err)
+}
+
+/// Same as [`parse_language_identifier_from_iter`], but on failure also returns the
+/// subtag that could not be parsed.
+fn parse_language_identifier_from_iter_with_subtag<'a>(
+    iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
+    mode: ParserMode,
+) -> Result<LanguageIdentifier, (ParserError, &'a [u8])> {
     let language;
     let mut script = None;
     let mut region = None;
     let mut variants = Vec::new();
 
     if let Some(subtag) = iter.next() {
-        language = subtags::Language::from_bytes(subtag)?;
+        language = subtags::Language::from_bytes(subtag).map_err(|err| (err, subtag))?;
     } else {
-        return Err(ParserError::InvalidLanguage);
+        return Err((ParserError::InvalidLanguage, &[]));
     }
 
     let mut position = ParserPosition::Script;
 
-    while let Some(subtag) = iter.peek() {
+    while let Some(&subtag) = iter.peek() {
         if mode != ParserMode::LanguageIdentifier && subtag.len() == 1 {
             break;
         }
@@ -60,7 +69,7 @@ pub fn parse_language_identifier_from_iter<'a>(
             } else if mode == ParserMode::Partial {
                 break;
             } else {
-                return Err(ParserError::InvalidSubtag);
+                return Err((ParserError::InvalidSubtag, subtag));
             }
         } else if position == ParserPosition::Region {
             if let Ok(s) = subtags::Region::from_bytes(subtag) {
@@ -74,7 +83,7 @@ pub fn parse_language_identifier_from_iter<'a>(
             } else if mode == ParserMode::Partial {
                 break;
             } else {
-                return Err(ParserError::InvalidSubtag);
+                return Err((ParserError::InvalidSubtag, subtag));
             }
         } else if let Ok(v) = subtags::Variant::from_bytes(subtag) {
             if let Err(idx) = variants.binary_search(&v) {
@@ -83,7 +92,7 @@ pub fn parse_language_identifier_from_iter<'a>(
         } else if mode == ParserMode::Partial {
             break;
         } else {
-            return Err(ParserError::InvalidSubtag);
+            return Err((ParserError::InvalidSubtag, subtag));
         }
         iter.next();
     }
@@ -103,3 +112,18 @@ pub fn parse_language_identifier(
     let mut iter = get_subtag_iterator(t).peekable();
     parse_language_identifier_from_iter(&mut iter, mode)
 }
+
+/// Same as [`parse_language_identifier`], but on failure also returns the byte offset
+/// of the subtag that could not be parsed.
+pub fn parse_language_identifier_with_position(
+    t: &[u8],
+    mode: ParserMode,
+) -> Result<LanguageIdentifier, (ParserError, usize)> {
+    let mut iter = get_subtag_iterator(t).peekable();
+    parse_language_identifier_from_iter_with_subtag(&mut iter, mode).map_err(|(err, subtag)| {
+        // All subtags are subslices of `t`, apart from the empty slice returned when the
+        // iterator is exhausted, which `get_subtag_iterator` never is at the start.
+        let offset = (subtag.as_ptr() as usize).wrapping_sub(t.as_ptr() as usize);
+        (err, offset.min(t.len()))
+    })
+}
@@ -7,7 +7,10 @@ mod langid;
 mod locale;
 
 pub use errors::ParserError;
-pub use langid::{parse_language_identifier, parse_language_identifier_from_iter, ParserMode};
+pub use langid::{
+    parse_language_identifier, parse_language_identifier_from_iter,
+    parse_language_identifier_with_position, ParserMode,
+};
 pub use locale::parse_locale;
 
 pub fn get_subtag_iterator(t: &[u8]) -> impl Iterator<Item = &[u8]> {
@@ -77,6 +77,28 @@ impl Variants {
         }
     }
 
+    /// Creates a new [`Variants`] set from a [`Vec`], sorting and deduplicating
+    /// the variants into canonical order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::subtags::{Variant, Variants};
+    ///
+    /// let variant1: Variant = "rozaj".parse()
+    ///     .expect("Parsing failed.");
+    /// let variant2: Variant = "biske".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let variants = Variants::from_vec(vec![variant1, variant2, variant1]);
+    /// assert_eq!(variants.to_string(), "biske-rozaj");
+    /// ```
+    pub fn from_vec(mut input: Vec<Variant>) -> Self {
+        input.sort();
+        input.dedup();
+        Self::from_vec_unchecked(input)
+    }
+
     /// Deconstructs the [`Variants`] into raw format to be consumed
     /// by [`from_raw_unchecked()`](Variants::from_raw_unchecked()).
     ///
@@ -116,6 +116,31 @@ fn test_langid_subtag_variants() {
     assert_eq!(variants.get(0).unwrap(), "macos");
     variants.clear();
     assert_eq!(variants.len(), 0);
+
+    let rozaj: subtags::Variant = "rozaj".parse().expect("Failed to parse a variant.");
+    let biske: subtags::Variant = "biske".parse().expect("Failed to parse a variant.");
+    let variants = subtags::Variants::from_vec(vec![rozaj, biske, rozaj]);
+    assert_eq!(&*variants, &[biske, rozaj]);
+    assert_eq!(variants.to_string(), "biske-rozaj");
+}
+
+#[test]
+fn test_langid_error_position() {
+    let cases = vec![
+        ("", ParserError::InvalidLanguage, 0),
+        ("e-US", ParserError::InvalidLanguage, 0),
+        ("en-Latn-U", ParserError::InvalidSubtag, 8),
+        ("en_US_macos_x", ParserError::InvalidSubtag, 12),
+        ("en-US-", ParserError::InvalidSubtag, 6),
+    ];
+    for (input, err, offset) in cases {
+        assert_eq!(
+            LanguageIdentifier::try_from_bytes_with_position(input.as_bytes()),
+            Err((err, offset)),
+            "{}",
+            input
+        );
+    }
 }
 
 #[test]
@@ -10,7 +10,7 @@
 icu_benchmark_macros::static_setup!();
 
 use icu_locid_macros::langid;
-use icu_plurals::{PluralCategory, PluralRuleType, PluralRules};
+use icu_plurals::OrdinalFormatter;
 
 const VALUES: &[usize] = &[0, 2, 25, 1, 3, 2, 4, 10, 7, 0];
 
@@ -32,16 +32,14 @@ fn main(_argc: isize, _argv: *const *const u8) -> isize {
 
     {
         print("\n====== Elevator Floor (en) example ============", None);
-        let pr = PluralRules::try_new(lid, &provider, PluralRuleType::Ordinal)
-            .expect("Failed to create a PluralRules instance.");
+        let of = OrdinalFormatter::try_new(lid, &provider)
+            .expect("Failed to create an OrdinalFormatter instance.");
 
         for value in VALUES {
-            match pr.select(*value) {
-                PluralCategory::One => print("You are on the {}st floor.", Some(*value)),
-                PluralCategory::Two => print("You are on the {}nd floor.", Some(*value)),
-                PluralCategory::Few => print("You are on the {}rd floor.", Some(*value)),
-                _ => print("You are on the {}th floor.", Some(*value)),
-            }
+            print(
+                &format!("You are on the {} floor.", of.format_to_string(*value)),
+                None,
+            );
         }
     }
 
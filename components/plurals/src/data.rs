@@ -3,12 +3,13 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::operands::PluralOperands;
-use crate::provider::PluralRuleStringsV1;
+use crate::provider::{PluralRuleListV1, PluralRuleStringsV1};
 use crate::rules;
 use crate::rules::ast;
 use crate::{PluralCategory, PluralRulesError};
 use std::borrow::Cow;
-use std::convert::TryInto;
+use std::convert::{TryFrom, TryInto};
+use zerovec::ZeroVec;
 
 /// A raw function pointer to a `PluralRulesFn`
 // pub type PluralRulesFn = fn(&PluralOperands) -> PluralCategory;
@@ -59,15 +60,149 @@ fn parse_rule(input: &Option<Cow<str>>) -> Result<Option<ast::Condition>, Plural
     })
 }
 
-impl<'s> TryInto<PluralRuleList> for &PluralRuleStringsV1<'s> {
+// The layout of the header word of a relation in a [`PluralRuleListV1`].
+const OPERAND_MASK: u64 = 0b111;
+const NOT_EQ_FLAG: u64 = 1 << 3;
+const MODULUS_FLAG: u64 = 1 << 4;
+const OR_FLAG: u64 = 1 << 5;
+const ITEM_COUNT_SHIFT: u32 = 32;
+/// The bits of the header word below the item count that have no meaning.
+const UNKNOWN_HEADER_BITS: u64 =
+    ((1 << ITEM_COUNT_SHIFT) - 1) & !(OPERAND_MASK | NOT_EQ_FLAG | MODULUS_FLAG | OR_FLAG);
+
+/// The operands in the order of their encoding in a [`PluralRuleListV1`].
+const OPERANDS: [ast::Operand; 8] = [
+    ast::Operand::N,
+    ast::Operand::I,
+    ast::Operand::V,
+    ast::Operand::W,
+    ast::Operand::F,
+    ast::Operand::T,
+    ast::Operand::C,
+    ast::Operand::E,
+];
+
+fn encode_condition(condition: &ast::Condition) -> ZeroVec<'static, u64> {
+    let mut result = vec![];
+    for (i, and_condition) in condition.0.iter().enumerate() {
+        for (j, relation) in and_condition.0.iter().enumerate() {
+            let operand = OPERANDS
+                .iter()
+                .position(|operand| *operand == relation.expression.operand)
+                .expect("All operands have an encoding.");
+            let mut header = operand as u64;
+            if relation.operator == ast::Operator::NotEq {
+                header |= NOT_EQ_FLAG;
+            }
+            if relation.expression.modulus.is_some() {
+                header |= MODULUS_FLAG;
+            }
+            if i > 0 && j == 0 {
+                header |= OR_FLAG;
+            }
+            header |= (relation.range_list.0.len() as u64) << ITEM_COUNT_SHIFT;
+            result.push(header);
+            if let Some(modulus) = &relation.expression.modulus {
+                result.push(modulus.0);
+            }
+            for item in relation.range_list.0.iter() {
+                match item {
+                    ast::RangeListItem::Value(value) => {
+                        result.push(value.0);
+                        result.push(value.0);
+                    }
+                    ast::RangeListItem::Range(range) => {
+                        result.push(range.start().0);
+                        result.push(range.end().0);
+                    }
+                }
+            }
+        }
+    }
+    ZeroVec::from_aligned(&result)
+}
+
+fn decode_condition(input: &ZeroVec<u64>) -> Result<ast::Condition, PluralRulesError> {
+    let mut words = input.iter();
+    let mut next = || words.next().ok_or(PluralRulesError::InvalidRuleList);
+    let mut and_conditions = vec![];
+    let mut relations = vec![];
+    while let Ok(header) = next() {
+        if header & UNKNOWN_HEADER_BITS != 0 {
+            return Err(PluralRulesError::InvalidRuleList);
+        }
+        if header & OR_FLAG != 0 {
+            if relations.is_empty() {
+                return Err(PluralRulesError::InvalidRuleList);
+            }
+            and_conditions.push(ast::AndCondition(
+                std::mem::take(&mut relations).into_boxed_slice(),
+            ));
+        }
+        let operand = OPERANDS[(header & OPERAND_MASK) as usize];
+        let modulus = if header & MODULUS_FLAG != 0 {
+            Some(ast::Value(next()?))
+        } else {
+            None
+        };
+        let operator = if header & NOT_EQ_FLAG != 0 {
+            ast::Operator::NotEq
+        } else {
+            ast::Operator::Eq
+        };
+        let mut items = vec![];
+        for _ in 0..(header >> ITEM_COUNT_SHIFT) {
+            let (start, end) = (next()?, next()?);
+            items.push(if start == end {
+                ast::RangeListItem::Value(ast::Value(start))
+            } else {
+                ast::RangeListItem::Range(ast::Value(start)..=ast::Value(end))
+            });
+        }
+        relations.push(ast::Relation {
+            expression: ast::Expression { operand, modulus },
+            operator,
+            range_list: ast::RangeList(items.into_boxed_slice()),
+        });
+    }
+    if !relations.is_empty() {
+        and_conditions.push(ast::AndCondition(relations.into_boxed_slice()));
+    }
+    Ok(ast::Condition(and_conditions.into_boxed_slice()))
+}
+
+fn encode_rule(
+    input: &Option<Cow<str>>,
+) -> Result<Option<ZeroVec<'static, u64>>, PluralRulesError> {
+    Ok(parse_rule(input)?.as_ref().map(encode_condition))
+}
+
+fn decode_rule(input: &Option<ZeroVec<u64>>) -> Result<Option<ast::Condition>, PluralRulesError> {
+    input.as_ref().map(decode_condition).transpose()
+}
+
+impl<'s> TryFrom<&PluralRuleStringsV1<'s>> for PluralRuleListV1<'static> {
+    type Error = PluralRulesError;
+    fn try_from(input: &PluralRuleStringsV1<'s>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            zero: encode_rule(&input.zero)?,
+            one: encode_rule(&input.one)?,
+            two: encode_rule(&input.two)?,
+            few: encode_rule(&input.few)?,
+            many: encode_rule(&input.many)?,
+        })
+    }
+}
+
+impl<'s> TryInto<PluralRuleList> for &PluralRuleListV1<'s> {
     type Error = PluralRulesError;
     fn try_into(self) -> Result<PluralRuleList, Self::Error> {
         Ok(PluralRuleList {
-            zero: parse_rule(&self.zero)?,
-            one: parse_rule(&self.one)?,
-            two: parse_rule(&self.two)?,
-            few: parse_rule(&self.few)?,
-            many: parse_rule(&self.many)?,
+            zero: decode_rule(&self.zero)?,
+            one: decode_rule(&self.one)?,
+            two: decode_rule(&self.two)?,
+            few: decode_rule(&self.few)?,
+            many: decode_rule(&self.many)?,
         })
     }
 }
@@ -12,6 +12,10 @@ use icu_provider::prelude::DataError;
 pub enum PluralRulesError {
     #[displaydoc("Parser error: {0}")]
     Parser(ParserError),
+    /// Compiled plural rules from a [`PluralRuleListV1`](crate::provider::PluralRuleListV1)
+    /// that could not be decoded
+    #[displaydoc("Invalid compiled plural rules")]
+    InvalidRuleList,
     /// An error originating inside of the [`DataProvider`](icu_provider::DataProvider)
     #[displaydoc("Data provider error: {0}")]
     DataProvider(DataError),
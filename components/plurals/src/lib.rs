@@ -67,6 +67,7 @@
 mod data;
 mod error;
 mod operands;
+mod ordinal;
 pub mod provider;
 pub mod rules;
 
@@ -74,7 +75,8 @@ pub use error::PluralRulesError;
 use icu_locid::LanguageIdentifier;
 use icu_provider::prelude::*;
 pub use operands::PluralOperands;
-use provider::{resolver, PluralRuleStringsV1, PluralRuleStringsV1Marker};
+pub use ordinal::OrdinalFormatter;
+use provider::{resolver, PluralRuleListV1, PluralRuleListV1Marker};
 use std::convert::TryInto;
 
 /// A type of a plural rule which can be associated with the [`PluralRules`] struct.
@@ -248,7 +250,13 @@ impl PluralCategory {
 /// [`Plural Category`]: PluralCategory
 pub struct PluralRules {
     _langid: LanguageIdentifier,
+    /// The [`PluralRuleType`] of `selector`, unless constructed with
+    /// [`new_from_data()`](PluralRules::new_from_data()).
+    type_: Option<PluralRuleType>,
     selector: data::RulesSelector,
+    /// The rules of the other [`PluralRuleType`], if loaded with
+    /// [`try_new_combined()`](PluralRules::try_new_combined()).
+    other_selector: Option<data::RulesSelector>,
 }
 
 impl PluralRules {
@@ -272,13 +280,94 @@ impl PluralRules {
     ///
     /// [`type`]: PluralRuleType
     /// [`data provider`]: icu_provider::DataProvider
-    pub fn try_new<'d, 's: 'd, D: DataProvider<'d, 's, PluralRuleStringsV1Marker> + ?Sized>(
+    pub fn try_new<'d, 's: 'd, D: DataProvider<'d, 's, PluralRuleListV1Marker> + ?Sized>(
         langid: LanguageIdentifier,
         data_provider: &D,
         type_: PluralRuleType,
     ) -> Result<Self, PluralRulesError> {
         let data = resolver::resolve_plural_data(langid.clone(), data_provider, type_)?;
-        Self::new_from_data(langid, data.get())
+        let list: data::PluralRuleList = data.get().try_into()?;
+        Ok(Self {
+            _langid: langid,
+            type_: Some(type_),
+            selector: list.into(),
+            other_selector: None,
+        })
+    }
+
+    /// Constructs a new `PluralRules` for a given locale and [`data provider`] with the rules of
+    /// both [`types`], for use when the [`type`] to select with is only known at the time of
+    /// selection.
+    ///
+    /// This constructor will fail only if the [`data provider`] has the data for neither
+    /// [`type`], in which case the error for [`PluralRuleType::Cardinal`] is returned, or if
+    /// the data for either [`type`] is invalid.
+    ///
+    /// [`select()`](PluralRules::select()) and [`categories()`](PluralRules::categories())
+    /// use the cardinal rules, unless only the ordinal rules are available.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::macros::langid;
+    /// use icu::plurals::{PluralRules, PluralRuleType, PluralCategory};
+    ///
+    /// let lid = langid!("en");
+    ///
+    /// let dp = icu_testdata::get_provider();
+    ///
+    /// let pr = PluralRules::try_new_combined(lid, &dp)
+    ///     .expect("Failed to construct a PluralRules struct.");
+    ///
+    /// assert_eq!(pr.select_for_type(PluralRuleType::Cardinal, 2_usize), Some(PluralCategory::Other));
+    /// assert_eq!(pr.select_for_type(PluralRuleType::Ordinal, 2_usize), Some(PluralCategory::Two));
+    /// ```
+    ///
+    /// [`types`]: PluralRuleType
+    /// [`type`]: PluralRuleType
+    /// [`data provider`]: icu_provider::DataProvider
+    pub fn try_new_combined<
+        'd,
+        's: 'd,
+        D: DataProvider<'d, 's, PluralRuleListV1Marker> + ?Sized,
+    >(
+        langid: LanguageIdentifier,
+        data_provider: &D,
+    ) -> Result<Self, PluralRulesError> {
+        let cardinal = Self::load_if_available(&langid, data_provider, PluralRuleType::Cardinal)?;
+        let ordinal = Self::load_if_available(&langid, data_provider, PluralRuleType::Ordinal)?;
+        let (type_, selector, other_selector) = match (cardinal, ordinal) {
+            (Ok(cardinal), ordinal) => (PluralRuleType::Cardinal, cardinal, ordinal.ok()),
+            (Err(_), Ok(ordinal)) => (PluralRuleType::Ordinal, ordinal, None),
+            (Err(e), Err(_)) => return Err(e),
+        };
+        Ok(Self {
+            _langid: langid,
+            type_: Some(type_),
+            selector,
+            other_selector,
+        })
+    }
+
+    /// Loads the rules of the given type. The outer result holds errors that are fatal to
+    /// [`try_new_combined()`](PluralRules::try_new_combined()), while the inner one holds the
+    /// error if the data is merely unavailable.
+    fn load_if_available<'d, 's: 'd, D: DataProvider<'d, 's, PluralRuleListV1Marker> + ?Sized>(
+        langid: &LanguageIdentifier,
+        data_provider: &D,
+        type_: PluralRuleType,
+    ) -> Result<Result<data::RulesSelector, PluralRulesError>, PluralRulesError> {
+        match resolver::resolve_plural_data(langid.clone(), data_provider, type_) {
+            Ok(data) => {
+                let list: data::PluralRuleList = data.get().try_into()?;
+                Ok(Ok(list.into()))
+            }
+            Err(e @ PluralRulesError::DataProvider(DataError::UnsupportedResourceKey(_)))
+            | Err(e @ PluralRulesError::DataProvider(DataError::UnavailableResourceOptions(_))) => {
+                Ok(Err(e))
+            }
+            Err(e) => Err(e),
+        }
     }
 
     /// Returns the [`Plural Category`] appropriate for the given number.
@@ -343,6 +432,31 @@ impl PluralRules {
         self.selector.select(&input.into())
     }
 
+    /// Returns the [`Plural Category`] appropriate for the given number according to the rules
+    /// of the given [`type`], or [`None`] if the rules of that [`type`] were not loaded.
+    /// Since the [`type`] of the data passed to [`new_from_data()`](PluralRules::new_from_data())
+    /// is unknown, this always returns [`None`] for a [`PluralRules`] constructed with it.
+    ///
+    /// Only a [`PluralRules`] constructed with
+    /// [`try_new_combined()`](PluralRules::try_new_combined()) holds the rules of both
+    /// [`types`]. See [`select()`](PluralRules::select()) for the accepted inputs.
+    ///
+    /// [`Plural Category`]: PluralCategory
+    /// [`type`]: PluralRuleType
+    /// [`types`]: PluralRuleType
+    pub fn select_for_type<I: Into<PluralOperands>>(
+        &self,
+        type_: PluralRuleType,
+        input: I,
+    ) -> Option<PluralCategory> {
+        let selector = if Some(type_) == self.type_ {
+            Some(&self.selector)
+        } else {
+            self.other_selector.as_ref()
+        };
+        selector.map(|selector| selector.select(&input.into()))
+    }
+
     /// Returns all [`Plural Categories`] appropriate for a [`PluralRules`] object
     /// based on the [`LanguageIdentifier`] and [`PluralRuleType`].
     ///
@@ -378,14 +492,19 @@ impl PluralRules {
 
     /// Lower-level constructor that allows constructing a [`PluralRules`] directly from
     /// data obtained from a provider.
+    ///
+    /// Rules given as strings can be compiled into a [`PluralRuleListV1`] with
+    /// [`TryFrom`](std::convert::TryFrom).
     pub fn new_from_data(
         langid: LanguageIdentifier,
-        data: &PluralRuleStringsV1,
+        data: &PluralRuleListV1,
     ) -> Result<Self, PluralRulesError> {
         let data: data::PluralRuleList = data.try_into()?;
         Ok(Self {
             _langid: langid,
+            type_: None,
             selector: data.into(),
+            other_selector: None,
         })
     }
 }
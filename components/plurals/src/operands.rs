@@ -3,7 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use displaydoc::Display;
-use fixed_decimal::FixedDecimal;
+use fixed_decimal::{CompactDecimal, FixedDecimal};
 use std::convert::TryFrom;
 use std::io::Error as IOError;
 use std::isize;
@@ -60,7 +60,19 @@ use std::str::FromStr;
 ///    c: 0,
 /// }), "123.45".parse())
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq)]
+///
+/// Operands are equal only if all of their fields are equal. Since plural selection depends on
+/// the visible fraction digits, numbers that differ only in trailing zeros are not equal.
+///
+/// ```
+/// use icu::plurals::PluralOperands;
+/// assert_ne!(
+///     "1.0".parse::<PluralOperands>(),
+///     "1.00".parse::<PluralOperands>(),
+/// );
+/// assert_eq!(PluralOperands::from(1_usize), PluralOperands::from(1_u8));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct PluralOperands {
     /// Integer value of input
     pub i: u64,
@@ -262,3 +274,25 @@ impl From<&FixedDecimal> for PluralOperands {
         }
     }
 }
+
+impl From<&CompactDecimal> for PluralOperands {
+    /// Converts a [`fixed_decimal::CompactDecimal`] to [`PluralOperands`]. The `i`, `v`, `w`, `f`,
+    /// and `t` operands are taken from the significand, and `c` is set to the compact exponent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{CompactDecimal, FixedDecimal};
+    /// use icu::plurals::PluralOperands;
+    ///
+    /// let significand = FixedDecimal::from(12).multiplied_pow10(-1).unwrap();
+    /// let compact = CompactDecimal::from_significand_and_exponent(significand, 6);
+    /// assert_eq!(Ok(PluralOperands::from(&compact)), "1.2e6".parse());
+    /// ```
+    fn from(compact: &CompactDecimal) -> Self {
+        Self {
+            c: compact.exponent() as usize,
+            ..Self::from(compact.significand())
+        }
+    }
+}
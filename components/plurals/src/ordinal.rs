@@ -0,0 +1,127 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::{key, OrdinalPatternsV1Marker, PluralRuleListV1Marker};
+use crate::{PluralCategory, PluralOperands, PluralRuleType, PluralRules, PluralRulesError};
+use fixed_decimal::FixedDecimal;
+use icu_decimal::provider::DecimalSymbolsV1Marker;
+use icu_decimal::{FixedDecimalFormat, FixedDecimalFormatError};
+use icu_locid::LanguageIdentifier;
+use icu_provider::prelude::*;
+use std::fmt;
+use writeable::Writeable;
+
+/// A formatter for ordinal numbers, such as `1st`, `22nd` or `103rd` in English.
+///
+/// [`OrdinalFormatter`] selects the [`Ordinal`] [`Plural Category`] of a number and writes the
+/// number using the locale's pattern for that category. The number is formatted with the
+/// locale's [`FixedDecimalFormat`], including its grouping separators, such as `1,001st`.
+///
+/// # Examples
+///
+/// ```
+/// use icu::locid::macros::langid;
+/// use icu::plurals::OrdinalFormatter;
+///
+/// let lid = langid!("en");
+///
+/// let dp = icu_testdata::get_provider();
+///
+/// let of = OrdinalFormatter::try_new(lid, &dp)
+///     .expect("Failed to construct an OrdinalFormatter struct.");
+///
+/// assert_eq!(of.format_to_string(21_usize), "21st");
+/// assert_eq!(of.format_to_string(3_usize), "3rd");
+/// assert_eq!(of.format_to_string(11_usize), "11th");
+/// assert_eq!(of.format_to_string(1001_usize), "1,001st");
+/// ```
+///
+/// [`Ordinal`]: PluralRuleType::Ordinal
+/// [`Plural Category`]: PluralCategory
+pub struct OrdinalFormatter<'d> {
+    rules: PluralRules,
+    patterns: DataPayload<'d, 'd, OrdinalPatternsV1Marker>,
+    decimal: FixedDecimalFormat<'d, 'd>,
+}
+
+impl<'d> OrdinalFormatter<'d> {
+    /// Constructs a new `OrdinalFormatter` for a given locale and [`data provider`].
+    ///
+    /// This constructor will fail if the [`data provider`] does not have the ordinal plural
+    /// rules, the ordinal patterns, or the decimal symbols for the locale.
+    ///
+    /// [`data provider`]: icu_provider::DataProvider
+    pub fn try_new<D>(
+        langid: LanguageIdentifier,
+        data_provider: &D,
+    ) -> Result<Self, PluralRulesError>
+    where
+        D: DataProvider<'d, 'd, PluralRuleListV1Marker>
+            + DataProvider<'d, 'd, OrdinalPatternsV1Marker>
+            + DataProvider<'d, 'd, DecimalSymbolsV1Marker>
+            + ?Sized,
+    {
+        let rules = PluralRules::try_new(langid.clone(), data_provider, PluralRuleType::Ordinal)?;
+        let patterns = data_provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::ORDINAL_PATTERNS_V1,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(langid.clone()),
+                    },
+                },
+            })?
+            .take_payload()?;
+        let decimal = FixedDecimalFormat::try_new(langid, data_provider, Default::default())
+            .map_err(|e| match e {
+                FixedDecimalFormatError::Data(e) => PluralRulesError::DataProvider(e),
+            })?;
+        Ok(Self {
+            rules,
+            patterns,
+            decimal,
+        })
+    }
+
+    /// Writes the given number as an ordinal to a [`fmt::Write`] sink.
+    ///
+    /// The number is written with the locale's [`FixedDecimalFormat`], and its
+    /// [`Plural Category`] is selected from its [`Plural Operands`].
+    ///
+    /// [`Plural Category`]: PluralCategory
+    /// [`Plural Operands`]: PluralOperands
+    pub fn format_to_write<I>(&self, w: &mut impl fmt::Write, input: I) -> fmt::Result
+    where
+        I: Into<FixedDecimal>,
+    {
+        let input = input.into();
+        let number = self.decimal.format(&input).writeable_to_string();
+        let patterns = self.patterns.get();
+        let pattern = match self.rules.select(PluralOperands::from(&input)) {
+            PluralCategory::Zero => patterns.zero.as_ref(),
+            PluralCategory::One => patterns.one.as_ref(),
+            PluralCategory::Two => patterns.two.as_ref(),
+            PluralCategory::Few => patterns.few.as_ref(),
+            PluralCategory::Many => patterns.many.as_ref(),
+            PluralCategory::Other => None,
+        }
+        .unwrap_or(&patterns.other);
+        // TODO(#277) Use formatter utility instead of replacing "{0}".
+        w.write_str(&pattern.replace("{0}", &number))
+    }
+
+    /// Returns the given number formatted as an ordinal.
+    ///
+    /// See [`format_to_write()`](OrdinalFormatter::format_to_write()) for details.
+    pub fn format_to_string<I>(&self, input: I) -> String
+    where
+        I: Into<FixedDecimal>,
+    {
+        let mut s = String::new();
+        self.format_to_write(&mut s, input)
+            .expect("Failed to write to a String.");
+        s
+    }
+}
@@ -8,11 +8,15 @@
 
 use icu_provider::yoke::{self, *};
 use std::borrow::Cow;
+use zerovec::ZeroVec;
 
 pub mod key {
     use icu_provider::{resource_key, ResourceKey};
     pub const CARDINAL_V1: ResourceKey = resource_key!(Plurals, "cardinal", 1);
     pub const ORDINAL_V1: ResourceKey = resource_key!(Plurals, "ordinal", 1);
+    pub const CARDINAL_LIST_V1: ResourceKey = resource_key!(Plurals, "cardinal_list", 1);
+    pub const ORDINAL_LIST_V1: ResourceKey = resource_key!(Plurals, "ordinal_list", 1);
+    pub const ORDINAL_PATTERNS_V1: ResourceKey = resource_key!(Plurals, "ordinal_patterns", 1);
 }
 
 pub mod resolver;
@@ -34,3 +38,54 @@ pub struct PluralRuleStringsV1<'s> {
     pub few: Option<Cow<'s, str>>,
     pub many: Option<Cow<'s, str>>,
 }
+
+/// Plural rules in compiled form. Includes separate fields for five of the six standard plural
+/// forms, like [`PluralRuleStringsV1`], but the rules are stored as already parsed conditions, so
+/// that they can be loaded without running the rule parser. Convert from [`PluralRuleStringsV1`]
+/// with [`TryFrom`](std::convert::TryFrom).
+///
+/// Each condition is a flat list of relations. A relation is encoded as:
+///
+/// 1. A header word. Bits 0-2 hold the operand, in the order `n`, `i`, `v`, `w`, `f`, `t`, `c`,
+///    `e`. Bit 3 is set if the operator is `!=`, bit 4 if the relation has a modulus, and bit 5
+///    if the relation starts a new `or` branch of the condition. Bits 32-63 hold the number of
+///    items in the range list.
+/// 2. The modulus, if bit 4 of the header is set.
+/// 3. For each item in the range list, its first and last value. A single value is stored as a
+///    range containing only that value.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct PluralRuleListV1<'s> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub zero: Option<ZeroVec<'s, u64>>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub one: Option<ZeroVec<'s, u64>>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub two: Option<ZeroVec<'s, u64>>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub few: Option<ZeroVec<'s, u64>>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub many: Option<ZeroVec<'s, u64>>,
+}
+
+/// Patterns for formatting a number as an ordinal, such as `"{0}st"`, for each of the ordinal
+/// plural categories of a locale. `{0}` is replaced by the number, formatted with grouping
+/// separators. Categories without a pattern use the pattern for the "other" category.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct OrdinalPatternsV1<'s> {
+    pub zero: Option<Cow<'s, str>>,
+    pub one: Option<Cow<'s, str>>,
+    pub two: Option<Cow<'s, str>>,
+    pub few: Option<Cow<'s, str>>,
+    pub many: Option<Cow<'s, str>>,
+    pub other: Cow<'s, str>,
+}
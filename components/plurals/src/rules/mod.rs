@@ -151,5 +151,5 @@ pub(crate) mod serializer;
 
 pub use lexer::Lexer;
 pub use parser::{parse, parse_condition};
-pub use resolver::test_condition;
+pub use resolver::{evaluate, test_condition};
 pub use serializer::serialize;
@@ -28,6 +28,35 @@ pub fn test_condition(condition: &ast::Condition, operands: &PluralOperands) ->
     condition.0.is_empty() || condition.0.iter().any(|c| test_and_condition(c, operands))
 }
 
+/// Function used to evaluate a complete [`Rule`] against [`PluralOperands`].
+///
+/// Only the [`Condition`] of the rule takes part in the evaluation; its [`Samples`]
+/// are ignored. This allows hand-written rules to be tested without constructing
+/// [`PluralRules`] for a locale.
+///
+/// # Examples
+///
+/// ```
+/// use icu::plurals::PluralOperands;
+/// use icu::plurals::rules::{evaluate, parse};
+///
+/// let rule = parse(b"i % 10 = 2..4 and i % 100 != 12..14")
+///     .expect("Failed to parse a rule.");
+///
+/// assert_eq!(evaluate(&rule, &PluralOperands::from(23_usize)), true);
+/// assert_eq!(evaluate(&rule, &PluralOperands::from(13_usize)), false);
+/// assert_eq!(evaluate(&rule, &PluralOperands::from(5_usize)), false);
+/// ```
+///
+/// [`PluralRules`]: crate::PluralRules
+/// [`PluralOperands`]: crate::PluralOperands
+/// [`Rule`]: super::ast::Rule
+/// [`Condition`]: super::ast::Condition
+/// [`Samples`]: super::ast::Samples
+pub fn evaluate(rule: &ast::Rule, operands: &PluralOperands) -> bool {
+    test_condition(&rule.condition, operands)
+}
+
 fn test_and_condition(condition: &ast::AndCondition, operands: &PluralOperands) -> bool {
     condition.0.iter().all(|r| test_relation(r, operands))
 }
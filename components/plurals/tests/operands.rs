@@ -7,7 +7,8 @@ mod helpers;
 
 use std::convert::TryInto;
 
-use fixed_decimal::FixedDecimal;
+use fixed_decimal::{CompactDecimal, FixedDecimal};
+use icu_plurals::rules::{evaluate, parse};
 use icu_plurals::PluralOperands;
 
 #[test]
@@ -46,6 +47,35 @@ fn test_parsing_operands() {
     }
 }
 
+#[test]
+fn test_operands_equality() {
+    use std::collections::HashSet;
+
+    let one_zero: PluralOperands = "1.0".parse().expect("Failed to parse to operands.");
+    let one_zero_zero: PluralOperands = "1.00".parse().expect("Failed to parse to operands.");
+    assert_ne!(one_zero, one_zero_zero);
+    assert_eq!(Ok(one_zero), "1.0".parse());
+
+    let one = PluralOperands::from(1_usize);
+    assert_eq!(one, PluralOperands::from(1_u8));
+    let signed: PluralOperands = 1_i64.try_into().expect("Failed to parse to operands.");
+    assert_eq!(one, signed);
+    assert_eq!(Ok(one), "1".parse());
+    assert_ne!(one, one_zero);
+
+    let set: HashSet<PluralOperands> = vec![
+        one,
+        PluralOperands::from(1_u8),
+        PluralOperands::from(1_u64),
+        one_zero,
+        one_zero_zero,
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(&PluralOperands::from(1_u16)));
+}
+
 #[test]
 fn test_parsing_operand_errors() {
     let operands: Result<PluralOperands, _> = "".parse();
@@ -68,3 +98,35 @@ fn test_from_fixed_decimals() {
         );
     }
 }
+
+#[test]
+fn test_from_compact_decimals() {
+    // French "many": 1000000 is not many, but 1.2 million (1.2c6) is.
+    let rule = parse(b"e = 0 and i != 0 and i % 1000000 = 0 and v = 0 or e != 0..5")
+        .expect("Failed to parse.");
+
+    let significand = FixedDecimal::from(12)
+        .multiplied_pow10(-1)
+        .expect("Bounds are small");
+    let compact = CompactDecimal::from_significand_and_exponent(significand, 6);
+    let operands = PluralOperands::from(&compact);
+    assert_eq!(
+        PluralOperands {
+            i: 1,
+            v: 1,
+            w: 1,
+            f: 2,
+            t: 2,
+            c: 6,
+        },
+        operands
+    );
+    assert!(evaluate(&rule, &operands));
+
+    let operands = PluralOperands::from(&FixedDecimal::from(1200000));
+    assert_eq!(0, operands.c);
+    assert!(!evaluate(&rule, &operands));
+
+    let compact = CompactDecimal::from_significand_and_exponent(FixedDecimal::from(1200), 3);
+    assert!(!evaluate(&rule, &PluralOperands::from(&compact)));
+}
@@ -3,10 +3,14 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use icu_locid_macros::langid;
-use icu_plurals::provider::{self, PluralRuleStringsV1};
-use icu_plurals::{PluralCategory, PluralRuleType, PluralRules};
+use icu_plurals::provider::{self, PluralRuleListV1, PluralRuleStringsV1};
+use icu_plurals::{
+    OrdinalFormatter, PluralCategory, PluralOperands, PluralRuleType, PluralRules, PluralRulesError,
+};
 use icu_provider::struct_provider::StructProvider;
 use std::borrow::Cow;
+use std::convert::TryFrom;
+use zerovec::ZeroVec;
 
 #[test]
 fn test_plural_rules() {
@@ -19,6 +23,21 @@ fn test_plural_rules() {
     assert_eq!(pr.select(5_usize), PluralCategory::Other);
 }
 
+#[test]
+fn test_plural_rules_select_for_type() {
+    let provider = icu_testdata::get_provider();
+
+    let lid = langid!("en");
+
+    let pr = PluralRules::try_new(lid, &provider, PluralRuleType::Ordinal).unwrap();
+
+    assert_eq!(
+        pr.select_for_type(PluralRuleType::Ordinal, 2_usize),
+        Some(PluralCategory::Two)
+    );
+    assert_eq!(pr.select_for_type(PluralRuleType::Cardinal, 2_usize), None);
+}
+
 #[test]
 fn test_plural_rules_missing() {
     let provider = icu_testdata::get_provider();
@@ -30,6 +49,106 @@ fn test_plural_rules_missing() {
     assert!(pr.is_err());
 }
 
+#[test]
+fn test_combined_plural_rules() {
+    let provider = icu_testdata::get_provider();
+
+    let lid = langid!("en");
+
+    let pr = PluralRules::try_new_combined(lid, &provider).unwrap();
+
+    assert_eq!(
+        pr.select_for_type(PluralRuleType::Cardinal, 1_usize),
+        Some(PluralCategory::One)
+    );
+    assert_eq!(
+        pr.select_for_type(PluralRuleType::Ordinal, 1_usize),
+        Some(PluralCategory::One)
+    );
+    assert_eq!(
+        pr.select_for_type(PluralRuleType::Cardinal, 23_usize),
+        Some(PluralCategory::Other)
+    );
+    assert_eq!(
+        pr.select_for_type(PluralRuleType::Ordinal, 23_usize),
+        Some(PluralCategory::Few)
+    );
+}
+
+#[test]
+fn test_combined_plural_rules_partial() {
+    let local_data = PluralRuleListV1::try_from(&PluralRuleStringsV1 {
+        zero: None,
+        one: Some(Cow::Borrowed("n = 1")),
+        two: None,
+        few: None,
+        many: None,
+    })
+    .unwrap();
+    let provider = StructProvider {
+        key: provider::key::ORDINAL_LIST_V1,
+        data: &local_data,
+    };
+
+    let pr = PluralRules::try_new_combined(langid!("und"), &provider).unwrap();
+
+    assert_eq!(pr.select(1_usize), PluralCategory::One);
+    assert_eq!(pr.select_for_type(PluralRuleType::Cardinal, 1_usize), None);
+    assert_eq!(
+        pr.select_for_type(PluralRuleType::Ordinal, 1_usize),
+        Some(PluralCategory::One)
+    );
+}
+
+#[test]
+fn test_combined_plural_rules_missing() {
+    let provider = icu_testdata::get_provider();
+
+    let lid = langid!("xx");
+
+    let pr = PluralRules::try_new_combined(lid, &provider);
+
+    assert!(pr.is_err());
+}
+
+#[test]
+fn test_ordinal_formatter() {
+    let provider = icu_testdata::get_provider();
+
+    let lid = langid!("en");
+
+    let of = OrdinalFormatter::try_new(lid, &provider).unwrap();
+
+    let samples: &[(usize, &str)] = &[
+        (0, "0th"),
+        (1, "1st"),
+        (2, "2nd"),
+        (3, "3rd"),
+        (4, "4th"),
+        (11, "11th"),
+        (12, "12th"),
+        (13, "13th"),
+        (21, "21st"),
+        (102, "102nd"),
+        (113, "113th"),
+        (1001, "1,001st"),
+    ];
+    for (value, expected) in samples {
+        assert_eq!(of.format_to_string(*value), *expected);
+    }
+}
+
+#[test]
+fn test_ordinal_formatter_missing() {
+    let provider = icu_testdata::get_provider();
+
+    let lid = langid!("xx");
+
+    let of = OrdinalFormatter::try_new(lid, &provider);
+
+    assert!(of.is_err());
+}
+
 #[test]
 fn test_plural_category_all() {
     let categories: Vec<&PluralCategory> = PluralCategory::all().collect();
@@ -46,16 +165,21 @@ fn test_plural_category_all() {
 
 #[test]
 fn test_plural_rules_non_static_lifetime() {
-    let local_string = "v = 0 and i % 10 = 1".to_string();
-    let local_data = PluralRuleStringsV1 {
+    let compiled = PluralRuleListV1::try_from(&PluralRuleStringsV1 {
         zero: None,
-        one: Some(Cow::Borrowed(&local_string)),
+        one: Some(Cow::Borrowed("v = 0 and i % 10 = 1")),
         two: None,
         few: None,
         many: None,
+    })
+    .unwrap();
+    let local_bytes = compiled.one.unwrap().as_bytes().to_vec();
+    let local_data = PluralRuleListV1 {
+        one: Some(ZeroVec::try_from_bytes(&local_bytes).unwrap()),
+        ..Default::default()
     };
     let provider = StructProvider {
-        key: provider::key::CARDINAL_V1,
+        key: provider::key::CARDINAL_LIST_V1,
         data: &local_data,
     };
 
@@ -66,3 +190,102 @@ fn test_plural_rules_non_static_lifetime() {
     assert_eq!(pr.select(5_usize), PluralCategory::Other);
     assert_eq!(pr.select(11_usize), PluralCategory::One);
 }
+
+#[test]
+fn test_plural_rule_list_compiled() {
+    let strings = PluralRuleStringsV1 {
+        zero: None,
+        one: Some(Cow::Borrowed("i = 1 and v = 0")),
+        two: None,
+        few: Some(Cow::Borrowed(
+            "n % 10 = 3..4,9 and n % 100 != 10..19,70..79,90..99",
+        )),
+        many: Some(Cow::Borrowed("n != 0 and n % 1000000 = 0 or t = 5")),
+    };
+    let compiled = PluralRuleListV1::try_from(&strings).unwrap();
+    assert_eq!(compiled.zero, None);
+    assert_eq!(compiled.two, None);
+
+    let provider = StructProvider {
+        key: provider::key::CARDINAL_LIST_V1,
+        data: &compiled,
+    };
+    let pr = PluralRules::try_new(langid!("und"), &provider, PluralRuleType::Cardinal).unwrap();
+
+    let samples: &[(&str, PluralCategory)] = &[
+        ("1", PluralCategory::One),
+        ("1.0", PluralCategory::Other),
+        ("3", PluralCategory::Few),
+        ("29", PluralCategory::Few),
+        ("73", PluralCategory::Other),
+        ("2", PluralCategory::Other),
+        ("1000000", PluralCategory::Many),
+        ("0.5", PluralCategory::Many),
+    ];
+    for (input, expected) in samples {
+        let operands: PluralOperands = input.parse().unwrap();
+        assert_eq!(pr.select(operands), *expected, "{}", input);
+    }
+    let categories: Vec<&PluralCategory> = pr.categories().collect();
+    assert_eq!(
+        categories,
+        vec![
+            &PluralCategory::Few,
+            &PluralCategory::Many,
+            &PluralCategory::One,
+            &PluralCategory::Other
+        ]
+    );
+}
+
+#[test]
+fn test_plural_rule_list_bincode() {
+    let compiled = PluralRuleListV1::try_from(&PluralRuleStringsV1 {
+        one: Some(Cow::Borrowed("i = 1 and v = 0")),
+        ..Default::default()
+    })
+    .unwrap();
+    let bytes = bincode::serialize(&compiled).unwrap();
+
+    let zc: PluralRuleListV1 = bincode::deserialize(&bytes).unwrap();
+    assert!(matches!(zc.one, Some(ZeroVec::Borrowed(_))));
+    assert_eq!(zc, compiled);
+}
+
+#[test]
+fn test_plural_rule_list_invalid() {
+    // A relation on `i` with one range list item, but without the item's values.
+    let invalid = [1u64 << 32];
+    let data = PluralRuleListV1 {
+        one: Some(ZeroVec::from_aligned(&invalid)),
+        ..Default::default()
+    };
+    assert!(matches!(
+        PluralRules::new_from_data(langid!("und"), &data),
+        Err(PluralRulesError::InvalidRuleList)
+    ));
+}
+
+#[test]
+fn test_plural_rule_list_unknown_header_bits() {
+    // A relation `i = 1`, with a header bit that has no meaning.
+    let invalid = [1u64 << 32 | 1 | 1 << 6, 1, 1];
+    let data = PluralRuleListV1 {
+        one: Some(ZeroVec::from_aligned(&invalid)),
+        ..Default::default()
+    };
+    assert!(matches!(
+        PluralRules::new_from_data(langid!("und"), &data),
+        Err(PluralRulesError::InvalidRuleList)
+    ));
+
+    // The same relation without it is valid.
+    let valid = [1u64 << 32 | 1, 1, 1];
+    let data = PluralRuleListV1 {
+        one: Some(ZeroVec::from_aligned(&valid)),
+        ..Default::default()
+    };
+    let pr = PluralRules::new_from_data(langid!("und"), &data).unwrap();
+    assert_eq!(pr.select(1_usize), PluralCategory::One);
+    assert_eq!(pr.select(2_usize), PluralCategory::Other);
+}
@@ -5,7 +5,7 @@
 mod fixtures;
 mod helpers;
 
-use icu_plurals::rules::{parse, parse_condition, serialize, test_condition, Lexer};
+use icu_plurals::rules::{evaluate, parse, parse_condition, serialize, test_condition, Lexer};
 use icu_plurals::PluralOperands;
 
 #[test]
@@ -58,3 +58,29 @@ fn test_parsing_operands() {
         }
     }
 }
+
+#[test]
+fn test_evaluate() {
+    let rule = parse(b"i % 10 = 2..4 and i % 100 != 12..14 @integer 2~4, 22~24")
+        .expect("Failed to parse.");
+    for (input, expected) in &[
+        (2_u64, true),
+        (4, true),
+        (12, false),
+        (24, true),
+        (5, false),
+    ] {
+        let operands: PluralOperands = (*input).into();
+        assert_eq!(evaluate(&rule, &operands), *expected, "{}", input);
+    }
+
+    let rule = parse(b"v = 2 and w = 1 and f = 50 and t = 5").expect("Failed to parse.");
+    let operands: PluralOperands = "1.50".parse().expect("Failed to parse operands.");
+    assert!(evaluate(&rule, &operands));
+    let operands: PluralOperands = "1.5".parse().expect("Failed to parse operands.");
+    assert!(!evaluate(&rule, &operands));
+
+    let rule = parse(b"n = 1,3..5").expect("Failed to parse.");
+    assert!(evaluate(&rule, &PluralOperands::from(4_u64)));
+    assert!(!evaluate(&rule, &PluralOperands::from(2_u64)));
+}
@@ -0,0 +1,42 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Error types for relative time formatting.
+
+use displaydoc::Display;
+use icu_decimal::FixedDecimalFormatError;
+use icu_plurals::PluralRulesError;
+
+#[derive(Display, Debug)]
+pub enum Error {
+    #[displaydoc("error loading data: {0}")]
+    Data(icu_provider::DataError),
+    #[displaydoc("error loading plural rules: {0}")]
+    PluralRules(PluralRulesError),
+    #[displaydoc("error loading decimal symbols: {0}")]
+    Decimal(FixedDecimalFormatError),
+    /// A datetime is missing a field needed to compute the offset between two datetimes.
+    #[displaydoc("missing input field")]
+    MissingInputField,
+}
+
+impl std::error::Error for Error {}
+
+impl From<icu_provider::DataError> for Error {
+    fn from(e: icu_provider::DataError) -> Self {
+        Error::Data(e)
+    }
+}
+
+impl From<PluralRulesError> for Error {
+    fn from(e: PluralRulesError) -> Self {
+        Error::PluralRules(e)
+    }
+}
+
+impl From<FixedDecimalFormatError> for Error {
+    fn from(e: FixedDecimalFormatError) -> Self {
+        Error::Decimal(e)
+    }
+}
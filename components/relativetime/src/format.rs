@@ -0,0 +1,45 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Lower-level types for relative time formatting.
+
+use fixed_decimal::FixedDecimal;
+use icu_decimal::FixedDecimalFormat;
+use std::fmt;
+use writeable::Writeable;
+
+/// An intermediate structure returned by [`RelativeTimeFormat`](crate::RelativeTimeFormat).
+/// Use [`Writeable`][Writeable] or [`Display`](fmt::Display) to render the formatted relative
+/// time to a string or buffer.
+pub struct FormattedRelativeTime<'l, 'd> {
+    /// A phrase such as "yesterday", or a pattern such as "in {0} days".
+    pub(crate) pattern: &'l str,
+    /// The number to substitute for `{0}`, without a sign.
+    pub(crate) value: FixedDecimal,
+    pub(crate) fixed_decimal_format: &'l FixedDecimalFormat<'d, 'd>,
+}
+
+impl<'l, 'd> Writeable for FormattedRelativeTime<'l, 'd> {
+    fn write_to<W>(&self, sink: &mut W) -> fmt::Result
+    where
+        W: fmt::Write + ?Sized,
+    {
+        match self.pattern.find("{0}") {
+            Some(index) => {
+                sink.write_str(&self.pattern[..index])?;
+                self.fixed_decimal_format
+                    .format(&self.value)
+                    .write_to(sink)?;
+                sink.write_str(&self.pattern[index + "{0}".len()..])
+            }
+            None => sink.write_str(self.pattern),
+        }
+    }
+}
+
+impl<'l, 'd> fmt::Display for FormattedRelativeTime<'l, 'd> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
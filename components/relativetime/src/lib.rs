@@ -0,0 +1,317 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! [`icu_relativetime`](crate) offers localized formatting of relative times, such as
+//! "yesterday" or "in 3 days".
+//!
+//! [`RelativeTimeFormat`] formats an offset in a [`RelativeTimeUnit`], or the offset between two
+//! datetimes. It uses a phrase of the locale for the offset when there is one, such as
+//! "tomorrow" or "last week", and formats the number of units otherwise, such as "in 3 days".
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::locid::Locale;
+//! use icu::relativetime::{RelativeTimeFormat, RelativeTimeUnit};
+//!
+//! let provider = icu_provider::inv::InvariantDataProvider;
+//! let rtf = RelativeTimeFormat::try_new(Locale::und(), &provider, Default::default())
+//!     .expect("Data should load successfully");
+//!
+//! assert_eq!(rtf.format(3, RelativeTimeUnit::Day).to_string(), "+3 d");
+//! assert_eq!(rtf.format(-2, RelativeTimeUnit::Week).to_string(), "-2 w");
+//! ```
+//!
+//! The unit can also be chosen from the offset between two datetimes:
+//!
+//! ```
+//! use icu::datetime::mock::datetime::MockDateTime;
+//! use icu::locid::Locale;
+//! use icu::relativetime::RelativeTimeFormat;
+//!
+//! let provider = icu_provider::inv::InvariantDataProvider;
+//! let rtf = RelativeTimeFormat::try_new(Locale::und(), &provider, Default::default())
+//!     .expect("Data should load successfully");
+//!
+//! let now: MockDateTime = "2021-03-03T12:00:00".parse().expect("Failed to parse date.");
+//! let then: MockDateTime = "2021-05-10T09:30:00".parse().expect("Failed to parse date.");
+//!
+//! let formatted = rtf.format_between(&now, &then).expect("The datetimes are complete");
+//! assert_eq!(formatted.to_string(), "+2 m");
+//! ```
+
+pub mod error;
+pub mod format;
+pub mod options;
+pub mod provider;
+
+pub use error::Error as RelativeTimeFormatError;
+pub use format::FormattedRelativeTime;
+pub use options::RelativeTimeFormatOptions;
+
+use fixed_decimal::FixedDecimal;
+use icu_datetime::arithmetic::iso_date_to_epoch_days;
+use icu_datetime::date::DateTimeInput;
+use icu_decimal::{provider::DecimalSymbolsV1Marker, FixedDecimalFormat};
+use icu_locid::Locale;
+use icu_plurals::{provider::PluralRuleListV1Marker, PluralCategory, PluralRuleType, PluralRules};
+use icu_provider::prelude::*;
+use options::{Numeric, Width};
+use provider::{FieldV1, FieldsV1, PluralPatternsV1, RelativeTimePatternsV1Marker};
+use std::convert::TryFrom;
+
+/// The unit of a relative time.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum RelativeTimeUnit {
+    Year,
+    Quarter,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+}
+
+/// A formatter for relative times, such as "yesterday" or "in 3 days".
+///
+/// See the crate-level documentation for examples.
+pub struct RelativeTimeFormat<'d> {
+    options: RelativeTimeFormatOptions,
+    patterns: DataPayload<'d, 'd, RelativeTimePatternsV1Marker>,
+    plural_rules: PluralRules,
+    fixed_decimal_format: FixedDecimalFormat<'d, 'd>,
+}
+
+impl<'d> RelativeTimeFormat<'d> {
+    /// Creates a new [`RelativeTimeFormat`] from locale data and an options bag.
+    pub fn try_new<
+        T: Into<Locale>,
+        D: DataProvider<'d, 'd, RelativeTimePatternsV1Marker>
+            + DataProvider<'d, 'd, PluralRuleListV1Marker>
+            + DataProvider<'d, 'd, DecimalSymbolsV1Marker>
+            + ?Sized,
+    >(
+        locale: T,
+        data_provider: &D,
+        options: RelativeTimeFormatOptions,
+    ) -> Result<Self, RelativeTimeFormatError> {
+        let locale = locale.into();
+        let patterns = data_provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: provider::key::RELATIVE_TIME_V1,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(locale.clone().into()),
+                    },
+                },
+            })?
+            .take_payload()?;
+        let plural_rules = PluralRules::try_new(
+            locale.clone().into(),
+            data_provider,
+            PluralRuleType::Cardinal,
+        )?;
+        let fixed_decimal_format =
+            FixedDecimalFormat::try_new(locale, data_provider, Default::default())?;
+        Ok(Self {
+            options,
+            patterns,
+            plural_rules,
+            fixed_decimal_format,
+        })
+    }
+
+    /// Formats an offset of `value` units from now, such as "in 3 days" for 3 days and "yesterday"
+    /// for -1 day. Negative values are in the past.
+    pub fn format(&self, value: i64, unit: RelativeTimeUnit) -> FormattedRelativeTime<'_, 'd> {
+        let field = self.field(unit);
+        let phrase = match self.options.numeric {
+            Numeric::Auto => i8::try_from(value)
+                .ok()
+                .and_then(|value| field.relatives.get(&value)),
+            Numeric::Always => None,
+        };
+        let magnitude = FixedDecimal::from(value.unsigned_abs());
+        let pattern: &str = match phrase {
+            Some(phrase) => phrase,
+            None => {
+                let patterns = if value < 0 {
+                    &field.past
+                } else {
+                    &field.future
+                };
+                select_pattern(patterns, self.plural_rules.select(&magnitude))
+            }
+        };
+        FormattedRelativeTime {
+            pattern,
+            value: magnitude,
+            fixed_decimal_format: &self.fixed_decimal_format,
+        }
+    }
+
+    /// Formats the offset from `start` to `end`, such as "tomorrow" when `end` is on the day after
+    /// `start`.
+    ///
+    /// The offset is counted in calendar units: any time on the next day is "tomorrow", and any
+    /// day in the previous calendar year is "last year". The unit is the largest of the following
+    /// that fits the offset:
+    ///
+    /// - Hours, minutes, or seconds when both datetimes are on the same day
+    /// - Days when they are less than 7 days apart
+    /// - Weeks when they are less than 28 days apart, or in the same month
+    /// - Months when they are less than 12 months apart
+    /// - Years otherwise
+    ///
+    /// Returns an error if either datetime is missing a date or time field.
+    pub fn format_between<T: DateTimeInput>(
+        &self,
+        start: &T,
+        end: &T,
+    ) -> Result<FormattedRelativeTime<'_, 'd>, RelativeTimeFormatError> {
+        let (value, unit) = offset_between(start, end)?;
+        Ok(self.format(value, unit))
+    }
+
+    fn field(&self, unit: RelativeTimeUnit) -> &FieldV1 {
+        let patterns = self.patterns.get();
+        let fields: &FieldsV1 = match self.options.width {
+            Width::Long => &patterns.long,
+            Width::Short => &patterns.short,
+            Width::Narrow => &patterns.narrow,
+        };
+        match unit {
+            RelativeTimeUnit::Year => &fields.year,
+            RelativeTimeUnit::Quarter => &fields.quarter,
+            RelativeTimeUnit::Month => &fields.month,
+            RelativeTimeUnit::Week => &fields.week,
+            RelativeTimeUnit::Day => &fields.day,
+            RelativeTimeUnit::Hour => &fields.hour,
+            RelativeTimeUnit::Minute => &fields.minute,
+            RelativeTimeUnit::Second => &fields.second,
+        }
+    }
+}
+
+fn select_pattern(patterns: &PluralPatternsV1, category: PluralCategory) -> &str {
+    let pattern = match category {
+        PluralCategory::Zero => patterns.zero.as_ref(),
+        PluralCategory::One => patterns.one.as_ref(),
+        PluralCategory::Two => patterns.two.as_ref(),
+        PluralCategory::Few => patterns.few.as_ref(),
+        PluralCategory::Many => patterns.many.as_ref(),
+        PluralCategory::Other => None,
+    };
+    pattern.unwrap_or(&patterns.other)
+}
+
+/// Returns the calendar offset from `start` to `end`, in the unit described in
+/// [`RelativeTimeFormat::format_between`].
+fn offset_between<T: DateTimeInput>(
+    start: &T,
+    end: &T,
+) -> Result<(i64, RelativeTimeUnit), RelativeTimeFormatError> {
+    let (start_year, start_month, start_days) = date_fields(start)?;
+    let (end_year, end_month, end_days) = date_fields(end)?;
+    let days = end_days - start_days;
+    if days == 0 {
+        let seconds = seconds_of_day(end)? - seconds_of_day(start)?;
+        return Ok(if seconds.abs() >= 3600 {
+            (seconds / 3600, RelativeTimeUnit::Hour)
+        } else if seconds.abs() >= 60 {
+            (seconds / 60, RelativeTimeUnit::Minute)
+        } else {
+            (seconds, RelativeTimeUnit::Second)
+        });
+    }
+    let months = (end_year - start_year) * 12 + end_month - start_month;
+    Ok(if days.abs() < 7 {
+        (days, RelativeTimeUnit::Day)
+    } else if days.abs() < 28 || months == 0 {
+        (days / 7, RelativeTimeUnit::Week)
+    } else if months.abs() < 12 {
+        (months, RelativeTimeUnit::Month)
+    } else {
+        (end_year - start_year, RelativeTimeUnit::Year)
+    })
+}
+
+/// Returns the ISO year, the zero-based month, and the epoch days of a datetime.
+fn date_fields<T: DateTimeInput>(input: &T) -> Result<(i64, i64, i64), RelativeTimeFormatError> {
+    let year = input
+        .year()
+        .ok_or(RelativeTimeFormatError::MissingInputField)?
+        .related_iso;
+    let month = input
+        .month()
+        .ok_or(RelativeTimeFormatError::MissingInputField)?
+        .number
+        - 1;
+    let day = input
+        .day_of_month()
+        .ok_or(RelativeTimeFormatError::MissingInputField)?
+        .0
+        - 1;
+    Ok((
+        i64::from(year),
+        i64::from(month),
+        iso_date_to_epoch_days(year, month, day),
+    ))
+}
+
+fn seconds_of_day<T: DateTimeInput>(input: &T) -> Result<i64, RelativeTimeFormatError> {
+    let hour = input
+        .hour()
+        .ok_or(RelativeTimeFormatError::MissingInputField)?;
+    let minute = input
+        .minute()
+        .ok_or(RelativeTimeFormatError::MissingInputField)?;
+    let second = input
+        .second()
+        .ok_or(RelativeTimeFormatError::MissingInputField)?;
+    Ok(i64::from(u8::from(hour)) * 3600
+        + i64::from(u8::from(minute)) * 60
+        + i64::from(u8::from(second)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_datetime::mock::datetime::MockDateTime;
+
+    fn offset(start: &str, end: &str) -> (i64, RelativeTimeUnit) {
+        let start: MockDateTime = start.parse().unwrap();
+        let end: MockDateTime = end.parse().unwrap();
+        offset_between(&start, &end).unwrap()
+    }
+
+    #[test]
+    fn test_offset_between() {
+        use RelativeTimeUnit::*;
+        let now = "2021-03-03T12:00:00";
+        assert_eq!(offset(now, now), (0, Second));
+        assert_eq!(offset(now, "2021-03-03T12:00:59"), (59, Second));
+        assert_eq!(offset(now, "2021-03-03T11:58:30"), (-1, Minute));
+        assert_eq!(offset(now, "2021-03-03T23:59:59"), (11, Hour));
+        assert_eq!(offset(now, "2021-03-04T00:00:00"), (1, Day));
+        assert_eq!(offset(now, "2021-02-25T12:00:00"), (-6, Day));
+        assert_eq!(offset(now, "2021-03-10T00:00:00"), (1, Week));
+        assert_eq!(offset(now, "2021-03-31T00:00:00"), (4, Week));
+        assert_eq!(offset(now, "2021-02-03T00:00:00"), (-1, Month));
+        assert_eq!(offset(now, "2022-02-28T00:00:00"), (11, Month));
+        assert_eq!(offset(now, "2022-03-01T00:00:00"), (1, Year));
+        assert_eq!(offset(now, "2019-12-31T00:00:00"), (-2, Year));
+        // Calendar months of different lengths
+        assert_eq!(
+            offset("2021-01-31T00:00:00", "2021-02-01T00:00:00"),
+            (1, Day)
+        );
+        assert_eq!(
+            offset("2021-01-31T00:00:00", "2021-02-28T00:00:00"),
+            (1, Month)
+        );
+    }
+}
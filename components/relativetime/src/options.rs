@@ -0,0 +1,48 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`RelativeTimeFormat`](crate::RelativeTimeFormat).
+
+/// A bag of options defining how relative times will be formatted by
+/// [`RelativeTimeFormat`](crate::RelativeTimeFormat).
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+pub struct RelativeTimeFormatOptions {
+    /// The length of the phrases and units.
+    pub width: Width,
+    /// Whether to use phrases such as "yesterday" instead of numbers.
+    pub numeric: Numeric,
+}
+
+/// The length of the phrases and units, as in "in 3 months", "in 3 mo.", and "in 3mo".
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Width {
+    /// Full units, such as "in 3 months".
+    Long,
+    /// Abbreviated units, such as "in 3 mo.".
+    Short,
+    /// The shortest units of the locale, such as "in 3mo".
+    Narrow,
+}
+
+impl Default for Width {
+    fn default() -> Self {
+        Self::Long
+    }
+}
+
+/// Configuration for when to render numbers.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Numeric {
+    /// Use a phrase when the locale has one for the offset, such as "yesterday", and a number
+    /// otherwise, such as "3 days ago".
+    Auto,
+    /// Always use a number, such as "1 day ago".
+    Always,
+}
+
+impl Default for Numeric {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
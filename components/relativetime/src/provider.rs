@@ -0,0 +1,120 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider struct definitions for this ICU4X component.
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use icu_provider::yoke::{self, *};
+use litemap::LiteMap;
+use std::borrow::Cow;
+
+pub mod key {
+    //! Resource keys for [`icu_relativetime`](crate).
+    use icu_provider::{resource_key, ResourceKey};
+
+    /// Resource key: phrases and patterns for relative times, such as "yesterday" or
+    /// "in {0} days".
+    pub const RELATIVE_TIME_V1: ResourceKey = resource_key!(DateTime, "relative", 1);
+}
+
+/// The phrases and patterns for formatting relative times, by width.
+/// See CLDR-JSON dateFields.json for more context.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+#[yoke(cloning_zcf)]
+pub struct RelativeTimePatternsV1 {
+    pub long: FieldsV1,
+
+    pub short: FieldsV1,
+
+    pub narrow: FieldsV1,
+}
+
+/// The phrases and patterns of each unit for one width.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct FieldsV1 {
+    pub year: FieldV1,
+    pub quarter: FieldV1,
+    pub month: FieldV1,
+    pub week: FieldV1,
+    pub day: FieldV1,
+    pub hour: FieldV1,
+    pub minute: FieldV1,
+    pub second: FieldV1,
+}
+
+/// The default contains the numeric patterns of the CLDR root locale, such as `+{0} d`, and no
+/// phrases.
+impl Default for FieldsV1 {
+    fn default() -> Self {
+        Self {
+            year: FieldV1::numeric("y"),
+            quarter: FieldV1::numeric("Q"),
+            month: FieldV1::numeric("m"),
+            week: FieldV1::numeric("w"),
+            day: FieldV1::numeric("d"),
+            hour: FieldV1::numeric("h"),
+            minute: FieldV1::numeric("min"),
+            second: FieldV1::numeric("s"),
+        }
+    }
+}
+
+/// The phrases and patterns of a unit, such as days.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct FieldV1 {
+    /// Phrases for particular offsets, such as "yesterday" for -1 day.
+    pub relatives: LiteMap<i8, Cow<'static, str>>,
+
+    /// The patterns for offsets in the future, such as "in {0} days".
+    pub future: PluralPatternsV1,
+
+    /// The patterns for offsets in the past, such as "{0} days ago".
+    pub past: PluralPatternsV1,
+}
+
+impl FieldV1 {
+    fn numeric(unit: &str) -> Self {
+        Self {
+            relatives: LiteMap::new(),
+            future: PluralPatternsV1 {
+                other: Cow::Owned(format!("+{{0}} {}", unit)),
+                ..Default::default()
+            },
+            past: PluralPatternsV1 {
+                other: Cow::Owned(format!("-{{0}} {}", unit)),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+/// A pattern for each plural category, in which `{0}` is replaced by the formatted number. Only
+/// `other` is required; the patterns of the other categories default to it.
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(
+    feature = "provider_serde",
+    derive(serde::Serialize, serde::Deserialize)
+)]
+pub struct PluralPatternsV1 {
+    pub zero: Option<Cow<'static, str>>,
+    pub one: Option<Cow<'static, str>>,
+    pub two: Option<Cow<'static, str>>,
+    pub few: Option<Cow<'static, str>>,
+    pub many: Option<Cow<'static, str>>,
+    pub other: Cow<'static, str>,
+}
@@ -0,0 +1,141 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_datetime::mock::datetime::MockDateTime;
+use icu_locid_macros::langid;
+use icu_relativetime::options::{Numeric, RelativeTimeFormatOptions, Width};
+use icu_relativetime::{RelativeTimeFormat, RelativeTimeUnit};
+use writeable::Writeable;
+
+fn assert_formats(
+    langid: icu_locid::LanguageIdentifier,
+    width: Width,
+    numeric: Numeric,
+    cases: &[(i64, RelativeTimeUnit, &str)],
+) {
+    let provider = icu_testdata::get_provider();
+    let options = RelativeTimeFormatOptions { width, numeric };
+    let rtf = RelativeTimeFormat::try_new(langid, &provider, options).unwrap();
+    for (value, unit, expected) in cases {
+        assert_eq!(
+            rtf.format(*value, *unit).writeable_to_string(),
+            *expected,
+            "{} {:?} {:?} {:?}",
+            value,
+            unit,
+            width,
+            numeric
+        );
+    }
+}
+
+#[test]
+fn test_english() {
+    use RelativeTimeUnit::*;
+    assert_formats(
+        langid!("en"),
+        Width::Long,
+        Numeric::Auto,
+        &[
+            (-1, Day, "yesterday"),
+            (0, Day, "today"),
+            (1, Day, "tomorrow"),
+            (2, Day, "in 2 days"),
+            (-3, Day, "3 days ago"),
+            (-1, Week, "last week"),
+            (2, Month, "in 2 months"),
+            (1, Quarter, "next quarter"),
+            (-1, Hour, "1 hour ago"),
+            (0, Second, "now"),
+            (1000, Year, "in 1,000 years"),
+        ],
+    );
+    assert_formats(
+        langid!("en"),
+        Width::Long,
+        Numeric::Always,
+        &[
+            (-1, Day, "1 day ago"),
+            (0, Day, "in 0 days"),
+            (1, Week, "in 1 week"),
+        ],
+    );
+}
+
+#[test]
+fn test_english_widths() {
+    use RelativeTimeUnit::*;
+    assert_formats(
+        langid!("en"),
+        Width::Short,
+        Numeric::Auto,
+        &[
+            (2, Month, "in 2 mo."),
+            (-1, Month, "last mo."),
+            (-5, Minute, "5 min. ago"),
+        ],
+    );
+    assert_formats(
+        langid!("en"),
+        Width::Narrow,
+        Numeric::Auto,
+        &[
+            (2, Month, "in 2mo"),
+            (-1, Month, "last mo."),
+            (-5, Minute, "5m ago"),
+        ],
+    );
+}
+
+#[test]
+fn test_arabic_dual() {
+    use RelativeTimeUnit::*;
+    assert_formats(
+        langid!("ar"),
+        Width::Long,
+        Numeric::Auto,
+        &[
+            (-2, Day, "أول أمس"),
+            (-1, Day, "أمس"),
+            (2, Day, "بعد الغد"),
+            (3, Day, "خلال ٣ أيام"),
+            (2, Week, "خلال أسبوعين"),
+            (-2, Month, "قبل شهرين"),
+            (11, Month, "خلال ١١ شهرًا"),
+        ],
+    );
+    assert_formats(
+        langid!("ar"),
+        Width::Long,
+        Numeric::Always,
+        &[
+            (0, Day, "خلال ٠ يوم"),
+            (1, Day, "خلال يوم واحد"),
+            (-2, Day, "قبل يومين"),
+            (100, Day, "خلال ١٠٠ يوم"),
+        ],
+    );
+}
+
+#[test]
+fn test_format_between() {
+    let provider = icu_testdata::get_provider();
+    let rtf = RelativeTimeFormat::try_new(langid!("en"), &provider, Default::default()).unwrap();
+    let now: MockDateTime = "2021-03-03T12:00:00".parse().unwrap();
+    for (then, expected) in &[
+        ("2021-03-03T12:00:00", "now"),
+        ("2021-03-03T12:05:30", "in 5 minutes"),
+        ("2021-03-02T23:59:00", "yesterday"),
+        ("2021-03-06T08:00:00", "in 3 days"),
+        ("2021-02-24T12:00:00", "last week"),
+        ("2021-05-01T00:00:00", "in 2 months"),
+        ("2020-01-01T00:00:00", "last year"),
+    ] {
+        let then: MockDateTime = then.parse().unwrap();
+        assert_eq!(
+            rtf.format_between(&now, &then).unwrap().to_string(),
+            *expected
+        );
+    }
+}
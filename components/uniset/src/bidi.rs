@@ -0,0 +1,278 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Bidi_Class lookup and paragraph level detection for the Unicode Bidirectional Algorithm
+//! ([UAX #9](https://www.unicode.org/reports/tr9/)).
+//!
+//! Only the base level detection of rules P2 and P3 is implemented; resolving embedding levels
+//! and reordering are not.
+
+use crate::enum_props::BidiClass;
+use crate::props::get_bidi_class_val_set;
+use crate::provider::UnicodePropertyV1Marker;
+use crate::UnicodeSetError;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use icu_provider::prelude::*;
+
+/// All values of the Bidi_Class property, in the order in which their sets are loaded.
+const ALL_BIDI_CLASSES: [BidiClass; 23] = [
+    BidiClass::ArabicLetter,
+    BidiClass::ArabicNumber,
+    BidiClass::ParagraphSeparator,
+    BidiClass::BoundaryNeutral,
+    BidiClass::CommonSeparator,
+    BidiClass::EuropeanNumber,
+    BidiClass::EuropeanSeparator,
+    BidiClass::EuropeanTerminator,
+    BidiClass::FirstStrongIsolate,
+    BidiClass::LeftToRight,
+    BidiClass::LeftToRightEmbedding,
+    BidiClass::LeftToRightIsolate,
+    BidiClass::LeftToRightOverride,
+    BidiClass::NonspacingMark,
+    BidiClass::OtherNeutral,
+    BidiClass::PopDirectionalFormat,
+    BidiClass::PopDirectionalIsolate,
+    BidiClass::RightToLeft,
+    BidiClass::RightToLeftEmbedding,
+    BidiClass::RightToLeftIsolate,
+    BidiClass::RightToLeftOverride,
+    BidiClass::SegmentSeparator,
+    BidiClass::WhiteSpace,
+];
+
+/// The Bidi_Class values of the isolate initiators.
+const ISOLATE_INITIATORS: [BidiClass; 3] = [
+    BidiClass::LeftToRightIsolate, // LRI
+    BidiClass::RightToLeftIsolate, // RLI
+    BidiClass::FirstStrongIsolate, // FSI
+];
+
+/// An embedding level of the Unicode Bidirectional Algorithm. Even levels are left-to-right and
+/// odd levels are right-to-left.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Level(u8);
+
+impl Level {
+    /// The left-to-right paragraph level, 0.
+    pub const LTR: Level = Level(0);
+
+    /// The right-to-left paragraph level, 1.
+    pub const RTL: Level = Level(1);
+
+    /// Returns the numeric value of this level.
+    pub fn number(self) -> u8 {
+        self.0
+    }
+
+    /// Returns whether text at this level is laid out right-to-left.
+    pub fn is_rtl(self) -> bool {
+        self.0 % 2 == 1
+    }
+}
+
+/// A map from code points to their Bidi_Class, built from the per-value sets of the property.
+///
+/// Loading the map reads all Bidi_Class sets once; lookups are then a binary search over the
+/// merged ranges. Code points not contained in any set have the class [`BidiClass::LeftToRight`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BidiClassMap {
+    /// Disjoint half-open code point ranges, sorted by start.
+    ranges: Vec<(u32, u32, BidiClass)>,
+}
+
+impl BidiClassMap {
+    /// Loads the sets of all Bidi_Class values from `provider` and merges them into a map.
+    pub fn try_new<'d, D>(provider: &D) -> Result<Self, UnicodeSetError>
+    where
+        D: DataProvider<'d, 'd, UnicodePropertyV1Marker> + ?Sized,
+    {
+        let mut ranges = Vec::new();
+        for bidi_class in ALL_BIDI_CLASSES.iter() {
+            let set = get_bidi_class_val_set(provider, bidi_class.clone())?;
+            for range in set.as_inversion_list().chunks(2) {
+                ranges.push((range[0], range[1], bidi_class.clone()));
+            }
+        }
+        ranges.sort_by_key(|&(start, _, _)| start);
+        Ok(Self { ranges })
+    }
+
+    /// Returns the Bidi_Class of `c`.
+    pub fn get(&self, c: char) -> BidiClass {
+        let c = c as u32;
+        self.ranges
+            .binary_search_by(|&(start, end, _)| {
+                if end <= c {
+                    Ordering::Less
+                } else if start > c {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .map(|idx| self.ranges[idx].2.clone())
+            .unwrap_or(BidiClass::LeftToRight)
+    }
+}
+
+/// Returns the Bidi_Class of `c`. Code points not contained in any Bidi_Class set have the class
+/// [`BidiClass::LeftToRight`].
+///
+/// This loads up to one set per Bidi_Class value. To look up many code points, load a
+/// [`BidiClassMap`] once instead.
+pub fn get_bidi_class<'d, D>(provider: &D, c: char) -> Result<BidiClass, UnicodeSetError>
+where
+    D: DataProvider<'d, 'd, UnicodePropertyV1Marker> + ?Sized,
+{
+    for bidi_class in ALL_BIDI_CLASSES.iter() {
+        if get_bidi_class_val_set(provider, bidi_class.clone())?.contains(c) {
+            return Ok(bidi_class.clone());
+        }
+    }
+    Ok(BidiClass::LeftToRight)
+}
+
+/// Returns the paragraph embedding level of the first paragraph of `text`, following rules P2
+/// and P3 of the Unicode Bidirectional Algorithm.
+///
+/// The level is [`Level::RTL`] if the first strong character (of class L, R, or AL) is R or AL,
+/// and [`Level::LTR`] otherwise. Characters between an isolate initiator and its matching PDI
+/// are skipped. The scan stops at the first paragraph separator.
+///
+/// `bidi_class` returns the Bidi_Class of a character, usually [`BidiClassMap::get()`].
+///
+/// # Examples
+///
+/// ```
+/// use icu::uniset::bidi::{resolve_paragraph_level, Level};
+/// use icu::uniset::enum_props::BidiClass;
+///
+/// let bidi_class = |c: char| match c {
+///     '\u{05D0}'..='\u{05EA}' => BidiClass::RightToLeft,
+///     ' ' => BidiClass::WhiteSpace,
+///     '1' => BidiClass::EuropeanNumber,
+///     _ => BidiClass::LeftToRight,
+/// };
+///
+/// assert_eq!(resolve_paragraph_level("1 abc", bidi_class), Level::LTR);
+/// let hebrew = "1 \u{05E9}\u{05DC}\u{05D5}\u{05DD} abc";
+/// assert_eq!(resolve_paragraph_level(hebrew, bidi_class), Level::RTL);
+/// ```
+pub fn resolve_paragraph_level<F: Fn(char) -> BidiClass>(text: &str, bidi_class: F) -> Level {
+    let mut isolate_depth = 0usize;
+    for class in text.chars().map(bidi_class) {
+        match class {
+            BidiClass::ParagraphSeparator => break,
+            // A PDI without a matching isolate initiator is ignored.
+            BidiClass::PopDirectionalIsolate => isolate_depth = isolate_depth.saturating_sub(1),
+            _ if ISOLATE_INITIATORS.contains(&class) => isolate_depth += 1,
+            // Characters between an isolate initiator and its PDI are skipped.
+            _ if isolate_depth > 0 => {}
+            BidiClass::LeftToRight => return Level::LTR,
+            BidiClass::RightToLeft | BidiClass::ArabicLetter => return Level::RTL,
+            _ => {}
+        }
+    }
+    Level::LTR
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::provider::{key, UnicodePropertyV1};
+    use crate::UnicodeSet;
+    use alloc::borrow::Cow;
+    use alloc::vec;
+
+    /// Serves the given sets, and an empty set for any other key.
+    struct TestProvider(Vec<(ResourceKey, UnicodeSet)>);
+
+    type Response<'d> = DataResponse<'d, 'd, UnicodePropertyV1Marker>;
+
+    impl<'d> DataProvider<'d, 'd, UnicodePropertyV1Marker> for TestProvider {
+        fn load_payload(&self, req: &DataRequest) -> Result<Response<'d>, DataError> {
+            let prop = match self.0.iter().find(|(k, _)| *k == req.resource_path.key) {
+                Some((_, set)) => UnicodePropertyV1::from_uniset(set, Cow::Borrowed("")),
+                None => UnicodePropertyV1::default(),
+            };
+            Ok(DataResponse {
+                metadata: DataResponseMetadata { data_langid: None },
+                payload: Some(DataPayload::from_owned(prop)),
+            })
+        }
+    }
+
+    fn test_provider() -> TestProvider {
+        let set = |inv_list| UnicodeSet::from_inversion_list(inv_list).unwrap();
+        TestProvider(vec![
+            (key::BIDI_CLASS_LEFT_TO_RIGHT_V1, set(vec![0x41, 0x5B, 0x61, 0x7B])),
+            (key::BIDI_CLASS_WHITE_SPACE_V1, set(vec![0x20, 0x21])),
+            (key::BIDI_CLASS_EUROPEAN_NUMBER_V1, set(vec![0x30, 0x3A])),
+            (key::BIDI_CLASS_PARAGRAPH_SEPARATOR_V1, set(vec![0x0A, 0x0B, 0x2029, 0x202A])),
+            (key::BIDI_CLASS_RIGHT_TO_LEFT_V1, set(vec![0x05D0, 0x05EB])),
+            (key::BIDI_CLASS_ARABIC_LETTER_V1, set(vec![0x0620, 0x064B])),
+            (key::BIDI_CLASS_LEFT_TO_RIGHT_ISOLATE_V1, set(vec![0x2066, 0x2067])),
+            (key::BIDI_CLASS_RIGHT_TO_LEFT_ISOLATE_V1, set(vec![0x2067, 0x2068])),
+            (key::BIDI_CLASS_FIRST_STRONG_ISOLATE_V1, set(vec![0x2068, 0x2069])),
+            (key::BIDI_CLASS_POP_DIRECTIONAL_ISOLATE_V1, set(vec![0x2069, 0x206A])),
+        ])
+    }
+
+    #[test]
+    fn test_get_bidi_class() {
+        let provider = test_provider();
+        let map = BidiClassMap::try_new(&provider).unwrap();
+        for (c, expected) in [
+            ('a', BidiClass::LeftToRight),
+            ('5', BidiClass::EuropeanNumber),
+            (' ', BidiClass::WhiteSpace),
+            ('\u{05D0}', BidiClass::RightToLeft),
+            ('\u{05EA}', BidiClass::RightToLeft),
+            ('\u{0628}', BidiClass::ArabicLetter),
+            ('\u{2069}', BidiClass::PopDirectionalIsolate),
+            // Not in any set
+            ('\u{10FFFF}', BidiClass::LeftToRight),
+        ]
+        .iter()
+        {
+            assert_eq!(&map.get(*c), expected, "{:?}", c);
+            assert_eq!(&get_bidi_class(&provider, *c).unwrap(), expected, "{:?}", c);
+        }
+    }
+
+    #[test]
+    fn test_resolve_paragraph_level() {
+        let map = BidiClassMap::try_new(&test_provider()).unwrap();
+        let level = |text| resolve_paragraph_level(text, |c| map.get(c));
+
+        assert_eq!(level("hello world"), Level::LTR);
+        // Hebrew "shalom"
+        assert_eq!(level("\u{05E9}\u{05DC}\u{05D5}\u{05DD}"), Level::RTL);
+        // Arabic "marhaban"
+        assert_eq!(level("\u{0645}\u{0631}\u{062D}\u{0628}\u{0627}"), Level::RTL);
+        // Weak and neutral characters are skipped.
+        assert_eq!(level("123 \u{05D0}bc"), Level::RTL);
+        assert_eq!(level("123 abc \u{05D0}"), Level::LTR);
+        // No strong characters
+        assert_eq!(level(""), Level::LTR);
+        assert_eq!(level("123 "), Level::LTR);
+        // Isolates are skipped up to the matching PDI, or to the end of the paragraph.
+        assert_eq!(level("\u{2066}abc\u{2069}\u{05D0}"), Level::RTL);
+        assert_eq!(level("\u{2067}\u{2068}a\u{2069}b\u{2069}\u{05D0}"), Level::RTL);
+        assert_eq!(level("\u{2068}abc"), Level::LTR);
+        assert_eq!(level("\u{2069}\u{05D0}"), Level::RTL);
+        // Only the first paragraph is considered.
+        assert_eq!(level("123\n\u{05D0}"), Level::LTR);
+        assert_eq!(level("\u{05D0}\u{2029}abc"), Level::RTL);
+    }
+
+    #[test]
+    fn test_level() {
+        assert!(!Level::LTR.is_rtl());
+        assert!(Level::RTL.is_rtl());
+        assert_eq!(Level::RTL.number(), 1);
+    }
+}
@@ -2,7 +2,8 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use std::{
+use alloc::vec;
+use core::{
     convert::TryFrom,
     ops::{Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive},
 };
@@ -99,7 +100,7 @@ mod tests {
     fn test_try_from_range_from() {
         let uset = UnicodeSet::try_from(&('A'..)).unwrap();
         let check: usize = uset.size();
-        let expected: usize = (char::MAX as usize) + 1 - 65;
+        let expected: usize = (char::MAX as usize) + 1 - 65 - 0x800;
         assert_eq!(expected, check);
     }
     #[test]
@@ -125,7 +126,7 @@ mod tests {
     fn test_try_from_range_full() {
         let uset = UnicodeSet::try_from(&(..)).unwrap();
         let check: usize = uset.size();
-        let expected: usize = (char::MAX as usize) + 1;
+        let expected: usize = (char::MAX as usize) + 1 - 0x800;
         assert_eq!(expected, check);
     }
 }
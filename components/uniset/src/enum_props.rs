@@ -4,6 +4,14 @@
 
 #![allow(clippy::upper_case_acronyms)]
 
+#[cfg(feature = "std")]
+use crate::UnicodeSetError;
+#[cfg(feature = "std")]
+use core::convert::TryFrom;
+use core::ops::{BitOr, BitOrAssign};
+#[cfg(feature = "std")]
+use icu_locid::subtags;
+
 #[derive(Clone, PartialEq, Debug)]
 pub enum EnumeratedProperty {
     BidiClass,
@@ -203,6 +211,100 @@ pub enum GeneralCategory {
     SpaceSeparator,
 }
 
+impl GeneralCategory {
+    /// Returns the major category group (the first letter of the short General_Category name)
+    /// that this value belongs to. Group values such as [`GeneralCategory::Letter`] belong to
+    /// their own group.
+    pub fn group(&self) -> GeneralCategoryGroup {
+        match self {
+            GeneralCategory::Other | GeneralCategory::Cntrl | GeneralCategory::Format | GeneralCategory::Unassigned | GeneralCategory::PrivateUse | GeneralCategory::Surrogate => {
+                GeneralCategoryGroup::OTHER
+            }
+            GeneralCategory::Letter
+            | GeneralCategory::CasedLetter
+            | GeneralCategory::LowercaseLetter
+            | GeneralCategory::ModifierLetter
+            | GeneralCategory::OtherLetter
+            | GeneralCategory::TitlecaseLetter
+            | GeneralCategory::UppercaseLetter => GeneralCategoryGroup::LETTER,
+            GeneralCategory::CombiningMark | GeneralCategory::SpacingMark | GeneralCategory::EnclosingMark | GeneralCategory::NonspacingMark => GeneralCategoryGroup::MARK,
+            GeneralCategory::Number | GeneralCategory::Digit | GeneralCategory::LetterNumber | GeneralCategory::OtherNumber => GeneralCategoryGroup::NUMBER,
+            GeneralCategory::Punct
+            | GeneralCategory::ConnectorPunctuation
+            | GeneralCategory::DashPunctuation
+            | GeneralCategory::ClosePunctuation
+            | GeneralCategory::FinalPunctuation
+            | GeneralCategory::InitialPunctuation
+            | GeneralCategory::OtherPunctuation
+            | GeneralCategory::OpenPunctuation => GeneralCategoryGroup::PUNCTUATION,
+            GeneralCategory::Symbol | GeneralCategory::CurrencySymbol | GeneralCategory::ModifierSymbol | GeneralCategory::MathSymbol | GeneralCategory::OtherSymbol => GeneralCategoryGroup::SYMBOL,
+            GeneralCategory::Separator | GeneralCategory::LineSeparator | GeneralCategory::ParagraphSeparator | GeneralCategory::SpaceSeparator => GeneralCategoryGroup::SEPARATOR,
+        }
+    }
+}
+
+/// A bit mask of [`GeneralCategory`] groups, used to test membership in a major category such
+/// as "any Letter" (`L`) without listing each of its values. Masks can be combined with `|`.
+///
+/// # Examples
+///
+/// ```
+/// use icu::uniset::enum_props::{GeneralCategory, GeneralCategoryGroup};
+///
+/// assert!(GeneralCategoryGroup::LETTER.contains(GeneralCategory::TitlecaseLetter));
+/// assert!(!GeneralCategoryGroup::LETTER.contains(GeneralCategory::Digit));
+///
+/// let mask = GeneralCategoryGroup::LETTER | GeneralCategoryGroup::NUMBER;
+/// assert!(mask.contains(GeneralCategory::Digit));
+/// assert!(!mask.contains(GeneralCategory::SpaceSeparator));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct GeneralCategoryGroup(u8);
+
+impl GeneralCategoryGroup {
+    pub const LETTER: GeneralCategoryGroup = GeneralCategoryGroup(1 << 0);
+    pub const MARK: GeneralCategoryGroup = GeneralCategoryGroup(1 << 1);
+    pub const NUMBER: GeneralCategoryGroup = GeneralCategoryGroup(1 << 2);
+    pub const PUNCTUATION: GeneralCategoryGroup = GeneralCategoryGroup(1 << 3);
+    pub const SYMBOL: GeneralCategoryGroup = GeneralCategoryGroup(1 << 4);
+    pub const SEPARATOR: GeneralCategoryGroup = GeneralCategoryGroup(1 << 5);
+    pub const OTHER: GeneralCategoryGroup = GeneralCategoryGroup(1 << 6);
+
+    /// All groups, in the order of their bits.
+    pub(crate) const ALL: [GeneralCategoryGroup; 7] = [
+        GeneralCategoryGroup::LETTER,
+        GeneralCategoryGroup::MARK,
+        GeneralCategoryGroup::NUMBER,
+        GeneralCategoryGroup::PUNCTUATION,
+        GeneralCategoryGroup::SYMBOL,
+        GeneralCategoryGroup::SEPARATOR,
+        GeneralCategoryGroup::OTHER,
+    ];
+
+    /// Returns whether the given General_Category value belongs to any group in this mask.
+    pub fn contains(self, gc: GeneralCategory) -> bool {
+        self.0 & gc.group().0 != 0
+    }
+
+    /// Returns whether every group in `other` is also in this mask.
+    pub fn contains_group(self, other: GeneralCategoryGroup) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for GeneralCategoryGroup {
+    type Output = GeneralCategoryGroup;
+    fn bitor(self, rhs: GeneralCategoryGroup) -> GeneralCategoryGroup {
+        GeneralCategoryGroup(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for GeneralCategoryGroup {
+    fn bitor_assign(&mut self, rhs: GeneralCategoryGroup) {
+        self.0 |= rhs.0;
+    }
+}
+
 #[derive(Clone, PartialEq, Debug)]
 pub enum GraphemeClusterBreak {
     Control,
@@ -556,6 +658,466 @@ pub enum NumericType {
     Numeric,
 }
 
+// The Rust enum discriminant values match the `UScriptCode` values in ICU4C, so those
+// numerical values are preserved here, too. `InvalidCode` and `CodeLimit` are not scripts.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "provider_serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Script {
+    InvalidCode = -1,
+    Common = 0,
+    Inherited = 1,
+    Arabic = 2,
+    Armenian = 3,
+    Bengali = 4,
+    Bopomofo = 5,
+    Cherokee = 6,
+    Coptic = 7,
+    Cyrillic = 8,
+    Deseret = 9,
+    Devanagari = 10,
+    Ethiopic = 11,
+    Georgian = 12,
+    Gothic = 13,
+    Greek = 14,
+    Gujarati = 15,
+    Gurmukhi = 16,
+    Han = 17,
+    Hangul = 18,
+    Hebrew = 19,
+    Hiragana = 20,
+    Kannada = 21,
+    Katakana = 22,
+    Khmer = 23,
+    Lao = 24,
+    Latin = 25,
+    Malayalam = 26,
+    Mongolian = 27,
+    Myanmar = 28,
+    Ogham = 29,
+    OldItalic = 30,
+    Oriya = 31,
+    Runic = 32,
+    Sinhala = 33,
+    Syriac = 34,
+    Tamil = 35,
+    Telugu = 36,
+    Thaana = 37,
+    Thai = 38,
+    Tibetan = 39,
+    CanadianAboriginal = 40,
+    Yi = 41,
+    Tagalog = 42,
+    Hanunoo = 43,
+    Buhid = 44,
+    Tagbanwa = 45,
+    Braille = 46,
+    Cypriot = 47,
+    Limbu = 48,
+    LinearB = 49,
+    Osmanya = 50,
+    Shavian = 51,
+    TaiLe = 52,
+    Ugaritic = 53,
+    KatakanaOrHiragana = 54,
+    Buginese = 55,
+    Glagolitic = 56,
+    Kharoshthi = 57,
+    SylotiNagri = 58,
+    NewTaiLue = 59,
+    Tifinagh = 60,
+    OldPersian = 61,
+    Balinese = 62,
+    Batak = 63,
+    Blissymbols = 64,
+    Brahmi = 65,
+    Cham = 66,
+    Cirth = 67,
+    OldChurchSlavonicCyrillic = 68,
+    DemoticEgyptian = 69,
+    HieraticEgyptian = 70,
+    EgyptianHieroglyphs = 71,
+    Khutsuri = 72,
+    SimplifiedHan = 73,
+    TraditionalHan = 74,
+    PahawhHmong = 75,
+    OldHungarian = 76,
+    HarappanIndus = 77,
+    Javanese = 78,
+    KayahLi = 79,
+    LatinFraktur = 80,
+    LatinGaelic = 81,
+    Lepcha = 82,
+    LinearA = 83,
+    Mandaic = 84,
+    MayanHieroglyphs = 85,
+    MeroiticHieroglyphs = 86,
+    Nko = 87,
+    OldTurkic = 88,
+    OldPermic = 89,
+    PhagsPa = 90,
+    Phoenician = 91,
+    Miao = 92,
+    Rongorongo = 93,
+    Sarati = 94,
+    EstrangeloSyriac = 95,
+    WesternSyriac = 96,
+    EasternSyriac = 97,
+    Tengwar = 98,
+    Vai = 99,
+    VisibleSpeech = 100,
+    Cuneiform = 101,
+    UnwrittenLanguages = 102,
+    Unknown = 103,
+    Carian = 104,
+    Japanese = 105,
+    TaiTham = 106,
+    Lycian = 107,
+    Lydian = 108,
+    OlChiki = 109,
+    Rejang = 110,
+    Saurashtra = 111,
+    SignWriting = 112,
+    Sundanese = 113,
+    Moon = 114,
+    MeeteiMayek = 115,
+    ImperialAramaic = 116,
+    Avestan = 117,
+    Chakma = 118,
+    Korean = 119,
+    Kaithi = 120,
+    Manichaean = 121,
+    InscriptionalPahlavi = 122,
+    PsalterPahlavi = 123,
+    BookPahlavi = 124,
+    InscriptionalParthian = 125,
+    Samaritan = 126,
+    TaiViet = 127,
+    MathematicalNotation = 128,
+    Symbols = 129,
+    Bamum = 130,
+    Lisu = 131,
+    NakhiGeba = 132,
+    OldSouthArabian = 133,
+    BassaVah = 134,
+    Duployan = 135,
+    Elbasan = 136,
+    Grantha = 137,
+    Kpelle = 138,
+    Loma = 139,
+    MendeKikakui = 140,
+    MeroiticCursive = 141,
+    OldNorthArabian = 142,
+    Nabataean = 143,
+    Palmyrene = 144,
+    Khudawadi = 145,
+    WarangCiti = 146,
+    Afaka = 147,
+    Jurchen = 148,
+    Mro = 149,
+    Nushu = 150,
+    Sharada = 151,
+    SoraSompeng = 152,
+    Takri = 153,
+    Tangut = 154,
+    Woleai = 155,
+    AnatolianHieroglyphs = 156,
+    Khojki = 157,
+    Tirhuta = 158,
+    CaucasianAlbanian = 159,
+    Mahajani = 160,
+    Ahom = 161,
+    Hatran = 162,
+    Modi = 163,
+    Multani = 164,
+    PauCinHau = 165,
+    Siddham = 166,
+    Adlam = 167,
+    Bhaiksuki = 168,
+    Marchen = 169,
+    Newa = 170,
+    Osage = 171,
+    HanWithBopomofo = 172,
+    Jamo = 173,
+    SymbolsEmoji = 174,
+    MasaramGondi = 175,
+    Soyombo = 176,
+    ZanabazarSquare = 177,
+    Dogra = 178,
+    GunjalaGondi = 179,
+    Makasar = 180,
+    Medefaidrin = 181,
+    HanifiRohingya = 182,
+    Sogdian = 183,
+    OldSogdian = 184,
+    Elymaic = 185,
+    NyiakengPuachueHmong = 186,
+    Nandinagari = 187,
+    Wancho = 188,
+    Chorasmian = 189,
+    DivesAkuru = 190,
+    KhitanSmallScript = 191,
+    Yezidi = 192,
+    CodeLimit = 193,
+}
+
+/// ISO 15924 codes of the scripts, indexed by the [`Script`] discriminant value.
+const ISO_15924_CODES: [(&str, Script); 193] = [
+    ("Zyyy", Script::Common),
+    ("Zinh", Script::Inherited),
+    ("Arab", Script::Arabic),
+    ("Armn", Script::Armenian),
+    ("Beng", Script::Bengali),
+    ("Bopo", Script::Bopomofo),
+    ("Cher", Script::Cherokee),
+    ("Copt", Script::Coptic),
+    ("Cyrl", Script::Cyrillic),
+    ("Dsrt", Script::Deseret),
+    ("Deva", Script::Devanagari),
+    ("Ethi", Script::Ethiopic),
+    ("Geor", Script::Georgian),
+    ("Goth", Script::Gothic),
+    ("Grek", Script::Greek),
+    ("Gujr", Script::Gujarati),
+    ("Guru", Script::Gurmukhi),
+    ("Hani", Script::Han),
+    ("Hang", Script::Hangul),
+    ("Hebr", Script::Hebrew),
+    ("Hira", Script::Hiragana),
+    ("Knda", Script::Kannada),
+    ("Kana", Script::Katakana),
+    ("Khmr", Script::Khmer),
+    ("Laoo", Script::Lao),
+    ("Latn", Script::Latin),
+    ("Mlym", Script::Malayalam),
+    ("Mong", Script::Mongolian),
+    ("Mymr", Script::Myanmar),
+    ("Ogam", Script::Ogham),
+    ("Ital", Script::OldItalic),
+    ("Orya", Script::Oriya),
+    ("Runr", Script::Runic),
+    ("Sinh", Script::Sinhala),
+    ("Syrc", Script::Syriac),
+    ("Taml", Script::Tamil),
+    ("Telu", Script::Telugu),
+    ("Thaa", Script::Thaana),
+    ("Thai", Script::Thai),
+    ("Tibt", Script::Tibetan),
+    ("Cans", Script::CanadianAboriginal),
+    ("Yiii", Script::Yi),
+    ("Tglg", Script::Tagalog),
+    ("Hano", Script::Hanunoo),
+    ("Buhd", Script::Buhid),
+    ("Tagb", Script::Tagbanwa),
+    ("Brai", Script::Braille),
+    ("Cprt", Script::Cypriot),
+    ("Limb", Script::Limbu),
+    ("Linb", Script::LinearB),
+    ("Osma", Script::Osmanya),
+    ("Shaw", Script::Shavian),
+    ("Tale", Script::TaiLe),
+    ("Ugar", Script::Ugaritic),
+    ("Hrkt", Script::KatakanaOrHiragana),
+    ("Bugi", Script::Buginese),
+    ("Glag", Script::Glagolitic),
+    ("Khar", Script::Kharoshthi),
+    ("Sylo", Script::SylotiNagri),
+    ("Talu", Script::NewTaiLue),
+    ("Tfng", Script::Tifinagh),
+    ("Xpeo", Script::OldPersian),
+    ("Bali", Script::Balinese),
+    ("Batk", Script::Batak),
+    ("Blis", Script::Blissymbols),
+    ("Brah", Script::Brahmi),
+    ("Cham", Script::Cham),
+    ("Cirt", Script::Cirth),
+    ("Cyrs", Script::OldChurchSlavonicCyrillic),
+    ("Egyd", Script::DemoticEgyptian),
+    ("Egyh", Script::HieraticEgyptian),
+    ("Egyp", Script::EgyptianHieroglyphs),
+    ("Geok", Script::Khutsuri),
+    ("Hans", Script::SimplifiedHan),
+    ("Hant", Script::TraditionalHan),
+    ("Hmng", Script::PahawhHmong),
+    ("Hung", Script::OldHungarian),
+    ("Inds", Script::HarappanIndus),
+    ("Java", Script::Javanese),
+    ("Kali", Script::KayahLi),
+    ("Latf", Script::LatinFraktur),
+    ("Latg", Script::LatinGaelic),
+    ("Lepc", Script::Lepcha),
+    ("Lina", Script::LinearA),
+    ("Mand", Script::Mandaic),
+    ("Maya", Script::MayanHieroglyphs),
+    ("Mero", Script::MeroiticHieroglyphs),
+    ("Nkoo", Script::Nko),
+    ("Orkh", Script::OldTurkic),
+    ("Perm", Script::OldPermic),
+    ("Phag", Script::PhagsPa),
+    ("Phnx", Script::Phoenician),
+    ("Plrd", Script::Miao),
+    ("Roro", Script::Rongorongo),
+    ("Sara", Script::Sarati),
+    ("Syre", Script::EstrangeloSyriac),
+    ("Syrj", Script::WesternSyriac),
+    ("Syrn", Script::EasternSyriac),
+    ("Teng", Script::Tengwar),
+    ("Vaii", Script::Vai),
+    ("Visp", Script::VisibleSpeech),
+    ("Xsux", Script::Cuneiform),
+    ("Zxxx", Script::UnwrittenLanguages),
+    ("Zzzz", Script::Unknown),
+    ("Cari", Script::Carian),
+    ("Jpan", Script::Japanese),
+    ("Lana", Script::TaiTham),
+    ("Lyci", Script::Lycian),
+    ("Lydi", Script::Lydian),
+    ("Olck", Script::OlChiki),
+    ("Rjng", Script::Rejang),
+    ("Saur", Script::Saurashtra),
+    ("Sgnw", Script::SignWriting),
+    ("Sund", Script::Sundanese),
+    ("Moon", Script::Moon),
+    ("Mtei", Script::MeeteiMayek),
+    ("Armi", Script::ImperialAramaic),
+    ("Avst", Script::Avestan),
+    ("Cakm", Script::Chakma),
+    ("Kore", Script::Korean),
+    ("Kthi", Script::Kaithi),
+    ("Mani", Script::Manichaean),
+    ("Phli", Script::InscriptionalPahlavi),
+    ("Phlp", Script::PsalterPahlavi),
+    ("Phlv", Script::BookPahlavi),
+    ("Prti", Script::InscriptionalParthian),
+    ("Samr", Script::Samaritan),
+    ("Tavt", Script::TaiViet),
+    ("Zmth", Script::MathematicalNotation),
+    ("Zsym", Script::Symbols),
+    ("Bamu", Script::Bamum),
+    ("Lisu", Script::Lisu),
+    ("Nkgb", Script::NakhiGeba),
+    ("Sarb", Script::OldSouthArabian),
+    ("Bass", Script::BassaVah),
+    ("Dupl", Script::Duployan),
+    ("Elba", Script::Elbasan),
+    ("Gran", Script::Grantha),
+    ("Kpel", Script::Kpelle),
+    ("Loma", Script::Loma),
+    ("Mend", Script::MendeKikakui),
+    ("Merc", Script::MeroiticCursive),
+    ("Narb", Script::OldNorthArabian),
+    ("Nbat", Script::Nabataean),
+    ("Palm", Script::Palmyrene),
+    ("Sind", Script::Khudawadi),
+    ("Wara", Script::WarangCiti),
+    ("Afak", Script::Afaka),
+    ("Jurc", Script::Jurchen),
+    ("Mroo", Script::Mro),
+    ("Nshu", Script::Nushu),
+    ("Shrd", Script::Sharada),
+    ("Sora", Script::SoraSompeng),
+    ("Takr", Script::Takri),
+    ("Tang", Script::Tangut),
+    ("Wole", Script::Woleai),
+    ("Hluw", Script::AnatolianHieroglyphs),
+    ("Khoj", Script::Khojki),
+    ("Tirh", Script::Tirhuta),
+    ("Aghb", Script::CaucasianAlbanian),
+    ("Mahj", Script::Mahajani),
+    ("Ahom", Script::Ahom),
+    ("Hatr", Script::Hatran),
+    ("Modi", Script::Modi),
+    ("Mult", Script::Multani),
+    ("Pauc", Script::PauCinHau),
+    ("Sidd", Script::Siddham),
+    ("Adlm", Script::Adlam),
+    ("Bhks", Script::Bhaiksuki),
+    ("Marc", Script::Marchen),
+    ("Newa", Script::Newa),
+    ("Osge", Script::Osage),
+    ("Hanb", Script::HanWithBopomofo),
+    ("Jamo", Script::Jamo),
+    ("Zsye", Script::SymbolsEmoji),
+    ("Gonm", Script::MasaramGondi),
+    ("Soyo", Script::Soyombo),
+    ("Zanb", Script::ZanabazarSquare),
+    ("Dogr", Script::Dogra),
+    ("Gong", Script::GunjalaGondi),
+    ("Maka", Script::Makasar),
+    ("Medf", Script::Medefaidrin),
+    ("Rohg", Script::HanifiRohingya),
+    ("Sogd", Script::Sogdian),
+    ("Sogo", Script::OldSogdian),
+    ("Elym", Script::Elymaic),
+    ("Hmnp", Script::NyiakengPuachueHmong),
+    ("Nand", Script::Nandinagari),
+    ("Wcho", Script::Wancho),
+    ("Chrs", Script::Chorasmian),
+    ("Diak", Script::DivesAkuru),
+    ("Kits", Script::KhitanSmallScript),
+    ("Yezi", Script::Yezidi),
+];
+
+impl Script {
+    /// Returns the script with the given ISO 15924 four-letter code, such as `"Latn"`. The code is
+    /// matched ASCII case-insensitively.
+    ///
+    /// Returns `None` for codes that do not name a script, so this never returns
+    /// [`Script::InvalidCode`] or [`Script::CodeLimit`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::uniset::enum_props::Script;
+    ///
+    /// assert_eq!(Script::from_iso15924("Latn"), Some(Script::Latin));
+    /// assert_eq!(Script::from_iso15924("hani"), Some(Script::Han));
+    /// assert_eq!(Script::from_iso15924("Abcd"), None);
+    /// ```
+    pub fn from_iso15924(code: &str) -> Option<Script> {
+        ISO_15924_CODES.iter().find(|(iso, _)| iso.eq_ignore_ascii_case(code)).map(|(_, script)| *script)
+    }
+
+    /// Returns the ISO 15924 four-letter code of this script, such as `"Latn"`.
+    ///
+    /// Returns `None` for [`Script::InvalidCode`] and [`Script::CodeLimit`], which are not scripts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::uniset::enum_props::Script;
+    ///
+    /// assert_eq!(Script::Latin.to_iso15924(), Some("Latn"));
+    /// assert_eq!(Script::InvalidCode.to_iso15924(), None);
+    /// ```
+    pub fn to_iso15924(self) -> Option<&'static str> {
+        match self {
+            Script::InvalidCode | Script::CodeLimit => None,
+            _ => ISO_15924_CODES.get(self as usize).map(|(iso, _)| *iso),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<subtags::Script> for Script {
+    type Error = UnicodeSetError;
+
+    /// Converts a locale script subtag to a [`Script`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locid::subtags;
+    /// use icu::uniset::enum_props::Script;
+    /// use std::convert::TryFrom;
+    ///
+    /// let subtag: subtags::Script = "Cyrl".parse().unwrap();
+    /// assert_eq!(Script::try_from(subtag).unwrap(), Script::Cyrillic);
+    /// ```
+    fn try_from(subtag: subtags::Script) -> Result<Self, Self::Error> {
+        Script::from_iso15924(subtag.as_str()).ok_or(UnicodeSetError::UnknownScript(subtag))
+    }
+}
+
 #[derive(Clone, PartialEq, Debug)]
 pub enum SentenceBreak {
     ATerm,
@@ -673,3 +1235,31 @@ pub enum WordBreak {
     Other,
     ZWJ,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iso15924_round_trip() {
+        for (i, (iso, script)) in ISO_15924_CODES.iter().enumerate() {
+            assert_eq!(*script as usize, i, "{}", iso);
+            assert_eq!(script.to_iso15924(), Some(*iso));
+            assert_eq!(Script::from_iso15924(iso), Some(*script));
+        }
+        assert_eq!(Script::CodeLimit as usize, ISO_15924_CODES.len());
+        assert_eq!(Script::from_iso15924("LATN"), Some(Script::Latin));
+        assert_eq!(Script::from_iso15924(""), None);
+        assert_eq!(Script::from_iso15924("Latin"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_try_from_subtag() {
+        let subtag: subtags::Script = "Latn".parse().unwrap();
+        assert_eq!(Script::try_from(subtag).unwrap(), Script::Latin);
+
+        let subtag: subtags::Script = "Qaaa".parse().unwrap();
+        assert!(matches!(Script::try_from(subtag), Err(UnicodeSetError::UnknownScript(s)) if s == subtag));
+    }
+}
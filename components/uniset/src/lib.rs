@@ -47,21 +47,38 @@
 //! assert_eq!(set.iter_chars().next(), Some('A'));
 //! ```
 //!
+//! # Features
+//!
+//! - `std` (default): links the standard library and enables the data-provider-backed [`props`],
+//!   [`bidi`], and [`provider`] modules. Without it, this crate is `no_std` and only requires `alloc`.
+//! - `provider_serde` (default): enables Serde support for the data structs in [`provider`]. Implies `std`.
+//!
 //! [`ICU4X`]: ../icu/index.html
 
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod bidi;
 #[macro_use]
 mod builder;
 mod conversions;
 pub mod enum_props;
+#[cfg(feature = "std")]
 pub mod props;
+#[cfg(feature = "std")]
 pub mod provider;
 mod uniset;
 mod utils;
 
+use alloc::vec::Vec;
 pub use builder::UnicodeSetBuilder;
-pub use conversions::*;
 use displaydoc::Display;
+#[cfg(feature = "std")]
 use icu_provider::DataError;
+#[cfg(feature = "serde")]
+pub use uniset::delta;
 pub use uniset::UnicodeSet;
 pub use utils::*;
 
@@ -72,20 +89,29 @@ pub enum UnicodeSetError {
     InvalidSet(Vec<u32>),
     #[displaydoc("Invalid range: {0}..{1}")]
     InvalidRange(u32, u32),
+    #[cfg(feature = "std")]
     #[displaydoc("{0}")]
     PropDataLoad(DataError),
+    #[cfg(feature = "std")]
+    #[displaydoc("Unknown script: {0}")]
+    UnknownScript(icu_locid::subtags::Script),
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for UnicodeSetError {}
 
+#[cfg(feature = "std")]
 impl From<DataError> for UnicodeSetError {
     fn from(e: DataError) -> Self {
         UnicodeSetError::PropDataLoad(e)
     }
 }
 
-#[derive(PartialEq)]
+/// Which characters [`UnicodeSet::span()`] and [`UnicodeSet::span_back()`] should include in the span.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum UnicodeSetSpanCondition {
+    /// Span over characters that are contained in the set.
     Contained,
+    /// Span over characters that are not contained in the set.
     NotContained,
 }
@@ -9,9 +9,9 @@
 
 use crate::enum_props::*;
 use crate::provider::*;
-use crate::{UnicodeSet, UnicodeSetError};
+use crate::{UnicodeSet, UnicodeSetBuilder, UnicodeSetError};
+use core::convert::TryInto;
 use icu_provider::prelude::*;
-use std::convert::TryInto;
 
 type UnisetResult = Result<UnicodeSet, UnicodeSetError>;
 
@@ -480,6 +480,25 @@ pub fn get_general_category_val_set<'d, D: DataProvider<'d, 'd, UnicodePropertyV
     }
 }
 
+/// Return a [`UnicodeSet`] of the code points whose General_Category value belongs to any of the
+/// groups in `groups`, such as all letters (`\p{L}`).
+pub fn get_general_category_group_set<'d, D: DataProvider<'d, 'd, UnicodePropertyV1Marker> + ?Sized>(provider: &D, groups: GeneralCategoryGroup) -> UnisetResult {
+    let mut builder = UnicodeSetBuilder::new();
+    for group in GeneralCategoryGroup::ALL.iter().filter(|g| groups.contains_group(**g)) {
+        let group_val = match *group {
+            GeneralCategoryGroup::LETTER => GeneralCategory::Letter,
+            GeneralCategoryGroup::MARK => GeneralCategory::CombiningMark,
+            GeneralCategoryGroup::NUMBER => GeneralCategory::Number,
+            GeneralCategoryGroup::PUNCTUATION => GeneralCategory::Punct,
+            GeneralCategoryGroup::SYMBOL => GeneralCategory::Symbol,
+            GeneralCategoryGroup::SEPARATOR => GeneralCategory::Separator,
+            _ => GeneralCategory::Other,
+        };
+        builder.add_set(&get_general_category_val_set(provider, group_val)?);
+    }
+    Ok(builder.build())
+}
+
 /// Return a [`UnicodeSet`] for a particular value of the Grapheme_Cluster_Break Unicode enumerated property
 pub fn get_grapheme_cluster_break_val_set<'d, D: DataProvider<'d, 'd, UnicodePropertyV1Marker> + ?Sized>(provider: &D, enum_val: GraphemeClusterBreak) -> UnisetResult {
     match enum_val {
@@ -980,3 +999,44 @@ pub fn get_word_break_val_set<'d, D: DataProvider<'d, 'd, UnicodePropertyV1Marke
         WordBreak::ZWJ => get_prop(provider, key::WORD_BREAK_ZWJ_V1),
     }
 }
+
+//
+// Script_Extensions getter fn
+//
+
+/// Return the data for the Script_Extensions Unicode property, which maps each code point to the
+/// scripts that it is used with. Load it once and look up code points with
+/// [`ScriptExtensionsV1::get()`]. A code point that is used with a single script yields exactly
+/// that script.
+///
+/// # Examples
+///
+/// ```
+/// use icu::uniset::enum_props::Script;
+/// use icu::uniset::props::get_script_extensions;
+/// use icu::uniset::provider::{key, ScriptExtensionsV1};
+/// use icu_provider::struct_provider::StructProvider;
+///
+/// let data = ScriptExtensionsV1 {
+///     ranges: vec![(0x41, 0x5B, 0), (0x640, 0x641, 1)],
+///     extensions: vec![vec![Script::Latin], vec![Script::Arabic, Script::Syriac]],
+/// };
+/// let provider = StructProvider { key: key::SCRIPT_EXTENSIONS_V1, data: &data };
+///
+/// let payload = get_script_extensions(&provider).unwrap();
+/// let scx = payload.get();
+/// assert_eq!(scx.get('A'), &[Script::Latin]);
+///
+/// // U+0640 ARABIC TATWEEL
+/// assert_eq!(scx.get('\u{0640}'), &[Script::Arabic, Script::Syriac]);
+/// ```
+pub fn get_script_extensions<'d, D: DataProvider<'d, 'd, ScriptExtensionsV1Marker> + ?Sized>(provider: &D) -> Result<DataPayload<'d, 'd, ScriptExtensionsV1Marker>, UnicodeSetError> {
+    let data_req = DataRequest {
+        resource_path: ResourcePath {
+            key: key::SCRIPT_EXTENSIONS_V1,
+            options: ResourceOptions { variant: None, langid: None },
+        },
+    };
+    let resp: DataResponse<ScriptExtensionsV1Marker> = provider.load_payload(&data_req)?;
+    Ok(resp.take_payload()?)
+}
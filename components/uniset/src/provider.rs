@@ -7,10 +7,14 @@
 //! Read more about data providers: [`icu_provider`]
 
 use crate::builder::UnicodeSetBuilder;
+use crate::enum_props::Script;
 use crate::uniset::UnicodeSet;
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::{TryFrom, TryInto};
 use icu_provider::yoke::{self, *};
-use std::borrow::Cow;
-use std::convert::TryInto;
+use zerovec::ZeroVec;
 //
 // resource key structs - the structs used directly by users of data provider
 //
@@ -653,6 +657,10 @@ pub mod key {
         (WORD_BREAK_OTHER_V1, "22=21"),
         (WORD_BREAK_ZWJ_V1, "22=22"),
     );
+
+    /// Key for [`ScriptExtensionsV1`](super::ScriptExtensionsV1), which is not a set of code
+    /// points and is therefore not part of [`ALL_KEYS`].
+    pub const SCRIPT_EXTENSIONS_V1: ResourceKey = resource_key!(UnicodeSet, "scx", 1);
 }
 
 #[icu_provider::data_struct]
@@ -685,3 +693,128 @@ impl<'s> TryInto<UnicodeSet> for UnicodePropertyV1<'s> {
         Ok(self.inv_list)
     }
 }
+
+/// Zero-copy form of [`UnicodePropertyV1`], whose name and inversion list can be borrowed from
+/// the buffer they are deserialized from, such as a bincode blob.
+///
+/// The inversion list is not validated when deserializing; it is validated when converting into a
+/// [`UnicodeSet`] or a [`UnicodePropertyV1`].
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "provider_serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnicodePropertyZcV1<'s> {
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub name: Cow<'s, str>,
+    #[cfg_attr(feature = "provider_serde", serde(borrow))]
+    pub inv_list: ZeroVec<'s, u32>,
+}
+
+impl<'s> From<UnicodePropertyV1<'s>> for UnicodePropertyZcV1<'s> {
+    fn from(prop: UnicodePropertyV1<'s>) -> UnicodePropertyZcV1<'s> {
+        UnicodePropertyZcV1 {
+            name: prop.name,
+            inv_list: ZeroVec::from_aligned(prop.inv_list.as_inversion_list()),
+        }
+    }
+}
+
+impl<'s> TryFrom<UnicodePropertyZcV1<'s>> for UnicodePropertyV1<'s> {
+    type Error = crate::UnicodeSetError;
+    fn try_from(prop: UnicodePropertyZcV1<'s>) -> Result<UnicodePropertyV1<'s>, Self::Error> {
+        Ok(UnicodePropertyV1 {
+            inv_list: UnicodeSet::from_inversion_list(prop.inv_list.to_vec())?,
+            name: prop.name,
+        })
+    }
+}
+
+impl<'s> TryInto<UnicodeSet> for UnicodePropertyZcV1<'s> {
+    type Error = crate::UnicodeSetError;
+    fn try_into(self) -> Result<UnicodeSet, Self::Error> {
+        UnicodeSet::from_inversion_list(self.inv_list.to_vec())
+    }
+}
+
+/// Data for the Script_Extensions Unicode property, which maps each code point to the set of
+/// scripts it is used with.
+///
+/// Code point ranges are mapped to an index into a table of distinct script sets, so ranges with
+/// the same Script_Extensions value share a single entry. A code point used with only one script
+/// maps to a set containing exactly that script.
+#[icu_provider::data_struct]
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "provider_serde", derive(serde::Serialize, serde::Deserialize))]
+#[yoke(cloning_zcf)]
+pub struct ScriptExtensionsV1 {
+    /// Sorted, non-overlapping code point ranges as `(start, end, index)`, where `end` is
+    /// exclusive and `index` points into `extensions`.
+    pub ranges: Vec<(u32, u32, u16)>,
+    /// The distinct Script_Extensions values.
+    pub extensions: Vec<Vec<Script>>,
+}
+
+impl ScriptExtensionsV1 {
+    /// Returns the Script_Extensions value of a code point. Code points that are not covered by
+    /// any range have the value [`Script::Unknown`].
+    pub fn get(&self, c: char) -> &[Script] {
+        let cp = c as u32;
+        self.ranges
+            .binary_search_by(|&(start, end, _)| {
+                if end <= cp {
+                    Ordering::Less
+                } else if start > cp {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .ok()
+            .and_then(|i| self.extensions.get(self.ranges[i].2 as usize))
+            .map(Vec::as_slice)
+            .unwrap_or(&[Script::Unknown])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_script_extensions_get() {
+        let scx = ScriptExtensionsV1 {
+            ranges: vec![(0x41, 0x5B, 0), (0x640, 0x641, 1), (0x660, 0x66A, 2)],
+            extensions: vec![vec![Script::Latin], vec![Script::Arabic, Script::Syriac, Script::Mandaic], vec![Script::Arabic, Script::Thaana]],
+        };
+        assert_eq!(scx.get('A'), &[Script::Latin]);
+        assert_eq!(scx.get('Z'), &[Script::Latin]);
+        assert_eq!(scx.get('\u{0640}'), &[Script::Arabic, Script::Syriac, Script::Mandaic]);
+        assert_eq!(scx.get('\u{0669}'), &[Script::Arabic, Script::Thaana]);
+        assert_eq!(scx.get('['), &[Script::Unknown]);
+        assert_eq!(scx.get('\u{066A}'), &[Script::Unknown]);
+    }
+
+    #[test]
+    #[cfg(feature = "provider_serde")]
+    fn test_unicode_property_zc_bincode() {
+        let set = UnicodeSet::from_inversion_list(vec![0x41, 0x5B, 0x61, 0x7B]).unwrap();
+        let prop = UnicodePropertyV1::from_uniset(&set, Cow::Borrowed("Alpha"));
+        let bytes = bincode::serialize(&UnicodePropertyZcV1::from(prop.clone())).unwrap();
+
+        let zc: UnicodePropertyZcV1 = bincode::deserialize(&bytes).unwrap();
+        assert!(matches!(zc.name, Cow::Borrowed("Alpha")));
+        assert!(matches!(zc.inv_list, ZeroVec::Borrowed(_)));
+        assert_eq!(UnicodePropertyV1::try_from(zc.clone()).unwrap(), prop);
+        let zc_set: UnicodeSet = zc.try_into().unwrap();
+        assert_eq!(zc_set, set);
+    }
+
+    #[test]
+    fn test_unicode_property_zc_invalid() {
+        let zc = UnicodePropertyZcV1 {
+            name: Cow::Borrowed("Invalid"),
+            inv_list: ZeroVec::from_aligned(&[0x5B, 0x41]),
+        };
+        let result: Result<UnicodeSet, _> = zc.try_into();
+        assert!(matches!(result, Err(crate::UnicodeSetError::InvalidSet(_))));
+    }
+}
@@ -2,18 +2,33 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use icu_provider::yoke::{self, *};
-use std::{char, ops::RangeBounds, slice::Chunks};
+use alloc::{vec, vec::Vec};
+use core::{char, convert::TryFrom, ops::RangeBounds, slice::Chunks};
+use yoke::{Yokeable, ZeroCopyFrom};
 
 #[cfg(feature = "serde")]
 use serde::ser::SerializeSeq;
 
-use super::UnicodeSetError;
+use super::{UnicodeSetError, UnicodeSetSpanCondition};
 use crate::utils::{deconstruct_range, is_valid};
 
 /// Represents the end code point of the Basic Multilingual Plane range, starting from code point 0, inclusive
 const BMP_MAX: u32 = 0xFFFF;
 
+/// Represents the range of surrogate code points, start inclusive and end exclusive, which are not [`char`]s
+const SURROGATES: (u32, u32) = (0xD800, 0xE000);
+
+/// Returns the number of [`char`]s in the ranges of a valid inversion list, excluding surrogate code points
+fn count_chars(inv_list: &[u32]) -> usize {
+    inv_list
+        .chunks(2)
+        .map(|range| {
+            let surrogates = range[1].min(SURROGATES.1).saturating_sub(range[0].max(SURROGATES.0));
+            (range[1] - range[0] - surrogates) as usize
+        })
+        .sum()
+}
+
 /// A membership wrapper for [`UnicodeSet`].
 ///
 /// Provides exposure to membership functions and constructors from serialized [`UnicodeSets`](UnicodeSet)
@@ -38,6 +53,7 @@ impl<'de> serde::Deserialize<'de> for UnicodeSet {
     where
         D: serde::Deserializer<'de>,
     {
+        use alloc::format;
         use serde::de::Error;
         let parsed_inv_list = Vec::<u32>::deserialize(deserializer)?;
 
@@ -63,6 +79,90 @@ impl serde::Serialize for UnicodeSet {
     }
 }
 
+/// Serialization of a [`UnicodeSet`] with the varint delta encoding of
+/// [`to_delta_bytes()`](UnicodeSet::to_delta_bytes()), for use with `#[serde(with = "icu_uniset::delta")]`.
+///
+/// Binary formats store the delta bytes, which are much smaller than the inversion list for dense sets.
+/// Human-readable formats store the inversion list, as the default [`serde::Serialize`] impl does.
+///
+/// # Examples
+///
+/// ```
+/// use icu::uniset::UnicodeSet;
+///
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Property {
+///     #[serde(with = "icu_uniset::delta")]
+///     set: UnicodeSet,
+/// }
+///
+/// let set = UnicodeSet::from_inversion_list(vec![65, 70, 0x10000, 0x10FFFF]).unwrap();
+/// let json = serde_json::to_string(&Property { set: set.clone() }).unwrap();
+/// assert_eq!(json, r#"{"set":[65,70,65536,1114111]}"#);
+///
+/// let bytes = bincode::serialize(&Property { set: set.clone() }).unwrap();
+/// let property: Property = bincode::deserialize(&bytes).unwrap();
+/// assert_eq!(property.set, set);
+/// ```
+#[cfg(feature = "serde")]
+pub mod delta {
+    use super::UnicodeSet;
+    use alloc::format;
+    use alloc::vec::Vec;
+    use core::fmt;
+    use serde::de::{Error, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes `set` as delta bytes in binary formats, and as an inversion list otherwise.
+    pub fn serialize<S>(set: &UnicodeSet, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            set.serialize(serializer)
+        } else {
+            serializer.serialize_bytes(&set.to_delta_bytes())
+        }
+    }
+
+    /// Deserializes a [`UnicodeSet`] written by [`serialize()`], validating it as
+    /// [`from_inversion_list_delta()`](UnicodeSet::from_inversion_list_delta()) does.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<UnicodeSet, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            UnicodeSet::deserialize(deserializer)
+        } else {
+            deserializer.deserialize_bytes(DeltaVisitor)
+        }
+    }
+
+    struct DeltaVisitor;
+
+    impl<'de> Visitor<'de> for DeltaVisitor {
+        type Value = UnicodeSet;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a delta-encoded inversion list")
+        }
+
+        fn visit_bytes<E>(self, bytes: &[u8]) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            UnicodeSet::from_inversion_list_delta(bytes).map_err(|e| E::custom(format!("Cannot deserialize invalid inversion list for UnicodeSet: {:?}", e)))
+        }
+
+        fn visit_byte_buf<E>(self, bytes: Vec<u8>) -> Result<Self::Value, E>
+        where
+            E: Error,
+        {
+            self.visit_bytes(&bytes)
+        }
+    }
+}
+
 impl UnicodeSet {
     /// Returns [`UnicodeSet`] from an [inversion list.](https://en.wikipedia.org/wiki/Inversion_list)
     /// represented by a [`Vec`]`<`[`u32`]`>` of codepoints.
@@ -84,13 +184,97 @@ impl UnicodeSet {
     /// ```
     pub fn from_inversion_list(inv_list: Vec<u32>) -> Result<Self, UnicodeSetError> {
         if is_valid(&inv_list) {
-            let size: usize = inv_list.chunks(2).map(|end_points| end_points[1] - end_points[0]).sum::<u32>() as usize;
+            let size = count_chars(&inv_list);
             Ok(Self { inv_list, size })
         } else {
             Err(UnicodeSetError::InvalidSet(inv_list))
         }
     }
 
+    /// Returns [`UnicodeSet`] from an inversion list encoded by [`to_delta_bytes()`](UnicodeSet::to_delta_bytes()).
+    ///
+    /// The encoding is a sequence of unsigned [LEB128](https://en.wikipedia.org/wiki/LEB128) varints, the
+    /// first being the first code point of the inversion list, and each following one being the difference
+    /// from the previous code point. Since inversion lists are strictly ascending, this is much smaller
+    /// than a [`Vec`]`<`[`u32`]`>` for dense sets.
+    ///
+    /// Returns [`UnicodeSetError::InvalidSet`] with the code points decoded so far if a difference is zero,
+    /// a varint is truncated or does not fit in a [`u32`], or the decoded inversion list is not valid
+    /// according to [`from_inversion_list()`](UnicodeSet::from_inversion_list()).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::uniset::UnicodeSet;
+    /// use icu::uniset::UnicodeSetError;
+    /// let example = UnicodeSet::from_inversion_list_delta(&[65, 3, 0x80, 0x01, 2]).unwrap();
+    /// assert_eq!(example.get_inversion_list(), vec![65, 68, 196, 198]);
+    ///
+    /// let zero_delta = UnicodeSet::from_inversion_list_delta(&[65, 0]);
+    /// assert!(matches!(zero_delta, Err(UnicodeSetError::InvalidSet(_))));
+    /// ```
+    pub fn from_inversion_list_delta(bytes: &[u8]) -> Result<Self, UnicodeSetError> {
+        let mut inv_list: Vec<u32> = Vec::new();
+        let mut delta: u64 = 0;
+        let mut shift = 0;
+        for &byte in bytes {
+            // A u32 takes at most 5 bytes, with 4 bits in the last byte.
+            if shift > 28 {
+                return Err(UnicodeSetError::InvalidSet(inv_list));
+            }
+            delta |= u64::from(byte & 0x7F) << shift;
+            if byte & 0x80 != 0 {
+                shift += 7;
+                continue;
+            }
+            let previous = inv_list.last().copied();
+            let code_point = match previous {
+                // A zero difference would create an empty or inverted range.
+                Some(_) if delta == 0 => None,
+                Some(previous) => u32::try_from(delta).ok().and_then(|delta| previous.checked_add(delta)),
+                None => u32::try_from(delta).ok(),
+            };
+            match code_point {
+                Some(code_point) => inv_list.push(code_point),
+                None => return Err(UnicodeSetError::InvalidSet(inv_list)),
+            }
+            delta = 0;
+            shift = 0;
+        }
+        if shift != 0 {
+            return Err(UnicodeSetError::InvalidSet(inv_list));
+        }
+        Self::from_inversion_list(inv_list)
+    }
+
+    /// Returns the inversion list of the [`UnicodeSet`] encoded as varint differences between consecutive
+    /// code points, which can be decoded with [`from_inversion_list_delta()`](UnicodeSet::from_inversion_list_delta()).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::uniset::UnicodeSet;
+    /// let example_list = vec![65, 68, 0x10000, 0x10FFFF];
+    /// let example = UnicodeSet::from_inversion_list(example_list).unwrap();
+    /// let bytes = example.to_delta_bytes();
+    /// assert_eq!(bytes.len(), 8);
+    /// assert_eq!(UnicodeSet::from_inversion_list_delta(&bytes).unwrap(), example);
+    /// ```
+    pub fn to_delta_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut previous = 0;
+        for &code_point in &self.inv_list {
+            let mut delta = code_point - previous;
+            previous = code_point;
+            while delta >= 0x80 {
+                bytes.push((delta & 0x7F) as u8 | 0x80);
+                delta >>= 7;
+            }
+            bytes.push(delta as u8);
+        }
+        bytes
+    }
+
     /// Returns an owned inversion list representing the current [`UnicodeSet`]
     pub fn get_inversion_list(&self) -> Vec<u32> {
         let result: Vec<u32> = self
@@ -105,7 +289,7 @@ impl UnicodeSet {
     pub fn all() -> Self {
         Self {
             inv_list: vec![0, (char::MAX as u32) + 1],
-            size: (char::MAX as usize) + 1,
+            size: (char::MAX as usize) + 1 - (SURROGATES.1 - SURROGATES.0) as usize,
         }
     }
 
@@ -115,7 +299,7 @@ impl UnicodeSet {
     pub fn bmp() -> Self {
         Self {
             inv_list: vec![0, BMP_MAX + 1],
-            size: (BMP_MAX as usize) + 1,
+            size: (BMP_MAX as usize) + 1 - (SURROGATES.1 - SURROGATES.0) as usize,
         }
     }
 
@@ -145,7 +329,24 @@ impl UnicodeSet {
         self.inv_list.chunks(2).flat_map(|pair| (pair[0]..pair[1])).filter_map(char::from_u32)
     }
 
-    /// Returns the number of elements of the [`UnicodeSet`]
+    /// Returns the number of characters in the [`UnicodeSet`]
+    ///
+    /// Surrogate code points (`0xD800 -> 0xDFFF`) are not counted, even if the inversion list
+    /// contains them, so that this matches the number of items yielded by
+    /// [`iter_chars()`](UnicodeSet::iter_chars()). The count is computed upon construction.
+    ///
+    /// A set that contains only surrogate code points therefore has a size of 0, but is not
+    /// [`empty`](UnicodeSet::is_empty()).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::uniset::UnicodeSet;
+    /// let example_list = vec![65, 68, 0xD000, 0xE000]; // {A, B, C} and 0xD000 -> 0xDFFF
+    /// let example = UnicodeSet::from_inversion_list(example_list).unwrap();
+    /// assert_eq!(example.size(), 3 + 0x800);
+    /// assert_eq!(UnicodeSet::all().size(), 1_112_064);
+    /// ```
     pub fn size(&self) -> usize {
         if self.is_empty() {
             return 0;
@@ -154,6 +355,19 @@ impl UnicodeSet {
     }
 
     /// Returns whether or not the [`UnicodeSet`] is empty
+    ///
+    /// This checks for code points rather than characters: a set that contains only surrogate
+    /// code points is not empty, even though its [`size()`](UnicodeSet::size()) is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::uniset::UnicodeSet;
+    /// let surrogates = UnicodeSet::from_inversion_list(vec![0xD800, 0xE000]).unwrap();
+    /// assert!(!surrogates.is_empty());
+    /// assert!(surrogates.contains_u32(0xD800));
+    /// assert_eq!(surrogates.size(), 0);
+    /// ```
     pub fn is_empty(&self) -> bool {
         self.inv_list.is_empty()
     }
@@ -303,44 +517,52 @@ impl UnicodeSet {
     }
 
     /// Returns the end of the initial substring where the characters are either contained/not contained
-    /// in the set.
+    /// in the set, as a byte offset into `span_str`. Returns `0` if the first character does not satisfy
+    /// the `condition`, including when `span_str` is empty.
+    ///
+    /// Walks `span_str` once, running a binary search on the set for each character.
     ///
     /// # Examples
     ///
     /// ```
-    /// use icu::uniset::UnicodeSet;
+    /// use icu::uniset::{UnicodeSet, UnicodeSetSpanCondition};
     /// let example_list = vec![65, 68]; // {A, B, C}
     /// let example = UnicodeSet::from_inversion_list(example_list).unwrap();
-    /// assert_eq!(example.span("CABXYZ", true), 3);
-    /// assert_eq!(example.span("XYZC", false), 3);
-    /// assert_eq!(example.span("XYZ", true), 0);
-    /// assert_eq!(example.span("ABC", false), 0);
+    /// assert_eq!(example.span("CABXYZ", UnicodeSetSpanCondition::Contained), 3);
+    /// assert_eq!(example.span("XYZC", UnicodeSetSpanCondition::NotContained), 3);
+    /// assert_eq!(example.span("XYZ", UnicodeSetSpanCondition::Contained), 0);
+    /// assert_eq!(example.span("ABC", UnicodeSetSpanCondition::NotContained), 0);
+    /// assert_eq!(example.span("ñAB", UnicodeSetSpanCondition::NotContained), 2);
     /// ```
-    pub fn span(&self, span_str: &str, contained: bool) -> usize {
-        span_str.chars().take_while(|&x| self.contains(x) == contained).count()
+    pub fn span(&self, span_str: &str, condition: UnicodeSetSpanCondition) -> usize {
+        let contained = condition == UnicodeSetSpanCondition::Contained;
+        span_str.char_indices().find(|&(_, c)| self.contains(c) != contained).map_or(span_str.len(), |(i, _)| i)
     }
 
     /// Returns the start of the trailing substring (starting from end of string) where the characters are
-    /// either contained/not contained in the set. Returns the length of the string if no valid return.
+    /// either contained/not contained in the set, as a byte offset into `span_str`. Returns the length of
+    /// the string if no valid return.
     ///
     /// # Examples
     ///
     /// ```
-    /// use icu::uniset::UnicodeSet;
+    /// use icu::uniset::{UnicodeSet, UnicodeSetSpanCondition};
     /// let example_list = vec![65, 68]; // {A, B, C}
     /// let example = UnicodeSet::from_inversion_list(example_list).unwrap();
-    /// assert_eq!(example.span_back("XYZCAB", true), 3);
-    /// assert_eq!(example.span_back("ABCXYZ", true), 6);
-    /// assert_eq!(example.span_back("CABXYZ", false), 3);
+    /// assert_eq!(example.span_back("XYZCAB", UnicodeSetSpanCondition::Contained), 3);
+    /// assert_eq!(example.span_back("ABCXYZ", UnicodeSetSpanCondition::Contained), 6);
+    /// assert_eq!(example.span_back("CABXYZ", UnicodeSetSpanCondition::NotContained), 3);
+    /// assert_eq!(example.span_back("ñAB", UnicodeSetSpanCondition::Contained), 2);
     /// ```
-    pub fn span_back(&self, span_str: &str, contained: bool) -> usize {
-        span_str.len() - span_str.chars().rev().take_while(|&x| self.contains(x) == contained).count()
+    pub fn span_back(&self, span_str: &str, condition: UnicodeSetSpanCondition) -> usize {
+        let contained = condition == UnicodeSetSpanCondition::Contained;
+        span_str.char_indices().rev().find(|&(_, c)| self.contains(c) != contained).map_or(0, |(i, c)| i + c.len_utf8())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{UnicodeSet, UnicodeSetError, BMP_MAX};
+    use super::{UnicodeSet, UnicodeSetError, UnicodeSetSpanCondition, BMP_MAX};
     use std::{char, vec::Vec};
 
     #[test]
@@ -361,18 +583,46 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_unicodeset_delta_round_trip() {
+        let inv_lists = vec![vec![], vec![0, 1], vec![65, 68, 70, 75], vec![0x7F, 0x80, 0x3FFF, 0x4000], vec![0, (char::MAX as u32) + 1]];
+        for inv_list in inv_lists {
+            let set = UnicodeSet::from_inversion_list(inv_list.clone()).unwrap();
+            let check = UnicodeSet::from_inversion_list_delta(&set.to_delta_bytes()).unwrap();
+            assert_eq!(inv_list, check.inv_list);
+        }
+        let set = UnicodeSet::from_inversion_list(vec![0, 128]).unwrap();
+        assert_eq!(set.to_delta_bytes(), vec![0, 0x80, 0x01]);
+    }
+
+    #[test]
+    fn test_unicodeset_delta_error() {
+        let invalid: [&[u8]; 6] = [
+            &[65, 66, 0, 1],                       // zero delta
+            &[65, 0x83],                           // truncated varint
+            &[0x80, 0x80, 0x80, 0x80, 0x10],       // delta larger than u32::MAX
+            &[0x80, 0x80, 0x80, 0x80, 0x80, 0x01], // varint longer than 5 bytes
+            &[65],                                 // odd length
+            &[0, 0x81, 0x80, 0x44],                // past char::MAX
+        ];
+        for bytes in invalid.iter() {
+            let set = UnicodeSet::from_inversion_list_delta(bytes);
+            assert!(matches!(set, Err(UnicodeSetError::InvalidSet(_))), "{:?}", bytes);
+        }
+    }
+
     #[test]
     fn test_unicodeset_all() {
         let expected = vec![0, (char::MAX as u32) + 1];
         assert_eq!(UnicodeSet::all().inv_list, expected);
-        assert_eq!(UnicodeSet::all().size(), (expected[1] - expected[0]) as usize)
+        assert_eq!(UnicodeSet::all().size(), (expected[1] - expected[0]) as usize - 0x800)
     }
 
     #[test]
     fn test_unicodeset_bmp() {
         let expected = vec![0, BMP_MAX + 1];
         assert_eq!(UnicodeSet::bmp().inv_list, expected);
-        assert_eq!(UnicodeSet::bmp().size(), (expected[1] - expected[0]) as usize);
+        assert_eq!(UnicodeSet::bmp().size(), (expected[1] - expected[0]) as usize - 0x800);
     }
 
     // UnicodeSet membership functions
@@ -459,8 +709,11 @@ mod tests {
         let check = UnicodeSet::from_inversion_list(ex).unwrap();
         assert_eq!(8, check.size());
         let check = UnicodeSet::all();
-        let expected = (char::MAX as u32) + 1;
-        assert_eq!(expected as usize, check.size());
+        assert_eq!(1_112_064, check.size());
+        assert_eq!(check.iter_chars().count(), check.size());
+        let check = UnicodeSet::from_inversion_list(vec![0xD7FE, 0xD801, 0xDFFF, 0xE002]).unwrap();
+        assert_eq!(4, check.size());
+        assert_eq!(check.iter_chars().count(), check.size());
         let check = UnicodeSet { inv_list: Vec::new(), size: 0 };
         assert_eq!(check.size(), 0);
     }
@@ -471,6 +724,15 @@ mod tests {
         assert!(check.is_empty());
     }
 
+    #[test]
+    fn test_unicodeset_only_surrogates_is_not_empty() {
+        let check = UnicodeSet::from_inversion_list(vec![0xD800, 0xE000]).unwrap();
+        assert_eq!(check.size(), 0);
+        assert_eq!(check.iter_chars().count(), 0);
+        assert!(!check.is_empty());
+        assert!(check.contains_u32(0xDFFF));
+    }
+
     #[test]
     fn test_unicodeset_is_not_empty() {
         let check = UnicodeSet::all();
@@ -493,32 +755,38 @@ mod tests {
     fn test_unicodeset_span_contains() {
         let ex = vec![65, 68, 70, 75]; // A - D, F - K
         let check = UnicodeSet::from_inversion_list(ex).unwrap();
-        assert_eq!(check.span("ABCDE", true), 3);
-        assert_eq!(check.span("E", true), 0);
+        assert_eq!(check.span("ABCDE", UnicodeSetSpanCondition::Contained), 3);
+        assert_eq!(check.span("E", UnicodeSetSpanCondition::Contained), 0);
+        assert_eq!(check.span("", UnicodeSetSpanCondition::Contained), 0);
+        assert_eq!(check.span("AB\u{0301}", UnicodeSetSpanCondition::Contained), 2);
     }
 
     #[test]
     fn test_unicodeset_span_does_not_contain() {
         let ex = vec![65, 68, 70, 75]; // A - D, F - K
         let check = UnicodeSet::from_inversion_list(ex).unwrap();
-        assert_eq!(check.span("DEF", false), 2);
-        assert_eq!(check.span("KLMA", false), 3);
+        assert_eq!(check.span("DEF", UnicodeSetSpanCondition::NotContained), 2);
+        assert_eq!(check.span("KLMA", UnicodeSetSpanCondition::NotContained), 3);
+        assert_eq!(check.span("\u{00E9}\u{4E00}A", UnicodeSetSpanCondition::NotContained), 5);
+        assert_eq!(check.span("", UnicodeSetSpanCondition::NotContained), 0);
     }
 
     #[test]
     fn test_unicodeset_span_back_contains() {
         let ex = vec![65, 68, 70, 75]; // A - D, F - K
         let check = UnicodeSet::from_inversion_list(ex).unwrap();
-        assert_eq!(check.span_back("XYZABFH", true), 3);
-        assert_eq!(check.span_back("ABCXYZ", true), 6);
+        assert_eq!(check.span_back("XYZABFH", UnicodeSetSpanCondition::Contained), 3);
+        assert_eq!(check.span_back("ABCXYZ", UnicodeSetSpanCondition::Contained), 6);
+        assert_eq!(check.span_back("\u{4E00}AB", UnicodeSetSpanCondition::Contained), 3);
+        assert_eq!(check.span_back("", UnicodeSetSpanCondition::Contained), 0);
     }
 
     #[test]
     fn test_unicodeset_span_back_does_not_contain() {
         let ex = vec![65, 68, 70, 75]; // A - D, F - K
         let check = UnicodeSet::from_inversion_list(ex).unwrap();
-        assert_eq!(check.span_back("ABCXYZ", false), 3);
-        assert_eq!(check.span_back("XYZABC", false), 6);
+        assert_eq!(check.span_back("ABCXYZ", UnicodeSetSpanCondition::NotContained), 3);
+        assert_eq!(check.span_back("XYZABC", UnicodeSetSpanCondition::NotContained), 6);
     }
 
     #[test]
@@ -547,6 +815,33 @@ mod tests {
         assert_eq!(act_uniset, exp_uniset);
     }
 
+    #[test]
+    fn test_serde_delta() {
+        #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+        struct Property {
+            #[serde(with = "crate::delta")]
+            set: UnicodeSet,
+        }
+
+        let set = UnicodeSet::from_inversion_list(vec![0x20, 0x7F, 0xA0, 0xD800, 0xE000, 0x10000]).unwrap();
+        let property = Property { set: set.clone() };
+
+        // Human-readable formats keep the inversion list.
+        let json_str = serde_json::to_string(&property).unwrap();
+        assert_eq!(json_str, r#"{"set":[32,127,160,55296,57344,65536]}"#);
+        assert_eq!(serde_json::from_str::<Property>(&json_str).unwrap(), property);
+
+        // Binary formats store the delta bytes, with a length prefix.
+        let bytes = bincode::serialize(&property).unwrap();
+        assert_eq!(bytes[8..], set.to_delta_bytes()[..]);
+        assert!(bytes.len() < bincode::serialize(&set).unwrap().len());
+        assert_eq!(bincode::deserialize::<Property>(&bytes).unwrap(), property);
+
+        // A zero delta is rejected.
+        let bytes = bincode::serialize(&(2u64, 65u8, 0u8)).unwrap();
+        assert!(bincode::deserialize::<Property>(&bytes).is_err());
+    }
+
     #[test]
     fn test_serde_deserialize_invalid() {
         let inv_list_str = "[65,70,98775,85]";
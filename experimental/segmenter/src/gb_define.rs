@@ -0,0 +1,21 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// This file is generated by generate_grapheme_properties.py. DO NOT EDIT MANUALLY!
+
+pub const XX: u8 = 0;
+pub const CR: u8 = 1;
+pub const LF: u8 = 2;
+pub const CN: u8 = 3;
+pub const EX: u8 = 4;
+pub const ZWJ: u8 = 5;
+pub const RI: u8 = 6;
+pub const PP: u8 = 7;
+pub const SM: u8 = 8;
+pub const L: u8 = 9;
+pub const V: u8 = 10;
+pub const T: u8 = 11;
+pub const LV: u8 = 12;
+pub const LVT: u8 = 13;
+pub const EP: u8 = 14;
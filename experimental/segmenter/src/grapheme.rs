@@ -0,0 +1,223 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::gb_define::*;
+use crate::grapheme_property_table::*;
+
+use core::str::CharIndices;
+
+fn get_grapheme_property_utf32(codepoint: u32) -> u8 {
+    if codepoint < 0x20000 {
+        let codepoint = codepoint as usize;
+        return GRAPHEME_PROPERTY_TABLE[codepoint / 1024][codepoint & 0x3ff];
+    }
+
+    match codepoint {
+        0xe0020..=0xe007f => EX,
+        0xe0100..=0xe01ef => EX,
+        0xe0000..=0xe0fff => CN,
+        _ => XX,
+    }
+}
+
+#[inline]
+fn get_grapheme_property(codepoint: char) -> u8 {
+    get_grapheme_property_utf32(codepoint as u32)
+}
+
+/// Tracks the GB11 sequence `Extended_Pictographic Extend* ZWJ` preceding the current position.
+#[derive(Copy, Clone, PartialEq)]
+enum PictographicState {
+    None,
+    /// `Extended_Pictographic Extend*`
+    Pictographic,
+    /// `Extended_Pictographic Extend* ZWJ`
+    PictographicZwj,
+}
+
+/// Context of the text preceding the current position in a grapheme cluster.
+struct GraphemeState {
+    /// Number of consecutive Regional_Indicator characters preceding the current position.
+    ri_count: usize,
+    pictographic: PictographicState,
+}
+
+impl GraphemeState {
+    fn new(prop: u8) -> Self {
+        let mut state = Self {
+            ri_count: 0,
+            pictographic: PictographicState::None,
+        };
+        state.advance(prop);
+        state
+    }
+
+    fn advance(&mut self, prop: u8) {
+        self.ri_count = if prop == RI { self.ri_count + 1 } else { 0 };
+        self.pictographic = match (prop, self.pictographic) {
+            (EP, _) => PictographicState::Pictographic,
+            (EX, PictographicState::Pictographic) => PictographicState::Pictographic,
+            (ZWJ, PictographicState::Pictographic) => PictographicState::PictographicZwj,
+            _ => PictographicState::None,
+        };
+    }
+}
+
+/// Returns whether there is a grapheme cluster boundary between `left` and `right`.
+fn is_grapheme_break(state: &GraphemeState, left: u8, right: u8) -> bool {
+    match (left, right) {
+        // GB3
+        (CR, LF) => false,
+        // GB4
+        (CN, _) | (CR, _) | (LF, _) => true,
+        // GB5
+        (_, CN) | (_, CR) | (_, LF) => true,
+        // GB6
+        (L, L) | (L, V) | (L, LV) | (L, LVT) => false,
+        // GB7
+        (LV, V) | (LV, T) | (V, V) | (V, T) => false,
+        // GB8
+        (LVT, T) | (T, T) => false,
+        // GB9
+        (_, EX) | (_, ZWJ) => false,
+        // GB9a
+        (_, SM) => false,
+        // GB9b
+        (PP, _) => false,
+        // GB11
+        (ZWJ, EP) => state.pictographic != PictographicState::PictographicZwj,
+        // GB12, GB13
+        (RI, RI) => state.ri_count % 2 == 0,
+        // GB999
+        _ => true,
+    }
+}
+
+/// Grapheme cluster segmenter compatible with [Unicode Standard Annex #29][UAX29].
+///
+/// [UAX29]: http://www.unicode.org/reports/tr29/
+///
+/// # Examples
+///
+/// ```rust
+/// use icu_segmenter::GraphemeClusterSegmenter;
+///
+/// let segmenter = GraphemeClusterSegmenter::new();
+/// let breaks: Vec<usize> = segmenter.segment_str("🇺🇸🇬🇧").collect();
+/// assert_eq!(breaks, vec![8, 16]);
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct GraphemeClusterSegmenter;
+
+impl GraphemeClusterSegmenter {
+    /// Create grapheme cluster segmenter
+    pub fn new() -> Self {
+        GraphemeClusterSegmenter
+    }
+
+    /// Create grapheme cluster break iterator. The iterator yields the byte offset at the end of
+    /// each grapheme cluster.
+    pub fn segment_str<'a>(&self, input: &'a str) -> GraphemeClusterBreakIterator<'a> {
+        GraphemeClusterBreakIterator {
+            iter: input.char_indices(),
+            len: input.len(),
+            current_pos_data: None,
+        }
+    }
+}
+
+/// Iterator over grapheme cluster boundaries, created by [`GraphemeClusterSegmenter::segment_str`].
+pub struct GraphemeClusterBreakIterator<'a> {
+    iter: CharIndices<'a>,
+    len: usize,
+    current_pos_data: Option<(usize, char)>,
+}
+
+impl<'a> Iterator for GraphemeClusterBreakIterator<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current_pos_data.is_none() {
+            self.current_pos_data = self.iter.next();
+        }
+        let mut left_prop = get_grapheme_property(self.current_pos_data?.1);
+        let mut state = GraphemeState::new(left_prop);
+
+        loop {
+            self.current_pos_data = self.iter.next();
+            let (pos, c) = match self.current_pos_data {
+                Some(data) => data,
+                // EOF
+                None => return Some(self.len),
+            };
+            let right_prop = get_grapheme_property(c);
+            if is_grapheme_break(&state, left_prop, right_prop) {
+                return Some(pos);
+            }
+            state.advance(right_prop);
+            left_prop = right_prop;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::gb_define::*;
+    use crate::grapheme::get_grapheme_property;
+    use crate::GraphemeClusterSegmenter;
+
+    #[test]
+    fn grapheme_property() {
+        assert_eq!(get_grapheme_property('\r'), CR);
+        assert_eq!(get_grapheme_property('\n'), LF);
+        assert_eq!(get_grapheme_property('\u{0000}'), CN);
+        assert_eq!(get_grapheme_property('\u{0300}'), EX);
+        assert_eq!(get_grapheme_property('\u{200D}'), ZWJ);
+        assert_eq!(get_grapheme_property('\u{1F1FA}'), RI);
+        assert_eq!(get_grapheme_property('\u{0600}'), PP);
+        assert_eq!(get_grapheme_property('\u{0903}'), SM);
+        assert_eq!(get_grapheme_property('\u{1100}'), L);
+        assert_eq!(get_grapheme_property('\u{1160}'), V);
+        assert_eq!(get_grapheme_property('\u{11A8}'), T);
+        assert_eq!(get_grapheme_property('\u{AC00}'), LV);
+        assert_eq!(get_grapheme_property('\u{AC01}'), LVT);
+        assert_eq!(get_grapheme_property('\u{1F469}'), EP);
+        assert_eq!(get_grapheme_property('a'), XX);
+        assert_eq!(get_grapheme_property('\u{E0061}'), EX);
+        assert_eq!(get_grapheme_property('\u{E0001}'), CN);
+    }
+
+    fn breaks(input: &str) -> Vec<usize> {
+        GraphemeClusterSegmenter::new().segment_str(input).collect()
+    }
+
+    #[test]
+    fn grapheme_break() {
+        assert_eq!(breaks(""), Vec::<usize>::new());
+        assert_eq!(breaks("abc"), vec![1, 2, 3]);
+
+        // GB3, GB4, GB5
+        assert_eq!(breaks("a\r\nb"), vec![1, 3, 4]);
+        assert_eq!(breaks("\n\r"), vec![1, 2]);
+
+        // GB6, GB7, GB8
+        assert_eq!(breaks("\u{1100}\u{1161}\u{11A8}"), vec![9]);
+        assert_eq!(breaks("\u{AC00}\u{11A8}a"), vec![6, 7]);
+
+        // GB9, GB9a, GB9b
+        assert_eq!(breaks("e\u{0301}x"), vec![3, 4]);
+        assert_eq!(breaks("\u{0915}\u{0903}"), vec![6]);
+        assert_eq!(breaks("\u{0600}1"), vec![3]);
+
+        // GB11
+        assert_eq!(breaks("\u{1F469}\u{200D}\u{1F4BB}"), vec![11]);
+        assert_eq!(breaks("\u{1F469}\u{1F3FB}\u{200D}\u{1F4BB}"), vec![15]);
+        assert_eq!(breaks("a\u{200D}\u{1F4BB}"), vec![4, 8]);
+
+        // GB12, GB13
+        assert_eq!(breaks("🇺🇸🇬🇧"), vec![8, 16]);
+        assert_eq!(breaks("🇺🇸🇬"), vec![8, 12]);
+        assert_eq!(breaks("a🇺🇸🇬🇧"), vec![1, 9, 17]);
+    }
+}
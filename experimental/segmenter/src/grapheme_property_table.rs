@@ -0,0 +1,2431 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// This file is generated by generate_grapheme_properties.py. DO NOT EDIT MANUALLY!
+
+use crate::gb_define::*;
+
+pub const GRAPHEME_PROPERTIES_0: [u8; 1024] = [
+    CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, LF, CN, CN, CR, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+    CN, CN, CN, CN, CN, CN, CN, CN, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN,
+    CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, EP, XX, XX, XX, CN, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_1: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, EX, XX, EX, EX, XX, EX, EX, XX, EX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, PP, PP, PP, PP, PP, PP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, CN, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, PP, XX, EX, EX, EX, EX, EX, EX, XX, XX, EX,
+    EX, XX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, PP, XX, EX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_2: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX,
+    EX, EX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, EX, EX, EX, XX, EX, EX, EX, EX, EX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    PP, PP, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, PP, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, SM, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, EX, SM, EX, XX, SM, SM, SM, EX, EX, EX, EX, EX, EX, EX, EX, SM, SM, SM, SM, EX, SM, SM,
+    XX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, EX, SM, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, EX, SM, SM, EX, EX, EX, EX, XX, XX, SM,
+    SM, XX, XX, SM, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, EX, XX, XX, EX, EX, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, SM, SM,
+    SM, EX, EX, XX, XX, XX, XX, EX, EX, XX, XX, EX, EX, EX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    EX, EX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, SM, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, EX, XX, SM, SM, SM, EX, EX, EX, EX, EX, XX, EX, EX, SM, XX, SM, SM, EX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX,
+    XX, EX, SM, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, EX, EX, SM, EX, EX, EX, EX, XX, XX, SM,
+    SM, XX, XX, SM, SM, EX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, SM,
+    EX, SM, SM, XX, XX, XX, SM, SM, SM, XX, SM, SM, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_3: [u8; 1024] = [
+    EX, SM, SM, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, EX, EX, EX, SM, SM, SM, SM, XX, EX, EX,
+    EX, XX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, SM, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, SM, EX,
+    EX, SM, EX, SM, SM, XX, EX, EX, EX, XX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, SM, SM, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, EX, EX, XX, EX, SM, SM, EX, EX, EX, EX, XX, SM, SM, SM, XX, SM, SM, SM, EX, PP, XX,
+    XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, EX, SM, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, EX, XX, XX, XX, XX, EX, SM, SM, EX, EX, EX, XX, EX, XX, SM, SM, SM, SM, SM, SM, SM, EX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, SM, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, SM, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, EX, XX, EX, XX, EX, XX, XX, XX, XX, SM, SM, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, SM, EX, EX, EX, EX, EX, XX, EX, EX, XX, XX, XX, XX, XX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_4: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX,
+    EX, SM, EX, EX, EX, EX, EX, EX, XX, EX, EX, SM, SM, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, EX, EX, XX, XX, XX, XX, EX, EX,
+    EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, SM, EX, EX, XX, XX, XX, XX, XX, XX, EX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, L, L, L, L, L, L, L, L, L, L,
+    L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+    L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L,
+    L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, V, V, V, V, V, V, V, V, V, V,
+    V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V,
+    V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, V, T, T,
+    T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+    T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+    T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX,
+];
+
+pub const GRAPHEME_PROPERTIES_5: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, SM, EX, EX, EX, EX, EX, EX, EX, SM, SM,
+    SM, SM, SM, SM, SM, SM, EX, SM, SM, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_6: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, CN, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    EX, EX, EX, SM, SM, SM, SM, EX, EX, SM, SM, SM, XX, XX, XX, XX, SM, SM, EX, SM, SM, SM, SM, SM,
+    SM, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, EX, EX, SM, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM, EX, SM,
+    EX, EX, EX, EX, EX, EX, EX, XX, EX, XX, EX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, SM, SM, SM,
+    SM, SM, SM, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    EX, EX, EX, EX, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, SM, SM, SM, SM, EX, EX, EX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM, EX, EX, EX, EX, SM, SM,
+    EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, SM, EX, EX, SM, SM, SM, EX, SM, EX,
+    EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_7: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, SM, SM, SM, SM, SM, SM, EX, EX, EX, EX,
+    EX, EX, EX, EX, SM, SM, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, XX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, SM, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, EX, XX, XX,
+    XX, XX, XX, XX, EX, XX, XX, SM, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_8: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CN, EX, ZWJ, CN, CN, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CN, CN, CN, CN, CN, CN, CN,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP, EP,
+    EP, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, XX, XX, XX, XX, EP, EP, EP, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_9: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, EP, EP, EP, EP, XX, EP, EP, EP, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, XX,
+    XX, EP, XX, XX, EP, EP, XX, XX, EP, XX, XX, XX, XX, EP, XX, XX, EP, XX, EP, EP, XX, XX, EP, XX,
+    XX, XX, EP, XX, XX, XX, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP, EP, XX, XX, XX, XX, XX,
+    EP, XX, EP, XX, XX, XX, XX, XX, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, EP, EP, XX, XX, EP, XX, EP, EP, XX, EP, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, XX, XX, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP, EP, EP, EP, EP, XX, EP, XX, EP, EP, XX, XX, XX,
+    EP, EP, XX, XX, XX, XX, XX, EP, XX, XX, EP, EP, XX, XX, XX, XX, EP, EP, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, EP, EP, XX, XX, XX, XX, XX, EP, EP, XX, XX, EP, XX, XX, XX, XX, XX, EP, EP,
+    XX, EP, XX, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, EP, EP, XX, XX, XX, XX, XX, EP, EP, EP, EP, EP, EP, XX, EP, EP, EP, EP, XX, XX, EP, XX, XX,
+    XX, XX, EP, XX, XX, EP, XX, XX, EP, EP, EP, EP, EP, EP, XX, EP, XX, XX, EP, XX, EP, XX, EP, XX,
+    XX, XX, XX, XX, XX, EP, XX, XX, XX, EP, XX, XX, XX, XX, XX, XX, EP, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, XX, XX, EP,
+    XX, XX, XX, XX, EP, XX, EP, XX, XX, XX, XX, EP, EP, EP, XX, EP, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, EP, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_10: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, EP, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, EP, XX, XX, XX, XX, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_11: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX,
+    EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_12: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX,
+    EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, XX, EP, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_XX: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_41: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX,
+    EX, EX, EX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_42: [u8; 1024] = [
+    XX, XX, EX, XX, XX, XX, EX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, EX, EX, SM, XX, XX, XX, XX, EX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM,
+    SM, SM, SM, SM, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, L, L, L, L, L, L, L, L, L, L,
+    L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, L, XX, XX, XX, EX, EX, EX, SM, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, SM, SM,
+    EX, EX, EX, EX, SM, SM, EX, EX, SM, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX,
+    EX, SM, SM, EX, EX, SM, SM, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX,
+    XX, XX, XX, XX, XX, XX, EX, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, EX, EX, EX, XX, XX, EX, EX, XX, XX, XX, XX, XX,
+    EX, EX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM, EX, EX,
+    SM, SM, XX, XX, XX, XX, XX, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, SM, SM, EX, SM, SM, EX, SM, SM, XX, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_43: [u8; 1024] = [
+    LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+];
+
+pub const GRAPHEME_PROPERTIES_44: [u8; 1024] = [
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT,
+];
+
+pub const GRAPHEME_PROPERTIES_45: [u8; 1024] = [
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+];
+
+pub const GRAPHEME_PROPERTIES_46: [u8; 1024] = [
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+];
+
+pub const GRAPHEME_PROPERTIES_47: [u8; 1024] = [
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+];
+
+pub const GRAPHEME_PROPERTIES_48: [u8; 1024] = [
+    LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+];
+
+pub const GRAPHEME_PROPERTIES_49: [u8; 1024] = [
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+];
+
+pub const GRAPHEME_PROPERTIES_53: [u8; 1024] = [
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LV, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT, LVT,
+    LVT, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, V, V, V, V, V, V, V, V, V, V, V, V, V, V,
+    V, V, V, V, V, V, V, V, V, XX, XX, XX, XX, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+    T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T, T,
+    XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_62: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_63: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, CN,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_64: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX,
+    EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_66: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, XX, EX, EX, XX, XX, XX, XX, XX, EX, EX, EX, EX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, XX, XX, XX, XX, EX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_67: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_68: [u8; 1024] = [
+    SM, EX, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, EX, EX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, SM, EX, EX, EX, EX, SM, SM, EX, EX, XX, XX, PP, XX, XX,
+    XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, PP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, SM, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    EX, EX, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, SM, SM, SM, EX, EX, EX, EX, EX, EX, EX, EX, EX, SM, EX, XX, PP, PP, XX, XX, XX, XX,
+    XX, EX, EX, EX, EX, XX, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, SM, SM, SM, EX, EX, EX, SM, SM, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, EX, XX,
+    XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, SM, SM, SM, EX, EX, EX, EX, EX,
+    EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    EX, EX, SM, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, EX, SM, EX, SM, SM, SM, SM, XX, XX, SM,
+    SM, XX, XX, SM, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, SM, SM, XX, XX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, EX, EX, EX, EX, EX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, SM, SM, EX, EX, EX, EX, EX,
+    EX, XX, EX, XX, XX, EX, XX, EX, EX, EX, SM, XX, SM, SM, EX, EX, EX, PP, EX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_69: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, SM, SM, SM, EX, EX, EX, EX, EX, EX, EX, EX, SM, SM, EX, EX, EX, SM, EX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, EX, SM, SM, EX, EX, EX, EX, EX, EX, SM, EX, SM, SM, EX, SM, EX,
+    EX, SM, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX,
+    SM, SM, EX, EX, EX, EX, XX, XX, SM, SM, SM, SM, EX, EX, SM, EX, EX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, SM, EX, EX, EX, EX, EX, EX, EX, EX, SM, SM, EX, SM, EX,
+    EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, SM, EX, SM, SM, EX, EX, EX, EX, EX, EX, EX, EX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, EX, SM, EX, XX, XX, EX, EX, EX, EX, SM, EX, EX, EX, EX, EX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_70: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, SM, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, SM, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, SM, SM, SM, SM, SM, XX, SM,
+    SM, XX, XX, EX, EX, EX, EX, PP, SM, PP, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, SM, EX, EX, EX, EX, XX, XX, EX, EX, SM, SM, SM, SM,
+    EX, XX, XX, XX, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, SM, XX, EX, EX, EX, EX, XX,
+    XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, SM,
+    SM, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, PP, PP, PP, PP,
+    PP, PP, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, SM, EX, EX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    EX, SM, EX, EX, EX, SM, EX, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_71: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM,
+    EX, EX, EX, EX, EX, EX, EX, XX, EX, EX, EX, EX, EX, EX, SM, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    XX, SM, EX, EX, EX, EX, EX, EX, EX, SM, EX, EX, SM, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, XX,
+    XX, XX, EX, XX, EX, EX, XX, EX, EX, EX, EX, EX, EX, EX, PP, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, SM, SM, SM, SM, SM, XX, EX, EX, XX, SM, SM, EX, SM, EX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, SM, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    EX, EX, PP, SM, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, SM, SM, EX, EX, EX, EX, EX, XX, XX, XX, SM, SM, EX, EX, EX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_77: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, CN, EX, XX, XX, XX, XX, XX, XX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_88: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, SM, SM, SM, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_90: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_91: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, V, XX, XX, XX, V,
+    V, V, V, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, EX, XX, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM,
+    SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM,
+    SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, SM, XX, XX, XX, XX, XX, XX, XX, EX,
+    EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_111: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, CN, CN, CN, CN, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_115: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_116: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX,
+    EX, EX, XX, XX, XX, EX, EX, EX, EX, EX, EX, CN, CN, CN, CN, CN, CN, CN, CN, EX, EX, EX, EX, EX,
+    EX, EX, EX, XX, XX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_118: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX,
+    XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX,
+    XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_120: [u8; 1024] = [
+    EX, EX, EX, EX, EX, EX, EX, XX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX, EX,
+    EX, XX, XX, EX, EX, EX, EX, EX, EX, EX, XX, EX, EX, XX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, EX, EX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_121: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, XX, XX, EX, XX,
+    XX, XX, XX, XX, XX, XX, EX, EX, XX, XX, XX, XX, XX, EX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_122: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EX, EX, EX, EX, EX, EX, EX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_124: [u8; 1024] = [
+    XX, XX, XX, XX, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP, EP, EP,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, XX, XX, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI, RI,
+    RI, RI, RI, RI, RI, RI, RI, RI, XX, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, EP, XX, XX, EP, EP, EP, EP, EP, EP, EP, EP, EP, XX, EP, EP, EP, EP,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, XX, XX, XX, XX, XX, XX, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, XX, XX, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, XX, XX, EP, EP, XX, EP, EP, EP, XX, XX, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, XX, XX, EP, EP, EP, XX, EP, EP, EP, EP, EX, EX, EX, EX, EX,
+];
+
+pub const GRAPHEME_PROPERTIES_125: [u8; 1024] = [
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, XX, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP, EP, EP, EP, EP, XX,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    XX, XX, XX, XX, XX, XX, XX, EP, EP, XX, XX, EP, EP, EP, EP, EP, EP, EP, EP, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, EP, XX, XX, EP, EP, EP, EP, XX, XX, EP, XX, XX, XX, XX, EP, EP, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP, XX, XX, EP, XX, XX, XX, XX, XX, XX, XX,
+    XX, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, XX, XX, XX, XX, XX, EP, EP, EP, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP, EP, XX,
+    XX, EP, XX, EP, XX, XX, XX, XX, EP, XX, XX, XX, XX, XX, XX, EP, XX, XX, XX, EP, XX, XX, XX, XX,
+    XX, XX, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, XX, XX, XX, XX, XX, EP, EP, EP, EP, EP,
+    EP, EP, EP, XX, XX, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, XX, XX,
+    XX, EP, XX, EP, EP, EP, EP, EP, EP, XX, XX, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+];
+
+pub const GRAPHEME_PROPERTIES_126: [u8; 1024] = [
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    EP, EP, EP, EP, EP, EP, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP, EP, EP, EP, EP,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP, EP, EP, EP, EP, EP, EP,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP, EP, EP,
+    XX, XX, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, XX, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, XX, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    EP, EP, EP, EP, EP, EP, EP, EP, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+    XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTIES_127: [u8; 1024] = [
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP,
+    EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, EP, XX, XX,
+];
+
+pub const GRAPHEME_PROPERTY_TABLE: [&[u8; 1024]; 128] = [
+    &GRAPHEME_PROPERTIES_0,
+    &GRAPHEME_PROPERTIES_1,
+    &GRAPHEME_PROPERTIES_2,
+    &GRAPHEME_PROPERTIES_3,
+    &GRAPHEME_PROPERTIES_4,
+    &GRAPHEME_PROPERTIES_5,
+    &GRAPHEME_PROPERTIES_6,
+    &GRAPHEME_PROPERTIES_7,
+    &GRAPHEME_PROPERTIES_8,
+    &GRAPHEME_PROPERTIES_9,
+    &GRAPHEME_PROPERTIES_10,
+    &GRAPHEME_PROPERTIES_11,
+    &GRAPHEME_PROPERTIES_12,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_41,
+    &GRAPHEME_PROPERTIES_42,
+    &GRAPHEME_PROPERTIES_43,
+    &GRAPHEME_PROPERTIES_44,
+    &GRAPHEME_PROPERTIES_45,
+    &GRAPHEME_PROPERTIES_46,
+    &GRAPHEME_PROPERTIES_47,
+    &GRAPHEME_PROPERTIES_48,
+    &GRAPHEME_PROPERTIES_49,
+    &GRAPHEME_PROPERTIES_43,
+    &GRAPHEME_PROPERTIES_44,
+    &GRAPHEME_PROPERTIES_45,
+    &GRAPHEME_PROPERTIES_53,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_62,
+    &GRAPHEME_PROPERTIES_63,
+    &GRAPHEME_PROPERTIES_64,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_66,
+    &GRAPHEME_PROPERTIES_67,
+    &GRAPHEME_PROPERTIES_68,
+    &GRAPHEME_PROPERTIES_69,
+    &GRAPHEME_PROPERTIES_70,
+    &GRAPHEME_PROPERTIES_71,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_77,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_88,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_90,
+    &GRAPHEME_PROPERTIES_91,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_111,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_115,
+    &GRAPHEME_PROPERTIES_116,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_118,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_120,
+    &GRAPHEME_PROPERTIES_121,
+    &GRAPHEME_PROPERTIES_122,
+    &GRAPHEME_PROPERTIES_XX,
+    &GRAPHEME_PROPERTIES_124,
+    &GRAPHEME_PROPERTIES_125,
+    &GRAPHEME_PROPERTIES_126,
+    &GRAPHEME_PROPERTIES_127,
+];
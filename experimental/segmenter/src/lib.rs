@@ -39,12 +39,72 @@
 //! println!("{:?}", result);
 //! ```
 //!
+//! Line break opportunities, including whether each break is mandatory.
+//!
+//! ```rust
+//! use icu_segmenter::LineBreakSegmenter;
+//!
+//! let segmenter = LineBreakSegmenter::new(Default::default());
+//! let result: Vec<(usize, bool)> = segmenter
+//!     .segment_str("Hello\nWorld")
+//!     .map(|b| (b.offset, b.is_mandatory))
+//!     .collect();
+//! assert_eq!(result, vec![(6, true), (11, false)]);
+//! ```
+//!
+//! Grapheme cluster breaking compatible with [Unicode Standard Annex #29][UAX29].
+//!
+//! [UAX29]: http://www.unicode.org/reports/tr29/
+//!
+//! ```rust
+//! use icu_segmenter::GraphemeClusterSegmenter;
+//!
+//! let segmenter = GraphemeClusterSegmenter::new();
+//! let result: Vec<usize> = segmenter.segment_str("🇺🇸🇬🇧").collect();
+//! assert_eq!(result, vec![8, 16]);
+//! ```
+//!
+//! Word breaking compatible with [Unicode Standard Annex #29][UAX29], classifying each segment as
+//! word-like or not.
+//!
+//! ```rust
+//! use icu_segmenter::WordBreakSegmenter;
+//!
+//! let segmenter = WordBreakSegmenter::new();
+//! let result: Vec<(usize, bool)> = segmenter
+//!     .segment_str("can't stop")
+//!     .map(|b| (b.offset, b.is_word_like))
+//!     .collect();
+//! assert_eq!(result, vec![(5, true), (6, false), (10, true)]);
+//! ```
+//!
+//! Any of the above, with the kind of boundary chosen at runtime.
+//!
+//! ```rust
+//! use icu_segmenter::{GraphemeClusterSegmenter, Segmenter};
+//!
+//! let segmenter = Segmenter::from(GraphemeClusterSegmenter::new());
+//! let result: Vec<usize> = segmenter.segment_str("🇺🇸🇬🇧").collect();
+//! assert_eq!(result, vec![8, 16]);
+//! ```
+//!
 //! # Generating property table
 //!
 //! Copy the following files to tools directory. Then run `python ./generate_properties.py` in `tools` directory. Machine generated files are moved to `src` directory.
 //! - <https://www.unicode.org/Public/UCD/latest/ucd/LineBreak.txt>
 //! - <https://www.unicode.org/Public/UCD/latest/ucd/EastAsianWidth.txt>
+//!
+//! For the grapheme cluster break property table, copy the following files to tools directory and run `python ./generate_grapheme_properties.py`.
+//! - <https://www.unicode.org/Public/UCD/latest/ucd/auxiliary/GraphemeBreakProperty.txt>
+//! - <https://www.unicode.org/Public/UCD/latest/ucd/emoji/emoji-data.txt>
+//!
+//! For the word break property table, copy the following files to tools directory and run `python ./generate_word_properties.py`.
+//! - <https://www.unicode.org/Public/UCD/latest/ucd/auxiliary/WordBreakProperty.txt>
+//! - <https://www.unicode.org/Public/UCD/latest/ucd/emoji/emoji-data.txt>
 
+mod gb_define;
+mod grapheme;
+mod grapheme_property_table;
 mod lb_define;
 mod line_breaker;
 mod lstm;
@@ -52,11 +112,18 @@ mod properties_defines;
 mod properties_other;
 mod property_table;
 mod rule_table;
+mod segmenter;
+mod wb_define;
+mod word;
+mod word_property_table;
 
 #[macro_use]
 extern crate lazy_static;
 
+pub use crate::grapheme::*;
 pub use crate::line_breaker::*;
+pub use crate::segmenter::*;
+pub use crate::word::*;
 
 #[cfg(test)]
 mod tests {
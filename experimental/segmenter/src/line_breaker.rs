@@ -13,7 +13,7 @@ use core::char;
 use core::str::CharIndices;
 use unicode_width::UnicodeWidthChar;
 
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum LineBreakRule {
     /// Use `line-break: normal;` line break rule
     Normal,
@@ -25,7 +25,7 @@ pub enum LineBreakRule {
     Anywhere,
 }
 
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum WordBreakRule {
     /// Use `word-break: normal;` line break rule
     Normal,
@@ -512,6 +512,123 @@ impl<'a> LineBreakIterator<'a> {
     */
 }
 
+/// A line break opportunity found by [`LineBreakSegmenter`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LineBreak {
+    /// Byte offset of the break opportunity.
+    pub offset: usize,
+    /// Whether the line must break here because the preceding character is a hard line break
+    /// (BK, CR, LF, or NL). Otherwise, breaking here is only allowed.
+    pub is_mandatory: bool,
+}
+
+/// Options bag for [`LineBreakSegmenter`], corresponding to the CSS properties that control line
+/// breaking.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LineBreakOptions {
+    /// Strictness of line breaking, as in the CSS `line-break` property.
+    pub strictness: LineBreakRule,
+    /// Line break opportunities between letters, as in the CSS `word-break` property.
+    pub word_option: WordBreakRule,
+    /// Whether the content language is Japanese or Chinese, which allows additional breaks for
+    /// some rules of the CSS `line-break` property.
+    pub ja_zh: bool,
+}
+
+impl Default for LineBreakOptions {
+    fn default() -> Self {
+        Self {
+            strictness: LineBreakRule::Strict,
+            word_option: WordBreakRule::Normal,
+            ja_zh: false,
+        }
+    }
+}
+
+/// Line break segmenter compatible with [Unicode Standard Annex #14][UAX14] and CSS properties.
+///
+/// [UAX14]: http://www.unicode.org/reports/tr14/
+///
+/// # Examples
+///
+/// ```rust
+/// use icu_segmenter::{LineBreak, LineBreakSegmenter};
+///
+/// let segmenter = LineBreakSegmenter::new(Default::default());
+/// let breaks: Vec<LineBreak> = segmenter.segment_str("Hello\nWorld").collect();
+/// assert_eq!(
+///     breaks,
+///     vec![
+///         LineBreak { offset: 6, is_mandatory: true },
+///         LineBreak { offset: 11, is_mandatory: false },
+///     ]
+/// );
+/// ```
+///
+/// Using the CSS `line-break: loose` rule, which allows breaks before small kana:
+///
+/// ```rust
+/// use icu_segmenter::{LineBreakOptions, LineBreakRule, LineBreakSegmenter};
+///
+/// let mut options = LineBreakOptions::default();
+/// options.strictness = LineBreakRule::Loose;
+/// let segmenter = LineBreakSegmenter::new(options);
+/// let breaks: Vec<usize> = segmenter
+///     .segment_str("あぁ")
+///     .map(|b| b.offset)
+///     .collect();
+/// assert_eq!(breaks, vec![3, 6]);
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct LineBreakSegmenter {
+    options: LineBreakOptions,
+}
+
+impl LineBreakSegmenter {
+    /// Create line break segmenter with CSS rules
+    pub fn new(options: LineBreakOptions) -> Self {
+        LineBreakSegmenter { options }
+    }
+
+    /// Create an iterator over the line break opportunities in `input`.
+    pub fn segment_str<'a>(&self, input: &'a str) -> LineBreakSegmentIterator<'a> {
+        LineBreakSegmentIterator {
+            iter: LineBreakIterator::new_with_break_rule(
+                input,
+                self.options.strictness,
+                self.options.word_option,
+                self.options.ja_zh,
+            ),
+            input,
+        }
+    }
+}
+
+/// Iterator over line break opportunities, created by [`LineBreakSegmenter::segment_str`].
+pub struct LineBreakSegmentIterator<'a> {
+    iter: LineBreakIterator<'a>,
+    input: &'a str,
+}
+
+impl<'a> Iterator for LineBreakSegmentIterator<'a> {
+    type Item = LineBreak;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.iter.next()?;
+        let is_mandatory = match self.input[..offset].chars().next_back() {
+            Some(c) => matches!(
+                get_linebreak_property_with_rule(c, LineBreakRule::Strict, WordBreakRule::Normal),
+                BK | CR | LF | NL
+            ),
+            None => false,
+        };
+        Some(LineBreak {
+            offset,
+            is_mandatory,
+        })
+    }
+}
+
 /// Latin-1 version of line break iterator.
 #[derive(Clone)]
 struct Latin1Indices<'a> {
@@ -706,7 +823,9 @@ mod tests {
     use crate::line_breaker::get_linebreak_property_with_rule;
     use crate::line_breaker::is_break_from_table;
     use crate::rule_table::*;
+    use crate::LineBreakOptions;
     use crate::LineBreakRule;
+    use crate::LineBreakSegmenter;
     use crate::WordBreakRule;
 
     fn get_linebreak_property(codepoint: char) -> u8 {
@@ -735,6 +854,52 @@ mod tests {
         is_break_from_table(&UAX14_RULE_TABLE, PROP_COUNT, left, right)
     }
 
+    #[test]
+    fn line_break_segmenter() {
+        let segmenter = LineBreakSegmenter::new(Default::default());
+        let breaks = |s| {
+            segmenter
+                .segment_str(s)
+                .map(|b| (b.offset, b.is_mandatory))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(breaks(""), vec![]);
+        assert_eq!(breaks("hello world"), vec![(6, false), (11, false)]);
+        // LB4
+        assert_eq!(breaks("a\nb"), vec![(2, true), (3, false)]);
+        assert_eq!(breaks("a\u{2028}b"), vec![(4, true), (5, false)]);
+        // LB5
+        assert_eq!(breaks("a\r\nb"), vec![(3, true), (4, false)]);
+        assert_eq!(breaks("a\rb"), vec![(2, true), (3, false)]);
+        assert_eq!(breaks("a\n"), vec![(2, true)]);
+        assert_eq!(breaks("a\n\nb"), vec![(2, true), (3, true), (4, false)]);
+    }
+
+    #[test]
+    fn line_break_segmenter_strictness() {
+        let breaks = |strictness, s| {
+            let options = LineBreakOptions {
+                strictness,
+                ..Default::default()
+            };
+            LineBreakSegmenter::new(options)
+                .segment_str(s)
+                .map(|b| b.offset)
+                .collect::<Vec<_>>()
+        };
+
+        // Small kana (CJ) are treated as NS by strict, and as ID otherwise.
+        assert_eq!(breaks(LineBreakRule::Strict, "あぁ"), vec![6]);
+        assert_eq!(breaks(LineBreakRule::Normal, "あぁ"), vec![3, 6]);
+        assert_eq!(breaks(LineBreakRule::Loose, "あぁ"), vec![3, 6]);
+
+        // Iteration marks only allow a break before them with loose.
+        assert_eq!(breaks(LineBreakRule::Strict, "あゝ"), vec![6]);
+        assert_eq!(breaks(LineBreakRule::Normal, "あゝ"), vec![6]);
+        assert_eq!(breaks(LineBreakRule::Loose, "あゝ"), vec![3, 6]);
+    }
+
     #[test]
     fn break_rule() {
         // LB4
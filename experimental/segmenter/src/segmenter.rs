@@ -0,0 +1,132 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::grapheme::{GraphemeClusterBreakIterator, GraphemeClusterSegmenter};
+use crate::line_breaker::{LineBreakSegmentIterator, LineBreakSegmenter};
+use crate::word::{WordBreakIterator, WordBreakSegmenter};
+
+/// A segmenter of any of the supported kinds, selected at runtime.
+///
+/// All kinds yield the byte offsets of their boundaries through the same iterator type, so the
+/// kind of boundary can come from configuration without a separate code path per kind. Additional
+/// information about each boundary, such as whether a line break is mandatory, is only available
+/// from the individual segmenters.
+///
+/// # Examples
+///
+/// ```rust
+/// use icu_segmenter::{LineBreakSegmenter, Segmenter, WordBreakSegmenter};
+///
+/// let segmenters = [
+///     Segmenter::from(WordBreakSegmenter::new()),
+///     Segmenter::from(LineBreakSegmenter::new(Default::default())),
+/// ];
+/// let breaks: Vec<Vec<usize>> = segmenters
+///     .iter()
+///     .map(|segmenter| segmenter.segment_str("can't stop").collect())
+///     .collect();
+/// assert_eq!(breaks, vec![vec![5, 6, 10], vec![6, 10]]);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub enum Segmenter {
+    /// Grapheme cluster boundaries
+    GraphemeCluster(GraphemeClusterSegmenter),
+    /// Word boundaries
+    Word(WordBreakSegmenter),
+    /// Line break opportunities
+    Line(LineBreakSegmenter),
+}
+
+impl Segmenter {
+    /// Create an iterator over the boundaries in `input`. The iterator yields the byte offset of
+    /// each boundary.
+    pub fn segment_str<'a>(&self, input: &'a str) -> SegmenterBreakIterator<'a> {
+        SegmenterBreakIterator(match self {
+            Segmenter::GraphemeCluster(segmenter) => {
+                SegmenterBreakIteratorInner::GraphemeCluster(segmenter.segment_str(input))
+            }
+            Segmenter::Word(segmenter) => {
+                SegmenterBreakIteratorInner::Word(segmenter.segment_str(input))
+            }
+            Segmenter::Line(segmenter) => {
+                SegmenterBreakIteratorInner::Line(segmenter.segment_str(input))
+            }
+        })
+    }
+}
+
+impl From<GraphemeClusterSegmenter> for Segmenter {
+    fn from(segmenter: GraphemeClusterSegmenter) -> Self {
+        Segmenter::GraphemeCluster(segmenter)
+    }
+}
+
+impl From<WordBreakSegmenter> for Segmenter {
+    fn from(segmenter: WordBreakSegmenter) -> Self {
+        Segmenter::Word(segmenter)
+    }
+}
+
+impl From<LineBreakSegmenter> for Segmenter {
+    fn from(segmenter: LineBreakSegmenter) -> Self {
+        Segmenter::Line(segmenter)
+    }
+}
+
+/// Iterator over boundaries, created by [`Segmenter::segment_str`].
+pub struct SegmenterBreakIterator<'a>(SegmenterBreakIteratorInner<'a>);
+
+enum SegmenterBreakIteratorInner<'a> {
+    GraphemeCluster(GraphemeClusterBreakIterator<'a>),
+    Word(WordBreakIterator<'a>),
+    Line(LineBreakSegmentIterator<'a>),
+}
+
+impl<'a> Iterator for SegmenterBreakIterator<'a> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            SegmenterBreakIteratorInner::GraphemeCluster(iter) => iter.next(),
+            SegmenterBreakIteratorInner::Word(iter) => iter.next().map(|b| b.offset),
+            SegmenterBreakIteratorInner::Line(iter) => iter.next().map(|b| b.offset),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{GraphemeClusterSegmenter, LineBreakSegmenter, Segmenter, WordBreakSegmenter};
+
+    #[test]
+    fn segmenter_matches_individual_segmenters() {
+        let grapheme = GraphemeClusterSegmenter::new();
+        let word = WordBreakSegmenter::new();
+        let line = LineBreakSegmenter::new(Default::default());
+
+        for input in &[
+            "",
+            "Hello\nWorld",
+            "can't stop",
+            "🇺🇸🇬🇧 e\u{0301}",
+            "あぁ、東京",
+        ] {
+            let breaks: Vec<usize> = Segmenter::from(grapheme).segment_str(input).collect();
+            assert_eq!(
+                breaks,
+                grapheme.segment_str(input).collect::<Vec<_>>(),
+                "{}",
+                input
+            );
+
+            let breaks: Vec<usize> = Segmenter::from(word).segment_str(input).collect();
+            let expected: Vec<usize> = word.segment_str(input).map(|b| b.offset).collect();
+            assert_eq!(breaks, expected, "{}", input);
+
+            let breaks: Vec<usize> = Segmenter::from(line).segment_str(input).collect();
+            let expected: Vec<usize> = line.segment_str(input).map(|b| b.offset).collect();
+            assert_eq!(breaks, expected, "{}", input);
+        }
+    }
+}
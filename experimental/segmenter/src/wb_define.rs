@@ -0,0 +1,26 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// This file is generated by generate_word_properties.py. DO NOT EDIT MANUALLY!
+
+pub const XX: u8 = 0;
+pub const CR: u8 = 1;
+pub const LF: u8 = 2;
+pub const NL: u8 = 3;
+pub const EXTEND: u8 = 4;
+pub const ZWJ: u8 = 5;
+pub const RI: u8 = 6;
+pub const FO: u8 = 7;
+pub const KA: u8 = 8;
+pub const HL: u8 = 9;
+pub const LE: u8 = 10;
+pub const SQ: u8 = 11;
+pub const DQ: u8 = 12;
+pub const MB: u8 = 13;
+pub const ML: u8 = 14;
+pub const MN: u8 = 15;
+pub const NU: u8 = 16;
+pub const EX: u8 = 17;
+pub const WSEGSPACE: u8 = 18;
+pub const EP: u8 = 19;
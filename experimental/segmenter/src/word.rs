@@ -0,0 +1,335 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::wb_define::*;
+use crate::word_property_table::*;
+
+use core::str::CharIndices;
+
+fn get_word_property_utf32(codepoint: u32) -> u8 {
+    if codepoint < 0x20000 {
+        let codepoint = codepoint as usize;
+        return WORD_PROPERTY_TABLE[codepoint / 1024][codepoint & 0x3ff];
+    }
+
+    match codepoint {
+        0xe0001 => FO,
+        0xe0020..=0xe007f => EXTEND,
+        0xe0100..=0xe01ef => EXTEND,
+        _ => XX,
+    }
+}
+
+#[inline]
+fn get_word_property(codepoint: char) -> u8 {
+    get_word_property_utf32(codepoint as u32)
+}
+
+/// ALetter or Hebrew_Letter
+#[inline]
+fn is_ahletter(prop: u8) -> bool {
+    prop == LE || prop == HL
+}
+
+/// MidNumLet or Single_Quote
+#[inline]
+fn is_midnumletq(prop: u8) -> bool {
+    prop == MB || prop == SQ
+}
+
+/// Characters ignored by WB4
+#[inline]
+fn is_ignorable(prop: u8) -> bool {
+    prop == EXTEND || prop == FO || prop == ZWJ
+}
+
+/// A word boundary found by [`WordBreakSegmenter`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct WordBreak {
+    /// Byte offset of the word boundary.
+    pub offset: usize,
+    /// Whether the segment ending at this boundary is word-like, that is, whether it contains
+    /// letters or digits. Segments consisting only of spaces, punctuation, or symbols are not
+    /// word-like.
+    pub is_word_like: bool,
+}
+
+/// Word break segmenter compatible with [Unicode Standard Annex #29][UAX29].
+///
+/// [UAX29]: http://www.unicode.org/reports/tr29/
+///
+/// # Examples
+///
+/// ```rust
+/// use icu_segmenter::WordBreakSegmenter;
+///
+/// let segmenter = WordBreakSegmenter::new();
+/// let input = "Don't panic!";
+/// let mut start = 0;
+/// let mut words = Vec::new();
+/// for brk in segmenter.segment_str(input) {
+///     if brk.is_word_like {
+///         words.push(&input[start..brk.offset]);
+///     }
+///     start = brk.offset;
+/// }
+/// assert_eq!(words, vec!["Don't", "panic"]);
+/// ```
+#[derive(Copy, Clone, Debug, Default)]
+pub struct WordBreakSegmenter;
+
+impl WordBreakSegmenter {
+    /// Create word break segmenter
+    pub fn new() -> Self {
+        WordBreakSegmenter
+    }
+
+    /// Create word break iterator. The iterator yields the byte offset at the end of each
+    /// segment.
+    pub fn segment_str<'a>(&self, input: &'a str) -> WordBreakIterator<'a> {
+        WordBreakIterator {
+            iter: input.char_indices(),
+            len: input.len(),
+            current_pos_data: None,
+        }
+    }
+}
+
+/// Iterator over word boundaries, created by [`WordBreakSegmenter::segment_str`].
+pub struct WordBreakIterator<'a> {
+    iter: CharIndices<'a>,
+    len: usize,
+    current_pos_data: Option<(usize, char)>,
+}
+
+impl<'a> Iterator for WordBreakIterator<'a> {
+    type Item = WordBreak;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current_pos_data.is_none() {
+            self.current_pos_data = self.iter.next();
+        }
+        let first = self.current_pos_data?.1;
+
+        // Property of the previous character, ignoring characters skipped by WB4.
+        let mut left_prop = get_word_property(first);
+        // Property of the character before `left_prop`, ignoring characters skipped by WB4.
+        let mut left2_prop = XX;
+        // Property of the previous character.
+        let mut raw_left_prop = left_prop;
+        // Number of consecutive Regional_Indicator characters preceding the current position.
+        let mut ri_count = if left_prop == RI { 1 } else { 0 };
+        let mut is_word_like = first.is_alphanumeric();
+
+        loop {
+            self.current_pos_data = self.iter.next();
+            let (pos, c) = match self.current_pos_data {
+                Some(data) => data,
+                // EOF
+                None => {
+                    return Some(WordBreak {
+                        offset: self.len,
+                        is_word_like,
+                    })
+                }
+            };
+            let right_prop = get_word_property(c);
+
+            let is_break = match (raw_left_prop, right_prop) {
+                // WB3
+                (CR, LF) => false,
+                // WB3a
+                (CR, _) | (LF, _) | (NL, _) => true,
+                // WB3b
+                (_, CR) | (_, LF) | (_, NL) => true,
+                // WB3c
+                (ZWJ, EP) => false,
+                // WB3d
+                (WSEGSPACE, WSEGSPACE) => false,
+                // WB4
+                (_, right) if is_ignorable(right) => {
+                    raw_left_prop = right;
+                    is_word_like |= c.is_alphanumeric();
+                    continue;
+                }
+                _ => self.is_break(left2_prop, left_prop, right_prop, ri_count),
+            };
+
+            if is_break {
+                return Some(WordBreak {
+                    offset: pos,
+                    is_word_like,
+                });
+            }
+
+            ri_count = if right_prop == RI { ri_count + 1 } else { 0 };
+            left2_prop = left_prop;
+            left_prop = right_prop;
+            raw_left_prop = right_prop;
+            is_word_like |= c.is_alphanumeric();
+        }
+    }
+}
+
+impl<'a> WordBreakIterator<'a> {
+    /// Returns the property of the next character that is not ignored by WB4.
+    fn peek_property(&self) -> u8 {
+        self.iter
+            .clone()
+            .map(|(_, c)| get_word_property(c))
+            .find(|prop| !is_ignorable(*prop))
+            .unwrap_or(XX)
+    }
+
+    /// Applies WB5 to WB999 to the characters around the current position.
+    fn is_break(&self, left2: u8, left: u8, right: u8, ri_count: usize) -> bool {
+        // WB5
+        if is_ahletter(left) && is_ahletter(right) {
+            return false;
+        }
+        // WB6
+        if is_ahletter(left)
+            && (right == ML || is_midnumletq(right))
+            && is_ahletter(self.peek_property())
+        {
+            return false;
+        }
+        // WB7
+        if is_ahletter(left2) && (left == ML || is_midnumletq(left)) && is_ahletter(right) {
+            return false;
+        }
+        // WB7a
+        if left == HL && right == SQ {
+            return false;
+        }
+        // WB7b
+        if left == HL && right == DQ && self.peek_property() == HL {
+            return false;
+        }
+        // WB7c
+        if left2 == HL && left == DQ && right == HL {
+            return false;
+        }
+        // WB8, WB9, WB10
+        if (left == NU || is_ahletter(left)) && (right == NU || is_ahletter(right)) {
+            return false;
+        }
+        // WB11
+        if left2 == NU && (left == MN || is_midnumletq(left)) && right == NU {
+            return false;
+        }
+        // WB12
+        if left == NU && (right == MN || is_midnumletq(right)) && self.peek_property() == NU {
+            return false;
+        }
+        // WB13
+        if left == KA && right == KA {
+            return false;
+        }
+        // WB13a
+        if (is_ahletter(left) || left == NU || left == KA || left == EX) && right == EX {
+            return false;
+        }
+        // WB13b
+        if left == EX && (is_ahletter(right) || right == NU || right == KA) {
+            return false;
+        }
+        // WB15, WB16
+        if left == RI && right == RI && ri_count % 2 == 1 {
+            return false;
+        }
+        // WB999
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wb_define::*;
+    use crate::word::get_word_property;
+    use crate::WordBreakSegmenter;
+
+    #[test]
+    fn word_property() {
+        assert_eq!(get_word_property('\r'), CR);
+        assert_eq!(get_word_property('\n'), LF);
+        assert_eq!(get_word_property('\u{2028}'), NL);
+        assert_eq!(get_word_property('\u{0300}'), EXTEND);
+        assert_eq!(get_word_property('\u{200D}'), ZWJ);
+        assert_eq!(get_word_property('\u{1F1FA}'), RI);
+        assert_eq!(get_word_property('\u{00AD}'), FO);
+        assert_eq!(get_word_property('\u{30A2}'), KA);
+        assert_eq!(get_word_property('\u{05D0}'), HL);
+        assert_eq!(get_word_property('a'), LE);
+        assert_eq!(get_word_property('\''), SQ);
+        assert_eq!(get_word_property('"'), DQ);
+        assert_eq!(get_word_property('.'), MB);
+        assert_eq!(get_word_property(':'), ML);
+        assert_eq!(get_word_property(','), MN);
+        assert_eq!(get_word_property('1'), NU);
+        assert_eq!(get_word_property('_'), EX);
+        assert_eq!(get_word_property(' '), WSEGSPACE);
+        assert_eq!(get_word_property('\u{1F4BB}'), EP);
+        assert_eq!(get_word_property('\u{4E00}'), XX);
+        assert_eq!(get_word_property('\u{E0061}'), EXTEND);
+    }
+
+    fn breaks(input: &str) -> Vec<(usize, bool)> {
+        WordBreakSegmenter::new()
+            .segment_str(input)
+            .map(|b| (b.offset, b.is_word_like))
+            .collect()
+    }
+
+    #[test]
+    fn word_break() {
+        assert_eq!(breaks(""), vec![]);
+        assert_eq!(
+            breaks("hello, world"),
+            vec![(5, true), (6, false), (7, false), (12, true)]
+        );
+
+        // WB3, WB3a, WB3b
+        assert_eq!(breaks("a\r\nb"), vec![(1, true), (3, false), (4, true)]);
+
+        // WB3c
+        assert_eq!(breaks("\u{1F469}\u{200D}\u{1F4BB}"), vec![(11, false)]);
+
+        // WB3d
+        assert_eq!(breaks("a   b"), vec![(1, true), (4, false), (5, true)]);
+
+        // WB4
+        assert_eq!(breaks("e\u{0301}\u{00AD}f"), vec![(6, true)]);
+
+        // WB6, WB7
+        assert_eq!(breaks("can't"), vec![(5, true)]);
+        assert_eq!(
+            breaks("can't stop"),
+            vec![(5, true), (6, false), (10, true)]
+        );
+        assert_eq!(breaks("e.g"), vec![(3, true)]);
+        assert_eq!(breaks("end."), vec![(3, true), (4, false)]);
+        assert_eq!(breaks("a:"), vec![(1, true), (2, false)]);
+
+        // WB7a, WB7b, WB7c
+        assert_eq!(breaks("\u{05D0}'"), vec![(3, true)]);
+        assert_eq!(breaks("\u{05D0}\"\u{05D0}"), vec![(5, true)]);
+
+        // WB8, WB9, WB10, WB11, WB12
+        assert_eq!(breaks("3.14"), vec![(4, true)]);
+        assert_eq!(breaks("1,000a"), vec![(6, true)]);
+        assert_eq!(breaks("1,"), vec![(1, true), (2, false)]);
+
+        // WB13, WB13a, WB13b
+        assert_eq!(breaks("\u{30A2}\u{30A4}"), vec![(6, true)]);
+        assert_eq!(breaks("foo_bar"), vec![(7, true)]);
+
+        // WB15, WB16
+        assert_eq!(breaks("🇺🇸🇬🇧"), vec![(8, false), (16, false)]);
+        assert_eq!(breaks("🇺🇸🇬"), vec![(8, false), (12, false)]);
+
+        // WB999
+        assert_eq!(breaks("\u{4E00}\u{4E8C}"), vec![(3, true), (6, true)]);
+    }
+}
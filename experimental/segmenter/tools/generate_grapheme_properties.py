@@ -0,0 +1,112 @@
+#!/usr/bin/env python3
+
+# This file is part of ICU4X. For terms of use, please see the file
+# called LICENSE at the top level of the ICU4X source tree
+# (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+import os
+import re
+import subprocess
+
+# Short names of Grapheme_Cluster_Break property values (UAX29).
+# Extended_Pictographic is taken from emoji-data.txt.
+prop_names = {
+    "CR": "CR",
+    "LF": "LF",
+    "Control": "CN",
+    "Extend": "EX",
+    "ZWJ": "ZWJ",
+    "Regional_Indicator": "RI",
+    "Prepend": "PP",
+    "SpacingMark": "SM",
+    "L": "L",
+    "V": "V",
+    "T": "T",
+    "LV": "LV",
+    "LVT": "LVT",
+    "Extended_Pictographic": "EP",
+}
+
+prop = []
+table = []
+
+for x in range(0x20000):
+    prop.append('XX')
+
+
+def read_property_file(filename, accept):
+    with open(filename, 'r') as file:
+        line = file.readline()
+        while line:
+            line = line.strip()
+            if not line.startswith('#'):
+                m = re.search(
+                    "([0-9A-F]{4,6})(?:\.\.([0-9A-F]{4,6}))?\s*\;\s*([A-Za-z_]+)",
+                    line)
+                if m and m.group(3) in accept:
+                    start = int(m.group(1), 16)
+                    end = int(m.group(2), 16) if m.group(2) else start
+                    for x in range(start, min(end + 1, 0x20000)):
+                        prop[x] = prop_names[m.group(3)]
+            line = file.readline()
+
+
+read_property_file('GraphemeBreakProperty.txt', prop_names)
+read_property_file('emoji-data.txt', ("Extended_Pictographic", ))
+
+prop_type = ["XX"] + list(prop_names.values())
+
+header = """// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// This file is generated by generate_grapheme_properties.py. DO NOT EDIT MANUALLY!
+
+"""
+
+with open('gb_define.rs', 'w') as prop_file:
+    prop_file.write(header)
+
+    count = 0
+    for i in prop_type:
+        prop_file.write("pub const %s: u8 = %s;\n" % (i, str(count)))
+        count = count + 1
+
+# For Grapheme_Cluster_Break property
+with open('grapheme_property_table.rs', 'w') as table_file:
+    table_file.write(header)
+    table_file.write("use crate::gb_define::*;\n\n")
+
+    blocks = {}
+    for a in range(128):
+        block = tuple(prop[a * 1024:(a + 1) * 1024])
+        if block in blocks:
+            table.append(blocks[block])
+            continue
+
+        if len(set(block)) == 1:
+            name = "GRAPHEME_PROPERTIES_%s" % block[0]
+        else:
+            name = "GRAPHEME_PROPERTIES_%s" % str(a)
+        blocks[block] = name
+        table.append(name)
+
+        table_file.write("pub const %s: [u8; 1024] = [\n" % name)
+        for i in range(1024):
+            table_file.write(" %s," % block[i])
+        table_file.write("];\n\n")
+
+    table_file.write(
+        "pub const GRAPHEME_PROPERTY_TABLE: [&[u8; 1024]; 128] = [\n")
+    for i in table:
+        table_file.write("  &%s,\n" % i)
+    table_file.write("];\n")
+
+# Format and copy generated rs files to src directory.
+
+# gb_define.rs is Grapheme_Cluster_Break property value defines.
+subprocess.call(["rustfmt", "gb_define.rs"])
+os.rename("gb_define.rs", "../src/gb_define.rs")
+# grapheme_property_table.rs is Grapheme_Cluster_Break property table map for UAX29
+subprocess.call(["rustfmt", "grapheme_property_table.rs"])
+os.rename("grapheme_property_table.rs", "../src/grapheme_property_table.rs")
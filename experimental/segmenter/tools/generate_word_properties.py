@@ -0,0 +1,119 @@
+#!/usr/bin/env python3
+
+# This file is part of ICU4X. For terms of use, please see the file
+# called LICENSE at the top level of the ICU4X source tree
+# (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+import os
+import re
+import subprocess
+
+# Short names of Word_Break property values (UAX29).
+# Extended_Pictographic is taken from emoji-data.txt.
+prop_names = {
+    "CR": "CR",
+    "LF": "LF",
+    "Newline": "NL",
+    "Extend": "EXTEND",
+    "ZWJ": "ZWJ",
+    "Regional_Indicator": "RI",
+    "Format": "FO",
+    "Katakana": "KA",
+    "Hebrew_Letter": "HL",
+    "ALetter": "LE",
+    "Single_Quote": "SQ",
+    "Double_Quote": "DQ",
+    "MidNumLet": "MB",
+    "MidLetter": "ML",
+    "MidNum": "MN",
+    "Numeric": "NU",
+    "ExtendNumLet": "EX",
+    "WSegSpace": "WSEGSPACE",
+    "Extended_Pictographic": "EP",
+}
+
+prop = []
+table = []
+
+for x in range(0x20000):
+    prop.append('XX')
+
+
+def read_property_file(filename, accept):
+    with open(filename, 'r') as file:
+        line = file.readline()
+        while line:
+            line = line.strip()
+            if not line.startswith('#'):
+                m = re.search(
+                    "([0-9A-F]{4,6})(?:\.\.([0-9A-F]{4,6}))?\s*\;\s*([A-Za-z_]+)",
+                    line)
+                if m and m.group(3) in accept:
+                    start = int(m.group(1), 16)
+                    end = int(m.group(2), 16) if m.group(2) else start
+                    for x in range(start, min(end + 1, 0x20000)):
+                        # Word_Break property values take precedence over Extended_Pictographic.
+                        if prop[x] == 'XX':
+                            prop[x] = prop_names[m.group(3)]
+            line = file.readline()
+
+
+read_property_file('WordBreakProperty.txt', prop_names)
+read_property_file('emoji-data.txt', ("Extended_Pictographic", ))
+
+prop_type = ["XX"] + list(prop_names.values())
+
+header = """// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// This file is generated by generate_word_properties.py. DO NOT EDIT MANUALLY!
+
+"""
+
+with open('wb_define.rs', 'w') as prop_file:
+    prop_file.write(header)
+
+    count = 0
+    for i in prop_type:
+        prop_file.write("pub const %s: u8 = %s;\n" % (i, str(count)))
+        count = count + 1
+
+# For Word_Break property
+with open('word_property_table.rs', 'w') as table_file:
+    table_file.write(header)
+    table_file.write("use crate::wb_define::*;\n\n")
+
+    blocks = {}
+    for a in range(128):
+        block = tuple(prop[a * 1024:(a + 1) * 1024])
+        if block in blocks:
+            table.append(blocks[block])
+            continue
+
+        if len(set(block)) == 1:
+            name = "WORD_PROPERTIES_%s" % block[0]
+        else:
+            name = "WORD_PROPERTIES_%s" % str(a)
+        blocks[block] = name
+        table.append(name)
+
+        table_file.write("pub const %s: [u8; 1024] = [\n" % name)
+        for i in range(1024):
+            table_file.write(" %s," % block[i])
+        table_file.write("];\n\n")
+
+    table_file.write(
+        "pub const WORD_PROPERTY_TABLE: [&[u8; 1024]; 128] = [\n")
+    for i in table:
+        table_file.write("  &%s,\n" % i)
+    table_file.write("];\n")
+
+# Format and copy generated rs files to src directory.
+
+# wb_define.rs is Word_Break property value defines.
+subprocess.call(["rustfmt", "wb_define.rs"])
+os.rename("wb_define.rs", "../src/wb_define.rs")
+# word_property_table.rs is Word_Break property table map for UAX29
+subprocess.call(["rustfmt", "word_property_table.rs"])
+os.rename("word_property_table.rs", "../src/word_property_table.rs")
@@ -0,0 +1,98 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#include "../../include/datetime.h"
+#include <string.h>
+#include <stdio.h>
+
+const char* path = "../../../../provider/testdata/data/json/";
+int main() {
+    ICU4XLocale* locale = icu4x_locale_create("en", 2);
+    ICU4XCreateDataProviderResult result = icu4x_fs_data_provider_create(path, strlen(path));
+    if (!result.success) {
+        printf("Failed to create FsDataProvider\n");
+        return 1;
+    }
+    ICU4XDataProvider provider = result.provider;
+
+    ICU4XDateTimeFormatOptions opts = {ICU4XDateLength_Medium, ICU4XTimeLength_Short};
+
+    ICU4XCreateDateTimeFormatResult dtf_result = icu4x_datetime_format_create(locale, &provider, opts);
+    if (!dtf_result.success) {
+        printf("Failed to create DateTimeFormat\n");
+        return 1;
+    }
+    ICU4XDateTimeFormat* dtf = dtf_result.dtf;
+    char output[40];
+
+    ICU4XWriteable write = icu4x_simple_writeable(output, 40);
+
+    const char* datetime = "2020-10-14T13:21:50";
+    ICU4XDateTimeFormatError error = icu4x_datetime_format_write(dtf, datetime, strlen(datetime), &write);
+    if (error != ICU4XDateTimeFormatError_Ok) {
+        printf("Failed to write result of DateTimeFormat::format to string.\n");
+        return 1;
+    }
+    printf("Output is %s\n", output);
+
+    const char* expected = u8"Oct 14, 2020, 1:21 PM";
+    if (strcmp(output, expected) != 0) {
+        printf("Output does not match expected output!\n");
+        return 1;
+    }
+
+    write = icu4x_simple_writeable(output, 40);
+
+    datetime = "2020-10-14";
+    error = icu4x_datetime_format_write(dtf, datetime, strlen(datetime), &write);
+    if (error != ICU4XDateTimeFormatError_InvalidInput) {
+        printf("A date without a time should not be formatted.\n");
+        return 1;
+    }
+
+    icu4x_datetime_format_destroy(dtf);
+    icu4x_locale_destroy(locale);
+
+    // The calendar is selected by the locale.
+    locale = icu4x_locale_create("th-u-ca-buddhist", 16);
+    opts.date_length = ICU4XDateLength_Short;
+    opts.time_length = ICU4XTimeLength_None;
+
+    dtf_result = icu4x_datetime_format_create(locale, &provider, opts);
+    if (!dtf_result.success) {
+        printf("Failed to create DateTimeFormat\n");
+        return 1;
+    }
+    dtf = dtf_result.dtf;
+
+    write = icu4x_simple_writeable(output, 40);
+
+    datetime = "2020-10-14T13:21:50";
+    error = icu4x_datetime_format_write(dtf, datetime, strlen(datetime), &write);
+    if (error != ICU4XDateTimeFormatError_Ok) {
+        printf("Failed to write result of DateTimeFormat::format to string.\n");
+        return 1;
+    }
+    printf("Output in the Buddhist calendar is %s\n", output);
+
+    expected = u8"14/10/63";
+    if (strcmp(output, expected) != 0) {
+        printf("Output does not match expected output!\n");
+        return 1;
+    }
+
+    icu4x_datetime_format_destroy(dtf);
+
+    opts.date_length = ICU4XDateLength_None;
+    dtf_result = icu4x_datetime_format_create(locale, &provider, opts);
+    if (dtf_result.success || dtf_result.error != ICU4XDateTimeFormatError_EmptyOptions) {
+        printf("Options without a date or a time should not create a DateTimeFormat.\n");
+        return 1;
+    }
+
+    icu4x_locale_destroy(locale);
+    icu4x_data_provider_destroy(provider);
+
+    return 0;
+}
@@ -90,6 +90,19 @@ int main() {
     }
 
     icu4x_fixed_decimal_destroy(decimal);
+
+    fd_result = icu4x_fixed_decimal_create_fromstr("\xff" "1.5", 4);
+    if (fd_result.success || fd_result.fd != NULL) {
+        printf("Invalid UTF-8 should not create a FixedDecimal.\n");
+        return 1;
+    }
+
+    fd_result = icu4x_fixed_decimal_create_fromstr("--1.5", 5);
+    if (fd_result.success || fd_result.fd != NULL) {
+        printf("Invalid string should not create a FixedDecimal.\n");
+        return 1;
+    }
+
     icu4x_fixed_decimal_format_destroy(fdf);
 
     return 0;
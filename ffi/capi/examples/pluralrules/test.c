@@ -47,6 +47,23 @@ int main() {
 
     printf("Plural Category %d (should be %d)\n", (int)cat2, (int)ICU4XPluralCategory_Many);
 
+    ICU4XPluralCategory cat3 = icu4x_plural_rules_select_i64(rules, -11);
+
+    printf("Plural Category %d (should be %d)\n", (int)cat3, (int)ICU4XPluralCategory_Many);
+
+    ICU4XCreateFixedDecimalResult fd_result = icu4x_fixed_decimal_create_fromstr("1.50", 4);
+
+    if (!fd_result.success) {
+        printf("Failed to create FixedDecimal from string\n");
+        return 1;
+    }
+
+    ICU4XPluralCategory cat4 = icu4x_plural_rules_select_fixed_decimal(rules, fd_result.fd);
+
+    printf("Plural Category %d (should be %d)\n", (int)cat4, (int)ICU4XPluralCategory_Other);
+
+    icu4x_fixed_decimal_destroy(fd_result.fd);
+
     icu4x_plural_rules_destroy(rules);
     icu4x_data_provider_destroy(provider);
     icu4x_locale_destroy(locale);
@@ -60,6 +77,8 @@ int main() {
 
     if (cat1 != ICU4XPluralCategory_Few)  { return 1; }
     if (cat2 != ICU4XPluralCategory_Many) { return 1; }
+    if (cat3 != ICU4XPluralCategory_Many) { return 1; }
+    if (cat4 != ICU4XPluralCategory_Other) { return 1; }
 
     return 0;
 }
@@ -0,0 +1,71 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#ifndef ICU4X_DATETIME_H
+#define ICU4X_DATETIME_H
+
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include "provider.h"
+#include "locale.h"
+#include "custom_writeable.h"
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+// opaque
+typedef struct ICU4XDateTimeFormat ICU4XDateTimeFormat;
+
+typedef enum {
+    ICU4XDateTimeFormatError_Ok,
+    ICU4XDateTimeFormatError_Pattern,
+    ICU4XDateTimeFormatError_Format,
+    ICU4XDateTimeFormatError_DataProvider,
+    ICU4XDateTimeFormatError_MissingInputField,
+    ICU4XDateTimeFormatError_Skeleton,
+    ICU4XDateTimeFormatError_UnsupportedField,
+    ICU4XDateTimeFormatError_InvalidInput,
+    ICU4XDateTimeFormatError_EmptyOptions,
+    ICU4XDateTimeFormatError_InvalidOptions,
+} ICU4XDateTimeFormatError;
+
+typedef struct {
+    ICU4XDateTimeFormat* dtf;
+    ICU4XDateTimeFormatError error;
+    bool success;
+} ICU4XCreateDateTimeFormatResult;
+
+typedef enum {
+    ICU4XDateLength_None,
+    ICU4XDateLength_Full,
+    ICU4XDateLength_Long,
+    ICU4XDateLength_Medium,
+    ICU4XDateLength_Short,
+} ICU4XDateLength;
+
+typedef enum {
+    ICU4XTimeLength_None,
+    ICU4XTimeLength_Full,
+    ICU4XTimeLength_Long,
+    ICU4XTimeLength_Medium,
+    ICU4XTimeLength_Short,
+} ICU4XTimeLength;
+
+typedef struct {
+    ICU4XDateLength date_length;
+    ICU4XTimeLength time_length;
+} ICU4XDateTimeFormatOptions;
+
+ICU4XCreateDateTimeFormatResult icu4x_datetime_format_create(const ICU4XLocale* locale, const ICU4XDataProvider* provider, ICU4XDateTimeFormatOptions options);
+
+ICU4XDateTimeFormatError icu4x_datetime_format_write(const ICU4XDateTimeFormat* dtf, const char* datetime, size_t len, ICU4XWriteable* write);
+void icu4x_datetime_format_destroy(ICU4XDateTimeFormat* dtf);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif // ICU4X_DATETIME_H
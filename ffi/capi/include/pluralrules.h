@@ -10,6 +10,7 @@
 #include <stdbool.h>
 #include "provider.h"
 #include "locale.h"
+#include "fixed_decimal.h"
 
 #ifdef __cplusplus
 extern "C" {
@@ -28,13 +29,14 @@ typedef enum {
     ICU4XPluralRuleType_Ordinal
 } ICU4XPluralRuleType;
 
+// The integer values of the plural categories are stable
 typedef enum {
-    ICU4XPluralCategory_Zero,
-    ICU4XPluralCategory_One,
-    ICU4XPluralCategory_Two,
-    ICU4XPluralCategory_Few,
-    ICU4XPluralCategory_Many,
-    ICU4XPluralCategory_Other,
+    ICU4XPluralCategory_Zero = 0,
+    ICU4XPluralCategory_One = 1,
+    ICU4XPluralCategory_Two = 2,
+    ICU4XPluralCategory_Few = 3,
+    ICU4XPluralCategory_Many = 4,
+    ICU4XPluralCategory_Other = 5,
 } ICU4XPluralCategory;
 
 typedef struct {
@@ -63,6 +65,8 @@ typedef struct {
 ICU4XCreatePluralRulesResult icu4x_plural_rules_create(const ICU4XLocale* locale, const ICU4XDataProvider* provider, ICU4XPluralRuleType ty);
 ICU4XCreatePluralOperandsResult icu4x_plural_operands_create(const char* number, size_t len);
 ICU4XPluralCategory icu4x_plural_rules_select(const ICU4XPluralRules* rules, const ICU4XPluralOperands* op);
+ICU4XPluralCategory icu4x_plural_rules_select_i64(const ICU4XPluralRules* rules, int64_t value);
+ICU4XPluralCategory icu4x_plural_rules_select_fixed_decimal(const ICU4XPluralRules* rules, const ICU4XFixedDecimal* value);
 ICU4XPluralCategories icu4x_plural_rules_categories(const ICU4XPluralRules* rules);
 void icu4x_plural_rules_destroy(ICU4XPluralRules* rules);
 
@@ -0,0 +1,207 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::custom_writeable::ICU4XWriteable;
+use crate::locale::ICU4XLocale;
+use crate::provider::ICU4XDataProvider;
+use icu_datetime::mock::datetime::MockDateTime;
+use icu_datetime::options::length;
+use icu_datetime::{DateTimeFormat, DateTimeFormatError};
+use std::ptr;
+use std::slice;
+use std::str::{self, FromStr};
+
+/// Opaque type for use behind a pointer, is [`DateTimeFormat`]
+///
+/// Can be obtained via [`icu4x_datetime_format_create()`] and destroyed via [`icu4x_datetime_format_destroy()`]
+pub type ICU4XDateTimeFormat<'d> = DateTimeFormat<'d>;
+
+#[repr(C)]
+/// This is the result returned by [`icu4x_datetime_format_create()`]
+pub struct ICU4XCreateDateTimeFormatResult<'d> {
+    /// Will be null if `success` is [`false`]
+    pub dtf: *mut ICU4XDateTimeFormat<'d>,
+    /// The reason construction failed, [`ICU4XDateTimeFormatError::Ok`] if `success` is [`true`]
+    pub error: ICU4XDateTimeFormatError,
+    pub success: bool,
+}
+
+#[no_mangle]
+/// FFI version of [`DateTimeFormat::try_new()`]. See its docs for more details.
+///
+/// # Safety
+/// - `locale` should be constructed via [`icu4x_locale_create()`](crate::locale::icu4x_locale_create)
+/// - `provider` should be constructed via one of the functions in [`crate::locale`](crate::locale)
+/// - Only access `dtf` in the result if `success` is [`true`].
+pub extern "C" fn icu4x_datetime_format_create<'d>(
+    locale: &ICU4XLocale,
+    provider: &'d ICU4XDataProvider,
+    options: ICU4XDateTimeFormatOptions,
+) -> ICU4XCreateDateTimeFormatResult<'d> {
+    let locale = locale.clone();
+    let provider = provider.as_dyn_ref();
+    let options = length::Bag::from(options).into();
+    match DateTimeFormat::try_new(locale, provider, &options) {
+        Ok(dtf) => {
+            let dtf = Box::new(dtf);
+            ICU4XCreateDateTimeFormatResult {
+                dtf: Box::into_raw(dtf),
+                error: ICU4XDateTimeFormatError::Ok,
+                success: true,
+            }
+        }
+        Err(e) => ICU4XCreateDateTimeFormatResult {
+            dtf: ptr::null_mut(),
+            error: e.into(),
+            success: false,
+        },
+    }
+}
+
+#[no_mangle]
+/// FFI version of [`DateTimeFormat::format_to_write()`]. See its docs for more details.
+///
+/// `datetime` is parsed the same way as [`MockDateTime::from_str()`], i.e. it must be of
+/// the form `YYYY-MM-DDThh:mm:ss`. Returns [`ICU4XDateTimeFormatError::InvalidInput`] if it
+/// is not valid UTF-8 or cannot be parsed, and [`ICU4XDateTimeFormatError::Format`] when
+/// there were errors writing to `write`.
+///
+/// # Safety
+/// `datetime` and `len` should point to a valid string of length `len`.
+///
+/// It does not need to be be null terminated, and `len` should not include a null
+/// terminator (this will just cause the function to return an error).
+pub unsafe extern "C" fn icu4x_datetime_format_write(
+    dtf: &ICU4XDateTimeFormat<'_>,
+    datetime: *const u8,
+    len: usize,
+    write: &mut ICU4XWriteable,
+) -> ICU4XDateTimeFormatError {
+    let datetime = match str::from_utf8(slice::from_raw_parts(datetime, len)) {
+        Ok(s) => s,
+        Err(_) => return ICU4XDateTimeFormatError::InvalidInput,
+    };
+    let datetime = match MockDateTime::from_str(datetime) {
+        Ok(datetime) => datetime,
+        Err(_) => return ICU4XDateTimeFormatError::InvalidInput,
+    };
+    let result = match dtf.format_to_write(write, &datetime) {
+        Ok(()) => ICU4XDateTimeFormatError::Ok,
+        Err(_) => ICU4XDateTimeFormatError::Format,
+    };
+    write.flush();
+    result
+}
+
+#[no_mangle]
+/// Destructor for [`ICU4XDateTimeFormat`]
+///
+/// # Safety
+/// `dtf` must be a pointer to a valid [`ICU4XDateTimeFormat`] constructed by
+/// [`icu4x_datetime_format_create()`].
+pub unsafe extern "C" fn icu4x_datetime_format_destroy(dtf: *mut ICU4XDateTimeFormat<'_>) {
+    let _ = Box::from_raw(dtf);
+}
+
+#[repr(C)]
+/// FFI version of [`DateTimeFormatError`], with additional variants for
+/// errors that can only occur on the FFI boundary.
+pub enum ICU4XDateTimeFormatError {
+    /// No error occurred
+    Ok,
+    /// See [`DateTimeFormatError::Pattern`]
+    Pattern,
+    /// See [`DateTimeFormatError::Format`]
+    Format,
+    /// See [`DateTimeFormatError::DataProvider`]
+    DataProvider,
+    /// See [`DateTimeFormatError::MissingInputField`]
+    MissingInputField,
+    /// See [`DateTimeFormatError::Skeleton`]
+    Skeleton,
+    /// See [`DateTimeFormatError::UnsupportedField`]
+    UnsupportedField,
+    /// The datetime string passed to [`icu4x_datetime_format_write()`] could not be parsed
+    InvalidInput,
+    /// See [`DateTimeFormatError::EmptyOptions`]
+    EmptyOptions,
+    /// See [`DateTimeFormatError::InvalidOptions`]
+    InvalidOptions,
+}
+
+impl From<DateTimeFormatError> for ICU4XDateTimeFormatError {
+    fn from(e: DateTimeFormatError) -> Self {
+        match e {
+            DateTimeFormatError::Pattern(_) => Self::Pattern,
+            DateTimeFormatError::Format(_) => Self::Format,
+            DateTimeFormatError::DataProvider(_) => Self::DataProvider,
+            DateTimeFormatError::MissingInputField => Self::MissingInputField,
+            DateTimeFormatError::Skeleton(_) => Self::Skeleton,
+            DateTimeFormatError::UnsupportedField(_) => Self::UnsupportedField,
+            DateTimeFormatError::EmptyOptions => Self::EmptyOptions,
+            DateTimeFormatError::InvalidOptions(_) => Self::InvalidOptions,
+        }
+    }
+}
+
+#[repr(C)]
+/// FFI version of [`length::Bag`]. See its docs for more details.
+pub struct ICU4XDateTimeFormatOptions {
+    date_length: ICU4XDateLength,
+    time_length: ICU4XTimeLength,
+}
+
+#[repr(C)]
+/// FFI version of [`length::Date`], where `None` omits the date. See its docs for more details.
+pub enum ICU4XDateLength {
+    None,
+    Full,
+    Long,
+    Medium,
+    Short,
+}
+
+#[repr(C)]
+/// FFI version of [`length::Time`], where `None` omits the time. See its docs for more details.
+pub enum ICU4XTimeLength {
+    None,
+    Full,
+    Long,
+    Medium,
+    Short,
+}
+
+impl From<ICU4XDateLength> for Option<length::Date> {
+    fn from(c: ICU4XDateLength) -> Self {
+        match c {
+            ICU4XDateLength::None => None,
+            ICU4XDateLength::Full => Some(length::Date::Full),
+            ICU4XDateLength::Long => Some(length::Date::Long),
+            ICU4XDateLength::Medium => Some(length::Date::Medium),
+            ICU4XDateLength::Short => Some(length::Date::Short),
+        }
+    }
+}
+
+impl From<ICU4XTimeLength> for Option<length::Time> {
+    fn from(c: ICU4XTimeLength) -> Self {
+        match c {
+            ICU4XTimeLength::None => None,
+            ICU4XTimeLength::Full => Some(length::Time::Full),
+            ICU4XTimeLength::Long => Some(length::Time::Long),
+            ICU4XTimeLength::Medium => Some(length::Time::Medium),
+            ICU4XTimeLength::Short => Some(length::Time::Short),
+        }
+    }
+}
+
+impl From<ICU4XDateTimeFormatOptions> for length::Bag {
+    fn from(c: ICU4XDateTimeFormatOptions) -> Self {
+        Self {
+            date: c.date_length.into(),
+            time: c.time_length.into(),
+            preferences: None,
+        }
+    }
+}
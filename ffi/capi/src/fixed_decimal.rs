@@ -10,7 +10,8 @@ use writeable::Writeable;
 
 /// Opaque type for use behind a pointer, is [`FixedDecimal`]
 ///
-/// Can be obtained via [`icu4x_fixed_decimal_create()`] and destroyed via [`icu4x_fixed_decimal_destroy()`]
+/// Can be obtained via [`icu4x_fixed_decimal_create()`] or [`icu4x_fixed_decimal_create_fromstr()`]
+/// and destroyed via [`icu4x_fixed_decimal_destroy()`]
 pub type ICU4XFixedDecimal = FixedDecimal;
 
 #[repr(C)]
@@ -31,15 +32,17 @@ pub extern "C" fn icu4x_fixed_decimal_create(number: i64) -> *mut ICU4XFixedDeci
 }
 
 #[no_mangle]
-/// FFI version of [`FixedDecimal::FromStr()`], see its docs for more details
+/// FFI version of [`FixedDecimal`]'s [`FromStr`](std::str::FromStr) implementation, see its docs for
+/// more details. Accepts strings such as `"-1234.567"` or `"+1.50"`.
 ///
 /// # Safety
-/// `value` and `len` should point to a valid ASCII string of length `len`.
+/// `value` and `len` should point to a buffer of `len` bytes, which need not be valid UTF-8.
 ///
 /// It does not need to be be null terminated, and `len` should not include a null
-/// terminator (this will just cause the function to panic, and is not a safety requirement).
+/// terminator (this will just cause the string to be rejected, and is not a safety requirement).
 ///
-/// Returns nullptr if passed an invalid string.
+/// Invalid UTF-8 and strings that do not parse as a [`FixedDecimal`] are reported by setting
+/// `success` to `false` and `fd` to null; this function does not panic.
 pub unsafe extern "C" fn icu4x_fixed_decimal_create_fromstr(
     value: *const u8,
     len: usize,
@@ -8,6 +8,7 @@
 mod macros;
 
 pub mod custom_writeable;
+pub mod datetime;
 pub mod decimal;
 pub mod fixed_decimal;
 pub mod locale;
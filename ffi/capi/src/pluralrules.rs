@@ -5,6 +5,7 @@
 use icu_locid::Locale as ICULocale;
 use icu_plurals::{PluralCategory, PluralOperands, PluralRuleType, PluralRules};
 
+use crate::fixed_decimal::ICU4XFixedDecimal;
 use crate::provider::ICU4XDataProvider;
 use std::ptr;
 use std::slice;
@@ -101,6 +102,27 @@ pub extern "C" fn icu4x_plural_rules_select(
     pr.select(*op).into()
 }
 
+#[no_mangle]
+/// FFI version of [`PluralRules::select()`] for integers. See its docs for more details.
+///
+/// Plural rules only depend on the absolute value, so negative numbers are accepted as well.
+pub extern "C" fn icu4x_plural_rules_select_i64(
+    pr: &ICU4XPluralRules,
+    value: i64,
+) -> ICU4XPluralCategory {
+    pr.select(value.unsigned_abs()).into()
+}
+
+#[no_mangle]
+/// FFI version of [`PluralRules::select()`] for a [`ICU4XFixedDecimal`], which retains
+/// the visible fraction digits. See its docs for more details.
+pub extern "C" fn icu4x_plural_rules_select_fixed_decimal(
+    pr: &ICU4XPluralRules,
+    value: &ICU4XFixedDecimal,
+) -> ICU4XPluralCategory {
+    pr.select(value).into()
+}
+
 #[no_mangle]
 /// FFI version of [`PluralRules::categories()`]. See its docs for more details.
 pub extern "C" fn icu4x_plural_rules_categories(pr: &ICU4XPluralRules) -> ICU4XPluralCategories {
@@ -153,6 +175,9 @@ c_enum! {
 #[repr(C)]
 c_enum! {
     /// FFI version of [`PluralCategory`]. See its docs for more details.
+    ///
+    /// The integer values of the variants are stable and can be relied upon by bindings:
+    /// `Zero = 0`, `One = 1`, `Two = 2`, `Few = 3`, `Many = 4`, `Other = 5`.
     pub c_enum ICU4XPluralCategory is PluralCategory {
         Zero,
         One,
@@ -0,0 +1,48 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#include "../../include/datetime.hpp"
+
+#include <iostream>
+
+const std::string_view path = "../../../../provider/testdata/data/json/";
+using namespace icu4x;
+
+int main() {
+    Locale locale("en");
+    std::cout << "Running test for locale " << locale.ToString().value() << std::endl;
+    DataProvider dp = DataProvider::FsDataProvider(path).value();
+
+    DateTimeFormatOptions opts = {DateLength::Medium, TimeLength::Short};
+    DateTimeFormat dtf = DateTimeFormat::Create(locale, dp, opts).value();
+
+    std::string out = dtf.Format("2020-10-14T13:21:50").value();
+    std::cout << "Formatted value is " << out << std::endl;
+    if (out != "Oct 14, 2020, 1:21 PM") {
+        std::cout << "Output does not match expected output" << std::endl;
+        return 1;
+    }
+
+    if (dtf.Format("2020-10-14").has_value()) {
+        std::cout << "A date without a time should not be formatted" << std::endl;
+        return 1;
+    }
+
+    Locale buddhist("th-u-ca-buddhist");
+    opts = {DateLength::Short, TimeLength::None};
+    dtf = DateTimeFormat::Create(buddhist, dp, opts).value();
+    out = dtf.Format("2020-10-14T13:21:50").value();
+    std::cout << "Value in the Buddhist calendar is " << out << std::endl;
+    if (out != "14/10/63") {
+        std::cout << "Output does not match expected output" << std::endl;
+        return 1;
+    }
+
+    opts = {DateLength::None, TimeLength::None};
+    if (DateTimeFormat::Create(locale, dp, opts).has_value()) {
+        std::cout << "Options without a date or a time should not create a DateTimeFormat" << std::endl;
+        return 1;
+    }
+    return 0;
+}
@@ -33,5 +33,18 @@ int main() {
         std::cout << "Output does not match expected output" << std::endl;
         return 1;
     }
+
+    decimal = FixedDecimal::Create("1000007.070").value();
+    out = fdf.Format(decimal).value();
+    std::cout << "Value from string is " << out << std::endl;
+    if (out != "১০,০০,০০৭.০৭০") {
+        std::cout << "Output does not match expected output" << std::endl;
+        return 1;
+    }
+
+    if (FixedDecimal::Create("1.2.3").has_value()) {
+        std::cout << "Invalid string should not create a FixedDecimal" << std::endl;
+        return 1;
+    }
     return 0;
 }
@@ -24,5 +24,22 @@ int main() {
     if (cat != PluralCategory::Few) {
         return 1;
     }
+
+    cat = pr.Select(11);
+    std::cout << "Category is " << static_cast<int32_t>(cat)
+                                << " (should be " << static_cast<int32_t>(PluralCategory::Many) << ")"
+                                << std::endl;
+    if (cat != PluralCategory::Many) {
+        return 1;
+    }
+
+    FixedDecimal fd = FixedDecimal::Create("1.50").value();
+    cat = pr.Select(fd);
+    std::cout << "Category is " << static_cast<int32_t>(cat)
+                                << " (should be " << static_cast<int32_t>(PluralCategory::Other) << ")"
+                                << std::endl;
+    if (cat != PluralCategory::Other) {
+        return 1;
+    }
     return 0;
 }
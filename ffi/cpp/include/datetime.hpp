@@ -0,0 +1,78 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#ifndef ICU4X_DATETIME_HPP
+#define ICU4X_DATETIME_HPP
+
+#include <memory>
+#include <optional>
+#include <string_view>
+
+#include "../../capi/include/datetime.h"
+#include "locale.hpp"
+#include "provider.hpp"
+#include "writeable_utils.hpp"
+
+namespace icu4x {
+struct ICU4XDateTimeFormatDeleter {
+  void operator()(ICU4XDateTimeFormat* l) const noexcept {
+    icu4x_datetime_format_destroy(l);
+  }
+};
+enum class DateLength {
+  None = ICU4XDateLength_None,
+  Full = ICU4XDateLength_Full,
+  Long = ICU4XDateLength_Long,
+  Medium = ICU4XDateLength_Medium,
+  Short = ICU4XDateLength_Short,
+};
+
+enum class TimeLength {
+  None = ICU4XTimeLength_None,
+  Full = ICU4XTimeLength_Full,
+  Long = ICU4XTimeLength_Long,
+  Medium = ICU4XTimeLength_Medium,
+  Short = ICU4XTimeLength_Short,
+};
+
+struct DateTimeFormatOptions {
+  DateLength date_length;
+  TimeLength time_length;
+};
+
+class DateTimeFormat {
+ public:
+  static std::optional<DateTimeFormat> Create(const Locale& locale,
+                                              const DataProvider& provider,
+                                              DateTimeFormatOptions opts) {
+    ICU4XDataProvider dp = provider.AsFFI();
+    ICU4XDateTimeFormatOptions opts_ffi = {
+        static_cast<ICU4XDateLength>(opts.date_length),
+        static_cast<ICU4XTimeLength>(opts.time_length)};
+    ICU4XCreateDateTimeFormatResult result =
+        icu4x_datetime_format_create(locale.AsFFI(), &dp, opts_ffi);
+    if (!result.success) {
+      return {};
+    }
+    return DateTimeFormat(result.dtf);
+  }
+
+  std::optional<std::string> Format(const std::string_view& datetime) {
+    std::string out;
+    ICU4XWriteable writer = icu4x::internal::WriteableFromString(out);
+    ICU4XDateTimeFormatError error = icu4x_datetime_format_write(
+        this->inner.get(), datetime.data(), datetime.size(), &writer);
+    if (error != ICU4XDateTimeFormatError_Ok) {
+      return {};
+    }
+    return out;
+  }
+
+ private:
+  DateTimeFormat(ICU4XDateTimeFormat* i) : inner(i) {}
+  std::unique_ptr<ICU4XDateTimeFormat, ICU4XDateTimeFormatDeleter> inner;
+};
+}  // namespace icu4x
+
+#endif  // ICU4X_DATETIME_HPP
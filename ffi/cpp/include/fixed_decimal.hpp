@@ -7,6 +7,8 @@
 
 #include <algorithm>
 #include <memory>
+#include <optional>
+#include <string_view>
 
 #include "../../capi/include/fixed_decimal.h"
 
@@ -20,6 +22,14 @@ class FixedDecimal {
  public:
   FixedDecimal(int64_t number)
       : FixedDecimal(icu4x_fixed_decimal_create(number)) {}
+  static std::optional<FixedDecimal> Create(const std::string_view& value) {
+    ICU4XCreateFixedDecimalResult result =
+        icu4x_fixed_decimal_create_fromstr(value.data(), value.size());
+    if (!result.success) {
+      return {};
+    }
+    return FixedDecimal(result.fd);
+  }
   void MultiplyPow10(int16_t power) {
     icu4x_fixed_decimal_multiply_pow10(this->inner.get(), power);
   }
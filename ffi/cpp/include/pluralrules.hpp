@@ -9,6 +9,7 @@
 #include <memory>
 
 #include "../../capi/include/pluralrules.h"
+#include "fixed_decimal.hpp"
 #include "locale.hpp"
 #include "provider.hpp"
 
@@ -49,6 +50,16 @@ class PluralRules {
     ICU4XPluralCategory cat = icu4x_plural_rules_select(this->inner.get(), &op);
     return static_cast<PluralCategory>(cat);
   }
+  PluralCategory Select(int64_t value) const {
+    ICU4XPluralCategory cat =
+        icu4x_plural_rules_select_i64(this->inner.get(), value);
+    return static_cast<PluralCategory>(cat);
+  }
+  PluralCategory Select(const FixedDecimal& value) const {
+    ICU4XPluralCategory cat = icu4x_plural_rules_select_fixed_decimal(
+        this->inner.get(), value.AsFFI());
+    return static_cast<PluralCategory>(cat);
+  }
 
  private:
   PluralRules(ICU4XPluralRules* i) : inner(i) {}
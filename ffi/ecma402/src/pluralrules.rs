@@ -262,7 +262,7 @@ impl PluralRules {
     ) -> Result<Self, PluralRulesError>
     where
         L: ecma402_traits::Locale,
-        P: icu_provider::DataProvider<'d, 'd, ipr::provider::PluralRuleStringsV1Marker>,
+        P: icu_provider::DataProvider<'d, 'd, ipr::provider::PluralRuleListV1Marker>,
         Self: Sized,
     {
         let locale: String = format!("{}", l);
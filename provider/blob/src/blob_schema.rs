@@ -5,16 +5,19 @@
 use litemap::LiteMap;
 
 /// A versioned Serde schema for ICU4X data blobs.
+///
+/// `R` is the type of the serialized resources. It is only changed from `&[u8]` by exporters
+/// that do not hold all of the resources in memory; it must serialize the same way.
 #[derive(serde::Serialize, serde::Deserialize)]
-pub enum BlobSchema<'s> {
+pub enum BlobSchema<'s, R = &'s [u8]> {
     #[serde(borrow)]
-    V001(BlobSchemaV1<'s>),
+    V001(BlobSchemaV1<'s, R>),
 }
 
 /// Version 1 of the ICU4X data blob schema.
 #[derive(serde::Serialize, serde::Deserialize)]
-pub struct BlobSchemaV1<'s> {
+pub struct BlobSchemaV1<'s, R = &'s [u8]> {
     // TODO(#829): Use ZeroMap instead of LiteMap.
     #[serde(borrow)]
-    pub resources: LiteMap<&'s str, &'s [u8]>,
+    pub resources: LiteMap<&'s str, R>,
 }
@@ -8,19 +8,96 @@ use icu_provider::export::DataExporter;
 use icu_provider::prelude::*;
 use icu_provider::serde::SerdeSeDataStructMarker;
 use litemap::LiteMap;
+use std::cell::RefCell;
+use std::io::{Read, Seek, SeekFrom, Write};
 
 /// A data exporter that writes data to a single-file blob.
 /// See the module-level docs for an example.
 pub struct BlobExporter<'w> {
-    resources: LiteMap<String, Vec<u8>>,
+    resources: Resources<'w>,
     sink: Box<dyn std::io::Write + 'w>,
 }
 
+/// An I/O stream that can be written to and read back, such as a [`File`](std::fs::File) or a
+/// [`Cursor`](std::io::Cursor).
+pub trait Spool: Read + Write + Seek {}
+
+impl<T: Read + Write + Seek> Spool for T {}
+
+enum Resources<'w> {
+    /// Resources are kept in memory until [`DataExporter::close`].
+    Buffered(LiteMap<String, Vec<u8>>),
+    /// Resources are written to the spool as they are exported; only their offsets and lengths
+    /// are kept in memory.
+    Spooled {
+        spool: Box<dyn Spool + 'w>,
+        index: LiteMap<String, (u64, u64)>,
+    },
+}
+
 impl<'w> BlobExporter<'w> {
     /// Create a [`BlobExporter`] that writes to the given I/O stream.
     pub fn new_with_sink(sink: Box<dyn std::io::Write + 'w>) -> Self {
         Self {
-            resources: LiteMap::new(),
+            resources: Resources::Buffered(LiteMap::new()),
+            sink,
+        }
+    }
+
+    /// Create a [`BlobExporter`] that writes to the given I/O stream, using `spool` as
+    /// temporary storage for the exported resources.
+    ///
+    /// Each resource is serialized to `spool` as soon as it is exported. On
+    /// [`close`](DataExporter::close), the resources are copied from `spool` to `sink` one at a
+    /// time, so peak memory is proportional to the size of the index rather than the size of
+    /// the data. The blob written to `sink` is identical to the one written by
+    /// [`BlobExporter::new_with_sink`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider::export::DataExporter;
+    /// use icu_provider::hello_world::{key, HelloWorldProvider};
+    /// use icu_provider_blob::export::BlobExporter;
+    /// use std::io::{Cursor, Read};
+    ///
+    /// let mut buffer: Vec<u8> = Vec::new();
+    /// {
+    ///     let spool = Cursor::new(Vec::new());
+    ///     let mut exporter = BlobExporter::new_with_sink_and_spool(
+    ///         Box::new(&mut buffer),
+    ///         Box::new(spool),
+    ///     );
+    ///     let source_provider = HelloWorldProvider::new_with_placeholder_data();
+    ///     icu_provider::export::export_from_iterable(
+    ///         &key::HELLO_WORLD_V1,
+    ///         &source_provider,
+    ///         &mut exporter)
+    ///     .expect("Should successfully export");
+    ///     exporter.close().expect("Should successfully dump to buffer");
+    /// }
+    ///
+    /// // The blob is the same as the one produced without a spool
+    /// let mut expected_buffer: Vec<u8> = Vec::new();
+    /// std::fs::File::open(concat!(
+    ///     env!("CARGO_MANIFEST_DIR"),
+    ///     "/tests/data/hello_world.bincode"
+    /// ))
+    /// .expect("File should exist")
+    /// .read_to_end(&mut expected_buffer)
+    /// .expect("Reading pre-computed bincode buffer");
+    ///
+    /// assert_eq!(buffer, expected_buffer);
+    /// ```
+    pub fn new_with_sink_and_spool(
+        sink: Box<dyn std::io::Write + 'w>,
+        spool: Box<dyn Spool + 'w>,
+    ) -> Self {
+        Self {
+            resources: Resources::Spooled {
+                spool,
+                index: LiteMap::new(),
+            },
             sink,
         }
     }
@@ -28,12 +105,54 @@ impl<'w> BlobExporter<'w> {
 
 impl Drop for BlobExporter<'_> {
     fn drop(&mut self) {
-        if !self.resources.is_empty() {
+        let is_empty = match &self.resources {
+            Resources::Buffered(resources) => resources.is_empty(),
+            Resources::Spooled { index, .. } => index.is_empty(),
+        };
+        if !is_empty {
             panic!("Please call close before dropping FilesystemExporter");
         }
     }
 }
 
+/// A resource in the spool, which serializes the same way as its bytes.
+///
+/// The bytes are read back from the spool when the resource is serialized, so only one
+/// resource is held in memory at a time.
+struct SpooledResource<'a, 'w> {
+    spool: &'a RefCell<&'a mut (dyn Spool + 'w)>,
+    path: &'a str,
+    offset: u64,
+    len: u64,
+}
+
+impl serde::Serialize for SpooledResource<'_, '_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::Error;
+        let mut spool = self.spool.borrow_mut();
+        spool
+            .seek(SeekFrom::Start(self.offset))
+            .map_err(S::Error::custom)?;
+        let mut buffer = Vec::new();
+        (&mut **spool)
+            .take(self.len)
+            .read_to_end(&mut buffer)
+            .map_err(S::Error::custom)?;
+        if buffer.len() as u64 != self.len {
+            return Err(S::Error::custom(format!(
+                "Spooled resource {} has {} bytes, expected {}",
+                self.path,
+                buffer.len(),
+                self.len
+            )));
+        }
+        serializer.serialize_bytes(&buffer)
+    }
+}
+
 /// TODO(#837): De-duplicate this code from icu_provider_fs.
 fn serialize(
     obj: &dyn erased_serde::Serialize,
@@ -57,28 +176,147 @@ impl<'d, 's: 'd> DataExporter<'d, 's, SerdeSeDataStructMarker> for BlobExporter<
     ) -> Result<(), DataError> {
         let path = path_util::resource_path_to_string(&req.resource_path);
         log::trace!("Adding: {}", path);
-        let mut buffer: Vec<u8> = Vec::new();
-        serialize(obj.get().as_serialize(), &mut buffer)?;
-        self.resources.insert(path, buffer);
+        match &mut self.resources {
+            Resources::Buffered(resources) => {
+                let mut buffer: Vec<u8> = Vec::new();
+                serialize(obj.get().as_serialize(), &mut buffer)?;
+                resources.insert(path, buffer);
+            }
+            Resources::Spooled { spool, index } => {
+                let offset = spool
+                    .seek(SeekFrom::End(0))
+                    .map_err(DataError::new_resc_error)?;
+                serialize(obj.get().as_serialize(), spool.as_mut())?;
+                let end = spool
+                    .seek(SeekFrom::Current(0))
+                    .map_err(DataError::new_resc_error)?;
+                index.insert(path, (offset, end - offset));
+            }
+        }
         Ok(())
     }
 
     fn close(&mut self) -> Result<(), DataError> {
-        // Convert from LiteMap<String, Vec> to LiteMap<&str, &[]>
-        let mut schema = BlobSchemaV1 {
-            resources: LiteMap::with_capacity(self.resources.len()),
-        };
-        for (k, v) in self.resources.iter() {
-            schema
-                .resources
-                .try_append(k, v)
-                .ok_or(())
-                .expect_err("Same order");
+        match &mut self.resources {
+            Resources::Buffered(resources) => {
+                // Convert from LiteMap<String, Vec> to LiteMap<&str, &[]>
+                let mut schema = BlobSchemaV1 {
+                    resources: LiteMap::with_capacity(resources.len()),
+                };
+                for (k, v) in resources.iter() {
+                    schema
+                        .resources
+                        .try_append(k, v)
+                        .ok_or(())
+                        .expect_err("Same order");
+                }
+                let blob = BlobSchema::V001(schema);
+                log::info!("Serializing blob to output stream...");
+                serialize(&blob, self.sink.as_mut())?;
+                resources.clear();
+            }
+            Resources::Spooled { spool, index } => {
+                // The index is taken even if copying fails, so that dropping the exporter
+                // does not panic.
+                let index = std::mem::take(index);
+                let spool = RefCell::new(spool.as_mut());
+                let mut schema = BlobSchemaV1 {
+                    resources: LiteMap::with_capacity(index.len()),
+                };
+                for (path, &(offset, len)) in index.iter() {
+                    let resource = SpooledResource {
+                        spool: &spool,
+                        path,
+                        offset,
+                        len,
+                    };
+                    schema
+                        .resources
+                        .try_append(path.as_str(), resource)
+                        .map(|_| ())
+                        .ok_or(())
+                        .expect_err("Same order");
+                }
+                let blob = BlobSchema::V001(schema);
+                log::info!("Copying spooled blob to output stream...");
+                serialize(&blob, self.sink.as_mut())?;
+            }
         }
-        let blob = BlobSchema::V001(schema);
-        log::info!("Serializing blob to output stream...");
-        serialize(&blob, self.sink.as_mut())?;
-        self.resources.clear();
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use bincode::Options;
+    use icu_provider::hello_world::{key, HelloWorldProvider};
+    use std::io::Cursor;
+
+    fn export(exporter: &mut BlobExporter<'_>) -> Result<(), DataError> {
+        let source_provider = HelloWorldProvider::new_with_placeholder_data();
+        icu_provider::export::export_from_iterable(
+            &key::HELLO_WORLD_V1,
+            &source_provider,
+            exporter,
+        )?;
+        exporter.close()
+    }
+
+    fn deserialize(blob: &[u8]) -> BlobSchema<'_> {
+        bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize(blob)
+            .expect("Valid blob")
+    }
+
+    #[test]
+    fn test_spooled_round_trip() {
+        let mut buffered: Vec<u8> = Vec::new();
+        export(&mut BlobExporter::new_with_sink(Box::new(&mut buffered))).expect("Export");
+
+        let mut spooled: Vec<u8> = Vec::new();
+        export(&mut BlobExporter::new_with_sink_and_spool(
+            Box::new(&mut spooled),
+            Box::new(Cursor::new(Vec::new())),
+        ))
+        .expect("Export");
+
+        let BlobSchema::V001(buffered) = deserialize(&buffered);
+        let BlobSchema::V001(spooled) = deserialize(&spooled);
+        assert!(!spooled.resources.is_empty());
+        assert_eq!(buffered.resources, spooled.resources);
+    }
+
+    #[test]
+    fn test_spooled_truncated() {
+        /// A spool that loses everything written to it.
+        struct Truncated(Cursor<Vec<u8>>);
+        impl Read for Truncated {
+            fn read(&mut self, _: &mut [u8]) -> std::io::Result<usize> {
+                Ok(0)
+            }
+        }
+        impl Write for Truncated {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.write(buf)
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                self.0.flush()
+            }
+        }
+        impl Seek for Truncated {
+            fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+                self.0.seek(pos)
+            }
+        }
+
+        let mut spooled: Vec<u8> = Vec::new();
+        let result = export(&mut BlobExporter::new_with_sink_and_spool(
+            Box::new(&mut spooled),
+            Box::new(Truncated(Cursor::new(Vec::new()))),
+        ));
+        let message = result.expect_err("Truncated spool").to_string();
+        assert!(message.contains("has 0 bytes"), "{}", message);
+    }
+}
@@ -49,4 +49,4 @@
 
 mod blob_exporter;
 
-pub use blob_exporter::BlobExporter;
+pub use blob_exporter::{BlobExporter, Spool};
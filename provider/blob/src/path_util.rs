@@ -4,9 +4,33 @@
 
 use icu_provider::prelude::*;
 
+/// Returns the string under which the data for a [`ResourcePath`] is stored in a blob: its
+/// [`Display`](std::fmt::Display) form with a leading slash, such as `/plurals/cardinal@1/ar`.
 pub fn resource_path_to_string(resource_path: &ResourcePath) -> String {
-    let key_components = resource_path.key.get_components();
-    let opt_components = resource_path.options.get_components();
-    let all_components: Vec<&str> = key_components.iter().chain(opt_components.iter()).collect();
-    "/".to_string() + &all_components.join("/")
+    format!("/{}", resource_path)
+}
+
+#[test]
+fn test_resource_path_to_string() {
+    use icu_locid_macros::langid;
+    use std::borrow::Cow;
+
+    let mut resource_path = ResourcePath {
+        key: icu_provider::resource_key!(Plurals, "cardinal", 1),
+        options: ResourceOptions::default(),
+    };
+    assert_eq!(
+        "/plurals/cardinal@1",
+        resource_path_to_string(&resource_path)
+    );
+    resource_path.options.langid = Some(langid!("ar"));
+    assert_eq!(
+        "/plurals/cardinal@1/ar",
+        resource_path_to_string(&resource_path)
+    );
+    resource_path.options.variant = Some(Cow::Borrowed("GBP"));
+    assert_eq!(
+        "/plurals/cardinal@1/GBP/ar",
+        resource_path_to_string(&resource_path)
+    );
 }
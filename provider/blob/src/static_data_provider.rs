@@ -73,6 +73,64 @@ impl StaticDataProvider {
         })
     }
 
+    /// Returns an iterator over the resource paths of all data in the blob, such as
+    /// `/core/helloworld@1/la`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider_blob::StaticDataProvider;
+    ///
+    /// const HELLO_WORLD_BLOB: &[u8] = include_bytes!(concat!(
+    ///     env!("CARGO_MANIFEST_DIR"),
+    ///     "/tests/data/hello_world.bincode"
+    /// ));
+    ///
+    /// let provider = StaticDataProvider::new_from_static_blob(&HELLO_WORLD_BLOB)
+    ///     .expect("Deserialization should succeed");
+    ///
+    /// assert!(provider.iter_paths().any(|path| path == "/core/helloworld@1/la"));
+    /// ```
+    pub fn iter_paths(&self) -> impl Iterator<Item = &str> {
+        let BlobSchema::V001(blob) = &self.blob;
+        blob.resources.iter_keys().copied()
+    }
+
+    /// Returns whether the blob contains data for the given request, without deserializing it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider::prelude::*;
+    /// use icu_provider::hello_world::key;
+    /// use icu_provider_blob::StaticDataProvider;
+    /// use icu_locid_macros::langid;
+    ///
+    /// const HELLO_WORLD_BLOB: &[u8] = include_bytes!(concat!(
+    ///     env!("CARGO_MANIFEST_DIR"),
+    ///     "/tests/data/hello_world.bincode"
+    /// ));
+    ///
+    /// let provider = StaticDataProvider::new_from_static_blob(&HELLO_WORLD_BLOB)
+    ///     .expect("Deserialization should succeed");
+    ///
+    /// let mut req = DataRequest {
+    ///     resource_path: ResourcePath {
+    ///         key: key::HELLO_WORLD_V1,
+    ///         options: langid!("la").into(),
+    ///     }
+    /// };
+    /// assert!(provider.contains(&req));
+    ///
+    /// req.resource_path.options = langid!("tlh").into();
+    /// assert!(!provider.contains(&req));
+    /// ```
+    pub fn contains(&self, req: &DataRequest) -> bool {
+        let path = path_util::resource_path_to_string(&req.resource_path);
+        let BlobSchema::V001(blob) = &self.blob;
+        blob.resources.contains_key(&*path)
+    }
+
     fn get_file(&self, req: &DataRequest) -> Result<&'static [u8], DataError> {
         let path = path_util::resource_path_to_string(&req.resource_path);
         let BlobSchema::V001(blob) = &self.blob;
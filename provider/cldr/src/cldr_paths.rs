@@ -3,11 +3,20 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::error::{Error, MissingSourceError};
+use crate::reader;
 use std::default::Default;
-use std::path::PathBuf;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+#[cfg(feature = "tar")]
+use {litemap::LiteMap, std::io, std::path::Component};
 
-/// Trait returning filesystem paths to CLDR JSON resource directories.
+/// Trait returning paths to CLDR JSON resource directories, and reading the files below them.
 /// The fields should be [`Ok`] if present. They default to [`Err`] when not present.
+///
+/// By default, the paths are filesystem paths. Implementations that store the CLDR JSON
+/// elsewhere, such as `CldrPathsTar`, override [`CldrPaths::open_reader`],
+/// [`CldrPaths::get_subdirectories`], and [`CldrPaths::get_files`] to resolve the paths
+/// themselves.
 pub trait CldrPaths: std::fmt::Debug {
     /// Path to checkout of cldr-core:
     /// <https://github.com/unicode-cldr/cldr-core>
@@ -20,6 +29,27 @@ pub trait CldrPaths: std::fmt::Debug {
     /// Path to checkout of cldr-numbers:
     /// <https://github.com/unicode-cldr/cldr-numbers-full>
     fn cldr_numbers(&self) -> Result<PathBuf, Error>;
+
+    /// Path to checkout of cldr-rbnf:
+    /// <https://github.com/unicode-cldr/cldr-rbnf>
+    fn cldr_rbnf(&self) -> Result<PathBuf, Error>;
+
+    /// Opens the file at `path`, which is below one of the directories returned above.
+    fn open_reader(&self, path: &Path) -> Result<Box<dyn Read + '_>, Error> {
+        Ok(reader::open_reader(path)?)
+    }
+
+    /// Returns a sorted list of the subdirectories of `path`, which is below one of the
+    /// directories returned above.
+    fn get_subdirectories(&self, path: &Path) -> Result<Vec<PathBuf>, Error> {
+        reader::get_subdirectories(path)
+    }
+
+    /// Returns a sorted list of the files in `path`, which is below one of the directories
+    /// returned above.
+    fn get_files(&self, path: &Path) -> Result<Vec<PathBuf>, Error> {
+        reader::get_files(path)
+    }
 }
 
 /// An implementation of [`CldrPaths`] for multiple separate local CLDR JSON directories per
@@ -44,6 +74,7 @@ pub struct CldrPathsLocal {
     pub cldr_core: Result<PathBuf, MissingSourceError>,
     pub cldr_dates: Result<PathBuf, MissingSourceError>,
     pub cldr_numbers: Result<PathBuf, MissingSourceError>,
+    pub cldr_rbnf: Result<PathBuf, MissingSourceError>,
 }
 
 impl CldrPaths for CldrPathsLocal {
@@ -56,6 +87,9 @@ impl CldrPaths for CldrPathsLocal {
     fn cldr_numbers(&self) -> Result<PathBuf, Error> {
         self.cldr_numbers.clone().map_err(|e| e.into())
     }
+    fn cldr_rbnf(&self) -> Result<PathBuf, Error> {
+        self.cldr_rbnf.clone().map_err(|e| e.into())
+    }
 }
 
 impl Default for CldrPathsLocal {
@@ -66,6 +100,7 @@ impl Default for CldrPathsLocal {
             cldr_numbers: Err(MissingSourceError {
                 src: "cldr-numbers",
             }),
+            cldr_rbnf: Err(MissingSourceError { src: "cldr-rbnf" }),
         }
     }
 }
@@ -110,6 +145,130 @@ impl CldrPaths for CldrPathsAllInOne {
             .clone()
             .join(format!("cldr-numbers-{}", self.locale_subset)))
     }
+    fn cldr_rbnf(&self) -> Result<PathBuf, Error> {
+        Ok(self.cldr_json_root.clone().join("cldr-rbnf"))
+    }
+}
+
+/// An implementation of [`CldrPaths`] for one combined CLDR JSON directory stored in an in-memory
+/// tar archive. Requires the "tar" feature.
+///
+/// The archive is laid out like the directory of [`CldrPathsAllInOne`], with `cldr-core` and the
+/// other components at its root. Paths returned by this type are relative to that root.
+///
+/// # Examples
+///
+/// ```no_run
+/// use icu_provider_cldr::CldrPathsTar;
+/// use icu_provider_cldr::CldrJsonDataProvider;
+///
+/// let tarball = std::fs::read("/path/to/cldr-json.tar").unwrap();
+/// let paths = CldrPathsTar::from_reader(tarball.as_slice(), "full".to_string()).unwrap();
+///
+/// let data_provider = CldrJsonDataProvider::new(&paths);
+/// ```
+#[cfg(feature = "tar")]
+pub struct CldrPathsTar {
+    files: LiteMap<PathBuf, Vec<u8>>,
+    /// CLDR JSON directory suffix: probably either "modern" or "full"
+    pub locale_subset: String,
+}
+
+#[cfg(feature = "tar")]
+impl CldrPathsTar {
+    /// Reads all files of the tar archive in `reader` into memory.
+    pub fn from_reader<R: Read>(reader: R, locale_subset: String) -> Result<Self, Error> {
+        let mut files = LiteMap::new();
+        let mut archive = tar::Archive::new(reader);
+        for entry in archive.entries().map_err(|e| Error::Io(e, None))? {
+            let mut entry = entry.map_err(|e| Error::Io(e, None))?;
+            if !entry.header().entry_type().is_file() {
+                continue;
+            }
+            let path: PathBuf = entry
+                .path()
+                .map_err(|e| Error::Io(e, None))?
+                .components()
+                .filter(|c| *c != Component::CurDir)
+                .collect();
+            let mut contents = Vec::with_capacity(entry.size() as usize);
+            entry.read_to_end(&mut contents).map_err(|e| (e, &path))?;
+            files.insert(path, contents);
+        }
+        Ok(Self {
+            files,
+            locale_subset,
+        })
+    }
+}
+
+#[cfg(feature = "tar")]
+impl std::fmt::Debug for CldrPathsTar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CldrPathsTar")
+            .field("files", &self.files.len())
+            .field("locale_subset", &self.locale_subset)
+            .finish()
+    }
+}
+
+#[cfg(feature = "tar")]
+impl CldrPaths for CldrPathsTar {
+    fn cldr_core(&self) -> Result<PathBuf, Error> {
+        Ok(PathBuf::from("cldr-core"))
+    }
+    fn cldr_dates(&self) -> Result<PathBuf, Error> {
+        Ok(PathBuf::from(format!("cldr-dates-{}", self.locale_subset)))
+    }
+    fn cldr_numbers(&self) -> Result<PathBuf, Error> {
+        Ok(PathBuf::from(format!(
+            "cldr-numbers-{}",
+            self.locale_subset
+        )))
+    }
+    fn cldr_rbnf(&self) -> Result<PathBuf, Error> {
+        Ok(PathBuf::from("cldr-rbnf"))
+    }
+    fn open_reader(&self, path: &Path) -> Result<Box<dyn Read + '_>, Error> {
+        match self.files.get(path) {
+            Some(contents) => Ok(Box::new(contents.as_slice())),
+            None => Err((io::Error::from(io::ErrorKind::NotFound), path).into()),
+        }
+    }
+    fn get_subdirectories(&self, path: &Path) -> Result<Vec<PathBuf>, Error> {
+        self.get_children(path, true)
+    }
+    fn get_files(&self, path: &Path) -> Result<Vec<PathBuf>, Error> {
+        self.get_children(path, false)
+    }
+}
+
+#[cfg(feature = "tar")]
+impl CldrPathsTar {
+    /// Returns the children of `path` that are directories if `directories` is true, or else
+    /// the children that are files.
+    fn get_children(&self, path: &Path, directories: bool) -> Result<Vec<PathBuf>, Error> {
+        let mut found = false;
+        let mut result = vec![];
+        for file in self.files.iter_keys() {
+            let mut components = match file.strip_prefix(path) {
+                Ok(relative) => relative.components(),
+                Err(_) => continue,
+            };
+            found = true;
+            // A child is a directory if there is anything below it.
+            if let Some(child) = components.next() {
+                if components.next().is_some() == directories {
+                    result.push(path.join(child));
+                }
+            }
+        }
+        if !found {
+            return Err((io::Error::from(io::ErrorKind::NotFound), path).into());
+        }
+        result.dedup();
+        Ok(result)
+    }
 }
 
 #[cfg(test)]
@@ -119,3 +278,56 @@ pub(crate) fn for_test() -> CldrPathsAllInOne {
         locale_subset: "full".to_string(),
     }
 }
+
+#[cfg(all(test, feature = "tar"))]
+mod tests {
+    use super::*;
+    use crate::transform::{
+        DateSymbolsProvider, NumbersProvider, OrdinalPatternsProvider, PluralsProvider,
+    };
+    use std::convert::TryFrom;
+
+    #[test]
+    fn test_tar_matches_directory() {
+        let dir_paths = for_test();
+        let mut builder = tar::Builder::new(Vec::new());
+        builder
+            .append_dir_all(".", &dir_paths.cldr_json_root)
+            .unwrap();
+        let tarball = builder.into_inner().unwrap();
+        let tar_paths = CldrPathsTar::from_reader(tarball.as_slice(), "full".to_string()).unwrap();
+
+        let main = tar_paths.cldr_dates().unwrap().join("main");
+        let dir_main = dir_paths.cldr_dates().unwrap().join("main");
+        let expected: Vec<PathBuf> = dir_paths
+            .get_subdirectories(&dir_main)
+            .unwrap()
+            .iter()
+            .map(|dir| main.join(dir.strip_prefix(&dir_main).unwrap()))
+            .collect();
+        assert_eq!(tar_paths.get_subdirectories(&main).unwrap(), expected);
+
+        let missing = main.join("missing.json");
+        assert!(matches!(
+            tar_paths.open_reader(&missing),
+            Err(Error::Io(_, Some(p))) if p == missing
+        ));
+
+        assert_eq!(
+            PluralsProvider::try_from(&tar_paths as &dyn CldrPaths).unwrap(),
+            PluralsProvider::try_from(&dir_paths as &dyn CldrPaths).unwrap()
+        );
+        assert_eq!(
+            DateSymbolsProvider::try_from(&tar_paths as &dyn CldrPaths).unwrap(),
+            DateSymbolsProvider::try_from(&dir_paths as &dyn CldrPaths).unwrap()
+        );
+        assert_eq!(
+            NumbersProvider::try_from(&tar_paths as &dyn CldrPaths).unwrap(),
+            NumbersProvider::try_from(&dir_paths as &dyn CldrPaths).unwrap()
+        );
+        assert_eq!(
+            OrdinalPatternsProvider::try_from(&tar_paths as &dyn CldrPaths).unwrap(),
+            OrdinalPatternsProvider::try_from(&dir_paths as &dyn CldrPaths).unwrap()
+        );
+    }
+}
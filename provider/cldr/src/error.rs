@@ -23,11 +23,20 @@ pub enum Error {
     #[cfg(feature = "download")]
     #[displaydoc("{0}")]
     Download(download::Error),
-    #[displaydoc("poisoned lock on CLDR provider")]
-    Poison,
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err, _) => Some(err),
+            Self::Json(err, _) => Some(err),
+            Self::MissingSource(err) => Some(err),
+            #[cfg(feature = "download")]
+            Self::Download(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 #[cfg(feature = "download")]
 impl From<download::Error> for Error {
@@ -57,9 +66,16 @@ impl<P: AsRef<Path>> From<(std::io::Error, P)> for Error {
 
 /// To help with debugging, JSON errors should be paired with a file path.
 /// If a path is unavailable, create the error directly: [`Error::Json`]`(err, `[`None`]`)`
+///
+/// JSON errors caused by I/O failures, such as corrupt gzip data, become [`Error::Io`].
 impl<P: AsRef<Path>> From<(serde_json::error::Error, P)> for Error {
     fn from(pieces: (serde_json::error::Error, P)) -> Self {
-        Self::Json(pieces.0, Some(pieces.1.as_ref().to_path_buf()))
+        let path = Some(pieces.1.as_ref().to_path_buf());
+        if pieces.0.is_io() {
+            Self::Io(pieces.0.into(), path)
+        } else {
+            Self::Json(pieces.0, path)
+        }
     }
 }
 
@@ -6,11 +6,13 @@
 //! based on the JSON files shipped by CLDR. Create a [`CldrPaths`] and then pass it into
 //! [`CldrJsonDataProvider`].
 //!
-//! This crate contains two implementations of [`CldrPaths`]:
+//! This crate contains several implementations of [`CldrPaths`]:
 //!
 //! - [`CldrPathsLocal`] points to local copies of the CLDR JSON repositories.
 //! - `CldrPathsDownload` downloads and caches the CLDR JSON repositories. Requires the
 //!   "download" feature.
+//! - `CldrPathsTar` reads the CLDR JSON from an in-memory tar archive. Requires the "tar"
+//!   feature.
 //!
 //! **Important:** This data provider implementation is not optimized for production use.
 //! It is much more efficient if you use [`FsDataProvider`] instead.
@@ -34,6 +36,9 @@ pub mod download;
 pub use cldr_paths::CldrPaths;
 pub use cldr_paths::CldrPathsAllInOne;
 pub use cldr_paths::CldrPathsLocal;
+#[cfg(feature = "tar")]
+pub use cldr_paths::CldrPathsTar;
 pub use error::Error as CldrError;
 pub use transform::get_all_cldr_keys;
+pub use transform::get_cldr_keys_for_category;
 pub use transform::CldrJsonDataProvider;
@@ -5,15 +5,40 @@
 use crate::error::Error;
 use std::fs;
 use std::fs::File;
-use std::io::BufReader;
+use std::io::{BufReader, Read};
 use std::path::{Path, PathBuf};
 
 /// Helper function to open a file and return failures as a crate error.
-pub fn open_reader(path: &Path) -> Result<BufReader<File>, Error> {
+///
+/// With the `gzip` feature, files with a `.gz` extension are decompressed, and if `path` does
+/// not exist but `path` with an added `.gz` extension does, that file is read instead.
+pub fn open_reader(path: &Path) -> Result<Box<dyn Read>, Error> {
+    #[cfg(feature = "gzip")]
+    {
+        let is_gzip = path.extension().map_or(false, |ext| ext == "gz");
+        if is_gzip {
+            return open_gzip_reader(path);
+        }
+        let mut gzip_path = path.as_os_str().to_owned();
+        gzip_path.push(".gz");
+        let gzip_path = PathBuf::from(gzip_path);
+        if !path.exists() && gzip_path.exists() {
+            return open_gzip_reader(&gzip_path);
+        }
+    }
     #[cfg(feature = "log")]
     log::trace!("Reading: {:?}", path);
     File::open(&path)
-        .map(BufReader::new)
+        .map(|file| Box::new(BufReader::new(file)) as Box<dyn Read>)
+        .map_err(|e| (e, path).into())
+}
+
+#[cfg(feature = "gzip")]
+fn open_gzip_reader(path: &Path) -> Result<Box<dyn Read>, Error> {
+    #[cfg(feature = "log")]
+    log::trace!("Reading gzip: {:?}", path);
+    File::open(&path)
+        .map(|file| Box::new(flate2::read::GzDecoder::new(BufReader::new(file))) as Box<dyn Read>)
         .map_err(|e| (e, path).into())
 }
 
@@ -28,3 +53,56 @@ pub fn get_subdirectories(root: &Path) -> Result<Vec<PathBuf>, Error> {
     result.sort();
     Ok(result)
 }
+
+/// Helper function which returns a sorted list of the files in a directory.
+pub fn get_files(root: &Path) -> Result<Vec<PathBuf>, Error> {
+    let mut result = vec![];
+    for entry in fs::read_dir(root).map_err(|e| (e, root))? {
+        let entry = entry.map_err(|e| (e, root))?;
+        let path = entry.path();
+        if path.is_file() {
+            result.push(path);
+        }
+    }
+    result.sort();
+    Ok(result)
+}
+
+#[cfg(all(test, feature = "gzip"))]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn test_open_gzip_reader() {
+        let temp_dir = mktemp::Temp::new_dir().unwrap();
+        let dir = temp_dir.to_path_buf();
+        let json_path = dir.join("plain.json");
+        fs::write(&json_path, b"{}").unwrap();
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(b"[1, 2]").unwrap();
+        fs::write(dir.join("zipped.json.gz"), encoder.finish().unwrap()).unwrap();
+        fs::write(dir.join("corrupt.json.gz"), b"not gzip").unwrap();
+
+        let value: serde_json::Value = serde_json::from_reader(open_reader(&json_path).unwrap())
+            .map_err(|e| Error::from((e, &json_path)))
+            .unwrap();
+        assert_eq!(value, serde_json::json!({}));
+
+        // Both the compressed path and the uncompressed path find the gzipped file.
+        for name in &["zipped.json.gz", "zipped.json"] {
+            let path = dir.join(name);
+            let value: serde_json::Value = serde_json::from_reader(open_reader(&path).unwrap())
+                .map_err(|e| Error::from((e, &path)))
+                .unwrap();
+            assert_eq!(value, serde_json::json!([1, 2]));
+        }
+
+        let path = dir.join("corrupt.json");
+        let result: Result<serde_json::Value, Error> =
+            serde_json::from_reader(open_reader(&path).unwrap())
+                .map_err(|e| Error::from((e, &path)));
+        assert!(matches!(result, Err(Error::Io(_, Some(p))) if p == path));
+    }
+}
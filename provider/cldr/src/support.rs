@@ -2,12 +2,13 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use crate::error::Error;
 use crate::CldrPaths;
 use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
 use icu_provider::prelude::*;
 use icu_provider::serde::SerdeSeDataStructMarker;
+use once_cell::sync::OnceCell;
 use std::convert::TryFrom;
-use std::sync::RwLock;
 
 pub trait ResourceKeySupport {
     fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError>;
@@ -15,22 +16,17 @@ pub trait ResourceKeySupport {
 
 #[derive(Debug)]
 pub struct LazyCldrProvider<T> {
-    src: RwLock<Option<T>>,
+    src: OnceCell<T>,
 }
 
 impl<T> Default for LazyCldrProvider<T> {
     fn default() -> Self {
         Self {
-            src: RwLock::new(None),
+            src: OnceCell::new(),
         }
     }
 }
 
-fn map_poison<E>(_err: E) -> DataError {
-    // Can't return the Poison directly because it has lifetime parameters.
-    DataError::new_resc_error(crate::error::Error::Poison)
-}
-
 /// A lazy-initialized CLDR JSON data provider.
 impl<'b, 'd, 's: 'd, T> LazyCldrProvider<T>
 where
@@ -49,17 +45,11 @@ where
         if T::supports_key(&req.resource_path.key).is_err() {
             return Ok(None);
         }
-        if let Some(data_provider) = self.src.read().map_err(map_poison)?.as_ref() {
-            return DataProvider::load_payload(data_provider, req).map(Some);
-        }
-        let mut src = self.src.write().map_err(map_poison)?;
-        if src.is_none() {
-            src.replace(T::try_from(cldr_paths).map_err(DataError::new_resc_error)?);
-        }
-        let data_provider = src
-            .as_ref()
-            .expect("The RwLock must be populated at this point.");
-        return DataProvider::load_payload(data_provider, req).map(Some);
+        let data_provider = self
+            .src
+            .get_or_try_init(|| T::try_from(cldr_paths))
+            .map_err(DataError::new_resc_error)?;
+        DataProvider::load_payload(data_provider, req).map(Some)
     }
 
     /// Call [`IterableDataProviderCore::supported_options_for_key()`], initializing `T` if necessary.
@@ -71,22 +61,31 @@ where
         if T::supports_key(resc_key).is_err() {
             return Ok(None);
         }
-        if let Some(data_provider) = self.src.read().map_err(map_poison)?.as_ref() {
-            return data_provider
-                .supported_options_for_key(resc_key)
-                .map(|i| i.collect())
-                .map(Some);
-        }
-        let mut src = self.src.write().map_err(map_poison)?;
-        if src.is_none() {
-            src.replace(T::try_from(cldr_paths).map_err(DataError::new_resc_error)?);
-        }
-        let data_provider = src
-            .as_ref()
-            .expect("The RwLock must be populated at this point.");
+        let data_provider = self
+            .src
+            .get_or_try_init(|| T::try_from(cldr_paths))
+            .map_err(DataError::new_resc_error)?;
         data_provider
             .supported_options_for_key(resc_key)
             .map(|i| i.collect())
             .map(Some)
     }
 }
+
+impl<'b, T> LazyCldrProvider<T>
+where
+    T: TryFrom<&'b dyn CldrPaths, Error = Error>,
+{
+    /// Initialize `T` if necessary, returning whether its CLDR source files are available.
+    ///
+    /// Missing sources result in [`Ok`]`(false)`; other errors, such as malformed CLDR JSON,
+    /// are returned. Failed initializations are not cached, so a later call tries again.
+    pub fn try_init(&self, cldr_paths: &'b dyn CldrPaths) -> Result<bool, DataError> {
+        match self.src.get_or_try_init(|| T::try_from(cldr_paths)) {
+            Ok(_) => Ok(true),
+            Err(Error::MissingSource(_)) => Ok(false),
+            Err(Error::Io(err, _)) if err.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(DataError::new_resc_error(err)),
+        }
+    }
+}
@@ -0,0 +1,170 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::{cldr_json, CalendarSource};
+use crate::cldr_langid::CldrLangID;
+use crate::error::Error;
+use crate::CldrPaths;
+use icu_datetime::provider::{eras::*, key};
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use litemap::LiteMap;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 1] = [
+    key::ERAS_V1, //
+];
+
+/// A data provider reading the eras from CLDR JSON dates files.
+#[derive(PartialEq, Debug)]
+pub struct ErasProvider<'d> {
+    /// The eras, sorted by calendar variant and then by locale.
+    data: Vec<(&'static str, CldrLangID, ErasV1)>,
+    _phantom: PhantomData<&'d ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for ErasProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let data = super::read_calendars(cldr_paths)?
+            .into_iter()
+            .map(|(calendar, langid, dates)| {
+                (calendar.variant, langid, convert(calendar, &dates.eras))
+            })
+            .collect();
+
+        Ok(Self {
+            data,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+/// Converts the eras of `calendar` from CLDR indices to era codes, dropping alternate names.
+fn convert(calendar: &CalendarSource, other: &cldr_json::Eras) -> ErasV1 {
+    let names = |list: &[(String, String)]| -> LiteMap<Cow<'static, str>, Cow<'static, str>> {
+        list.iter()
+            .filter_map(|(index, name)| {
+                calendar
+                    .era_codes
+                    .iter()
+                    .find(|(era_index, _)| era_index == index)
+                    .map(|(_, code)| (Cow::Borrowed(*code), Cow::Owned(name.clone())))
+            })
+            .collect()
+    };
+    ErasV1 {
+        names: names(&other.names),
+        abbr: names(&other.abbr),
+        narrow: names(&other.narrow),
+    }
+}
+
+impl<'d> KeyedDataProvider for ErasProvider<'d> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        key::ERAS_V1.match_key(*resc_key)
+    }
+}
+
+impl<'d, 's> DataProvider<'d, 's, ErasV1Marker> for ErasProvider<'d> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'d, 's, ErasV1Marker>, DataError> {
+        ErasProvider::supports_key(&req.resource_path.key)?;
+        let variant = match req.resource_path.options.variant.as_deref() {
+            Some(variant) => variant,
+            None => return Err(DataError::UnavailableResourceOptions(req.clone())),
+        };
+        let cldr_langid: CldrLangID = req.try_langid()?.clone().into();
+        let eras = match self
+            .data
+            .binary_search_by(|(v, lid, _)| (*v, lid).cmp(&(variant, &cldr_langid)))
+        {
+            Ok(idx) => &self.data[idx].2,
+            Err(_) => return Err(DataError::UnavailableResourceOptions(req.clone())),
+        };
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(eras.clone())),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(ErasProvider<'d>, {
+    _ => ErasV1Marker,
+}, SERDE_SE, 'd, 's);
+
+impl<'d> IterableDataProviderCore for ErasProvider<'d> {
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = self
+            .data
+            .iter()
+            .map(|(variant, l, _)| ResourceOptions {
+                variant: Some(Cow::Borrowed(*variant)),
+                // TODO: Avoid the clone
+                langid: Some(l.langid.clone()),
+            })
+            .collect();
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::LanguageIdentifier;
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = ErasProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let load = |variant: &'static str, langid: LanguageIdentifier| {
+        DataProvider::<ErasV1Marker>::load_payload(
+            &provider,
+            &DataRequest {
+                resource_path: ResourcePath {
+                    key: key::ERAS_V1,
+                    options: ResourceOptions {
+                        variant: Some(variant.into()),
+                        langid: Some(langid),
+                    },
+                },
+            },
+        )
+        .and_then(DataResponse::take_payload)
+    };
+
+    let cs_eras = load("gregory", langid!("cs")).unwrap();
+    assert_eq!(
+        Some(&Cow::Borrowed("našeho letopočtu")),
+        cs_eras.get().names.get("ce")
+    );
+    assert_eq!(
+        Some(&Cow::Borrowed("př. n. l.")),
+        cs_eras.get().abbr.get("bce")
+    );
+    assert_eq!(Some(&Cow::Borrowed("n.l.")), cs_eras.get().narrow.get("ce"));
+
+    let th_eras = load("buddhist", langid!("th")).unwrap();
+    assert_eq!(Some(&Cow::Borrowed("พ.ศ.")), th_eras.get().abbr.get("be"));
+
+    // Only the modern eras of the Japanese calendar are produced.
+    let en_eras = load("japanese", langid!("en")).unwrap();
+    assert_eq!(
+        Some(&Cow::Borrowed("Reiwa")),
+        en_eras.get().names.get("reiwa")
+    );
+    assert_eq!(5, en_eras.get().names.len());
+
+    // The test data has no Buddhist calendar for Czech.
+    assert!(load("buddhist", langid!("cs")).is_err());
+}
@@ -2,9 +2,14 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+pub mod eras;
 pub mod patterns;
 pub mod symbols;
 
+use crate::cldr_langid::CldrLangID;
+use crate::error::Error;
+use crate::CldrPaths;
+
 /// Serde structs for the CLDR JSON dates files.
 pub(self) mod cldr_json {
     use crate::cldr_langid::CldrLangID;
@@ -140,6 +145,8 @@ pub(self) mod cldr_json {
         pub short: LengthPattern,
         #[serde(rename = "availableFormats")]
         pub available_formats: AvailableFormats,
+        #[serde(rename = "intervalFormats")]
+        pub interval_formats: IntervalFormats,
     }
 
     #[derive(PartialEq, Clone, Debug, Deserialize)]
@@ -147,6 +154,21 @@ pub(self) mod cldr_json {
         #[serde(with = "tuple_vec_map")] pub(crate) Vec<(Cow<'static, str>, Cow<'static, str>)>,
     );
 
+    /// The interval patterns of a skeleton, keyed by the symbol of the greatest difference.
+    #[derive(PartialEq, Clone, Debug, Deserialize)]
+    pub struct IntervalFormat(
+        #[serde(with = "tuple_vec_map")] pub(crate) Vec<(Cow<'static, str>, Cow<'static, str>)>,
+    );
+
+    #[derive(PartialEq, Clone, Debug, Deserialize)]
+    pub struct IntervalFormats {
+        #[serde(rename = "intervalFormatFallback")]
+        pub fallback: String,
+        /// The interval patterns, keyed by skeleton.
+        #[serde(flatten, with = "tuple_vec_map")]
+        pub formats: Vec<(Cow<'static, str>, IntervalFormat)>,
+    }
+
     /// This struct represents a 1:1 mapping of the CLDR ca-gregorian.json data at the key
     /// "main.LANGID.dates.calendars.gregorian" where "LANGID" is the identifier.
     ///
@@ -188,4 +210,143 @@ pub(self) mod cldr_json {
     pub struct Resource {
         pub main: LangData,
     }
+
+    /// The names of the eras of a calendar, keyed by the CLDR index of the era, such as "1".
+    /// Alternate names are keyed by the index followed by an "-alt-" suffix.
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Eras {
+        #[serde(rename = "eraNames", with = "tuple_vec_map")]
+        pub names: Vec<(String, String)>,
+        #[serde(rename = "eraAbbr", with = "tuple_vec_map")]
+        pub abbr: Vec<(String, String)>,
+        #[serde(rename = "eraNarrow", with = "tuple_vec_map")]
+        pub narrow: Vec<(String, String)>,
+    }
+
+    /// The parts of a calendar that are read for every supported calendar, as opposed to
+    /// [`GregoryDates`], which is only read for the Gregorian calendar.
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct CalendarDates {
+        pub eras: Eras,
+        #[serde(rename = "dateFormats")]
+        pub date_formats: LengthPatterns,
+    }
+
+    /// This struct represents the calendar in one of the CLDR ca-*.json files, such as
+    /// ca-gregorian.json or ca-buddhist.json, each of which contains a single calendar.
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct AnyCalendars {
+        pub gregorian: Option<CalendarDates>,
+        pub buddhist: Option<CalendarDates>,
+        pub japanese: Option<CalendarDates>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct AnyDates {
+        pub calendars: AnyCalendars,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct AnyLangDates {
+        pub dates: AnyDates,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct AnyLangData(
+        #[serde(with = "tuple_vec_map")] pub(crate) Vec<(CldrLangID, AnyLangDates)>,
+    );
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct AnyResource {
+        pub main: AnyLangData,
+    }
+}
+
+/// A calendar that is read from a CLDR JSON dates file.
+struct CalendarSource {
+    /// The BCP-47 identifier of the calendar, which is the variant of its data.
+    variant: &'static str,
+    /// The CLDR JSON file containing the calendar.
+    file_name: &'static str,
+    /// The era codes of the calendar, keyed by the CLDR index of the era. Eras that are not
+    /// listed here, such as the pre-Meiji eras of the Japanese calendar, are not produced.
+    era_codes: &'static [(&'static str, &'static str)],
+}
+
+/// The supported calendars. The Gregorian calendar is required; the files of the other
+/// calendars are read if they are present.
+const CALENDARS: [CalendarSource; 3] = [
+    CalendarSource {
+        variant: "gregory",
+        file_name: "ca-gregorian.json",
+        era_codes: &[("0", "bce"), ("1", "ce")],
+    },
+    CalendarSource {
+        variant: "buddhist",
+        file_name: "ca-buddhist.json",
+        era_codes: &[("0", "be")],
+    },
+    CalendarSource {
+        variant: "japanese",
+        file_name: "ca-japanese.json",
+        era_codes: &[
+            ("232", "meiji"),
+            ("233", "taisho"),
+            ("234", "showa"),
+            ("235", "heisei"),
+            ("236", "reiwa"),
+        ],
+    },
+];
+
+/// Reads the eras and date formats of every supported calendar, sorted by calendar variant and
+/// then by locale.
+fn read_calendars(
+    cldr_paths: &dyn CldrPaths,
+) -> Result<
+    Vec<(
+        &'static CalendarSource,
+        CldrLangID,
+        cldr_json::CalendarDates,
+    )>,
+    Error,
+> {
+    let mut data = vec![];
+
+    let path = cldr_paths.cldr_dates()?.join("main");
+
+    let locale_dirs = cldr_paths.get_subdirectories(&path)?;
+
+    for dir in locale_dirs {
+        for calendar in CALENDARS.iter() {
+            let path = dir.join(calendar.file_name);
+
+            let reader = match cldr_paths.open_reader(&path) {
+                Ok(reader) => reader,
+                Err(Error::Io(err, _))
+                    if calendar.variant != "gregory"
+                        && err.kind() == std::io::ErrorKind::NotFound =>
+                {
+                    continue
+                }
+                Err(err) => return Err(err),
+            };
+            let resource: cldr_json::AnyResource =
+                serde_json::from_reader(reader).map_err(|e| (e, path))?;
+            for (langid, lang_dates) in resource.main.0 {
+                let calendars = lang_dates.dates.calendars;
+                let dates = match calendar.variant {
+                    "gregory" => calendars.gregorian,
+                    "buddhist" => calendars.buddhist,
+                    _ => calendars.japanese,
+                };
+                if let Some(dates) = dates {
+                    data.push((calendar, langid, dates));
+                }
+            }
+        }
+    }
+    data.sort_by(|(c1, l1, _), (c2, l2, _)| (c1.variant, l1).cmp(&(c2.variant, l2)));
+
+    Ok(data)
 }
@@ -5,7 +5,6 @@
 use super::cldr_json;
 use crate::cldr_langid::CldrLangID;
 use crate::error::Error;
-use crate::reader::{get_subdirectories, open_reader};
 use crate::CldrPaths;
 use icu_datetime::{provider::*, skeleton::SkeletonError};
 use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
@@ -15,14 +14,18 @@ use std::convert::TryFrom;
 use std::marker::PhantomData;
 
 /// All keys that this module is able to produce.
-pub const ALL_KEYS: [ResourceKey; 1] = [
-    key::GREGORY_DATE_PATTERNS_V1, //
+pub const ALL_KEYS: [ResourceKey; 2] = [
+    key::GREGORY_DATE_PATTERNS_V1,     //
+    key::GREGORY_INTERVAL_PATTERNS_V1, //
 ];
 
 /// A data provider reading from CLDR JSON dates files.
 #[derive(PartialEq, Debug)]
 pub struct DatePatternsProvider<'d> {
     data: Vec<(CldrLangID, cldr_json::LangDates)>,
+    /// The date formats of the calendars other than the Gregorian one, sorted by calendar
+    /// variant and then by locale.
+    calendars: Vec<(&'static str, CldrLangID, cldr_json::LengthPatterns)>,
     _phantom: PhantomData<&'d ()>, // placeholder for when we need the lifetime param
 }
 
@@ -33,18 +36,25 @@ impl TryFrom<&dyn CldrPaths> for DatePatternsProvider<'_> {
 
         let path = cldr_paths.cldr_dates()?.join("main");
 
-        let locale_dirs = get_subdirectories(&path)?;
+        let locale_dirs = cldr_paths.get_subdirectories(&path)?;
 
         for dir in locale_dirs {
             let path = dir.join("ca-gregorian.json");
 
             let mut resource: cldr_json::Resource =
-                serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+                serde_json::from_reader(cldr_paths.open_reader(&path)?).map_err(|e| (e, path))?;
             data.append(&mut resource.main.0);
         }
 
+        let calendars = super::read_calendars(cldr_paths)?
+            .into_iter()
+            .filter(|(calendar, _, _)| calendar.variant != "gregory")
+            .map(|(calendar, langid, dates)| (calendar.variant, langid, dates.date_formats))
+            .collect();
+
         Ok(Self {
             data,
+            calendars,
             _phantom: PhantomData,
         })
     }
@@ -52,7 +62,37 @@ impl TryFrom<&dyn CldrPaths> for DatePatternsProvider<'_> {
 
 impl<'d> KeyedDataProvider for DatePatternsProvider<'d> {
     fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
-        key::GREGORY_DATE_PATTERNS_V1.match_key(*resc_key)
+        key::GREGORY_DATE_PATTERNS_V1
+            .match_key(*resc_key)
+            .or_else(|_| key::GREGORY_INTERVAL_PATTERNS_V1.match_key(*resc_key))
+    }
+}
+
+impl<'d> DatePatternsProvider<'d> {
+    fn get_dates_for(&self, req: &DataRequest) -> Result<&cldr_json::Dates, DataError> {
+        let cldr_langid: CldrLangID = req.try_langid()?.clone().into();
+        match self
+            .data
+            .binary_search_by_key(&&cldr_langid, |(lid, _)| lid)
+        {
+            Ok(idx) => Ok(&self.data[idx].1.dates),
+            Err(_) => Err(DataError::UnavailableResourceOptions(req.clone())),
+        }
+    }
+
+    fn get_date_formats_for(
+        &self,
+        variant: &str,
+        req: &DataRequest,
+    ) -> Result<&cldr_json::LengthPatterns, DataError> {
+        let cldr_langid: CldrLangID = req.try_langid()?.clone().into();
+        match self
+            .calendars
+            .binary_search_by(|(v, lid, _)| (*v, lid).cmp(&(variant, &cldr_langid)))
+        {
+            Ok(idx) => Ok(&self.calendars[idx].2),
+            Err(_) => Err(DataError::UnavailableResourceOptions(req.clone())),
+        }
     }
 }
 
@@ -61,36 +101,59 @@ impl<'d, 's> DataProvider<'d, 's, gregory::DatePatternsV1Marker> for DatePattern
         &self,
         req: &DataRequest,
     ) -> Result<DataResponse<'d, 's, gregory::DatePatternsV1Marker>, DataError> {
-        DatePatternsProvider::supports_key(&req.resource_path.key)?;
-        let cldr_langid: CldrLangID = req.try_langid()?.clone().into();
-        let dates = match self
-            .data
-            .binary_search_by_key(&&cldr_langid, |(lid, _)| lid)
-        {
-            Ok(idx) => &self.data[idx].1.dates,
-            Err(_) => return Err(DataError::UnavailableResourceOptions(req.clone())),
-        };
+        key::GREGORY_DATE_PATTERNS_V1.match_key(req.resource_path.key)?;
+        let dates = self.get_dates_for(req)?;
+        let mut patterns = gregory::DatePatternsV1::from(dates);
+        if let Some(variant) = req.resource_path.options.variant.as_deref() {
+            // TODO(#493): Only the date patterns differ between calendars for now; the time and
+            // date-time patterns are those of the Gregorian calendar.
+            patterns.date = self.get_date_formats_for(variant, req)?.into();
+        }
         Ok(DataResponse {
             metadata: DataResponseMetadata {
                 data_langid: req.resource_path.options.langid.clone(),
             },
-            payload: Some(DataPayload::from_owned(gregory::DatePatternsV1::from(
-                dates,
-            ))),
+            payload: Some(DataPayload::from_owned(patterns)),
+        })
+    }
+}
+
+impl<'d, 's> DataProvider<'d, 's, gregory::DateIntervalPatternsV1Marker>
+    for DatePatternsProvider<'d>
+{
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'d, 's, gregory::DateIntervalPatternsV1Marker>, DataError> {
+        key::GREGORY_INTERVAL_PATTERNS_V1.match_key(req.resource_path.key)?;
+        if req.resource_path.options.variant.is_some() {
+            return Err(DataError::UnavailableResourceOptions(req.clone()));
+        }
+        let dates = self.get_dates_for(req)?;
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(
+                gregory::DateIntervalPatternsV1::from(
+                    &dates.calendars.gregorian.datetime_formats.interval_formats,
+                ),
+            )),
         })
     }
 }
 
 icu_provider::impl_dyn_provider!(DatePatternsProvider<'d>, {
+    key::GREGORY_INTERVAL_PATTERNS_V1 => gregory::DateIntervalPatternsV1Marker,
     _ => gregory::DatePatternsV1Marker,
 }, SERDE_SE, 'd, 's);
 
 impl<'d> IterableDataProviderCore for DatePatternsProvider<'d> {
     fn supported_options_for_key(
         &self,
-        _resc_key: &ResourceKey,
+        resc_key: &ResourceKey,
     ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
-        let list: Vec<ResourceOptions> = self
+        let mut list: Vec<ResourceOptions> = self
             .data
             .iter()
             .map(|(l, _)| ResourceOptions {
@@ -99,6 +162,16 @@ impl<'d> IterableDataProviderCore for DatePatternsProvider<'d> {
                 langid: Some(l.langid.clone()),
             })
             .collect();
+        if key::GREGORY_DATE_PATTERNS_V1.match_key(*resc_key).is_ok() {
+            list.extend(
+                self.calendars
+                    .iter()
+                    .map(|(variant, l, _)| ResourceOptions {
+                        variant: Some(Cow::Borrowed(*variant)),
+                        langid: Some(l.langid.clone()),
+                    }),
+            );
+        }
         Ok(Box::new(list.into_iter()))
     }
 }
@@ -177,6 +250,50 @@ impl From<&cldr_json::DateTimeFormats> for gregory::patterns::DateTimeFormatsV1
     }
 }
 
+impl From<&cldr_json::IntervalFormats> for gregory::DateIntervalPatternsV1 {
+    fn from(other: &cldr_json::IntervalFormats) -> Self {
+        use gregory::intervals::{IntervalPatternsV1, IntervalSkeletonsV1};
+        use gregory::patterns::{PatternV1, SkeletonV1};
+        use litemap::LiteMap;
+
+        let mut skeletons = IntervalSkeletonsV1(LiteMap::new());
+        for (skeleton_str, interval_format) in other.formats.iter() {
+            let skeleton = match SkeletonV1::try_from(skeleton_str as &str) {
+                Ok(s) => s,
+                Err(err) => match err {
+                    // Ignore unimplemented fields for now.
+                    SkeletonError::SymbolUnimplemented(_) => continue,
+                    _ => panic!("{:?} {}", skeleton_str, err),
+                },
+            };
+
+            let mut patterns = IntervalPatternsV1::default();
+            for (greatest_difference, pattern_str) in interval_format.0.iter() {
+                let pattern = match greatest_difference as &str {
+                    "y" => &mut patterns.year,
+                    "M" => &mut patterns.month,
+                    "d" => &mut patterns.day,
+                    "a" => &mut patterns.am_pm,
+                    "h" | "H" => &mut patterns.hour,
+                    "m" => &mut patterns.minute,
+                    // Eras, flexible day periods, and alternate variants are not yet supported.
+                    _ => continue,
+                };
+                *pattern = Some(
+                    PatternV1::try_from(pattern_str as &str).expect("Unable to parse a pattern"),
+                );
+            }
+
+            skeletons.0.insert(skeleton, patterns);
+        }
+
+        Self {
+            fallback: Cow::Owned(other.fallback.clone()),
+            skeletons,
+        }
+    }
+}
+
 impl From<&cldr_json::Dates> for gregory::DatePatternsV1 {
     fn from(other: &cldr_json::Dates) -> Self {
         Self {
@@ -211,6 +328,73 @@ fn test_basic() {
     assert_eq!("d. M. y", cs_dates.get().date.medium);
 }
 
+#[test]
+fn test_calendar_variant() {
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = DatePatternsProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let en_dates: DataPayload<gregory::DatePatternsV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::GREGORY_DATE_PATTERNS_V1,
+                options: ResourceOptions {
+                    variant: Some("japanese".into()),
+                    langid: Some(langid!("en")),
+                },
+            },
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!("MMM d, y G", en_dates.get().date.medium);
+    assert_eq!("h:mm:ss a", en_dates.get().time.medium);
+}
+
+#[test]
+fn test_interval_patterns() {
+    use icu_datetime::skeleton::Skeleton;
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = DatePatternsProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let en_intervals: DataPayload<gregory::DateIntervalPatternsV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::GREGORY_INTERVAL_PATTERNS_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(langid!("en")),
+                },
+            },
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!("{0} – {1}", en_intervals.get().fallback);
+    let skeleton =
+        gregory::patterns::SkeletonV1(Skeleton::try_from("yMMMd").expect("Invalid skeleton"));
+    let patterns = en_intervals
+        .get()
+        .skeletons
+        .0
+        .get(&skeleton)
+        .expect("Missing skeleton");
+    let to_string = |pattern: &Option<gregory::patterns::PatternV1>| {
+        pattern.as_ref().map(|pattern| pattern.0.to_string())
+    };
+    assert_eq!(to_string(&patterns.day).as_deref(), Some("MMM d – d, y"));
+    assert_eq!(
+        to_string(&patterns.year).as_deref(),
+        Some("MMM d, y – MMM d, y")
+    );
+    assert_eq!(patterns.hour, None);
+}
+
 #[test]
 fn test_with_numbering_system() {
     use icu_locid_macros::langid;
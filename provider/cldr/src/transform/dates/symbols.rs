@@ -5,7 +5,6 @@
 use super::cldr_json;
 use crate::cldr_langid::CldrLangID;
 use crate::error::Error;
-use crate::reader::{get_subdirectories, open_reader};
 use crate::CldrPaths;
 use icu_datetime::provider::*;
 use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
@@ -33,13 +32,13 @@ impl TryFrom<&dyn CldrPaths> for DateSymbolsProvider<'_> {
 
         let path = cldr_paths.cldr_dates()?.join("main");
 
-        let locale_dirs = get_subdirectories(&path)?;
+        let locale_dirs = cldr_paths.get_subdirectories(&path)?;
 
         for dir in locale_dirs {
             let path = dir.join("ca-gregorian.json");
 
             let mut resource: cldr_json::Resource =
-                serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+                serde_json::from_reader(cldr_paths.open_reader(&path)?).map_err(|e| (e, path))?;
             data.append(&mut resource.main.0);
         }
 
@@ -3,7 +3,6 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::error::Error;
-use crate::reader::open_reader;
 use crate::CldrPaths;
 use icu_locale_canonicalizer::provider::*;
 use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
@@ -31,7 +30,7 @@ impl TryFrom<&dyn CldrPaths> for LikelySubtagsProvider<'_> {
                 .cldr_core()?
                 .join("supplemental")
                 .join("likelySubtags.json");
-            serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?
+            serde_json::from_reader(cldr_paths.open_reader(&path)?).map_err(|e| (e, path))?
         };
         Ok(Self {
             data,
@@ -6,14 +6,20 @@ mod aliases;
 mod dates;
 mod likelysubtags;
 mod numbers;
+mod ordinal_patterns;
 mod plurals;
+mod relative_time;
 mod time_zones;
+mod week_data;
 
 pub use aliases::AliasesProvider;
-pub use dates::{patterns::DatePatternsProvider, symbols::DateSymbolsProvider};
+pub use dates::{eras::ErasProvider, patterns::DatePatternsProvider, symbols::DateSymbolsProvider};
 pub use likelysubtags::LikelySubtagsProvider;
 pub use numbers::NumbersProvider;
+pub use ordinal_patterns::OrdinalPatternsProvider;
 pub use plurals::PluralsProvider;
+pub use relative_time::RelativeTimeProvider;
+pub use week_data::WeekDataProvider;
 
 use crate::support::LazyCldrProvider;
 use crate::CldrPaths;
@@ -23,17 +29,50 @@ use icu_provider::serde::SerdeSeDataStructMarker;
 
 use self::time_zones::TimeZonesProvider;
 
+/// The [`ResourceKeys`](ResourceKey) of each sub-provider.
+const ALL_KEY_LISTS: [&[ResourceKey]; 11] = [
+    &aliases::ALL_KEYS,
+    &dates::symbols::ALL_KEYS,
+    &dates::patterns::ALL_KEYS,
+    &dates::eras::ALL_KEYS,
+    &likelysubtags::ALL_KEYS,
+    &numbers::ALL_KEYS,
+    &ordinal_patterns::ALL_KEYS,
+    &plurals::ALL_KEYS,
+    &relative_time::ALL_KEYS,
+    &time_zones::ALL_KEYS,
+    &week_data::ALL_KEYS,
+];
+
 /// Returns a list of all [`ResourceKeys`](ResourceKey) that this provider can produce.
 pub fn get_all_cldr_keys() -> Vec<ResourceKey> {
-    let mut result: Vec<ResourceKey> = vec![];
-    result.extend(&aliases::ALL_KEYS);
-    result.extend(&dates::symbols::ALL_KEYS);
-    result.extend(&dates::patterns::ALL_KEYS);
-    result.extend(&likelysubtags::ALL_KEYS);
-    result.extend(&numbers::ALL_KEYS);
-    result.extend(&plurals::ALL_KEYS);
-    result.extend(&time_zones::ALL_KEYS);
-    result
+    ALL_KEY_LISTS
+        .iter()
+        .flat_map(|keys| keys.iter())
+        .copied()
+        .collect()
+}
+
+/// Returns a list of the [`ResourceKeys`](ResourceKey) in the given [`ResourceCategory`] that
+/// this provider can produce.
+///
+/// # Examples
+///
+/// ```
+/// use icu_provider::prelude::*;
+/// use icu_provider_cldr::get_cldr_keys_for_category;
+///
+/// let keys = get_cldr_keys_for_category(ResourceCategory::Plurals);
+/// assert!(keys.iter().all(|key| key.category == ResourceCategory::Plurals));
+/// assert!(keys.contains(&icu_plurals::provider::key::CARDINAL_V1));
+/// ```
+pub fn get_cldr_keys_for_category(category: ResourceCategory) -> Vec<ResourceKey> {
+    ALL_KEY_LISTS
+        .iter()
+        .flat_map(|keys| keys.iter())
+        .filter(|key| key.category == category)
+        .copied()
+        .collect()
 }
 
 #[derive(Debug)]
@@ -42,10 +81,14 @@ pub struct CldrJsonDataProvider<'a, 'd> {
     aliases: LazyCldrProvider<AliasesProvider<'d>>,
     date_symbols: LazyCldrProvider<DateSymbolsProvider<'d>>,
     date_patterns: LazyCldrProvider<DatePatternsProvider<'d>>,
+    eras: LazyCldrProvider<ErasProvider<'d>>,
     likelysubtags: LazyCldrProvider<LikelySubtagsProvider<'d>>,
     numbers: LazyCldrProvider<NumbersProvider>,
+    ordinal_patterns: LazyCldrProvider<OrdinalPatternsProvider<'d>>,
     plurals: LazyCldrProvider<PluralsProvider<'d>>,
+    relative_time: LazyCldrProvider<RelativeTimeProvider<'d>>,
     time_zones: LazyCldrProvider<TimeZonesProvider<'d>>,
+    week_data: LazyCldrProvider<WeekDataProvider<'d>>,
 }
 
 impl<'a, 'd> CldrJsonDataProvider<'a, 'd> {
@@ -55,12 +98,58 @@ impl<'a, 'd> CldrJsonDataProvider<'a, 'd> {
             aliases: Default::default(),
             date_symbols: Default::default(),
             date_patterns: Default::default(),
+            eras: Default::default(),
             likelysubtags: Default::default(),
             numbers: Default::default(),
+            ordinal_patterns: Default::default(),
             plurals: Default::default(),
+            relative_time: Default::default(),
             time_zones: Default::default(),
+            week_data: Default::default(),
         }
     }
+
+    /// Returns the [`ResourceKeys`](ResourceKey) that this provider can produce given its
+    /// [`CldrPaths`], omitting keys whose CLDR source files are missing.
+    ///
+    /// Unlike [`get_all_cldr_keys()`], this loads the CLDR source files for each sub-provider.
+    pub fn supported_keys(&self) -> Result<Vec<ResourceKey>, DataError> {
+        let mut result: Vec<ResourceKey> = vec![];
+        if self.aliases.try_init(self.cldr_paths)? {
+            result.extend(&aliases::ALL_KEYS);
+        }
+        if self.date_symbols.try_init(self.cldr_paths)? {
+            result.extend(&dates::symbols::ALL_KEYS);
+        }
+        if self.date_patterns.try_init(self.cldr_paths)? {
+            result.extend(&dates::patterns::ALL_KEYS);
+        }
+        if self.eras.try_init(self.cldr_paths)? {
+            result.extend(&dates::eras::ALL_KEYS);
+        }
+        if self.likelysubtags.try_init(self.cldr_paths)? {
+            result.extend(&likelysubtags::ALL_KEYS);
+        }
+        if self.numbers.try_init(self.cldr_paths)? {
+            result.extend(&numbers::ALL_KEYS);
+        }
+        if self.ordinal_patterns.try_init(self.cldr_paths)? {
+            result.extend(&ordinal_patterns::ALL_KEYS);
+        }
+        if self.plurals.try_init(self.cldr_paths)? {
+            result.extend(&plurals::ALL_KEYS);
+        }
+        if self.relative_time.try_init(self.cldr_paths)? {
+            result.extend(&relative_time::ALL_KEYS);
+        }
+        if self.time_zones.try_init(self.cldr_paths)? {
+            result.extend(&time_zones::ALL_KEYS);
+        }
+        if self.week_data.try_init(self.cldr_paths)? {
+            result.extend(&week_data::ALL_KEYS);
+        }
+        Ok(result)
+    }
 }
 
 impl<'a, 'd, 's: 'd> DataProvider<'d, 's, SerdeSeDataStructMarker>
@@ -79,18 +168,30 @@ impl<'a, 'd, 's: 'd> DataProvider<'d, 's, SerdeSeDataStructMarker>
         if let Some(result) = self.date_patterns.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
+        if let Some(result) = self.eras.try_load_serde(req, self.cldr_paths)? {
+            return Ok(result);
+        }
         if let Some(result) = self.likelysubtags.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
         if let Some(result) = self.numbers.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
+        if let Some(result) = self.ordinal_patterns.try_load_serde(req, self.cldr_paths)? {
+            return Ok(result);
+        }
         if let Some(result) = self.plurals.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
+        if let Some(result) = self.relative_time.try_load_serde(req, self.cldr_paths)? {
+            return Ok(result);
+        }
         if let Some(result) = self.time_zones.try_load_serde(req, self.cldr_paths)? {
             return Ok(result);
         }
+        if let Some(result) = self.week_data.try_load_serde(req, self.cldr_paths)? {
+            return Ok(result);
+        }
         Err(DataError::UnsupportedResourceKey(req.resource_path.key))
     }
 }
@@ -118,6 +219,9 @@ impl<'a, 'd> IterableDataProviderCore for CldrJsonDataProvider<'a, 'd> {
         {
             return Ok(Box::new(resp.into_iter()));
         }
+        if let Some(resp) = self.eras.try_supported_options(resc_key, self.cldr_paths)? {
+            return Ok(Box::new(resp.into_iter()));
+        }
         if let Some(resp) = self
             .likelysubtags
             .try_supported_options(resc_key, self.cldr_paths)?
@@ -130,18 +234,36 @@ impl<'a, 'd> IterableDataProviderCore for CldrJsonDataProvider<'a, 'd> {
         {
             return Ok(Box::new(resp.into_iter()));
         }
+        if let Some(resp) = self
+            .ordinal_patterns
+            .try_supported_options(resc_key, self.cldr_paths)?
+        {
+            return Ok(Box::new(resp.into_iter()));
+        }
         if let Some(resp) = self
             .plurals
             .try_supported_options(resc_key, self.cldr_paths)?
         {
             return Ok(Box::new(resp.into_iter()));
         }
+        if let Some(resp) = self
+            .relative_time
+            .try_supported_options(resc_key, self.cldr_paths)?
+        {
+            return Ok(Box::new(resp.into_iter()));
+        }
         if let Some(resp) = self
             .time_zones
             .try_supported_options(resc_key, self.cldr_paths)?
         {
             return Ok(Box::new(resp.into_iter()));
         }
+        if let Some(resp) = self
+            .week_data
+            .try_supported_options(resc_key, self.cldr_paths)?
+        {
+            return Ok(Box::new(resp.into_iter()));
+        }
         Err(DataError::UnsupportedResourceKey(*resc_key))
     }
 }
@@ -151,5 +273,49 @@ impl<'a, 'd> KeyedDataProvider for CldrJsonDataProvider<'a, 'd> {
         PluralsProvider::supports_key(resc_key)
             .or_else(|err| DateSymbolsProvider::or_else_supports_key(err, resc_key))
             .or_else(|err| DatePatternsProvider::or_else_supports_key(err, resc_key))
+            .or_else(|err| ErasProvider::or_else_supports_key(err, resc_key))
+            .or_else(|err| OrdinalPatternsProvider::or_else_supports_key(err, resc_key))
+            .or_else(|err| RelativeTimeProvider::or_else_supports_key(err, resc_key))
+            .or_else(|err| WeekDataProvider::or_else_supports_key(err, resc_key))
     }
 }
+
+#[test]
+fn test_supports_key() {
+    assert!(CldrJsonDataProvider::supports_key(&icu_datetime::provider::key::WEEK_DATA_V1).is_ok());
+    assert!(
+        CldrJsonDataProvider::supports_key(&icu_provider::hello_world::key::HELLO_WORLD_V1)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_supported_keys() {
+    use crate::CldrPathsLocal;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = CldrJsonDataProvider::new(&cldr_paths);
+    assert_eq!(provider.supported_keys().unwrap(), get_all_cldr_keys());
+
+    let mut core_only = CldrPathsLocal::default();
+    core_only.cldr_core = Ok(cldr_paths.cldr_core().unwrap());
+    let provider = CldrJsonDataProvider::new(&core_only);
+    let mut expected: Vec<ResourceKey> = vec![];
+    expected.extend(&aliases::ALL_KEYS);
+    expected.extend(&likelysubtags::ALL_KEYS);
+    expected.extend(&plurals::ALL_KEYS);
+    assert_eq!(provider.supported_keys().unwrap(), expected);
+}
+
+#[test]
+fn test_keys_for_category() {
+    assert_eq!(
+        get_cldr_keys_for_category(ResourceCategory::TimeZone),
+        time_zones::ALL_KEYS.to_vec()
+    );
+    assert_eq!(
+        get_cldr_keys_for_category(ResourceCategory::LocaleCanonicalizer),
+        vec![aliases::ALL_KEYS[0], likelysubtags::ALL_KEYS[0]]
+    );
+    assert!(get_cldr_keys_for_category(ResourceCategory::UnicodeSet).is_empty());
+}
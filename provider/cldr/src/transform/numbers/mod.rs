@@ -4,7 +4,6 @@
 
 use crate::cldr_langid::CldrLangID;
 use crate::error::Error;
-use crate::reader::{get_subdirectories, open_reader};
 use crate::CldrPaths;
 use icu_decimal::provider::*;
 use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
@@ -37,17 +36,17 @@ impl TryFrom<&dyn CldrPaths> for NumbersProvider {
                 .cldr_core()?
                 .join("supplemental")
                 .join("numberingSystems.json");
-            serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?
+            serde_json::from_reader(cldr_paths.open_reader(&path)?).map_err(|e| (e, path))?
         };
 
         // Load data for each locale:
         let mut cldr_numbers_data = vec![];
         let path = cldr_paths.cldr_numbers()?.join("main");
-        let locale_dirs = get_subdirectories(&path)?;
+        let locale_dirs = cldr_paths.get_subdirectories(&path)?;
         for dir in locale_dirs {
             let path = dir.join("numbers.json");
             let mut resource: cldr_serde::numbers_json::Resource =
-                serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+                serde_json::from_reader(cldr_paths.open_reader(&path)?).map_err(|e| (e, path))?;
             cldr_numbers_data.append(&mut resource.main.0);
         }
 
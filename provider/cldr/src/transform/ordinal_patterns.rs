@@ -0,0 +1,341 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::cldr_langid::CldrLangID;
+use crate::error::Error;
+use crate::CldrPaths;
+use icu_plurals::provider::*;
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 1] = [
+    key::ORDINAL_PATTERNS_V1, //
+];
+
+/// The rule set formatting a number as an ordinal with digits.
+const DIGITS_ORDINAL: &str = "%digits-ordinal";
+
+/// The substitution of the number formatted with grouping separators in an RBNF rule. This is
+/// the `{0}` of the patterns, which `OrdinalFormatter` formats with grouping separators. Rules
+/// substituting the number in any other way, such as without grouping (`=0=`), are rejected.
+const NUMBER_SUBSTITUTION: &str = "=#,##0=";
+
+/// A data provider reading the ordinal patterns from the CLDR JSON RBNF files.
+#[derive(PartialEq, Debug)]
+pub struct OrdinalPatternsProvider<'d> {
+    /// The patterns, sorted by locale.
+    data: Vec<(CldrLangID, OrdinalPatternsV1<'static>)>,
+    _phantom: PhantomData<&'d ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for OrdinalPatternsProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let mut data = vec![];
+
+        let path = cldr_paths.cldr_rbnf()?.join("rbnf");
+
+        for path in cldr_paths.get_files(&path)? {
+            let langid = match path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| {
+                    name.strip_suffix(".json")
+                        .or_else(|| name.strip_suffix(".json.gz"))
+                }) {
+                Some(name) => CldrLangID::from_str(name)
+                    .map_err(|e| Error::Custom(format!("{}: {:?}", e, path), None))?,
+                None => continue,
+            };
+            let resource: cldr_json::Resource =
+                serde_json::from_reader(cldr_paths.open_reader(&path)?).map_err(|e| (e, path))?;
+            let rule_sets = match resource.rbnf.rbnf.ordinal_rules {
+                Some(rule_sets) => rule_sets,
+                None => continue,
+            };
+            match convert(&rule_sets) {
+                Ok(patterns) => data.push((langid, patterns)),
+                // Rules that do not reduce to one pattern per plural category are skipped, so
+                // no ordinal patterns are produced for the locale.
+                Err(_err) => {
+                    #[cfg(feature = "log")]
+                    log::warn!("Skipping ordinal rules of {}: {}", langid.langid, _err);
+                }
+            }
+        }
+        data.sort_by(|(l1, _), (l2, _)| l1.cmp(l2));
+
+        Ok(Self {
+            data,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+/// Converts the rule for 0 of the `%digits-ordinal` rule set to ordinal patterns, following
+/// rule sets that substitute another one, such as `=%digits-ordinal-masculine=`.
+///
+/// The rule must consist of text, the number, and either more text or a plural selection, such
+/// as `=#,##0=$(ordinal,one{st}two{nd}few{rd}other{th})$`.
+fn convert(rule_sets: &cldr_json::RuleSets) -> Result<OrdinalPatternsV1<'static>, String> {
+    let mut name = DIGITS_ORDINAL;
+    let mut substitutions = 0;
+    let rule = loop {
+        let rules = rule_sets
+            .0
+            .iter()
+            .find(|(set_name, _)| set_name == name)
+            .map(|(_, rules)| rules)
+            .ok_or_else(|| format!("Missing rule set {}", name))?;
+        let rule = rules
+            .iter()
+            .find(|(base, _)| base == "0")
+            .map(|(_, rule)| rule.trim_end_matches(';'))
+            .ok_or_else(|| format!("Missing rule for 0 in {}", name))?;
+        match rule
+            .strip_prefix("=%")
+            .and_then(|rest| rest.strip_suffix('='))
+        {
+            Some(other) if !other.contains('=') && substitutions < rule_sets.0.len() => {
+                name = &rule[1..rule.len() - 1];
+                substitutions += 1;
+            }
+            _ => break rule,
+        }
+    };
+
+    let index = rule
+        .find(NUMBER_SUBSTITUTION)
+        .ok_or_else(|| format!("Unsupported rule {:?}", rule))?;
+    let prefix = &rule[..index];
+    let suffix = &rule[index + NUMBER_SUBSTITUTION.len()..];
+    let is_text = |text: &str| !text.contains(|c| "=%$→←'".contains(c));
+    if !is_text(prefix) {
+        return Err(format!("Unsupported rule {:?}", rule));
+    }
+    let pattern =
+        |text: &str| -> Cow<'static, str> { Cow::Owned(format!("{}{{0}}{}", prefix, text)) };
+
+    if is_text(suffix) {
+        return Ok(OrdinalPatternsV1 {
+            other: pattern(suffix),
+            ..Default::default()
+        });
+    }
+
+    let mut selection = suffix
+        .strip_prefix("$(ordinal,")
+        .and_then(|rest| rest.strip_suffix(")$"))
+        .ok_or_else(|| format!("Unsupported rule {:?}", rule))?;
+    let mut result = OrdinalPatternsV1::default();
+    let mut other = None;
+    while !selection.is_empty() {
+        let (category, rest) = selection.split_at(
+            selection
+                .find('{')
+                .ok_or_else(|| format!("Unsupported rule {:?}", rule))?,
+        );
+        let end = rest
+            .find('}')
+            .ok_or_else(|| format!("Unsupported rule {:?}", rule))?;
+        let text = &rest[1..end];
+        if !is_text(text) {
+            return Err(format!("Unsupported rule {:?}", rule));
+        }
+        let slot = match category.trim() {
+            "zero" => &mut result.zero,
+            "one" => &mut result.one,
+            "two" => &mut result.two,
+            "few" => &mut result.few,
+            "many" => &mut result.many,
+            "other" => &mut other,
+            category => return Err(format!("Unknown plural category {:?}", category)),
+        };
+        *slot = Some(pattern(text));
+        selection = &rest[end + 1..];
+    }
+    result.other = other.ok_or_else(|| format!("Missing other category in {:?}", rule))?;
+    Ok(result)
+}
+
+impl<'d> KeyedDataProvider for OrdinalPatternsProvider<'d> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        key::ORDINAL_PATTERNS_V1.match_key(*resc_key)
+    }
+}
+
+impl<'d, 's> DataProvider<'d, 's, OrdinalPatternsV1Marker> for OrdinalPatternsProvider<'d> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'d, 's, OrdinalPatternsV1Marker>, DataError> {
+        OrdinalPatternsProvider::supports_key(&req.resource_path.key)?;
+        let cldr_langid: CldrLangID = req.try_langid()?.clone().into();
+        let patterns = match self
+            .data
+            .binary_search_by_key(&&cldr_langid, |(lid, _)| lid)
+        {
+            Ok(idx) => &self.data[idx].1,
+            Err(_) => return Err(DataError::UnavailableResourceOptions(req.clone())),
+        };
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(patterns.clone())),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(OrdinalPatternsProvider<'d>, {
+    _ => OrdinalPatternsV1Marker,
+}, SERDE_SE, 'd, 's);
+
+impl<'d> IterableDataProviderCore for OrdinalPatternsProvider<'d> {
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = self
+            .data
+            .iter()
+            .map(|(l, _)| ResourceOptions {
+                variant: None,
+                // TODO: Avoid the clone
+                langid: Some(l.langid.clone()),
+            })
+            .collect();
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+/// Serde structs for the CLDR JSON RBNF files.
+pub(self) mod cldr_json {
+    use serde::Deserialize;
+
+    /// The rules of each rule set, keyed by rule set name, such as `%digits-ordinal`. The rules
+    /// are keyed by their base value, such as "0", or by a special name, such as "-x".
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct RuleSets(
+        #[serde(with = "tuple_vec_map")] pub(crate) Vec<(String, Vec<(String, String)>)>,
+    );
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct RuleSetGroups {
+        #[serde(rename = "OrdinalRules")]
+        pub ordinal_rules: Option<RuleSets>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Rbnf {
+        pub rbnf: RuleSetGroups,
+    }
+
+    /// This struct represents the CLDR rbnf/LANGID.json files, which contain a single locale.
+    ///
+    /// e.g.
+    /// https://github.com/unicode-org/cldr-json/blob/master/cldr-json/cldr-rbnf/rbnf/en.json
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Resource {
+        pub rbnf: Rbnf,
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::LanguageIdentifier;
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = OrdinalPatternsProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let load = |langid: LanguageIdentifier| {
+        DataProvider::<OrdinalPatternsV1Marker>::load_payload(
+            &provider,
+            &DataRequest {
+                resource_path: ResourcePath {
+                    key: key::ORDINAL_PATTERNS_V1,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(langid),
+                    },
+                },
+            },
+        )
+        .and_then(DataResponse::take_payload)
+    };
+
+    let en = load(langid!("en")).unwrap();
+    assert_eq!(
+        en.get(),
+        &OrdinalPatternsV1 {
+            zero: None,
+            one: Some(Cow::Borrowed("{0}st")),
+            two: Some(Cow::Borrowed("{0}nd")),
+            few: Some(Cow::Borrowed("{0}rd")),
+            many: None,
+            other: Cow::Borrowed("{0}th"),
+        }
+    );
+
+    // French follows the masculine rule set.
+    let fr = load(langid!("fr")).unwrap();
+    assert_eq!(fr.get().one, Some(Cow::Borrowed("{0}er")));
+    assert_eq!(fr.get().other, "{0}e");
+
+    let ja = load(langid!("ja")).unwrap();
+    assert_eq!(ja.get().one, None);
+    assert_eq!(ja.get().other, "第{0}");
+
+    // Bengali has no ordinal rules in the test data.
+    assert!(load(langid!("bn")).is_err());
+}
+
+#[test]
+fn test_convert() {
+    fn convert_rules(rule_sets: &[(&str, &str)]) -> Result<OrdinalPatternsV1<'static>, String> {
+        convert(&cldr_json::RuleSets(
+            rule_sets
+                .iter()
+                .map(|(name, rule)| {
+                    (
+                        name.to_string(),
+                        vec![
+                            ("-x".to_string(), "−→→;".to_string()),
+                            ("0".to_string(), rule.to_string()),
+                        ],
+                    )
+                })
+                .collect(),
+        ))
+    }
+
+    assert_eq!(
+        convert_rules(&[("%digits-ordinal", "=#,##0=.;")])
+            .unwrap()
+            .other,
+        "{0}."
+    );
+    assert_eq!(
+        convert_rules(&[
+            ("%digits-ordinal-masculine", "=#,##0=.º;"),
+            ("%digits-ordinal", "=%digits-ordinal-masculine=;"),
+        ])
+        .unwrap()
+        .other,
+        "{0}.º"
+    );
+    // A rule depending on the range of the number has no pattern per plural category.
+    assert!(convert_rules(&[("%digits-ordinal", "=#,##0==%%dord-mascabbrev=.;")]).is_err());
+    assert!(convert_rules(&[("%digits-ordinal", "=#,##0=$(ordinal,one{st})$;")]).is_err());
+    assert!(convert_rules(&[("%digits-ordinal-feminine", "=#,##0=.ª;")]).is_err());
+    // Only the number with grouping separators is supported.
+    assert!(convert_rules(&[("%digits-ordinal", "=0=.;")]).is_err());
+    assert!(convert_rules(&[("%digits-ordinal", "=#,##0.00=.;")]).is_err());
+}
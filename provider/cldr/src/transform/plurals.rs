@@ -3,7 +3,6 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::error::Error;
-use crate::reader::open_reader;
 use crate::CldrPaths;
 use icu_plurals::provider::*;
 use icu_plurals::rules::{parse, serialize};
@@ -14,9 +13,11 @@ use std::convert::TryFrom;
 use std::marker::PhantomData;
 
 /// All keys that this module is able to produce.
-pub const ALL_KEYS: [ResourceKey; 2] = [
-    key::CARDINAL_V1, //
-    key::ORDINAL_V1,  //
+pub const ALL_KEYS: [ResourceKey; 4] = [
+    key::CARDINAL_V1,      //
+    key::ORDINAL_V1,       //
+    key::CARDINAL_LIST_V1, //
+    key::ORDINAL_LIST_V1,  //
 ];
 
 /// A data provider reading from CLDR JSON plural rule files.
@@ -36,7 +37,7 @@ impl TryFrom<&dyn CldrPaths> for PluralsProvider<'_> {
                 .join("supplemental")
                 .join("plurals.json");
             let data: cldr_json::Resource =
-                serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+                serde_json::from_reader(cldr_paths.open_reader(&path)?).map_err(|e| (e, path))?;
             data.supplemental.plurals_type_cardinal
         };
         let ordinal_rules = {
@@ -45,7 +46,7 @@ impl TryFrom<&dyn CldrPaths> for PluralsProvider<'_> {
                 .join("supplemental")
                 .join("ordinals.json");
             let data: cldr_json::Resource =
-                serde_json::from_reader(open_reader(&path)?).map_err(|e| (e, path))?;
+                serde_json::from_reader(cldr_paths.open_reader(&path)?).map_err(|e| (e, path))?;
             data.supplemental.plurals_type_ordinal
         };
         Ok(PluralsProvider {
@@ -58,10 +59,12 @@ impl TryFrom<&dyn CldrPaths> for PluralsProvider<'_> {
 
 impl<'d> KeyedDataProvider for PluralsProvider<'d> {
     fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
-        if resc_key.category != ResourceCategory::Plurals || resc_key.version != 1 {
-            return Err(resc_key.into());
+        match *resc_key {
+            key::CARDINAL_V1 | key::ORDINAL_V1 | key::CARDINAL_LIST_V1 | key::ORDINAL_LIST_V1 => {
+                Ok(())
+            }
+            _ => Err(resc_key.into()),
         }
-        Ok(())
     }
 }
 
@@ -69,12 +72,25 @@ impl<'d> PluralsProvider<'d> {
     fn get_rules_for(&self, resc_key: &ResourceKey) -> Result<&cldr_json::Rules, DataError> {
         PluralsProvider::supports_key(resc_key)?;
         match *resc_key {
-            key::CARDINAL_V1 => self.cardinal_rules.as_ref(),
-            key::ORDINAL_V1 => self.ordinal_rules.as_ref(),
+            key::CARDINAL_V1 | key::CARDINAL_LIST_V1 => self.cardinal_rules.as_ref(),
+            key::ORDINAL_V1 | key::ORDINAL_LIST_V1 => self.ordinal_rules.as_ref(),
             _ => return Err(resc_key.into()),
         }
         .ok_or_else(|| resc_key.into())
     }
+
+    fn get_locale_rules_for(
+        &self,
+        req: &DataRequest,
+    ) -> Result<&cldr_json::LocalePluralRules, DataError> {
+        let cldr_rules = self.get_rules_for(&req.resource_path.key)?;
+        // TODO: Implement language fallback?
+        let cldr_langid = req.try_langid()?.clone().into();
+        match cldr_rules.0.binary_search_by_key(&&cldr_langid, |(l, _)| l) {
+            Ok(idx) => Ok(&cldr_rules.0[idx].1),
+            Err(_) => Err(req.clone().into()),
+        }
+    }
 }
 
 impl<'d, 's> DataProvider<'d, 's, PluralRuleStringsV1Marker> for PluralsProvider<'d> {
@@ -82,13 +98,7 @@ impl<'d, 's> DataProvider<'d, 's, PluralRuleStringsV1Marker> for PluralsProvider
         &self,
         req: &DataRequest,
     ) -> Result<DataResponse<'d, 's, PluralRuleStringsV1Marker>, DataError> {
-        let cldr_rules = self.get_rules_for(&req.resource_path.key)?;
-        // TODO: Implement language fallback?
-        let cldr_langid = req.try_langid()?.clone().into();
-        let (_, r) = match cldr_rules.0.binary_search_by_key(&&cldr_langid, |(l, _)| l) {
-            Ok(idx) => &cldr_rules.0[idx],
-            Err(_) => return Err(req.clone().into()),
-        };
+        let r = self.get_locale_rules_for(req)?;
         Ok(DataResponse {
             metadata: DataResponseMetadata {
                 data_langid: req.resource_path.options.langid.clone(),
@@ -98,7 +108,27 @@ impl<'d, 's> DataProvider<'d, 's, PluralRuleStringsV1Marker> for PluralsProvider
     }
 }
 
+impl<'d, 's> DataProvider<'d, 's, PluralRuleListV1Marker> for PluralsProvider<'d> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'d, 's, PluralRuleListV1Marker>, DataError> {
+        let r = self.get_locale_rules_for(req)?;
+        let rules = PluralRuleListV1::try_from(&PluralRuleStringsV1::from(r))
+            .map_err(|e| Error::Custom(e.to_string(), req.try_langid().ok().cloned()))
+            .map_err(DataError::new_resc_error)?;
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(rules)),
+        })
+    }
+}
+
 icu_provider::impl_dyn_provider!(PluralsProvider<'d>, {
+    key::CARDINAL_LIST_V1 => PluralRuleListV1Marker,
+    key::ORDINAL_LIST_V1 => PluralRuleListV1Marker,
     _ => PluralRuleStringsV1Marker,
 }, SERDE_SE, 'd, 's);
 
@@ -221,4 +251,24 @@ fn test_basic() {
         Some("v != 0"),
         cs_rules.get().many.as_ref().map(|v| v.borrow())
     );
+
+    // The compiled rules are the compiled rule strings.
+    let cs_list: DataPayload<PluralRuleListV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::CARDINAL_LIST_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(langid!("cs")),
+                },
+            },
+        })
+        .unwrap()
+        .take_payload()
+        .unwrap();
+
+    assert_eq!(
+        cs_list.get(),
+        &PluralRuleListV1::try_from(cs_rules.get()).unwrap()
+    );
 }
@@ -0,0 +1,298 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::cldr_langid::CldrLangID;
+use crate::error::Error;
+use crate::CldrPaths;
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use icu_relativetime::provider::*;
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 1] = [
+    key::RELATIVE_TIME_V1, //
+];
+
+/// The prefix of the keys of the phrases for particular offsets, such as `relative-type--1`.
+const RELATIVE_PREFIX: &str = "relative-type-";
+
+/// A data provider reading from the CLDR JSON date fields files.
+#[derive(PartialEq, Debug)]
+pub struct RelativeTimeProvider<'d> {
+    /// The patterns, sorted by locale.
+    data: Vec<(CldrLangID, RelativeTimePatternsV1)>,
+    _phantom: PhantomData<&'d ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for RelativeTimeProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let mut data = vec![];
+
+        let path = cldr_paths.cldr_dates()?.join("main");
+
+        let locale_dirs = cldr_paths.get_subdirectories(&path)?;
+
+        for dir in locale_dirs {
+            let path = dir.join("dateFields.json");
+
+            let reader = match cldr_paths.open_reader(&path) {
+                Ok(reader) => reader,
+                // Locales with calendar data only, such as the extra test locales, are skipped.
+                Err(Error::Io(err, _)) if err.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(err) => return Err(err),
+            };
+            let resource: cldr_json::Resource =
+                serde_json::from_reader(reader).map_err(|e| (e, path))?;
+            for (langid, lang_dates) in resource.main.0 {
+                let patterns = RelativeTimePatternsV1::try_from(&lang_dates.dates.fields)
+                    .map_err(|e| (e, &langid.langid))?;
+                data.push((langid, patterns));
+            }
+        }
+        data.sort_by(|(l1, _), (l2, _)| l1.cmp(l2));
+
+        Ok(Self {
+            data,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<'d> KeyedDataProvider for RelativeTimeProvider<'d> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        key::RELATIVE_TIME_V1.match_key(*resc_key)
+    }
+}
+
+impl<'d, 's> DataProvider<'d, 's, RelativeTimePatternsV1Marker> for RelativeTimeProvider<'d> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'d, 's, RelativeTimePatternsV1Marker>, DataError> {
+        RelativeTimeProvider::supports_key(&req.resource_path.key)?;
+        let cldr_langid: CldrLangID = req.try_langid()?.clone().into();
+        let patterns = match self
+            .data
+            .binary_search_by_key(&&cldr_langid, |(lid, _)| lid)
+        {
+            Ok(idx) => &self.data[idx].1,
+            Err(_) => return Err(DataError::UnavailableResourceOptions(req.clone())),
+        };
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(patterns.clone())),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(RelativeTimeProvider<'d>, {
+    _ => RelativeTimePatternsV1Marker,
+}, SERDE_SE, 'd, 's);
+
+impl<'d> IterableDataProviderCore for RelativeTimeProvider<'d> {
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = self
+            .data
+            .iter()
+            .map(|(l, _)| ResourceOptions {
+                variant: None,
+                // TODO: Avoid the clone
+                langid: Some(l.langid.clone()),
+            })
+            .collect();
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+impl TryFrom<&cldr_json::Fields> for RelativeTimePatternsV1 {
+    type Error = String;
+    fn try_from(other: &cldr_json::Fields) -> Result<Self, Self::Error> {
+        Ok(Self {
+            long: fields_for_width(other, "")?,
+            short: fields_for_width(other, "-short")?,
+            narrow: fields_for_width(other, "-narrow")?,
+        })
+    }
+}
+
+/// Converts the fields of one width, whose CLDR names have the given suffix, such as
+/// `year-short` for the suffix `-short`.
+fn fields_for_width(fields: &cldr_json::Fields, suffix: &str) -> Result<FieldsV1, String> {
+    let field = |unit: &str| -> Result<FieldV1, String> {
+        let name = format!("{}{}", unit, suffix);
+        fields
+            .0
+            .iter()
+            .find(|(field_name, _)| *field_name == name)
+            .ok_or_else(|| format!("Missing field {}", name))
+            .and_then(|(_, field)| FieldV1::try_from(field))
+    };
+    Ok(FieldsV1 {
+        year: field("year")?,
+        quarter: field("quarter")?,
+        month: field("month")?,
+        week: field("week")?,
+        day: field("day")?,
+        hour: field("hour")?,
+        minute: field("minute")?,
+        second: field("second")?,
+    })
+}
+
+impl TryFrom<&cldr_json::Field> for FieldV1 {
+    type Error = String;
+    fn try_from(other: &cldr_json::Field) -> Result<Self, Self::Error> {
+        let relatives = other
+            .entries
+            .iter()
+            .filter_map(|(key, phrase)| {
+                key.strip_prefix(RELATIVE_PREFIX).map(|offset| {
+                    offset
+                        .parse::<i8>()
+                        .map(|offset| (offset, Cow::Owned(phrase.clone())))
+                        .map_err(|_| format!("Invalid relative offset: {:?}", key))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        let patterns = |patterns: &Option<cldr_json::PluralPatterns>| {
+            patterns
+                .as_ref()
+                .map(PluralPatternsV1::from)
+                .ok_or("Missing relative time patterns")
+        };
+        Ok(Self {
+            relatives,
+            future: patterns(&other.future)?,
+            past: patterns(&other.past)?,
+        })
+    }
+}
+
+impl From<&cldr_json::PluralPatterns> for PluralPatternsV1 {
+    fn from(other: &cldr_json::PluralPatterns) -> Self {
+        let convert = |pattern: &Option<String>| pattern.clone().map(Cow::Owned);
+        Self {
+            zero: convert(&other.zero),
+            one: convert(&other.one),
+            two: convert(&other.two),
+            few: convert(&other.few),
+            many: convert(&other.many),
+            other: Cow::Owned(other.other.clone()),
+        }
+    }
+}
+
+/// Serde structs for the CLDR JSON date fields files.
+pub(self) mod cldr_json {
+    use crate::cldr_langid::CldrLangID;
+    use serde::Deserialize;
+
+    /// The patterns of a field for the offsets in one direction, keyed by plural category.
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct PluralPatterns {
+        #[serde(rename = "relativeTimePattern-count-zero")]
+        pub zero: Option<String>,
+        #[serde(rename = "relativeTimePattern-count-one")]
+        pub one: Option<String>,
+        #[serde(rename = "relativeTimePattern-count-two")]
+        pub two: Option<String>,
+        #[serde(rename = "relativeTimePattern-count-few")]
+        pub few: Option<String>,
+        #[serde(rename = "relativeTimePattern-count-many")]
+        pub many: Option<String>,
+        #[serde(rename = "relativeTimePattern-count-other")]
+        pub other: String,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Field {
+        #[serde(rename = "relativeTime-type-future")]
+        pub future: Option<PluralPatterns>,
+        #[serde(rename = "relativeTime-type-past")]
+        pub past: Option<PluralPatterns>,
+        /// The remaining entries of the field, such as `displayName` and the phrases for
+        /// particular offsets, such as `relative-type--1`.
+        #[serde(flatten, with = "tuple_vec_map")]
+        pub entries: Vec<(String, String)>,
+    }
+
+    /// The fields, keyed by name, such as `day` or `day-short`. Only the fields of the units of
+    /// [`RelativeTimeUnit`](icu_relativetime::RelativeTimeUnit) are converted.
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Fields(#[serde(with = "tuple_vec_map")] pub(crate) Vec<(String, Field)>);
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Dates {
+        pub fields: Fields,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LangDates {
+        pub dates: Dates,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LangData(#[serde(with = "tuple_vec_map")] pub(crate) Vec<(CldrLangID, LangDates)>);
+
+    /// This struct represents the CLDR dateFields.json files, which contain a single locale.
+    ///
+    /// e.g.
+    /// https://github.com/unicode-org/cldr-json/blob/master/cldr-json/cldr-dates-full/main/en/dateFields.json
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct Resource {
+        pub main: LangData,
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid::LanguageIdentifier;
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = RelativeTimeProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let load = |langid: LanguageIdentifier| {
+        DataProvider::<RelativeTimePatternsV1Marker>::load_payload(
+            &provider,
+            &DataRequest {
+                resource_path: ResourcePath {
+                    key: key::RELATIVE_TIME_V1,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(langid),
+                    },
+                },
+            },
+        )
+        .and_then(DataResponse::take_payload)
+    };
+
+    let en = load(langid!("en")).unwrap();
+    let day = &en.get().long.day;
+    assert_eq!(day.relatives.get(&-1).map(|s| &**s), Some("yesterday"));
+    assert_eq!(day.relatives.get(&2), None);
+    assert_eq!(day.future.one, Some(Cow::Borrowed("in {0} day")));
+    assert_eq!(day.past.other, "{0} days ago");
+    assert_eq!(en.get().narrow.minute.past.other, "{0}m ago");
+
+    // Arabic has phrases for two days and a pattern for every plural category.
+    let ar = load(langid!("ar")).unwrap();
+    let day = &ar.get().long.day;
+    assert_eq!(day.relatives.get(&-2).map(|s| &**s), Some("أول أمس"));
+    assert_eq!(day.past.two, Some(Cow::Borrowed("قبل يومين")));
+    assert!(day.future.zero.is_some() && day.future.many.is_some());
+
+    // The extra test locales have no date fields.
+    assert!(load(langid!("haw")).is_err());
+}
@@ -0,0 +1,275 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::cldr_langid::CldrLangID;
+use crate::error::Error;
+use crate::CldrPaths;
+use icu_datetime::date::IsoWeekday;
+use icu_datetime::provider::{key, week_data::*};
+use icu_locid::LanguageIdentifier;
+use icu_provider::iter::{IterableDataProviderCore, KeyedDataProvider};
+use icu_provider::prelude::*;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// All keys that this module is able to produce.
+pub const ALL_KEYS: [ResourceKey; 1] = [
+    key::WEEK_DATA_V1, //
+];
+
+/// The CLDR region code of the week data that applies when a region has no data of its own.
+const DEFAULT_REGION: &str = "001";
+
+/// A data provider reading from the CLDR JSON week data file.
+///
+/// Week data is defined per region. The region of a locale is its region subtag, or the likely
+/// region of its language if it has none.
+#[derive(PartialEq, Debug)]
+pub struct WeekDataProvider<'d> {
+    first_weekday: BTreeMap<String, IsoWeekday>,
+    min_week_days: BTreeMap<String, u8>,
+    likely_subtags: Vec<(LanguageIdentifier, LanguageIdentifier)>,
+    /// The locales of the CLDR dates data, which are the locales this provider lists as supported.
+    langids: Vec<CldrLangID>,
+    _phantom: PhantomData<&'d ()>, // placeholder for when we need the lifetime param
+}
+
+impl TryFrom<&dyn CldrPaths> for WeekDataProvider<'_> {
+    type Error = Error;
+    fn try_from(cldr_paths: &dyn CldrPaths) -> Result<Self, Self::Error> {
+        let week_data: cldr_json::WeekDataResource = {
+            let path = cldr_paths
+                .cldr_core()?
+                .join("supplemental")
+                .join("weekData.json");
+            serde_json::from_reader(cldr_paths.open_reader(&path)?).map_err(|e| (e, path))?
+        };
+        let likely_subtags: cldr_json::LikelySubtagsResource = {
+            let path = cldr_paths
+                .cldr_core()?
+                .join("supplemental")
+                .join("likelySubtags.json");
+            serde_json::from_reader(cldr_paths.open_reader(&path)?).map_err(|e| (e, path))?
+        };
+
+        let mut langids = vec![];
+        let path = cldr_paths.cldr_dates()?.join("main");
+        for dir in cldr_paths.get_subdirectories(&path)? {
+            let name = dir.file_name().and_then(|name| name.to_str()).unwrap_or("");
+            let langid: CldrLangID = name
+                .parse()
+                .map_err(|_| Error::Custom(format!("Invalid locale directory: {:?}", dir), None))?;
+            langids.push(langid);
+        }
+        langids.sort();
+
+        let week_data = week_data.supplemental.week_data;
+        let first_weekday = week_data
+            .first_day
+            .into_iter()
+            .map(|(region, day)| Ok((region, parse_weekday(&day)?)))
+            .collect::<Result<_, Error>>()?;
+        let min_week_days = week_data
+            .min_days
+            .into_iter()
+            .map(|(region, days)| match days.parse() {
+                Ok(days) => Ok((region, days)),
+                Err(_) => Err(Error::Custom(format!("Invalid minDays: {:?}", days), None)),
+            })
+            .collect::<Result<_, Error>>()?;
+
+        Ok(Self {
+            first_weekday,
+            min_week_days,
+            likely_subtags: likely_subtags.supplemental.likely_subtags,
+            langids,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+fn parse_weekday(day: &str) -> Result<IsoWeekday, Error> {
+    match day {
+        "mon" => Ok(IsoWeekday::Monday),
+        "tue" => Ok(IsoWeekday::Tuesday),
+        "wed" => Ok(IsoWeekday::Wednesday),
+        "thu" => Ok(IsoWeekday::Thursday),
+        "fri" => Ok(IsoWeekday::Friday),
+        "sat" => Ok(IsoWeekday::Saturday),
+        "sun" => Ok(IsoWeekday::Sunday),
+        _ => Err(Error::Custom(format!("Invalid firstDay: {:?}", day), None)),
+    }
+}
+
+impl<'d> WeekDataProvider<'d> {
+    /// Returns the region whose week data applies to `langid`.
+    fn get_region(&self, langid: &LanguageIdentifier) -> Option<String> {
+        if let Some(region) = langid.region {
+            return Some(region.as_str().to_string());
+        }
+        // The root locale uses the default week data rather than the data of the likely
+        // region of "und".
+        if *langid == LanguageIdentifier::und() {
+            return None;
+        }
+        // Look up the likely subtags of language-script first, then of the language alone.
+        let find = |script| {
+            self.likely_subtags.iter().find(|(from, _)| {
+                from.language == langid.language && from.script == script && from.region.is_none()
+            })
+        };
+        find(langid.script)
+            .or_else(|| find(None))
+            .and_then(|(_, to)| to.region)
+            .map(|region| region.as_str().to_string())
+    }
+
+    fn get<T: Copy>(map: &BTreeMap<String, T>, region: &Option<String>) -> Option<T> {
+        region
+            .as_ref()
+            .and_then(|region| map.get(region))
+            .or_else(|| map.get(DEFAULT_REGION))
+            .copied()
+    }
+}
+
+impl<'d> KeyedDataProvider for WeekDataProvider<'d> {
+    fn supports_key(resc_key: &ResourceKey) -> Result<(), DataError> {
+        key::WEEK_DATA_V1.match_key(*resc_key)
+    }
+}
+
+impl<'d, 's> DataProvider<'d, 's, WeekDataV1Marker> for WeekDataProvider<'d> {
+    fn load_payload(
+        &self,
+        req: &DataRequest,
+    ) -> Result<DataResponse<'d, 's, WeekDataV1Marker>, DataError> {
+        WeekDataProvider::supports_key(&req.resource_path.key)?;
+        let region = self.get_region(req.try_langid()?);
+        let week_data = match (
+            Self::get(&self.first_weekday, &region),
+            Self::get(&self.min_week_days, &region),
+        ) {
+            (Some(first_weekday), Some(min_week_days)) => WeekDataV1 {
+                first_weekday,
+                min_week_days,
+            },
+            _ => return Err(DataError::UnavailableResourceOptions(req.clone())),
+        };
+        Ok(DataResponse {
+            metadata: DataResponseMetadata {
+                data_langid: req.resource_path.options.langid.clone(),
+            },
+            payload: Some(DataPayload::from_owned(week_data)),
+        })
+    }
+}
+
+icu_provider::impl_dyn_provider!(WeekDataProvider<'d>, {
+    _ => WeekDataV1Marker,
+}, SERDE_SE, 'd, 's);
+
+impl<'d> IterableDataProviderCore for WeekDataProvider<'d> {
+    fn supported_options_for_key(
+        &self,
+        _resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions>>, DataError> {
+        let list: Vec<ResourceOptions> = self
+            .langids
+            .iter()
+            .map(|l| ResourceOptions {
+                variant: None,
+                // TODO: Avoid the clone
+                langid: Some(l.langid.clone()),
+            })
+            .collect();
+        Ok(Box::new(list.into_iter()))
+    }
+}
+
+/// Serde structs for the CLDR JSON week data and likely subtags files.
+pub(self) mod cldr_json {
+    use icu_locid::LanguageIdentifier;
+    use serde::Deserialize;
+    use std::collections::BTreeMap;
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct WeekData {
+        #[serde(rename = "minDays")]
+        pub min_days: BTreeMap<String, String>,
+        #[serde(rename = "firstDay")]
+        pub first_day: BTreeMap<String, String>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct WeekDataSupplemental {
+        #[serde(rename = "weekData")]
+        pub week_data: WeekData,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct WeekDataResource {
+        pub supplemental: WeekDataSupplemental,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LikelySubtagsSupplemental {
+        #[serde(with = "tuple_vec_map", rename = "likelySubtags")]
+        pub likely_subtags: Vec<(LanguageIdentifier, LanguageIdentifier)>,
+    }
+
+    #[derive(PartialEq, Debug, Deserialize)]
+    pub struct LikelySubtagsResource {
+        pub supplemental: LikelySubtagsSupplemental,
+    }
+}
+
+#[test]
+fn test_basic() {
+    use icu_locid_macros::langid;
+
+    let cldr_paths = crate::cldr_paths::for_test();
+    let provider = WeekDataProvider::try_from(&cldr_paths as &dyn CldrPaths).unwrap();
+
+    let load = |langid: LanguageIdentifier| -> WeekDataV1 {
+        let payload: DataPayload<WeekDataV1Marker> = provider
+            .load_payload(&DataRequest {
+                resource_path: ResourcePath {
+                    key: key::WEEK_DATA_V1,
+                    options: ResourceOptions {
+                        variant: None,
+                        langid: Some(langid),
+                    },
+                },
+            })
+            .unwrap()
+            .take_payload()
+            .unwrap();
+        *payload.get()
+    };
+
+    // "en" is likely spoken in the US, where weeks start on Sunday.
+    let us = WeekDataV1 {
+        first_weekday: IsoWeekday::Sunday,
+        min_week_days: 1,
+    };
+    assert_eq!(load(langid!("en")), us);
+    assert_eq!(load(langid!("en-US")), us);
+
+    // France uses ISO-8601 weeks.
+    let iso = WeekDataV1 {
+        first_weekday: IsoWeekday::Monday,
+        min_week_days: 4,
+    };
+    assert_eq!(load(langid!("fr")), iso);
+    assert_eq!(load(langid!("en-FR")), iso);
+
+    assert_eq!(load(langid!("ar-EG")).first_weekday, IsoWeekday::Saturday);
+
+    // The root locale and regions without data of their own use the default for the world.
+    assert_eq!(load(langid!("und")), WeekDataV1::default());
+    assert_eq!(load(langid!("en-001")), WeekDataV1::default());
+    assert_eq!(load(langid!("es-AR")), WeekDataV1::default());
+}
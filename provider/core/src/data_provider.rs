@@ -15,6 +15,7 @@ use icu_locid::LanguageIdentifier;
 use std::convert::TryFrom;
 use std::fmt;
 use std::fmt::Debug;
+use std::marker::PhantomData;
 use std::rc::Rc;
 
 /// A struct to request a certain piece of data from a data provider.
@@ -45,6 +46,33 @@ impl From<ResourceKey> for DataRequest {
     }
 }
 
+/// Create a [`DataRequest`] to a particular [`ResourceKey`] and [`LanguageIdentifier`] with no
+/// variant.
+///
+/// # Examples
+///
+/// ```
+/// use icu_provider::prelude::*;
+/// use icu_locid_macros::langid;
+///
+/// const FOO_BAR: ResourceKey = icu_provider::resource_key!(x, "foo", "bar", 1);
+///
+/// let req = DataRequest::from((FOO_BAR, langid!("ar-EG")));
+///
+/// assert_eq!(req.resource_path.key, FOO_BAR);
+/// assert_eq!(req.resource_path.options, ResourceOptions::from_langid(langid!("ar-EG")));
+/// ```
+impl From<(ResourceKey, LanguageIdentifier)> for DataRequest {
+    fn from((key, langid): (ResourceKey, LanguageIdentifier)) -> Self {
+        Self {
+            resource_path: ResourcePath {
+                key,
+                options: langid.into(),
+            },
+        }
+    }
+}
+
 impl DataRequest {
     /// Returns the [`LanguageIdentifier`] for this [`DataRequest`], or an error if it is not present.
     ///
@@ -344,6 +372,26 @@ where
     ///
     /// assert_eq!("Hello World", payload.get());
     /// ```
+    ///
+    /// The cart is never mutated, so patching a borrowed payload leaves the source data
+    /// untouched; only the mutated fields are copied:
+    ///
+    /// ```
+    /// use icu_provider::prelude::*;
+    /// use icu_provider::hello_world::*;
+    /// use std::borrow::Cow;
+    ///
+    /// let local_struct = HelloWorldV1 {
+    ///     message: Cow::Borrowed("Hello World"),
+    /// };
+    ///
+    /// let mut payload = DataPayload::<HelloWorldV1Marker>::from_borrowed(&local_struct);
+    ///
+    /// payload.with_mut(|obj| obj.message = Cow::Borrowed("Hallo Welt"));
+    ///
+    /// assert_eq!("Hallo Welt", payload.get().message);
+    /// assert_eq!("Hello World", local_struct.message);
+    /// ```
     pub fn with_mut<'a, F>(&'a mut self, f: F)
     where
         F: 'static + for<'b> FnOnce(&'b mut <M::Yokeable as Yokeable<'a>>::Output),
@@ -382,6 +430,60 @@ where
             RcBuf(yoke) => yoke.get(),
         }
     }
+
+    /// Maps `DataPayload<M>` to `DataPayload<N>` by projecting it with [`Yoke::project`].
+    ///
+    /// This is useful for narrowing a payload down to one of its fields without copying or
+    /// reloading any data. The backing cart is kept alive by the new payload; it therefore
+    /// needs to be the same for `M` and `N`.
+    ///
+    /// # Examples
+    ///
+    /// Narrowing a [`HelloWorldV1`](crate::hello_world::HelloWorldV1) down to its message:
+    ///
+    /// ```
+    /// use icu_provider::prelude::*;
+    /// use icu_provider::hello_world::*;
+    /// use std::borrow::Cow;
+    ///
+    /// struct HelloWorldMessageMarker;
+    ///
+    /// impl<'s> DataMarker<'s> for HelloWorldMessageMarker {
+    ///     type Yokeable = Cow<'static, str>;
+    ///     type Cart = HelloWorldV1<'s>;
+    /// }
+    ///
+    /// let local_struct = HelloWorldV1 {
+    ///     message: Cow::Borrowed("example"),
+    /// };
+    ///
+    /// let payload = DataPayload::<HelloWorldV1Marker>::from_borrowed(&local_struct);
+    /// let message: DataPayload<HelloWorldMessageMarker> =
+    ///     payload.map_project(|obj, _| obj.message);
+    ///
+    /// assert_eq!("example", message.get());
+    /// assert!(matches!(message.get(), Cow::Borrowed(_)));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn map_project<N>(
+        self,
+        f: for<'a> fn(
+            <M::Yokeable as Yokeable<'a>>::Output,
+            PhantomData<&'a ()>,
+        ) -> <N::Yokeable as Yokeable<'a>>::Output,
+    ) -> DataPayload<'d, 's, N>
+    where
+        N: DataMarker<'s, Cart = M::Cart>,
+    {
+        use DataPayloadInner::*;
+        let inner = match self.inner {
+            Borrowed(yoke) => Borrowed(yoke.project(f)),
+            RcStruct(yoke) => RcStruct(yoke.project(f)),
+            Owned(yoke) => Owned(yoke.project(f)),
+            RcBuf(yoke) => RcBuf(yoke.project(f)),
+        };
+        DataPayload { inner }
+    }
 }
 
 /// A response object containing an object as payload and metadata about it.
@@ -76,7 +76,8 @@ impl<'s> DataMarker<'s> for ErasedDataStructMarker {
 impl<'d, M> crate::dynutil::UpcastDataPayload<'d, 'static, M> for ErasedDataStructMarker
 where
     M: DataMarker<'static>,
-    M::Cart: Sized,
+    M::Cart: Sized + Clone,
+    for<'a> <M::Yokeable as Yokeable<'a>>::Output: Clone,
 {
     /// Upcast for ErasedDataStruct performs the following mapping of the data payload variants,
     /// where `Y` is the concrete Yokeable and `S` is ErasedDataStruct Yokeable:
@@ -165,6 +166,7 @@ impl<'d> DataPayload<'d, 'static, ErasedDataStructMarker> {
         M: DataMarker<'static>,
         M::Cart: Sized,
         M::Yokeable: ZeroCopyFrom<M::Cart>,
+        for<'a> <M::Yokeable as Yokeable<'a>>::Output: Clone,
     {
         use crate::data_provider::DataPayloadInner::*;
         match self.inner {
@@ -193,8 +195,14 @@ impl<'d> DataPayload<'d, 'static, ErasedDataStructMarker> {
                                 inner: RcStruct(yoke),
                             })
                         }
-                        // Note: We could consider cloning the Yoke instead of erroring out.
-                        Err(_) => return Err(Error::MultipleReferences),
+                        // The Yoke is still shared, for example by a cache, so we cannot take
+                        // ownership of it. Clone it instead; this shares the cart and keeps any
+                        // changes made to the Yokeable.
+                        Err(rc_yoke) => {
+                            return Ok(DataPayload {
+                                inner: RcStruct(Yoke::clone(&rc_yoke)),
+                            })
+                        }
                     },
                     Err(any_rc) => any_rc,
                 };
@@ -203,8 +211,12 @@ impl<'d> DataPayload<'d, 'static, ErasedDataStructMarker> {
                 let any_rc = match y2 {
                     Ok(rc_yoke) => match Rc::try_unwrap(rc_yoke) {
                         Ok(yoke) => return Ok(DataPayload { inner: Owned(yoke) }),
-                        // Note: We could consider cloning the Yoke instead of erroring out.
-                        Err(_) => return Err(Error::MultipleReferences),
+                        // The Yoke is still shared; clone it as in the RcStruct case.
+                        Err(rc_yoke) => {
+                            return Ok(DataPayload {
+                                inner: Owned(Yoke::clone(&rc_yoke)),
+                            })
+                        }
                     },
                     Err(any_rc) => any_rc,
                 };
@@ -213,8 +225,12 @@ impl<'d> DataPayload<'d, 'static, ErasedDataStructMarker> {
                 let any_rc = match y2 {
                     Ok(rc_yoke) => match Rc::try_unwrap(rc_yoke) {
                         Ok(yoke) => return Ok(DataPayload { inner: RcBuf(yoke) }),
-                        // Note: We could consider cloning the Yoke instead of erroring out.
-                        Err(_) => return Err(Error::MultipleReferences),
+                        // The Yoke is still shared; clone it as in the RcStruct case.
+                        Err(rc_yoke) => {
+                            return Ok(DataPayload {
+                                inner: RcBuf(Yoke::clone(&rc_yoke)),
+                            })
+                        }
                     },
                     Err(any_rc) => any_rc,
                 };
@@ -235,12 +251,10 @@ impl<'d> DataPayload<'d, 'static, ErasedDataStructMarker> {
 
 impl<T> ErasedDataStruct for T
 where
-    T: Any,
-    for<'a> &'a T: Clone,
+    T: Any + Clone,
 {
     fn clone_into_box(&self) -> Box<dyn ErasedDataStruct> {
-        todo!("#753")
-        // Box::new(self.clone())
+        Box::new(self.clone())
     }
     fn into_any(self: Box<Self>) -> Box<dyn Any> {
         self
@@ -288,7 +302,8 @@ where
 impl<'d, M> DataProvider<'d, 'static, M> for dyn ErasedDataProvider<'d> + 'd
 where
     M: DataMarker<'static>,
-    <M::Yokeable as Yokeable<'static>>::Output: Clone + Any,
+    <M::Yokeable as Yokeable<'static>>::Output: Any,
+    for<'a> <M::Yokeable as Yokeable<'a>>::Output: Clone,
     M::Yokeable: ZeroCopyFrom<M::Cart>,
     M::Cart: Sized,
 {
@@ -331,6 +346,30 @@ mod test {
         assert_eq!(downcasted.get(), "foo");
     }
 
+    #[test]
+    fn test_erased_case_2_shared() {
+        use crate::data_provider::DataPayloadInner;
+        let data = Rc::new("foo".to_string());
+        let mut original = DataPayload::<CowStringMarker>::from_partial_owned(data);
+        // Changes to the Yokeable must survive the downcast of a shared payload.
+        original.with_mut(|s| s.to_mut().push_str("bar"));
+        let upcasted = ErasedDataStructMarker::upcast(original);
+        // Simulate a cache holding on to the erased data struct
+        let cart: Rc<dyn ErasedDataStruct> = match &upcasted.inner {
+            DataPayloadInner::RcStruct(yoke) => yoke.backing_cart().clone(),
+            _ => panic!("Expected an RcStruct payload"),
+        };
+        let cached = DataPayload::<ErasedDataStructMarker>::from_partial_owned(cart);
+        let downcasted = upcasted
+            .downcast::<CowStringMarker>()
+            .expect("Type conversion");
+        assert_eq!(downcasted.get(), "foobar");
+        let downcasted = cached
+            .downcast::<CowStringMarker>()
+            .expect("Type conversion");
+        assert_eq!(downcasted.get(), "foobar");
+    }
+
     #[test]
     fn test_erased_case_3() {
         let data = "foo".to_string();
@@ -342,6 +381,23 @@ mod test {
         assert_eq!(downcasted.get(), "foo");
     }
 
+    #[test]
+    fn test_erased_case_3_shared() {
+        use crate::data_provider::DataPayloadInner;
+        let data = "foo".to_string();
+        let original = DataPayload::<CowStringMarker>::from_owned(Cow::Owned(data));
+        let upcasted = ErasedDataStructMarker::upcast(original);
+        let cart: Rc<dyn ErasedDataStruct> = match &upcasted.inner {
+            DataPayloadInner::RcStruct(yoke) => yoke.backing_cart().clone(),
+            _ => panic!("Expected an RcStruct payload"),
+        };
+        let downcasted = upcasted
+            .downcast::<CowStringMarker>()
+            .expect("Type conversion");
+        assert_eq!(downcasted.get(), "foo");
+        drop(cart);
+    }
+
     #[test]
     fn test_erased_case_4() {
         let data: Rc<[u8]> = "foo".as_bytes().into();
@@ -355,4 +411,38 @@ mod test {
             .expect("Type conversion");
         assert_eq!(downcasted.get(), "foo");
     }
+
+    #[test]
+    fn test_erased_case_4_shared() {
+        use crate::data_provider::DataPayloadInner;
+        let data: Rc<[u8]> = "foo".as_bytes().into();
+        let original = DataPayload::<CowStringMarker>::try_from_rc_buffer_badly(data, |bytes| {
+            std::str::from_utf8(bytes).map(|s| Cow::Borrowed(s))
+        })
+        .expect("String is valid UTF-8");
+        let upcasted = ErasedDataStructMarker::upcast(original);
+        let cart: Rc<dyn ErasedDataStruct> = match &upcasted.inner {
+            DataPayloadInner::RcStruct(yoke) => yoke.backing_cart().clone(),
+            _ => panic!("Expected an RcStruct payload"),
+        };
+        let downcasted = upcasted
+            .downcast::<CowStringMarker>()
+            .expect("Type conversion");
+        assert_eq!(downcasted.get(), "foo");
+        drop(cart);
+    }
+
+    #[test]
+    fn test_erased_clone_into_box() {
+        use crate::hello_world::HelloWorldV1;
+        let data = HelloWorldV1 {
+            message: Cow::Borrowed("Hello World"),
+        };
+        let erased: Box<dyn ErasedDataStruct> = Box::new(data);
+        let cloned = erased.clone();
+        let original: Box<HelloWorldV1> = erased.into_any().downcast().expect("Types should match");
+        let cloned: Box<HelloWorldV1> = cloned.into_any().downcast().expect("Types should match");
+        assert_eq!(original.message, "Hello World");
+        assert_eq!(original, cloned);
+    }
 }
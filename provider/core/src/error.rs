@@ -58,9 +58,30 @@ pub enum Error {
     /// The data provider encountered some other error when loading the resource, such as I/O.
     #[displaydoc("Failed to load resource: {0}")]
     Resource(Box<dyn std::error::Error + Send + Sync>),
+
+    /// An error annotated with a description of the operation that failed, such as the resource
+    /// key and language identifier being loaded. See [`Error::context()`].
+    #[displaydoc("{context}: {source}")]
+    Context {
+        /// Description of the operation that failed.
+        context: String,
+
+        /// The underlying error.
+        source: Box<Error>,
+    },
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "erased-serde")]
+            Self::Serde(err) => Some(err),
+            Self::Resource(err) => Some(err.as_ref()),
+            Self::Context { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
 
 #[cfg(feature = "erased-serde")]
 impl From<erased_serde::Error> for Error {
@@ -82,6 +103,35 @@ impl Error {
     {
         Self::Resource(Box::new(err))
     }
+
+    /// Wraps this error with a description of the operation that failed, such as the resource
+    /// key and language identifier being loaded. The original error remains available via
+    /// [`std::error::Error::source()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider::prelude::*;
+    /// use std::error::Error;
+    ///
+    /// let key = icu_provider::resource_key!(Plurals, "cardinal", 1);
+    /// let err = DataError::UnsupportedResourceKey(key).context("en");
+    ///
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "en: Unsupported resource key: plurals/cardinal@1"
+    /// );
+    /// assert!(matches!(
+    ///     err.source().and_then(|e| e.downcast_ref::<DataError>()),
+    ///     Some(DataError::UnsupportedResourceKey(_))
+    /// ));
+    /// ```
+    pub fn context(self, context: &str) -> Self {
+        Self::Context {
+            context: context.to_string(),
+            source: Box::new(self),
+        }
+    }
 }
 
 impl From<&ResourceKey> for Error {
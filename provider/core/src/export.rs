@@ -67,15 +67,9 @@ where
     P: IterableDataProvider<'d, 's, M> + ?Sized,
     E: DataExporter<'d, 's, M> + ?Sized,
 {
-    let it = provider.supported_options_for_key(resc_key)?;
+    let it = provider.iter_requests(resc_key)?;
     let try_export = || -> Result<(), Error> {
-        for options in it {
-            let req = DataRequest {
-                resource_path: ResourcePath {
-                    key: *resc_key,
-                    options,
-                },
-            };
+        for req in it {
             let payload = provider.load_payload(&req)?.take_payload()?;
             exporter.put_payload(req, payload)?;
         }
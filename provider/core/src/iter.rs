@@ -17,6 +17,42 @@ pub trait IterableDataProviderCore {
         &self,
         resc_key: &ResourceKey,
     ) -> Result<Box<dyn Iterator<Item = ResourceOptions> + '_>, Error>;
+
+    /// Given a [`ResourceKey`], returns a boxed iterator over [`DataRequest`]s for all
+    /// supported [`ResourceOptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider::prelude::*;
+    /// use icu_provider::hello_world::*;
+    /// use icu_provider::iter::IterableDataProviderCore;
+    ///
+    /// let provider = HelloWorldProvider::new_with_placeholder_data();
+    ///
+    /// for req in provider.iter_requests(&key::HELLO_WORLD_V1).unwrap() {
+    ///     assert_eq!(req.resource_path.key, key::HELLO_WORLD_V1);
+    ///     let payload: DataPayload<HelloWorldV1Marker> = provider
+    ///         .load_payload(&req)
+    ///         .expect("Supported requests should load")
+    ///         .take_payload()
+    ///         .expect("Data should be present");
+    ///     assert!(!payload.get().message.is_empty());
+    /// }
+    /// ```
+    fn iter_requests(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = DataRequest> + '_>, Error> {
+        let resc_key = *resc_key;
+        let it = self.supported_options_for_key(&resc_key)?;
+        Ok(Box::new(it.map(move |options| DataRequest {
+            resource_path: ResourcePath {
+                key: resc_key,
+                options,
+            },
+        })))
+    }
 }
 
 /// A super-trait combining [`DataProvider`] and [`IterableDataProviderCore`], auto-implemented
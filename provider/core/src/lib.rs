@@ -38,6 +38,9 @@
 //! - [`StructProvider`] wraps a particular instance of a struct and returns it.
 //! - [`HelloWorldProvider`] returns "hello world" strings in several languages.
 //!
+//! [`RecordingProvider`] wraps another data provider and records the requests it receives, which
+//! is useful for asserting which data a component loads.
+//!
 //! ## Types and Lifetimes
 //!
 //! Types compatible with [`Yokeable`] can be passed through the data provider, so long as they are
@@ -102,6 +105,7 @@
 //! [`InvariantDataProvider`]: inv::InvariantDataProvider
 //! [`StructProvider`]: struct_provider::StructProvider
 //! [`HelloWorldProvider`]: hello_world::HelloWorldProvider
+//! [`RecordingProvider`]: recording::RecordingProvider
 //! [`ErasedDataProvider`]: erased::ErasedDataProvider
 //! [`SerdeDeDataProvider`]: serde::SerdeDeDataProvider
 //! [`SerdeSeDataStruct`]: serde::SerdeSeDataStruct
@@ -123,6 +127,7 @@ pub mod inv;
 pub mod iter;
 #[macro_use]
 pub mod marker;
+pub mod recording;
 #[cfg(feature = "provider_serde")]
 pub mod serde;
 pub mod struct_provider;
@@ -133,6 +138,7 @@ pub use icu_provider_macros::data_struct;
 mod error;
 
 pub use error::Error as DataError;
+pub use resource::ResourceKeyParseError;
 
 pub mod prelude {
     //! Core selection of APIs and structures for [`DataProvider`].
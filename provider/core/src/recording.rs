@@ -0,0 +1,125 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider that records the requests it receives, for testing.
+
+use crate::iter::IterableDataProviderCore;
+use crate::prelude::*;
+use std::cell::{Ref, RefCell};
+
+/// A data provider that forwards all requests to an inner provider and records them.
+///
+/// Responses and errors from the inner provider are returned unchanged. The recorded requests are
+/// available via [`RecordingProvider::requests()`], which makes it possible to assert which data
+/// a component loads.
+///
+/// # Examples
+///
+/// ```
+/// use icu_provider::hello_world::{key, HelloWorldProvider, HelloWorldV1Marker};
+/// use icu_provider::prelude::*;
+/// use icu_provider::recording::RecordingProvider;
+/// use icu_locid_macros::langid;
+///
+/// let provider = RecordingProvider::new(HelloWorldProvider::new_with_placeholder_data());
+///
+/// let request = DataRequest {
+///     resource_path: ResourcePath {
+///         key: key::HELLO_WORLD_V1,
+///         options: ResourceOptions {
+///             variant: None,
+///             langid: Some(langid!("de")),
+///         },
+///     },
+/// };
+/// let german_hello_world: DataPayload<HelloWorldV1Marker> = provider
+///     .load_payload(&request)
+///     .expect("Loading should succeed")
+///     .take_payload()
+///     .expect("Data should be present");
+///
+/// assert_eq!("Hallo Welt", german_hello_world.get().message);
+/// assert_eq!(&*provider.requests(), &[request]);
+/// ```
+#[derive(Debug)]
+pub struct RecordingProvider<D> {
+    /// The data provider to which we delegate requests.
+    pub inner: D,
+    requests: RefCell<Vec<DataRequest>>,
+}
+
+impl<D> RecordingProvider<D> {
+    /// Creates a [`RecordingProvider`] wrapping `inner`, with no requests recorded.
+    pub fn new(inner: D) -> Self {
+        Self {
+            inner,
+            requests: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Returns the requests received so far, in order.
+    pub fn requests(&self) -> Ref<'_, [DataRequest]> {
+        Ref::map(self.requests.borrow(), Vec::as_slice)
+    }
+
+    /// Returns the requests received so far, in order, and clears the record.
+    pub fn take_requests(&self) -> Vec<DataRequest> {
+        self.requests.replace(Vec::new())
+    }
+}
+
+impl<'d, 's, D, M> DataProvider<'d, 's, M> for RecordingProvider<D>
+where
+    M: DataMarker<'s>,
+    D: DataProvider<'d, 's, M>,
+{
+    fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'d, 's, M>, DataError> {
+        self.requests.borrow_mut().push(req.clone());
+        self.inner.load_payload(req)
+    }
+}
+
+impl<D> IterableDataProviderCore for RecordingProvider<D>
+where
+    D: IterableDataProviderCore,
+{
+    fn supported_options_for_key(
+        &self,
+        resc_key: &ResourceKey,
+    ) -> Result<Box<dyn Iterator<Item = ResourceOptions> + '_>, DataError> {
+        self.inner.supported_options_for_key(resc_key)
+    }
+}
+
+#[test]
+fn test_errors_are_forwarded() {
+    use crate::hello_world::{HelloWorldProvider, HelloWorldV1Marker};
+    use crate::inv::InvariantDataProvider;
+
+    let provider = RecordingProvider::new(HelloWorldProvider::new_with_placeholder_data());
+    let request = DataRequest {
+        resource_path: ResourcePath {
+            key: crate::hello_world::key::HELLO_WORLD_V1,
+            options: ResourceOptions::default(),
+        },
+    };
+
+    // HelloWorldProvider requires a langid.
+    let expected: Option<DataError> =
+        DataProvider::<HelloWorldV1Marker>::load_payload(&provider.inner, &request).err();
+    let actual: Option<DataError> =
+        DataProvider::<HelloWorldV1Marker>::load_payload(&provider, &request).err();
+    assert!(actual.is_some());
+    assert_eq!(format!("{:?}", actual), format!("{:?}", expected));
+    assert_eq!(provider.take_requests(), vec![request.clone()]);
+    assert!(provider.requests().is_empty());
+
+    let provider = RecordingProvider::new(InvariantDataProvider);
+    let _: DataPayload<HelloWorldV1Marker> = provider
+        .load_payload(&request)
+        .unwrap()
+        .take_payload()
+        .unwrap();
+    assert_eq!(&*provider.requests(), &[request]);
+}
@@ -5,12 +5,14 @@
 //! Resource paths and related types.
 
 use crate::error::Error;
+use displaydoc::Display;
 use icu_locid::LanguageIdentifier;
 use std::borrow::Borrow;
 use std::borrow::Cow;
 use std::default::Default;
 use std::fmt;
 use std::fmt::Write;
+use std::str::FromStr;
 use tinystr::{TinyStr16, TinyStr4};
 
 /// A top-level collection of related resource keys.
@@ -47,6 +49,39 @@ impl ResourceCategory {
     }
 }
 
+impl FromStr for ResourceCategory {
+    type Err = ResourceKeyParseError;
+
+    /// Parses the string form of a [`ResourceCategory`], as returned by
+    /// [`ResourceCategory::as_str()`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider::prelude::*;
+    ///
+    /// assert_eq!(Ok(ResourceCategory::Plurals), "plurals".parse());
+    /// assert_eq!("x-foo", "x-foo".parse::<ResourceCategory>().unwrap().as_str());
+    /// assert!("foo".parse::<ResourceCategory>().is_err());
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "core" => Ok(Self::Core),
+            "datetime" => Ok(Self::DateTime),
+            "decimal" => Ok(Self::Decimal),
+            "locale_canonicalizer" => Ok(Self::LocaleCanonicalizer),
+            "plurals" => Ok(Self::Plurals),
+            "time_zone" => Ok(Self::TimeZone),
+            "uniset" => Ok(Self::UnicodeSet),
+            _ => input
+                .strip_prefix("x-")
+                .and_then(|id| id.parse().ok())
+                .map(Self::PrivateUse)
+                .ok_or_else(|| ResourceKeyParseError::UnknownCategory(input.to_string())),
+        }
+    }
+}
+
 impl fmt::Display for ResourceCategory {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(&self.as_str())
@@ -208,6 +243,79 @@ impl ResourceKey {
     }
 }
 
+impl FromStr for ResourceKey {
+    type Err = ResourceKeyParseError;
+
+    /// Parses a [`ResourceKey`] from its string form, `category/subcategory@version`, as
+    /// produced by its [`Display`](fmt::Display) implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider::prelude::*;
+    /// use icu_provider::ResourceKeyParseError;
+    ///
+    /// let resc_key: ResourceKey = "plurals/ordinal@1".parse()
+    ///     .expect("Failed to parse a resource key.");
+    /// assert_eq!(icu_provider::resource_key!(Plurals, "ordinal", 1), resc_key);
+    ///
+    /// assert!(matches!(
+    ///     "plurals/ordinal".parse::<ResourceKey>(),
+    ///     Err(ResourceKeyParseError::InvalidSyntax(_))
+    /// ));
+    /// assert!(matches!(
+    ///     "foo/ordinal@1".parse::<ResourceKey>(),
+    ///     Err(ResourceKeyParseError::UnknownCategory(_))
+    /// ));
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let invalid_syntax = || ResourceKeyParseError::InvalidSyntax(input.to_string());
+        let slash = input.find('/').ok_or_else(invalid_syntax)?;
+        let at = input.rfind('@').ok_or_else(invalid_syntax)?;
+        if at < slash {
+            return Err(invalid_syntax());
+        }
+        let (category, sub_category, version) =
+            (&input[..slash], &input[slash + 1..at], &input[at + 1..]);
+        let category = category.parse()?;
+        let sub_category = Some(sub_category)
+            .filter(|s| !s.contains(&['/', '@'][..]))
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| ResourceKeyParseError::InvalidSubCategory(sub_category.to_string()))?;
+        let version = Some(version)
+            .filter(|s| s.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| ResourceKeyParseError::InvalidVersion(version.to_string()))?;
+        Ok(Self {
+            category,
+            sub_category,
+            version,
+        })
+    }
+}
+
+/// An error that occurs when parsing a [`ResourceKey`] or a [`ResourceCategory`] from a string.
+#[derive(Display, Debug, PartialEq, Clone)]
+pub enum ResourceKeyParseError {
+    /// The string is not of the form `category/subcategory@version`.
+    #[displaydoc("Invalid resource key, expected category/subcategory@version: {0}")]
+    InvalidSyntax(String),
+
+    /// The category is neither a known [`ResourceCategory`] nor a valid private-use category.
+    #[displaydoc("Unknown resource category: {0}")]
+    UnknownCategory(String),
+
+    /// The subcategory is empty, longer than 16 bytes, or contains invalid characters.
+    #[displaydoc("Invalid resource subcategory: {0}")]
+    InvalidSubCategory(String),
+
+    /// The version is not an integer between 0 and 65535.
+    #[displaydoc("Invalid resource key version: {0}")]
+    InvalidVersion(String),
+}
+
+impl std::error::Error for ResourceKeyParseError {}
+
 /// The standard components of a [`ResourceKey`] path.
 pub struct ResourceKeyComponents {
     components: [Cow<'static, str>; 2],
@@ -307,6 +415,30 @@ impl From<LanguageIdentifier> for ResourceOptions {
 }
 
 impl ResourceOptions {
+    /// Creates a [`ResourceOptions`] with the given language identifier and no variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider::prelude::*;
+    /// use icu_locid_macros::langid;
+    ///
+    /// let resc_options = ResourceOptions::from_langid(langid!("pt-BR")).with_variant("GBP");
+    ///
+    /// assert_eq!("GBP/pt-BR", resc_options.to_string());
+    /// ```
+    pub fn from_langid(langid: LanguageIdentifier) -> Self {
+        langid.into()
+    }
+
+    /// Returns this [`ResourceOptions`] with the variant field set to `variant`.
+    pub fn with_variant(self, variant: &str) -> Self {
+        Self {
+            variant: Some(Cow::Owned(variant.to_string())),
+            ..self
+        }
+    }
+
     /// Gets the standard path components of this [`ResourceOptions`]. These components should be used when
     /// persisting the [`ResourceOptions`] on the filesystem or in structured data.
     ///
@@ -365,6 +497,28 @@ impl From<&ResourceOptions> for ResourceOptionsComponents {
     }
 }
 
+/// A [`ResourceKey`] and [`ResourceOptions`], identifying a single data entry.
+///
+/// The [`Display`](fmt::Display) form of a [`ResourcePath`] is the key followed by the
+/// components of the options, separated by slashes. Providers that store data under a string
+/// path, such as the blob and HTTP providers, use this form.
+///
+/// # Examples
+///
+/// ```
+/// use icu_provider::prelude::*;
+/// use icu_locid_macros::langid;
+/// use std::borrow::Cow;
+///
+/// let resource_path = ResourcePath {
+///     key: icu_provider::resource_key!(Plurals, "cardinal", 1),
+///     options: ResourceOptions {
+///         variant: Some(Cow::Borrowed("GBP")),
+///         langid: Some(langid!("ar")),
+///     },
+/// };
+/// assert_eq!("plurals/cardinal@1/GBP/ar", resource_path.to_string());
+/// ```
 #[derive(Clone, PartialEq)]
 pub struct ResourcePath {
     pub key: ResourceKey,
@@ -453,6 +607,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_key_from_string() {
+        for cas in get_key_test_cases().iter() {
+            assert_eq!(Ok(cas.resc_key), cas.expected.parse());
+        }
+    }
+
+    #[test]
+    fn test_key_from_invalid_string() {
+        use ResourceKeyParseError::*;
+        let cases: [(&str, ResourceKeyParseError); 9] = [
+            ("", InvalidSyntax("".to_string())),
+            ("core/cardinal", InvalidSyntax("core/cardinal".to_string())),
+            (
+                "core@1/cardinal",
+                InvalidSyntax("core@1/cardinal".to_string()),
+            ),
+            ("dates/cardinal@1", UnknownCategory("dates".to_string())),
+            (
+                "x-toolong/cardinal@1",
+                UnknownCategory("x-toolong".to_string()),
+            ),
+            ("core/@1", InvalidSubCategory("".to_string())),
+            ("core/a/b@1", InvalidSubCategory("a/b".to_string())),
+            ("core/cardinal@", InvalidVersion("".to_string())),
+            ("core/cardinal@65536", InvalidVersion("65536".to_string())),
+        ];
+        for (input, expected) in cases.iter() {
+            assert_eq!(Err(expected.clone()), input.parse::<ResourceKey>());
+        }
+    }
+
     struct OptionsTestCase {
         pub resc_options: ResourceOptions,
         pub expected: &'static str,
@@ -501,6 +687,24 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_options_builder() {
+        use icu_locid_macros::langid;
+        let cases = get_options_test_cases();
+        assert_eq!(
+            cases[0].resc_options,
+            ResourceOptions::from_langid(LanguageIdentifier::und())
+        );
+        assert_eq!(
+            cases[1].resc_options,
+            ResourceOptions::from_langid(LanguageIdentifier::und()).with_variant("GBP")
+        );
+        assert_eq!(
+            cases[2].resc_options,
+            ResourceOptions::from_langid(langid!("en-ZA")).with_variant("GBP")
+        );
+    }
+
     #[test]
     fn test_resource_path_to_string() {
         for key_cas in get_key_test_cases().iter() {
@@ -6,6 +6,7 @@ use icu_locid::LanguageIdentifier;
 use icu_locid_macros::langid;
 use icu_provider::hello_world::*;
 use icu_provider::iter::IterableDataProviderCore;
+use icu_provider::prelude::*;
 
 #[test]
 fn test_supported_langids() {
@@ -53,3 +54,22 @@ fn test_export() {
 
     assert_eq!(source_provider, dest_provider);
 }
+
+#[test]
+fn test_iter_requests() {
+    let provider = HelloWorldProvider::new_with_placeholder_data();
+    let requests: Vec<DataRequest> = provider
+        .iter_requests(&key::HELLO_WORLD_V1)
+        .unwrap()
+        .collect();
+    let options: Vec<ResourceOptions> = provider
+        .supported_options_for_key(&key::HELLO_WORLD_V1)
+        .unwrap()
+        .collect();
+
+    assert_eq!(requests.len(), options.len());
+    for (req, options) in requests.iter().zip(options) {
+        assert_eq!(req.resource_path.key, key::HELLO_WORLD_V1);
+        assert_eq!(req.resource_path.options, options);
+    }
+}
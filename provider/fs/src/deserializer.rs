@@ -27,7 +27,17 @@ pub enum Error {
     UnknownSyntax(SyntaxOption),
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Json(err) => Some(err),
+            #[cfg(feature = "bincode")]
+            Self::Bincode(err) => Some(err),
+            Self::DataProvider(err) => Some(err),
+            Self::UnknownSyntax(_) => None,
+        }
+    }
+}
 
 impl From<serde_json::error::Error> for Error {
     fn from(e: serde_json::error::Error) -> Self {
@@ -66,6 +76,20 @@ impl Error {
         };
         DataError::Resource(Box::new(crate_error))
     }
+
+    /// Like [`Error::into_resource_error`], but for data that was fetched from a URL.
+    #[cfg(feature = "http")]
+    pub fn into_url_resource_error(self, url: &str) -> DataError {
+        use crate::error::Error as CrateError;
+        let crate_error = match self {
+            Self::Json(err) => CrateError::UrlDeserializer(Box::new(err), url.to_string()),
+            #[cfg(feature = "bincode")]
+            Self::Bincode(err) => CrateError::UrlDeserializer(Box::new(err), url.to_string()),
+            Self::DataProvider(err) => CrateError::UrlDeserializer(Box::new(err), url.to_string()),
+            Self::UnknownSyntax(v) => CrateError::UnknownSyntax(v),
+        };
+        DataError::Resource(Box::new(crate_error))
+    }
 }
 
 /// Get a JSON zero-copy deserializer. Implemeted as a macro because the return type is complex/private.
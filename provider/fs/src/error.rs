@@ -19,9 +19,36 @@ pub enum Error {
     Serializer(erased_serde::Error, Option<PathBuf>),
     #[displaydoc("Unknown syntax {0:?}. Do you need to enable a feature?")]
     UnknownSyntax(SyntaxOption),
+    #[cfg(feature = "http")]
+    #[displaydoc("URL template must contain {{path}}: {0}")]
+    InvalidUrlTemplate(String),
+    #[cfg(feature = "http")]
+    #[displaydoc("{0}: {1}")]
+    Http(reqwest::Error, String),
+    #[cfg(feature = "http")]
+    #[displaydoc("HTTP request failed: {0}: {1}")]
+    HttpStatus(reqwest::StatusCode, String),
+    #[cfg(feature = "http")]
+    #[displaydoc("Deserializer error: {0}: {1}")]
+    UrlDeserializer(Box<dyn std::error::Error + Send + Sync>, String),
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err, _) => Some(err),
+            Self::DataProvider(err) => Some(err),
+            Self::Deserializer(err, _) => Some(err.as_ref()),
+            #[cfg(feature = "export")]
+            Self::Serializer(err, _) => Some(err),
+            #[cfg(feature = "http")]
+            Self::Http(err, _) => Some(err),
+            #[cfg(feature = "http")]
+            Self::UrlDeserializer(err, _) => Some(err.as_ref()),
+            _ => None,
+        }
+    }
+}
 
 impl From<icu_provider::DataError> for Error {
     fn from(e: icu_provider::DataError) -> Self {
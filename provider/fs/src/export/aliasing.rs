@@ -8,7 +8,7 @@ use std::fmt;
 use std::fs;
 use std::hash::Hash;
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[cfg(target_family = "unix")]
 use std::os::unix::fs::symlink as symlink_file;
@@ -82,3 +82,72 @@ impl<T> Drop for AliasCollection<T> {
         }
     }
 }
+
+/// Writes `data` to a file next to `link_path` whose name is derived from a hash of `data`, and
+/// creates a symlink to that file at `link_path`. Identical data written to the same directory
+/// is therefore stored only once, without keeping it in memory.
+///
+/// The hash is stable across platforms and releases, so that re-exporting into an existing tree
+/// reuses its data files. Any existing file or symlink at `link_path` is replaced.
+///
+/// In the unlikely event of a hash collision, `data` is written to `link_path` directly.
+pub(crate) fn write_content_dedup(
+    link_path: &Path,
+    data: &[u8],
+    data_file_prefix: &str,
+) -> Result<(), Error> {
+    let mut data_filename = PathBuf::from(format!("{}-{:016x}", data_file_prefix, fnv1a_64(data)));
+    if let Some(extension) = link_path.extension() {
+        data_filename.set_extension(extension);
+    }
+
+    let root = link_path.parent().unwrap_or_else(|| Path::new(""));
+    fs::create_dir_all(root).map_err(|e| (e, root))?;
+    let data_path = root.join(&data_filename);
+
+    if fs::symlink_metadata(link_path).is_ok() {
+        fs::remove_file(link_path).map_err(|e| (e, link_path))?;
+    }
+    if data_path.exists() {
+        let existing = fs::read(&data_path).map_err(|e| (e, &data_path))?;
+        if existing != data {
+            fs::write(link_path, data).map_err(|e| (e, link_path))?;
+            return Ok(());
+        }
+    } else {
+        fs::write(&data_path, data).map_err(|e| (e, &data_path))?;
+    }
+    symlink_file(&data_filename, link_path).map_err(|e| (e, link_path))?;
+    Ok(())
+}
+
+/// 64-bit FNV-1a hash of `data`.
+fn fnv1a_64(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+#[test]
+#[cfg(target_family = "unix")]
+fn test_content_dedup_rewrite() {
+    let root = std::env::temp_dir().join("icu4x_content_dedup_rewrite");
+    let _ = fs::remove_dir_all(&root);
+    let link_path = root.join("a.json");
+
+    write_content_dedup(&link_path, b"first", "data").expect("Should write");
+    write_content_dedup(&link_path, b"second", "data").expect("Should replace the symlink");
+    assert_eq!(fs::read(&link_path).unwrap(), b"second");
+    assert_eq!(
+        fs::read_link(&link_path).unwrap(),
+        PathBuf::from(format!("data-{:016x}.json", fnv1a_64(b"second")))
+    );
+    // The data file of the replaced link is left untouched.
+    assert_eq!(
+        fs::read(root.join(format!("data-{:016x}.json", fnv1a_64(b"first")))).unwrap(),
+        b"first"
+    );
+    assert_eq!(fnv1a_64(b"a"), 0xaf63_dc4c_8601_ec8c);
+
+    fs::remove_dir_all(&root).expect("Should clean up test directory");
+}
@@ -6,8 +6,10 @@ use super::aliasing::{self, AliasCollection};
 use super::serializers::AbstractSerializer;
 use crate::error::Error;
 use crate::manifest::AliasOption;
+use crate::manifest::FallbackOption;
 use crate::manifest::Manifest;
 use crate::manifest::MANIFEST_FILE;
+use crate::path_util::resource_path_segments;
 use icu_provider::export::DataExporter;
 use icu_provider::prelude::*;
 use icu_provider::serde::SerdeSeDataStructMarker;
@@ -37,6 +39,10 @@ pub struct ExporterOptions {
     pub aliasing: AliasOption,
     /// Option for initializing the output directory.
     pub overwrite: OverwriteOption,
+    /// Strategy for locale fallback to record in the manifest.
+    pub fallback: FallbackOption,
+    /// If true, log the files that would be written instead of touching the filesystem.
+    pub dry_run: bool,
 }
 
 impl Default for ExporterOptions {
@@ -45,6 +51,8 @@ impl Default for ExporterOptions {
             root: PathBuf::from("icu4x_data"),
             aliasing: AliasOption::NoAliases,
             overwrite: OverwriteOption::CheckEmpty,
+            fallback: FallbackOption::default(),
+            dry_run: false,
         }
     }
 }
@@ -56,6 +64,7 @@ pub struct FilesystemExporter {
     manifest: Manifest,
     alias_collection: Option<AliasCollection<Vec<u8>>>,
     serializer: Box<dyn AbstractSerializer>,
+    dry_run: bool,
 }
 
 impl Drop for FilesystemExporter {
@@ -73,8 +82,7 @@ impl<'d, 's: 'd> DataExporter<'d, 's, SerdeSeDataStructMarker> for FilesystemExp
         obj: DataPayload<'d, 's, SerdeSeDataStructMarker>,
     ) -> Result<(), DataError> {
         let mut path_buf = self.root.clone();
-        path_buf.extend(req.resource_path.key.get_components().iter());
-        path_buf.extend(req.resource_path.options.get_components().iter());
+        path_buf.extend(resource_path_segments(&req.resource_path));
         log::trace!("Writing: {}", req);
         self.write_to_path(path_buf, obj.get().as_serialize())?;
         Ok(())
@@ -100,11 +108,40 @@ impl FilesystemExporter {
             manifest: Manifest {
                 aliasing: options.aliasing,
                 syntax: serializer.deref().clone(),
+                fallback: options.fallback,
             },
             alias_collection: None,
             serializer,
+            dry_run: options.dry_run,
         };
 
+        if result.dry_run {
+            // Validate the output directory without touching it, so that a dry run fails
+            // wherever the real run would.
+            if let OverwriteOption::CheckEmpty = options.overwrite {
+                if result.root.exists()
+                    && fs::read_dir(&result.root)
+                        .map_err(|e| (e, &result.root))?
+                        .next()
+                        .is_some()
+                {
+                    return Err((
+                        std::io::Error::new(
+                            std::io::ErrorKind::Other,
+                            "output directory is not empty",
+                        ),
+                        &result.root,
+                    )
+                        .into());
+                }
+            }
+            log::info!(
+                "Dry run: would initialize output directory: {:?}",
+                result.root
+            );
+            return Ok(result);
+        }
+
         match options.overwrite {
             OverwriteOption::CheckEmpty => {
                 if result.root.exists() {
@@ -138,6 +175,15 @@ impl FilesystemExporter {
         obj: &dyn erased_serde::Serialize,
     ) -> Result<(), Error> {
         let file_extension = self.serializer.get_file_extension();
+        if self.dry_run {
+            let mut buf: Vec<u8> = Vec::new();
+            self.serializer
+                .serialize(obj, &mut buf)
+                .map_err(Error::from_serializers_error)?;
+            path_buf.set_extension(file_extension);
+            log::info!("Dry run: would write {} bytes to {:?}", buf.len(), path_buf);
+            return Ok(());
+        }
         match self.manifest.aliasing {
             AliasOption::NoAliases => {
                 path_buf.set_extension(file_extension);
@@ -167,8 +213,107 @@ impl FilesystemExporter {
                     })
                     .put(path_buf, buf);
             }
+            AliasOption::ContentDedup => {
+                let mut buf: Vec<u8> = Vec::new();
+                self.serializer
+                    .serialize(obj, &mut buf)
+                    .map_err(Error::from_serializers_error)?;
+                path_buf.set_extension(file_extension);
+                aliasing::write_content_dedup(&path_buf, &buf, "data")?;
+            }
         }
 
         Ok(())
     }
 }
+
+#[test]
+fn test_dry_run() {
+    use crate::export::serializers;
+    use icu_provider::hello_world::{key, HelloWorldProvider};
+
+    let root = std::env::temp_dir().join("icu4x_json_dry_run");
+    let serializer = Box::new(serializers::json::Serializer::new(
+        serializers::json::Options::default(),
+    ));
+    let mut options = ExporterOptions::default();
+    options.root = root.clone();
+    options.dry_run = true;
+    let mut exporter =
+        FilesystemExporter::try_new(serializer, options).expect("Dry run should succeed");
+    icu_provider::export::export_from_iterable(
+        &key::HELLO_WORLD_V1,
+        &HelloWorldProvider::new_with_placeholder_data(),
+        &mut exporter,
+    )
+    .expect("Dry run should succeed");
+    assert!(!root.exists());
+}
+
+#[test]
+fn test_dry_run_non_empty() {
+    use crate::export::serializers;
+
+    let root = std::env::temp_dir().join("icu4x_json_dry_run_non_empty");
+    fs::create_dir_all(&root).unwrap();
+    fs::write(root.join("existing.json"), "{}").unwrap();
+    let serializer = Box::new(serializers::json::Serializer::new(
+        serializers::json::Options::default(),
+    ));
+    let mut options = ExporterOptions::default();
+    options.root = root.clone();
+    options.dry_run = true;
+    options.overwrite = OverwriteOption::CheckEmpty;
+    let result = FilesystemExporter::try_new(serializer, options);
+    assert!(result.is_err());
+    assert!(root.join("existing.json").exists());
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+#[cfg(target_family = "unix")]
+fn test_content_dedup() {
+    use crate::export::serializers;
+    use crate::FsDataProvider;
+    use icu_locid_macros::langid;
+    use icu_provider::hello_world::{key, HelloWorldProvider, HelloWorldV1Marker};
+
+    let root = std::env::temp_dir().join("icu4x_json_content_dedup");
+    let serializer = Box::new(serializers::json::Serializer::new(
+        serializers::json::Options::default(),
+    ));
+    let mut options = ExporterOptions::default();
+    options.root = root.clone();
+    options.aliasing = AliasOption::ContentDedup;
+    options.overwrite = OverwriteOption::RemoveAndReplace;
+    let mut exporter =
+        FilesystemExporter::try_new(serializer, options).expect("Should initialize output");
+    icu_provider::export::export_from_iterable(
+        &key::HELLO_WORLD_V1,
+        &HelloWorldProvider::new_with_placeholder_data(),
+        &mut exporter,
+    )
+    .expect("Should export");
+
+    let link_path = root.join("core/helloworld@1/bn.json");
+    assert!(fs::symlink_metadata(&link_path)
+        .expect("Should exist")
+        .file_type()
+        .is_symlink());
+
+    let provider = FsDataProvider::try_new(root.clone()).expect("Should read the manifest");
+    let response: DataResponse<HelloWorldV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: key::HELLO_WORLD_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(langid!("bn")),
+                },
+            },
+        })
+        .expect("Should load through the symlink");
+    assert_eq!(response.payload.unwrap().get().message, "ওহে বিশ্ব");
+
+    fs::remove_dir_all(&root).expect("Should clean up test directory");
+}
@@ -4,8 +4,10 @@
 
 use crate::deserializer;
 use crate::error::Error;
+use crate::manifest::FallbackOption;
 use crate::manifest::Manifest;
 use crate::manifest::MANIFEST_FILE;
+use icu_locid::LanguageIdentifier;
 use icu_provider::prelude::*;
 use icu_provider::serde::*;
 use icu_provider::yoke::trait_hack::YokeTraitHack;
@@ -58,7 +60,10 @@ impl FsDataProvider {
         })
     }
 
-    fn get_reader(&self, req: &DataRequest) -> Result<(impl Read, PathBuf), DataError> {
+    fn get_reader(
+        &self,
+        req: &DataRequest,
+    ) -> Result<(impl Read, PathBuf, ResourceOptions), DataError> {
         type Error = DataError;
         let mut path_buf = self.res_root.clone();
         path_buf.extend(req.resource_path.key.get_components().iter());
@@ -68,30 +73,63 @@ impl FsDataProvider {
         if !path_buf.exists() {
             return Err(Error::UnsupportedResourceKey(req.resource_path.key));
         }
-        if !req.resource_path.options.is_empty() {
-            // TODO: Implement proper locale fallback
-            path_buf.extend(req.resource_path.options.get_components().iter());
-            path_buf.set_extension(self.manifest.syntax.get_file_extension());
-        }
-        if !path_buf.exists() {
-            return Err(Error::UnavailableResourceOptions(req.clone()));
+        let mut options = req.resource_path.options.clone();
+        if !options.is_empty() {
+            loop {
+                let mut candidate = path_buf.clone();
+                candidate.extend(options.get_components().iter());
+                candidate.set_extension(self.manifest.syntax.get_file_extension());
+                if candidate.exists() {
+                    path_buf = candidate;
+                    break;
+                }
+                let has_parent = match (self.manifest.fallback, options.langid.as_mut()) {
+                    (FallbackOption::ParentChain, Some(langid)) => truncate_langid(langid),
+                    _ => false,
+                };
+                if !has_parent {
+                    return Err(Error::UnavailableResourceOptions(req.clone()));
+                }
+            }
         }
         let file = match File::open(&path_buf) {
             Ok(file) => file,
             Err(err) => return Err(Error::Resource(Box::new(err))),
         };
-        Ok((BufReader::new(file), path_buf))
+        Ok((BufReader::new(file), path_buf, options))
     }
 
-    fn get_rc_buffer(&self, req: &DataRequest) -> Result<(Rc<[u8]>, PathBuf), DataError> {
-        let (mut reader, path_buf) = self.get_reader(req)?;
+    fn get_rc_buffer(
+        &self,
+        req: &DataRequest,
+    ) -> Result<(Rc<[u8]>, PathBuf, ResourceOptions), DataError> {
+        let (mut reader, path_buf, options) = self.get_reader(req)?;
         let mut buffer = Vec::<u8>::new();
         reader
             .read_to_end(&mut buffer)
             .map_err(|e| DataError::Resource(Box::new(Error::Io(e, Some(path_buf.clone())))))?;
         let rc_buffer: Rc<[u8]> = buffer.into();
-        Ok((rc_buffer, path_buf))
+        Ok((rc_buffer, path_buf, options))
+    }
+}
+
+/// Replaces `langid` with its parent in the locale fallback chain, removing variants, then the
+/// region, then the script, and finally the language.
+///
+/// Returns `false` if `langid` is already the root locale (`und`).
+fn truncate_langid(langid: &mut LanguageIdentifier) -> bool {
+    if !langid.variants.is_empty() {
+        langid.variants.clear();
+    } else if langid.region.is_some() {
+        langid.region = None;
+    } else if langid.script.is_some() {
+        langid.script = None;
+    } else if !langid.language.is_empty() {
+        langid.language.clear();
+    } else {
+        return false;
     }
+    true
 }
 
 impl<'d, 's, M> DataProvider<'d, 's, M> for FsDataProvider
@@ -103,10 +141,10 @@ where
     for<'de> YokeTraitHack<<M::Yokeable as Yokeable<'de>>::Output>: serde::de::Deserialize<'de>,
 {
     fn load_payload(&self, req: &DataRequest) -> Result<DataResponse<'d, 's, M>, DataError> {
-        let (rc_buffer, path_buf) = self.get_rc_buffer(req)?;
+        let (rc_buffer, path_buf, options) = self.get_rc_buffer(req)?;
         Ok(DataResponse {
             metadata: DataResponseMetadata {
-                data_langid: req.resource_path.options.langid.clone(),
+                data_langid: options.langid,
             },
             payload: Some(
                 DataPayload::try_from_rc_buffer(
@@ -125,11 +163,11 @@ impl SerdeDeDataProvider for FsDataProvider {
         req: &DataRequest,
         receiver: &mut dyn SerdeDeDataReceiver,
     ) -> Result<DataResponseMetadata, DataError> {
-        let (rc_buffer, path_buf) = self.get_rc_buffer(req)?;
+        let (rc_buffer, path_buf, options) = self.get_rc_buffer(req)?;
         deserializer::deserialize_into_receiver(rc_buffer, &self.manifest.syntax, receiver)
             .map_err(|err| err.into_resource_error(&path_buf))?;
         Ok(DataResponseMetadata {
-            data_langid: req.resource_path.options.langid.clone(),
+            data_langid: options.langid,
         })
     }
 }
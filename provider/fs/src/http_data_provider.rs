@@ -0,0 +1,123 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::deserializer;
+use crate::error::Error;
+use crate::manifest::SyntaxOption;
+use icu_provider::prelude::*;
+use icu_provider::serde::*;
+use std::rc::Rc;
+
+/// The placeholder in a URL template that is replaced by the path of the requested resource.
+pub const PATH_PLACEHOLDER: &str = "{path}";
+
+/// A data provider that fetches ICU4X JSON data from an HTTP server.
+///
+/// The server is expected to serve the same hierarchy as the JSON output of
+/// [`FilesystemExporter`]. The URL for a request is built from a template by replacing
+/// [`PATH_PLACEHOLDER`] with the path of the resource, for example *plurals/cardinal@1/ar*.
+///
+/// Requires the `http` feature.
+///
+/// # Examples
+///
+/// ```
+/// use icu_provider_fs::HttpJsonProvider;
+///
+/// let provider = HttpJsonProvider::try_new("https://example.com/icu4x/{path}.json")
+///     .expect("The template contains {path}");
+/// ```
+///
+/// [`FilesystemExporter`]: crate::export::fs_exporter::FilesystemExporter
+#[derive(Debug)]
+pub struct HttpJsonProvider {
+    url_template: String,
+    client: reqwest::blocking::Client,
+}
+
+impl HttpJsonProvider {
+    /// Create a new [`HttpJsonProvider`] given a URL template containing [`PATH_PLACEHOLDER`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu_provider_fs::HttpJsonProvider;
+    ///
+    /// HttpJsonProvider::try_new("https://example.com/icu4x/")
+    ///     .expect_err("The template does not contain {path}");
+    /// ```
+    pub fn try_new<T: Into<String>>(url_template: T) -> Result<Self, Error> {
+        let url_template = url_template.into();
+        if !url_template.contains(PATH_PLACEHOLDER) {
+            return Err(Error::InvalidUrlTemplate(url_template));
+        }
+        Ok(Self {
+            url_template,
+            client: reqwest::blocking::Client::new(),
+        })
+    }
+
+    fn url_for(&self, resource_path: &ResourcePath) -> String {
+        self.url_template
+            .replace(PATH_PLACEHOLDER, &resource_path.to_string())
+    }
+
+    fn get_rc_buffer(&self, url: &str) -> Result<Rc<[u8]>, Error> {
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .map_err(|e| Error::Http(e, url.to_string()))?;
+        if !response.status().is_success() {
+            return Err(Error::HttpStatus(response.status(), url.to_string()));
+        }
+        let bytes = response
+            .bytes()
+            .map_err(|e| Error::Http(e, url.to_string()))?;
+        Ok(bytes.as_ref().into())
+    }
+}
+
+impl SerdeDeDataProvider for HttpJsonProvider {
+    fn load_to_receiver(
+        &self,
+        req: &DataRequest,
+        receiver: &mut dyn SerdeDeDataReceiver,
+    ) -> Result<DataResponseMetadata, DataError> {
+        let url = self.url_for(&req.resource_path);
+        let rc_buffer = self
+            .get_rc_buffer(&url)
+            .map_err(DataError::new_resc_error)?;
+        deserializer::deserialize_into_receiver(rc_buffer, &SyntaxOption::Json, receiver)
+            .map_err(|err| err.into_url_resource_error(&url))?;
+        Ok(DataResponseMetadata {
+            data_langid: req.resource_path.options.langid.clone(),
+        })
+    }
+}
+
+#[test]
+fn test_url_for() {
+    use icu_locid_macros::langid;
+    let provider = HttpJsonProvider::try_new("https://example.com/data/{path}.json").unwrap();
+    let resource_path = ResourcePath {
+        key: icu_plurals::provider::key::CARDINAL_V1,
+        options: ResourceOptions {
+            variant: None,
+            langid: Some(langid!("ar-EG")),
+        },
+    };
+    assert_eq!(
+        provider.url_for(&resource_path),
+        "https://example.com/data/plurals/cardinal@1/ar-EG.json"
+    );
+}
+
+#[test]
+fn test_deserializer_error_reports_url() {
+    let json_error = serde_json::from_str::<u32>("{").unwrap_err();
+    let url = "https://example.com/data/plurals/cardinal@1/ar.json";
+    let data_error = deserializer::Error::Json(json_error).into_url_resource_error(url);
+    assert!(data_error.to_string().ends_with(&format!(": {}", url)));
+}
@@ -87,15 +87,26 @@
 //! *Notice:* In order to use `bincode` encoded data in production, [`icu_provider_fs`](crate) has to be
 //! added with `bincode` feature.
 //!
+//! # Fetching data over HTTP
+//!
+//! With the `http` feature, [`HttpJsonProvider`] loads JSON data from a web server hosting the same
+//! hierarchy, such that data can be fetched at runtime instead of being bundled with the client.
+//!
 //! [`ICU4X`]: ../icu/index.html
 
 mod deserializer;
 mod error;
 mod fs_data_provider;
+#[cfg(feature = "http")]
+mod http_data_provider;
 pub mod manifest;
+#[cfg(feature = "export")]
+mod path_util;
 
 #[cfg(feature = "export")]
 pub mod export;
 
 pub use error::Error as FsDataError;
 pub use fs_data_provider::FsDataProvider;
+#[cfg(feature = "http")]
+pub use http_data_provider::{HttpJsonProvider, PATH_PLACEHOLDER};
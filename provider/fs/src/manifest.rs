@@ -14,7 +14,27 @@ pub enum AliasOption {
     NoAliases,
     /// De-duplicate data by using filesystem symlinks.
     Symlink,
-    // TODO: Alias based on a field in the JSON file
+    /// De-duplicate data by writing each distinct payload once, to a file named after a hash of
+    /// its contents, and symlinking every resource with that payload to it.
+    ContentDedup,
+}
+
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum FallbackOption {
+    /// Only serve data for the exact language identifier that was requested.
+    NoFallback,
+    /// If data for a language identifier is missing, look for data in its parents, ending at
+    /// the root locale (e.g., `en-US` → `en` → `und`).
+    ParentChain,
+}
+
+/// Defaults to [`FallbackOption::NoFallback`], which is also the behavior of manifests written
+/// before this option was added.
+impl Default for FallbackOption {
+    fn default() -> Self {
+        Self::NoFallback
+    }
 }
 
 #[non_exhaustive]
@@ -47,4 +67,7 @@ pub(crate) struct Manifest {
     pub aliasing: AliasOption,
     /// Which data serialization file format is used.
     pub syntax: SyntaxOption,
+    /// Strategy for locale fallback assumed when the data was exported.
+    #[serde(default)]
+    pub fallback: FallbackOption,
 }
@@ -0,0 +1,20 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_provider::prelude::*;
+
+/// Returns the path segments under which the data for a [`ResourcePath`] is stored, relative to
+/// the data root and without a file extension.
+///
+/// The segments are the key category and subcategory@version, followed by the variant and the
+/// language identifier, if present; for example, `["plurals", "cardinal@1", "ar"]`.
+pub(crate) fn resource_path_segments(resource_path: &ResourcePath) -> Vec<String> {
+    let key_components = resource_path.key.get_components();
+    let opt_components = resource_path.options.get_components();
+    key_components
+        .iter()
+        .chain(opt_components.iter())
+        .map(String::from)
+        .collect()
+}
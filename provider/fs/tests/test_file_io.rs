@@ -74,6 +74,48 @@ fn test_json_dyn_erased_serde() {
     assert_eq!(plurals_data.get(), &EXPECTED_RU_DATA);
 }
 
+#[test]
+fn test_json_fallback() {
+    let provider = FsDataProvider::try_new("./tests/testdata/json")
+        .expect("Loading file from testdata directory");
+
+    let response: DataResponse<PluralRuleStringsV1Marker> = provider
+        .load_payload(&get_request(langid!("ru-Cyrl-RU")))
+        .expect("The data should be valid");
+    assert_eq!(response.metadata.data_langid, Some(langid!("ru")));
+    assert_eq!(
+        response
+            .take_payload()
+            .expect("The data should be present")
+            .get(),
+        &EXPECTED_RU_DATA
+    );
+}
+
+#[test]
+fn test_json_decimal_symbols() {
+    use icu_decimal::provider::{key::SYMBOLS_V1, DecimalSymbolsV1Marker};
+
+    let provider = FsDataProvider::try_new("./tests/testdata/json")
+        .expect("Loading file from testdata directory");
+
+    let symbols: DataPayload<DecimalSymbolsV1Marker> = provider
+        .load_payload(&DataRequest {
+            resource_path: ResourcePath {
+                key: SYMBOLS_V1,
+                options: ResourceOptions {
+                    variant: None,
+                    langid: Some(langid!("en")),
+                },
+            },
+        })
+        .expect("The data should be valid")
+        .take_payload()
+        .expect("The data should be present");
+    assert_eq!(symbols.get().decimal_separator, ".");
+    assert_eq!(symbols.get().grouping_separator, ",");
+}
+
 #[test]
 fn test_json_errors() {
     let provider = FsDataProvider::try_new("./tests/testdata/json")
@@ -166,6 +208,21 @@ fn test_bincode() {
     assert_eq!(plurals_data.get(), &EXPECTED_SR_DATA);
 }
 
+#[test]
+#[cfg(feature = "bincode")]
+fn test_bincode_no_fallback() {
+    // The manifest does not set "fallback", so only exact matches are served.
+    let provider = FsDataProvider::try_new("./tests/testdata/bincode")
+        .expect("Loading file from testdata directory");
+
+    let response: Result<DataResponse<PluralRuleStringsV1Marker>, DataError> =
+        provider.load_payload(&get_request(langid!("sr-Latn")));
+    assert!(matches!(
+        response,
+        Err(DataError::UnavailableResourceOptions(_))
+    ));
+}
+
 #[test]
 #[cfg(feature = "bincode")]
 fn test_bincode_dyn_erased_serde() {
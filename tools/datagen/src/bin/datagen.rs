@@ -10,6 +10,7 @@ use icu_provider::filter::Filterable;
 use icu_provider::hello_world::{self, HelloWorldProvider};
 use icu_provider::iter::IterableDataProvider;
 use icu_provider::serde::SerdeSeDataStructMarker;
+use icu_provider::ResourceKey;
 use icu_provider_blob::export::BlobExporter;
 use icu_provider_cldr::download::CldrAllInOneDownloader;
 use icu_provider_cldr::get_all_cldr_keys;
@@ -57,6 +58,7 @@ fn main() -> anyhow::Result<()> {
                 .takes_value(true)
                 .possible_value("none")
                 .possible_value("symlink")
+                .possible_value("dedup")
                 .help("Sets the aliasing mode of the output on the filesystem."),
         )
         .arg(
@@ -217,15 +219,11 @@ fn main() -> anyhow::Result<()> {
         _ => anyhow::bail!("Only -v and -vv are supported"),
     }
 
-    if !matches.is_present("ALL_KEYS") && !matches.is_present("HELLO_WORLD") {
-        anyhow::bail!("Lists of keys are not yet supported (see #192)",);
-    }
-
-    if matches.is_present("DRY_RUN") {
-        anyhow::bail!("Dry-run is not yet supported");
-    }
-
-    // TODO: Build up this list from --keys and --key-file
+    let requested_keys = if matches.is_present("KEYS") || matches.is_present("KEY_FILE") {
+        Some(get_requested_keys(&matches)?)
+    } else {
+        None
+    };
 
     let format = matches
         .value_of("FORMAT")
@@ -258,7 +256,19 @@ fn main() -> anyhow::Result<()> {
     };
 
     if matches.is_present("ALL_KEYS") {
-        export_cldr(&matches, exporter, locales_vec.as_deref())?;
+        export_cldr(&matches, exporter, locales_vec.as_deref(), None)?;
+    }
+
+    if let Some(keys) = requested_keys {
+        let (hello_world_keys, cldr_keys): (Vec<ResourceKey>, Vec<ResourceKey>) = keys
+            .into_iter()
+            .partition(|key| *key == hello_world::key::HELLO_WORLD_V1);
+        if !cldr_keys.is_empty() {
+            export_cldr(&matches, exporter, locales_vec.as_deref(), Some(&cldr_keys))?;
+        }
+        if !hello_world_keys.is_empty() {
+            export_hello_world(&matches, exporter, locales_vec.as_deref())?;
+        }
     }
 
     if matches.is_present("HELLO_WORLD") {
@@ -270,6 +280,40 @@ fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Collects the resource keys passed via --keys and --key-file, checking that each of them is
+/// known to ICU4X.
+fn get_requested_keys(matches: &ArgMatches) -> anyhow::Result<Vec<ResourceKey>> {
+    let mut key_strs: Vec<String> = vec![];
+    if let Some(values) = matches.values_of("KEYS") {
+        key_strs.extend(values.map(String::from));
+    }
+    if let Some(path) = matches.value_of_os("KEY_FILE") {
+        let contents =
+            std::fs::read_to_string(path).with_context(|| path.to_string_lossy().to_string())?;
+        key_strs.extend(
+            contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(String::from),
+        );
+    }
+
+    let mut all_keys = get_all_cldr_keys();
+    all_keys.push(hello_world::key::HELLO_WORLD_V1);
+    key_strs
+        .iter()
+        .map(|key_str| {
+            let key: ResourceKey = key_str.parse()?;
+            if all_keys.contains(&key) {
+                Ok(key)
+            } else {
+                Err(anyhow::anyhow!("Unknown resource key: {}", key))
+            }
+        })
+        .collect()
+}
+
 fn get_fs_exporter(matches: &ArgMatches) -> anyhow::Result<FilesystemExporter> {
     let syntax = matches.value_of("SYNTAX").unwrap_or("json");
 
@@ -304,12 +348,14 @@ fn get_fs_exporter(matches: &ArgMatches) -> anyhow::Result<FilesystemExporter> {
         options.aliasing = match value {
             "none" => manifest::AliasOption::NoAliases,
             "symlink" => manifest::AliasOption::Symlink,
+            "dedup" => manifest::AliasOption::ContentDedup,
             _ => unreachable!(),
         };
     }
     if matches.is_present("OVERWRITE") {
         options.overwrite = fs_exporter::OverwriteOption::RemoveAndReplace
     }
+    options.dry_run = matches.is_present("DRY_RUN");
 
     let exporter = FilesystemExporter::try_new(serializer, options)?;
     Ok(exporter)
@@ -333,7 +379,10 @@ fn get_blob_exporter(matches: &ArgMatches) -> anyhow::Result<BlobExporter<'stati
         None => log::info!("Writing blob to standard out"),
     };
 
-    let sink: Box<dyn std::io::Write> = if let Some(path_buf) = output_path {
+    let sink: Box<dyn std::io::Write> = if matches.is_present("DRY_RUN") {
+        log::info!("Dry run: discarding blob output");
+        Box::new(std::io::sink())
+    } else if let Some(path_buf) = output_path {
         if !matches.is_present("OVERWRITE") && path_buf.exists() {
             anyhow::bail!("Output path is present: {:?}", path_buf);
         }
@@ -352,6 +401,7 @@ fn export_cldr<'d, 's: 'd>(
     matches: &ArgMatches,
     exporter: &mut (impl DataExporter<'d, 's, SerdeSeDataStructMarker> + ?Sized),
     allowed_locales: Option<&[LanguageIdentifier]>,
+    allowed_keys: Option<&[ResourceKey]>,
 ) -> anyhow::Result<()> {
     let locale_subset = matches.value_of("CLDR_LOCALE_SUBSET").unwrap_or("full");
     let cldr_paths: Box<dyn CldrPaths> = if let Some(tag) = matches.value_of("CLDR_TAG") {
@@ -370,9 +420,20 @@ fn export_cldr<'d, 's: 'd>(
         anyhow::bail!("Either --cldr-tag or --cldr-root must be specified",)
     };
 
-    let keys = get_all_cldr_keys();
-
     let raw_provider = CldrJsonDataProvider::new(cldr_paths.as_ref());
+
+    let keys = if let Some(allowed_keys) = allowed_keys {
+        allowed_keys.to_vec()
+    } else {
+        let keys = raw_provider.supported_keys()?;
+        for key in get_all_cldr_keys().iter() {
+            if !keys.contains(key) {
+                log::warn!("Skipping key with missing CLDR sources: {}", key);
+            }
+        }
+        keys
+    };
+
     let filtered_provider;
     let provider: &dyn IterableDataProvider<SerdeSeDataStructMarker>;
 
@@ -50,6 +50,20 @@
 //!    --out /tmp/icu4x_data/bincode \
 //!    -v
 //!```
+//!
+//! Generate ICU4X JSON file tree for a subset of keys:
+//!
+//!```bash
+//!# Run from the icu4x project folder
+//!$ cargo run --bin icu4x-datagen -- \
+//!    --cldr-tag 39.0.0 \
+//!    --keys plurals/cardinal@1 plurals/ordinal@1 \
+//!    --all-locales \
+//!    --out /tmp/icu4x_data/json \
+//!    -v
+//!```
+//!
+//! Keys can also be listed in a text file, one per line, with `--key-file`.
 
 fn main() {
     panic!("Please run a more specific binary")
@@ -0,0 +1,54 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Compact decimal representation, used for numbers such as "1.2 million".
+
+use crate::FixedDecimal;
+
+/// A decimal number in compact notation: a [`FixedDecimal`] significand together with a power-of-ten
+/// exponent that is expressed by the compact form (for example, "1.2M" has significand 1.2 and
+/// exponent 6).
+///
+/// Unlike [`FixedDecimal`], a `CompactDecimal` retains the exponent separately from the digits,
+/// which is needed for plural selection in locales whose rules depend on the `c`/`e` operand.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::CompactDecimal;
+/// use fixed_decimal::FixedDecimal;
+///
+/// let mut significand = FixedDecimal::from(12);
+/// significand.multiply_pow10(-1).unwrap();
+///
+/// let compact = CompactDecimal::from_significand_and_exponent(significand, 6);
+/// assert_eq!("1.2", compact.significand().to_string());
+/// assert_eq!(6, compact.exponent());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompactDecimal {
+    significand: FixedDecimal,
+    exponent: u8,
+}
+
+impl CompactDecimal {
+    /// Creates a `CompactDecimal` from a significand and the power-of-ten exponent expressed by
+    /// the compact form.
+    pub fn from_significand_and_exponent(significand: FixedDecimal, exponent: u8) -> Self {
+        Self {
+            significand,
+            exponent,
+        }
+    }
+
+    /// Returns the significand of this compact decimal.
+    pub fn significand(&self) -> &FixedDecimal {
+        &self.significand
+    }
+
+    /// Returns the power-of-ten exponent of this compact decimal.
+    pub fn exponent(&self) -> u8 {
+        self.exponent
+    }
+}
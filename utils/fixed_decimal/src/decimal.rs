@@ -329,6 +329,144 @@ impl FixedDecimal {
         self
     }
 
+    /// Zero-pad the number on the left so that at least `position` integer digits are visible,
+    /// modifying self. The numeric value does not change.
+    ///
+    /// If the number already has `position` or more integer digits, this is a no-op.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let mut dec = FixedDecimal::from(42);
+    /// dec.pad_left(4);
+    /// assert_eq!("0042", dec.to_string());
+    ///
+    /// dec.pad_left(1);
+    /// assert_eq!("0042", dec.to_string());
+    /// ```
+    pub fn pad_left(&mut self, position: i16) {
+        let upper_magnitude = position.saturating_sub(1);
+        self.upper_magnitude = cmp::max(self.upper_magnitude, upper_magnitude);
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+    }
+
+    /// Zero-pad the number on the left so that at least `position` integer digits are visible,
+    /// consuming self and returning a new object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// assert_eq!("007", FixedDecimal::from(7).padded_left(3).to_string());
+    /// ```
+    pub fn padded_left(mut self, position: i16) -> Self {
+        self.pad_left(position);
+        self
+    }
+
+    /// Zero-pad the number on the right so that the digit at magnitude `position` is visible,
+    /// modifying self. A negative `position` is the number of fraction digits to show. The numeric
+    /// value does not change.
+    ///
+    /// If digits are already visible down to `position` or beyond, this is a no-op; use
+    /// [`FixedDecimal::truncate_right`] to remove digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let mut dec = FixedDecimal::from(75).multiplied_pow10(-1).expect("Bounds are small");
+    /// dec.pad_right(-2);
+    /// assert_eq!("7.50", dec.to_string());
+    ///
+    /// dec.pad_right(-1);
+    /// assert_eq!("7.50", dec.to_string());
+    /// ```
+    pub fn pad_right(&mut self, position: i16) {
+        let lower_magnitude = cmp::min(position, 0);
+        self.lower_magnitude = cmp::min(self.lower_magnitude, lower_magnitude);
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+    }
+
+    /// Zero-pad the number on the right so that the digit at magnitude `position` is visible,
+    /// consuming self and returning a new object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// assert_eq!("7.000", FixedDecimal::from(7).padded_right(-3).to_string());
+    /// ```
+    pub fn padded_right(mut self, position: i16) -> Self {
+        self.pad_right(position);
+        self
+    }
+
+    /// Remove all digits with a magnitude less than `position`, modifying self. Unlike padding,
+    /// this can change the numeric value: digits are dropped without rounding.
+    ///
+    /// Digits below magnitude 0 are removed from the visible range, while integer digits below
+    /// `position` are replaced by zeros. If no digits are visible below `position`, this is a
+    /// no-op.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let mut dec = FixedDecimal::from(-12345).multiplied_pow10(-3).expect("Bounds are small");
+    /// dec.truncate_right(-1);
+    /// assert_eq!("-12.3", dec.to_string());
+    ///
+    /// dec.truncate_right(1);
+    /// assert_eq!("-10", dec.to_string());
+    /// ```
+    pub fn truncate_right(&mut self, position: i16) {
+        if position > self.magnitude {
+            self.digits.clear();
+            self.magnitude = 0;
+        } else {
+            // The following cast can't overflow: position <= self.magnitude.
+            let len = (self.magnitude as i32 - position as i32 + 1) as usize;
+            if len < self.digits.len() {
+                self.digits.truncate(len);
+                while let Some(0) = self.digits.last() {
+                    self.digits.pop();
+                }
+                if self.digits.is_empty() {
+                    self.magnitude = 0;
+                }
+            }
+        }
+        let lower_magnitude = cmp::min(position, 0);
+        self.lower_magnitude = cmp::max(self.lower_magnitude, lower_magnitude);
+        #[cfg(debug_assertions)]
+        self.check_invariants();
+    }
+
+    /// Remove all digits with a magnitude less than `position`, consuming self and returning a
+    /// new object.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::FixedDecimal;
+    ///
+    /// let dec = FixedDecimal::from(12345).multiplied_pow10(-3).expect("Bounds are small");
+    /// assert_eq!("12", dec.truncated_right(0).to_string());
+    /// ```
+    pub fn truncated_right(mut self, position: i16) -> Self {
+        self.truncate_right(position);
+        self
+    }
+
     /// Returns the [Signum][Signum] of this FixedDecimal.
     ///
     /// # Examples
@@ -436,13 +574,14 @@ impl FromStr for FixedDecimal {
     fn from_str(input_str: &str) -> Result<Self, Self::Err> {
         // input_str: the input string
         // no_sign_str: the input string when the sign is removed from it
-        // Check if the input string is "" or "-"
-        if input_str.is_empty() || input_str == "-" {
+        // Check if the input string is "", "-", or "+"
+        if input_str.is_empty() || input_str == "-" || input_str == "+" {
             return Err(Error::Syntax);
         }
         let input_str = input_str.as_bytes();
         let is_negative = input_str[0] == b'-';
-        let no_sign_str = if is_negative {
+        // A second sign is rejected below as a non-digit character
+        let no_sign_str = if is_negative || input_str[0] == b'+' {
             &input_str[1..]
         } else {
             input_str
@@ -737,6 +876,105 @@ fn test_from_str() {
     }
 }
 
+#[test]
+fn test_from_str_plus_sign() {
+    let dec = FixedDecimal::from_str("+1.50").unwrap();
+    assert_eq!(dec, FixedDecimal::from_str("1.50").unwrap());
+    assert_eq!(dec.magnitude_range(), -2..=0);
+    assert_eq!(dec.signum(), Signum::AboveZero);
+    assert_eq!("1.50", dec.to_string());
+
+    let dec = FixedDecimal::from_str("+007").unwrap();
+    assert_eq!(dec.magnitude_range(), 0..=2);
+    assert_eq!("007", dec.to_string());
+}
+
+#[test]
+fn test_pad() {
+    let mut dec = FixedDecimal::from_str("7.5").unwrap();
+    dec.pad_left(3);
+    dec.pad_right(-2);
+    assert_eq!("007.50", dec.to_string());
+    assert_eq!(dec.magnitude_range(), -2..=2);
+
+    // Padding to fewer digits than are present is a no-op
+    dec.pad_left(2);
+    dec.pad_right(-1);
+    assert_eq!("007.50", dec.to_string());
+    dec.pad_left(i16::MIN);
+    dec.pad_right(i16::MAX);
+    assert_eq!("007.50", dec.to_string());
+
+    let dec = FixedDecimal::from(-12345).padded_left(3).padded_right(1);
+    assert_eq!("-12345", dec.to_string());
+
+    let dec = FixedDecimal::from(0).padded_left(2).padded_right(-1);
+    assert_eq!("00.0", dec.to_string());
+}
+
+#[test]
+fn test_truncate_right() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input: &'static str,
+        pub position: i16,
+        pub expected: &'static str,
+    }
+    let cases = [
+        TestCase {
+            input: "12.345",
+            position: -2,
+            expected: "12.34",
+        },
+        TestCase {
+            input: "12.345",
+            position: 0,
+            expected: "12",
+        },
+        TestCase {
+            input: "12.345",
+            position: -5,
+            expected: "12.345",
+        },
+        TestCase {
+            input: "12.305",
+            position: -2,
+            expected: "12.30",
+        },
+        TestCase {
+            input: "0012.0050",
+            position: -2,
+            expected: "0012.00",
+        },
+        TestCase {
+            input: "-0.05",
+            position: -1,
+            expected: "-0.0",
+        },
+        TestCase {
+            input: "12345",
+            position: 2,
+            expected: "12300",
+        },
+        TestCase {
+            input: "12345",
+            position: 6,
+            expected: "00000",
+        },
+        TestCase {
+            input: "1.5",
+            position: 1,
+            expected: "0",
+        },
+    ];
+    for cas in &cases {
+        let dec = FixedDecimal::from_str(cas.input)
+            .unwrap()
+            .truncated_right(cas.position);
+        assert_eq!(cas.expected, dec.to_string(), "{:?}", cas);
+    }
+}
+
 #[test]
 fn test_isize_limits() {
     for num in &[std::isize::MAX, std::isize::MIN] {
@@ -939,6 +1177,26 @@ fn test_syntax_error() {
             input_str: "-1",
             expected_err: None,
         },
+        TestCase {
+            input_str: "+",
+            expected_err: Some(Error::Syntax),
+        },
+        TestCase {
+            input_str: "+-1",
+            expected_err: Some(Error::Syntax),
+        },
+        TestCase {
+            input_str: "-+1",
+            expected_err: Some(Error::Syntax),
+        },
+        TestCase {
+            input_str: "--1",
+            expected_err: Some(Error::Syntax),
+        },
+        TestCase {
+            input_str: "+.5",
+            expected_err: Some(Error::Syntax),
+        },
     ];
     for cas in &cases {
         match FixedDecimal::from_str(cas.input_str) {
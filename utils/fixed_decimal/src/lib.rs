@@ -38,10 +38,12 @@
 //!
 //! [`ICU4X`]: ../icu/index.html
 
+pub mod compact;
 pub mod decimal;
 pub mod signum;
 mod uint_iterator;
 
+pub use compact::CompactDecimal;
 pub use decimal::FixedDecimal;
 use displaydoc::Display;
 pub use signum::Signum;
@@ -65,8 +67,8 @@ pub enum Error {
     Limit,
     /// The input of a string that is supposed to be converted to FixedDecimal is not accepted.
     ///
-    /// Any string with non-digit characters (except for one '.' and one '-' at the beginning of the string) is not accepted.
-    /// Also, empty string ("") and its negation ("-") are not accepted.
+    /// Any string with non-digit characters (except for one '.' and one '-' or '+' at the beginning of the string) is not accepted.
+    /// Also, empty string ("") and a lone sign ("-" or "+") are not accepted.
     /// Strings of form "12_345_678" are not accepted, the accepted format is "12345678".
     /// Also '.' shouldn't be first or the last characters, i. e. .123 and 123. are not accepted, and instead 0.123 and
     /// 123 (or 123.0) must be used.
@@ -12,6 +12,9 @@
 // The lifetimes here are important for safety and explicitly writing
 // them out is good even when redundant
 #![allow(clippy::needless_lifetimes)]
+#![cfg_attr(not(test), no_std)]
+
+extern crate alloc;
 
 mod macro_impls;
 pub mod trait_hack;
@@ -3,11 +3,11 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::Yokeable;
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use core::marker::PhantomData;
+use core::ops::Deref;
 use stable_deref_trait::StableDeref;
-use std::marker::PhantomData;
-use std::ops::Deref;
-use std::rc::Rc;
-use std::sync::Arc;
 
 /// A Cow-like borrowed object "yoked" to its backing data.
 ///
@@ -50,6 +50,31 @@ use std::sync::Arc;
 /// assert!(matches!(yoke.get(), &Cow::Borrowed(_)));
 /// ```
 ///
+/// # Sharing between threads
+///
+/// A [`Yoke`] is [`Send`] and [`Sync`] when both the yokeable and the cart are, so using an
+/// [`Arc`] instead of an [`Rc`] as the cart allows the yoke to be moved to, or cloned into,
+/// another thread. Any [`StableDeref`] type can be used as a cart, so no separate constructor
+/// is needed:
+///
+/// ```rust
+/// # use yoke::{Yoke, Yokeable};
+/// # use std::sync::Arc;
+/// # use std::borrow::Cow;
+/// # use std::thread;
+/// let arc: Arc<[u8]> = Arc::new([0x5, 0, 0, 0, 0, 0, 0, 0, 0x68, 0x65, 0x6c, 0x6c, 0x6f]);
+/// let yoke = Yoke::<Cow<'static, str>, Arc<[u8]>>::attach_to_cart_badly(arc, |data: &[u8]| {
+///     Cow::Borrowed(bincode::deserialize(data).unwrap())
+/// });
+///
+/// let cloned = yoke.clone();
+/// let handle = thread::spawn(move || {
+///     assert_eq!(&**cloned.get(), "hello");
+///     cloned.get().len()
+/// });
+/// assert_eq!(handle.join().unwrap(), 5);
+/// assert_eq!(&**yoke.get(), "hello");
+/// ```
 pub struct Yoke<Y: for<'a> Yokeable<'a>, C> {
     // must be the first field for drop order
     // this will have a 'static lifetime parameter, that parameter is a lie
@@ -77,11 +102,28 @@ impl<Y: for<'a> Yokeable<'a>, C: StableDeref> Yoke<Y, C> {
     }
 
     /// Construct a [`Yoke`] by yokeing an object to a cart. If an error occurs in the
-    /// deserializer function, the error is passed up to the caller.
+    /// deserializer function, the error is passed up to the caller, and the cart is dropped.
     ///
     /// Due to [compiler bug #84937](https://github.com/rust-lang/rust/issues/84937), call sites
     /// for this function may not compile; if this happens, use
     /// [`Yoke::try_attach_to_cart_badly()`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use yoke::{Yoke, Yokeable};
+    /// # use std::rc::Rc;
+    /// # use std::borrow::Cow;
+    /// let rc: Rc<[u8]> = Rc::new([0xb, 0xa, 0xd]);
+    /// let weak = Rc::downgrade(&rc);
+    ///
+    /// let yoke_result: Result<Yoke<Cow<str>, Rc<[u8]>>, bincode::Error> =
+    ///     Yoke::try_attach_to_cart(rc, |data: &[u8]| bincode::deserialize(data));
+    ///
+    /// assert!(matches!(yoke_result, Err(_)));
+    /// // The cart does not outlive the failed attempt
+    /// assert!(weak.upgrade().is_none());
+    /// ```
     pub fn try_attach_to_cart<E, F>(cart: C, f: F) -> Result<Self, E>
     where
         F: for<'de> FnOnce(&'de <C as Deref>::Target) -> Result<<Y as Yokeable<'de>>::Output, E>,
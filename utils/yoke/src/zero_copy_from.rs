@@ -4,8 +4,10 @@
 
 use crate::Yoke;
 use crate::Yokeable;
-use std::borrow::{Cow, ToOwned};
-use std::rc::Rc;
+use alloc::borrow::{Cow, ToOwned};
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::string::String;
 
 /// Trait for types that can be crated from a reference to a cart type `C` with no allocations.
 ///
@@ -8,6 +8,8 @@
 use std::borrow::Cow;
 use std::mem;
 use std::rc::Rc;
+use std::sync::Arc;
+use std::thread;
 use yoke::{Yoke, Yokeable};
 
 fn load_from_cache(_filename: &str) -> Rc<[u8]> {
@@ -17,17 +19,25 @@ fn load_from_cache(_filename: &str) -> Rc<[u8]> {
 
 fn load_object(filename: &str) -> Yoke<Bar<'static>, Rc<[u8]>> {
     let rc: Rc<[u8]> = load_from_cache(filename);
-    Yoke::<Bar<'static>, Rc<[u8]>>::attach_to_cart_badly(rc, |data: &[u8]| {
-        // A real implementation would properly deserialize `Bar` as a whole
-        Bar {
-            numbers: Cow::Borrowed(bincode::deserialize(data).unwrap()),
-            string: Cow::Borrowed(bincode::deserialize(data).unwrap()),
-            owned: Vec::new(),
-        }
-    })
+    Yoke::<Bar<'static>, Rc<[u8]>>::attach_to_cart_badly(rc, deserialize_bar)
+}
+
+fn load_shared_object(filename: &str) -> Yoke<Bar<'static>, Arc<[u8]>> {
+    let arc: Arc<[u8]> = load_from_cache(filename).to_vec().into();
+    Yoke::<Bar<'static>, Arc<[u8]>>::attach_to_cart_badly(arc, deserialize_bar)
+}
+
+fn deserialize_bar(data: &[u8]) -> Bar<'_> {
+    // A real implementation would properly deserialize `Bar` as a whole
+    Bar {
+        numbers: Cow::Borrowed(bincode::deserialize(data).unwrap()),
+        string: Cow::Borrowed(bincode::deserialize(data).unwrap()),
+        owned: Vec::new(),
+    }
 }
 
 // also implements Yokeable
+#[derive(Clone)]
 struct Bar<'a> {
     numbers: Cow<'a, [u8]>,
     string: Cow<'a, str>,
@@ -80,3 +90,20 @@ fn test_load() {
     assert_eq!(&*bar.get().numbers, &[0x68, 0x65, 0x6c, 0x6c, 0x6f]);
     assert!(matches!(bar.get().numbers, Cow::Borrowed(_)));
 }
+
+#[test]
+fn test_send_to_thread() {
+    let bar = load_shared_object("filename.bincode");
+    let cloned = bar.clone();
+
+    let handle = thread::spawn(move || {
+        assert_eq!(cloned.get().string, "hello");
+        assert!(matches!(cloned.get().string, Cow::Borrowed(_)));
+        cloned.get().numbers.to_vec()
+    });
+    assert_eq!(handle.join().unwrap(), &[0x68, 0x65, 0x6c, 0x6c, 0x6f]);
+
+    // The original yoke still borrows from the same cart.
+    assert_eq!(bar.get().string, "hello");
+    assert!(matches!(bar.get().string, Cow::Borrowed(_)));
+}
@@ -0,0 +1,50 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use zerovec::ZeroMap;
+
+const COUNT: u32 = 10_000;
+
+/// Returns the keys `0..COUNT` in a scrambled but deterministic order, paired with their values.
+fn entries() -> Vec<(u32, u32)> {
+    // 7919 is coprime with COUNT, so this is a permutation of `0..COUNT`.
+    (0..COUNT).map(|i| (i * 7919 % COUNT, i)).collect()
+}
+
+fn construction_bench(c: &mut Criterion) {
+    let entries = entries();
+    let mut sorted_entries = entries.clone();
+    sorted_entries.sort_unstable();
+
+    c.bench_function("zeromap/construct/insert", |b| {
+        b.iter(|| {
+            let mut map = ZeroMap::<u32, u32>::new();
+            for (key, value) in black_box(&entries).iter() {
+                map.insert(*key, *value);
+            }
+            map
+        });
+    });
+
+    c.bench_function("zeromap/construct/from_iter", |b| {
+        b.iter(|| {
+            black_box(&entries)
+                .iter()
+                .copied()
+                .collect::<ZeroMap<u32, u32>>()
+        });
+    });
+
+    c.bench_function("zeromap/construct/from_sorted_iter", |b| {
+        b.iter(|| {
+            ZeroMap::<u32, u32>::from_sorted_iter(black_box(&sorted_entries).iter().copied())
+                .unwrap()
+        });
+    });
+}
+
+criterion_group!(benches, construction_bench);
+criterion_main!(benches);
@@ -71,6 +71,7 @@ fn overview_bench(c: &mut Criterion) {
     #[cfg(feature = "bench")]
     {
         sum_benches(c);
+        copy_benches(c);
         binary_search_benches(c);
     }
 }
@@ -91,6 +92,20 @@ fn sum_benches(c: &mut Criterion) {
     });
 }
 
+#[cfg(feature = "bench")]
+fn copy_benches(c: &mut Criterion) {
+    c.bench_function("zerovec/copy/sample/slice", |b| {
+        let mut buffer = vec![0u32; TEST_SLICE.len()];
+        b.iter(|| buffer.copy_from_slice(black_box(TEST_SLICE)));
+    });
+
+    c.bench_function("zerovec/copy/sample/zerovec", |b| {
+        let zerovec = ZeroVec::<u32>::try_from_bytes(black_box(&TEST_BUFFER_LE)).unwrap();
+        let mut buffer = vec![0u32; TEST_SLICE.len()];
+        b.iter(|| zerovec.copy_into_aligned(0..TEST_SLICE.len(), &mut buffer));
+    });
+}
+
 #[cfg(feature = "bench")]
 fn binary_search_benches(c: &mut Criterion) {
     c.bench_function("zerovec/binary_search/sample/slice", |b| {
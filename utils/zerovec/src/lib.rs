@@ -43,6 +43,8 @@
 //! # Features
 //!
 //! - `serde`: enables Serde Serialize/Deserialize impls for ZeroVec and VarZeroVec.
+//! - `std` (default): links the standard library. Without it, this crate is `no_std` and only
+//!   requires `alloc`.
 //!
 //! # Examples
 //!
@@ -77,6 +79,10 @@
 //! # } // feature = "serde"
 //! ```
 
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+extern crate alloc;
+
 pub mod map;
 #[cfg(test)]
 pub mod samples;
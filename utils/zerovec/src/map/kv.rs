@@ -6,7 +6,8 @@ use super::vecs::ZeroVecLike;
 use crate::ule::*;
 use crate::VarZeroVec;
 use crate::ZeroVec;
-use std::cmp::Ordering;
+use alloc::string::String;
+use core::cmp::Ordering;
 
 /// Trait marking types which are allowed to be keys or values in [`ZeroMap`](super::ZeroMap).
 ///
@@ -72,6 +73,7 @@ impl_sized_kv!(i32);
 impl_sized_kv!(i64);
 impl_sized_kv!(i128);
 impl_sized_kv!(char);
+impl_sized_kv!(bool);
 
 impl<'a> ZeroMapKV<'a> for String {
     type Container = VarZeroVec<'a, String>;
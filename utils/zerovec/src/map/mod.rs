@@ -6,7 +6,9 @@
 
 use crate::ule::AsULE;
 use crate::ZeroVec;
-use std::cmp::Ordering;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::iter::FromIterator;
 
 mod kv;
 #[cfg(feature = "serde")]
@@ -197,7 +199,64 @@ where
         None
     }
 
+    /// Construct a [`ZeroMap`] from an iterator of entries that are sorted by key, appending
+    /// them without searching or shifting. Returns the first entry that is not greater than the
+    /// previous one, if any, since the input is then unsorted or contains a duplicate key.
+    ///
+    /// Use [`FromIterator`] instead if the entries are not known to be sorted.
+    ///
+    /// ```rust
+    /// use zerovec::ZeroMap;
+    ///
+    /// let map: ZeroMap<u32, String> =
+    ///     ZeroMap::from_sorted_iter(vec![(1, "uno".to_owned()), (3, "tres".to_owned())])
+    ///         .expect("sorted input");
+    /// assert_eq!(map.get(&3), Some("tres"));
+    ///
+    /// let unsorted = ZeroMap::<u32, String>::from_sorted_iter(vec![
+    ///     (1, "uno".to_owned()),
+    ///     (3, "tres".to_owned()),
+    ///     (2, "dos".to_owned()),
+    /// ]);
+    /// assert!(matches!(unsorted, Err((2, _))));
+    ///
+    /// let duplicate = ZeroMap::<u32, String>::from_sorted_iter(vec![
+    ///     (1, "uno".to_owned()),
+    ///     (1, "one".to_owned()),
+    /// ]);
+    /// assert!(matches!(duplicate, Err((1, _))));
+    /// ```
+    pub fn from_sorted_iter<I>(iter: I) -> Result<Self, (K, V)>
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let iter = iter.into_iter();
+        let mut map = Self::with_capacity(iter.size_hint().0);
+        for (key, value) in iter {
+            if let Some(entry) = map.try_append(key, value) {
+                return Err(entry);
+            }
+        }
+        Ok(map)
+    }
+
     /// Produce an ordered iterator over key-value pairs
+    ///
+    /// ```rust
+    /// use zerovec::ule::AsULE;
+    /// use zerovec::ZeroMap;
+    ///
+    /// let mut map = ZeroMap::new();
+    /// map.insert(3u32, "three".to_owned());
+    /// map.insert(1, "one".to_owned());
+    /// map.insert(2, "two".to_owned());
+    ///
+    /// let entries: Vec<(u32, &str)> = map
+    ///     .iter()
+    ///     .map(|(k, v)| (u32::from_unaligned(k), v))
+    ///     .collect();
+    /// assert_eq!(entries, vec![(1, "one"), (2, "two"), (3, "three")]);
+    /// ```
     pub fn iter<'b>(
         &'b self,
     ) -> impl Iterator<
@@ -211,16 +270,81 @@ where
     }
 
     /// Produce an ordered iterator over keys
+    ///
+    /// ```rust
+    /// use zerovec::ZeroMap;
+    ///
+    /// let mut map = ZeroMap::new();
+    /// map.insert("two".to_owned(), 2u32);
+    /// map.insert("one".to_owned(), 1u32);
+    ///
+    /// assert_eq!(map.iter_keys().collect::<Vec<_>>(), vec!["one", "two"]);
+    /// ```
     pub fn iter_keys<'b>(&'b self) -> impl Iterator<Item = &'b <K as ZeroMapKV<'a>>::GetType> {
         (0..self.keys.len()).map(move |idx| self.keys.get(idx).unwrap())
     }
 
     /// Produce an iterator over values, ordered by keys
+    ///
+    /// ```rust
+    /// use zerovec::ZeroMap;
+    ///
+    /// let mut map = ZeroMap::new();
+    /// map.insert(2u32, "two".to_owned());
+    /// map.insert(1u32, "one".to_owned());
+    ///
+    /// assert_eq!(map.iter_values().collect::<Vec<_>>(), vec!["one", "two"]);
+    /// ```
     pub fn iter_values<'b>(&'b self) -> impl Iterator<Item = &'b <V as ZeroMapKV<'a>>::GetType> {
         (0..self.values.len()).map(move |idx| self.values.get(idx).unwrap())
     }
 }
 
+impl<'a, K, V> FromIterator<(K, V)> for ZeroMap<'a, K, V>
+where
+    K: ZeroMapKV<'a> + Ord,
+    V: ZeroMapKV<'a>,
+{
+    /// Construct a [`ZeroMap`] from an iterator of entries in any order. The entries are sorted
+    /// by key and then appended in a single pass, instead of being inserted one at a time.
+    ///
+    /// As with [`ZeroMap::insert()`], the last value of a duplicate key is kept.
+    ///
+    /// ```rust
+    /// use zerovec::ZeroMap;
+    ///
+    /// let map: ZeroMap<u32, String> = vec![
+    ///     (3, "tres".to_owned()),
+    ///     (1, "uno".to_owned()),
+    ///     (3, "three".to_owned()),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map.get(&1), Some("uno"));
+    /// assert_eq!(map.get(&3), Some("three"));
+    /// ```
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut entries: Vec<(K, V)> = iter.into_iter().collect();
+        // The sort is stable, so the entries of a duplicate key stay in their original order.
+        entries.sort_by(|(k1, _), (k2, _)| k1.cmp(k2));
+
+        let mut map = Self::with_capacity(entries.len());
+        let mut entries = entries.into_iter().peekable();
+        while let Some((key, value)) = entries.next() {
+            if matches!(entries.peek(), Some((next_key, _)) if *next_key == key) {
+                continue;
+            }
+            map.keys.push(key);
+            map.values.push(value);
+        }
+        map
+    }
+}
+
 impl<'a, K, V> ZeroMap<'a, K, V>
 where
     K: ZeroMapKV<'a>,
@@ -268,3 +392,78 @@ where
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_iter_varzerovec_values() {
+        let mut map: ZeroMap<u32, String> = ZeroMap::new();
+        for (key, value) in &[(5, "five"), (1, "one"), (3, "three"), (2, "two")] {
+            map.insert(*key, value.to_string());
+        }
+        map.remove(&3);
+
+        let keys: Vec<u32> = map.iter_keys().map(u32::from_unaligned).collect();
+        assert_eq!(keys, vec![1, 2, 5]);
+        let values: Vec<&str> = map.iter_values().collect();
+        assert_eq!(values, vec!["one", "two", "five"]);
+        let entries: Vec<(u32, &str)> = map
+            .iter()
+            .map(|(k, v)| (u32::from_unaligned(k), v))
+            .collect();
+        assert_eq!(entries, vec![(1, "one"), (2, "two"), (5, "five")]);
+    }
+
+    #[test]
+    fn test_iter_zerovec_values() {
+        let mut map: ZeroMap<String, u16> = ZeroMap::new();
+        for (key, value) in &[("c", 3), ("a", 1), ("d", 4), ("b", 2)] {
+            map.insert(key.to_string(), *value);
+        }
+
+        let keys: Vec<&str> = map.iter_keys().collect();
+        assert_eq!(keys, vec!["a", "b", "c", "d"]);
+        let values: Vec<u16> = map.iter_values().map(u16::from_unaligned).collect();
+        assert_eq!(values, vec![1, 2, 3, 4]);
+        let entries: Vec<(&str, u16)> = map.iter_copied_values().collect();
+        assert_eq!(entries, vec![("a", 1), ("b", 2), ("c", 3), ("d", 4)]);
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let map: ZeroMap<String, u32> = vec![("c", 3), ("a", 1), ("b", 0), ("b", 2)]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect();
+        let entries: Vec<(&str, u32)> = map.iter_copied_values().collect();
+        assert_eq!(entries, vec![("a", 1), ("b", 2), ("c", 3)]);
+
+        let empty: ZeroMap<u32, u32> = core::iter::empty().collect();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_from_sorted_iter() {
+        let map = ZeroMap::<u32, u16>::from_sorted_iter((0..100).map(|i| (i * 2, i as u16)))
+            .expect("sorted input");
+        assert_eq!(map.len(), 100);
+        assert_eq!(map.get_copied(&42), Some(21));
+        assert_eq!(map.get_copied(&43), None);
+
+        let result = ZeroMap::<u32, u16>::from_sorted_iter(vec![(1, 1), (3, 3), (2, 2), (4, 4)]);
+        assert!(matches!(result, Err((2, 2))));
+        let result = ZeroMap::<u32, u16>::from_sorted_iter(vec![(1, 1), (1, 2)]);
+        assert!(matches!(result, Err((1, 2))));
+    }
+
+    #[test]
+    fn test_iter_empty() {
+        let map: ZeroMap<u32, u32> = ZeroMap::new();
+        assert_eq!(map.iter().count(), 0);
+        assert_eq!(map.iter_keys().count(), 0);
+        assert_eq!(map.iter_values().count(), 0);
+        assert_eq!(map.iter_copied().count(), 0);
+    }
+}
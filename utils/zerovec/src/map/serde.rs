@@ -3,11 +3,11 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use super::{ZeroMap, ZeroMapKV, ZeroVecLike};
+use core::fmt;
+use core::marker::PhantomData;
 use serde::de::{self, MapAccess, Visitor};
 use serde::ser::SerializeMap;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::fmt;
-use std::marker::PhantomData;
 
 /// This impl can be made available by enabling the optional `serde` feature of the `zerovec` crate
 impl<'a, K, V> Serialize for ZeroMap<'a, K, V>
@@ -158,4 +158,22 @@ mod test {
             map.iter().collect::<Vec<_>>()
         );
     }
+
+    #[test]
+    fn test_unsorted_keys() {
+        let json_str = "{\"2\":\"dos\",\"1\":\"uno\",\"3\":\"tres\"}";
+        let result: Result<ZeroMap<u32, String>, _> = serde_json::from_str(json_str);
+        assert!(result.is_err());
+
+        let json_str = "{\"1\":\"uno\",\"1\":\"dos\"}";
+        let result: Result<ZeroMap<u32, String>, _> = serde_json::from_str(json_str);
+        assert!(result.is_err());
+
+        // BINCODE_BYTES with the first two keys swapped
+        let mut bincode_bytes = BINCODE_BYTES.to_vec();
+        bincode_bytes[8] = 2;
+        bincode_bytes[12] = 1;
+        let result: Result<ZeroMap<u32, String>, _> = bincode::deserialize(&bincode_bytes);
+        assert!(result.is_err());
+    }
 }
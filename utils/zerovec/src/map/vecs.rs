@@ -5,8 +5,9 @@
 use crate::ule::*;
 use crate::VarZeroVec;
 use crate::ZeroVec;
-use std::cmp::Ordering;
-use std::mem;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::mem;
 
 /// Trait abstracting over [`ZeroVec`] and [`VarZeroVec`], for use in [`ZeroMap`](super::ZeroMap). You
 /// should not be implementing or calling this trait directly.
@@ -60,17 +61,17 @@ where
         self.get_ule_ref(index)
     }
     fn insert(&mut self, index: usize, value: T) {
-        self.make_mut().insert(index, value.as_unaligned())
+        self.insert(index, value)
     }
     fn remove(&mut self, index: usize) -> T {
-        T::from_unaligned(&self.make_mut().remove(index))
+        self.remove(index)
     }
     fn replace(&mut self, index: usize, value: T) -> T {
         let vec = self.make_mut();
         T::from_unaligned(&mem::replace(&mut vec[index], value.as_unaligned()))
     }
     fn push(&mut self, value: T) {
-        self.make_mut().push(value.as_unaligned())
+        self.push(value)
     }
     fn len(&self) -> usize {
         self.len()
@@ -0,0 +1,157 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+#![allow(clippy::upper_case_acronyms)]
+//! ULE implementation for the `bool` type.
+
+use super::*;
+use core::fmt;
+
+/// A single byte that is either 0 (`false`) or 1 (`true`).
+///
+/// The byte of a `BoolULE` is guaranteed to be 0 or 1, so it can be converted to a `bool`
+/// without validation.
+///
+/// # Examples
+///
+/// Convert a `bool` to a `BoolULE` and back again:
+///
+/// ```
+/// use zerovec::ule::{ULE, AsULE, BoolULE};
+///
+/// let ules = [true.as_unaligned(), false.as_unaligned()];
+/// assert_eq!(BoolULE::as_byte_slice(&ules), &[0x01, 0x00]);
+/// assert!(bool::from_unaligned(&ules[0]));
+/// assert!(!bool::from_unaligned(&ules[1]));
+/// ```
+///
+/// Attempt to parse invalid bytes to a `BoolULE`:
+///
+/// ```
+/// use zerovec::ule::{ULE, BoolULE};
+///
+/// let bytes: &[u8] = &[0x00, 0x01, 0x02];
+/// let err = BoolULE::parse_byte_slice(bytes).expect_err("Invalid bytes");
+/// assert_eq!(err.offset, 2);
+/// assert_eq!(err.value, 0x02);
+/// ```
+#[repr(transparent)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BoolULE(u8);
+
+/// The error returned by [`BoolULE::parse_byte_slice`] if the bytes contain a value other than
+/// 0 or 1.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BoolULEError {
+    /// The byte offset of the first invalid byte.
+    pub offset: usize,
+    /// The invalid byte.
+    pub value: u8,
+}
+
+impl fmt::Display for BoolULEError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid bool value {:#X} at byte offset {}",
+            self.value, self.offset
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BoolULEError {}
+
+impl ULE for BoolULE {
+    type Error = BoolULEError;
+
+    #[inline]
+    fn parse_byte_slice(bytes: &[u8]) -> Result<&[Self], Self::Error> {
+        // Validate the bytes
+        if let Some(offset) = bytes.iter().position(|&b| b > 1) {
+            return Err(BoolULEError {
+                offset,
+                value: bytes[offset],
+            });
+        }
+        let data = bytes.as_ptr();
+        let len = bytes.len();
+        // Safe because Self is transparent over u8
+        Ok(unsafe { core::slice::from_raw_parts(data as *const Self, len) })
+    }
+
+    #[inline]
+    fn as_byte_slice(slice: &[Self]) -> &[u8] {
+        let data = slice.as_ptr();
+        let len = slice.len();
+        // Safe because Self is transparent over u8
+        unsafe { core::slice::from_raw_parts(data as *const u8, len) }
+    }
+}
+
+impl AsULE for bool {
+    type ULE = BoolULE;
+
+    #[inline]
+    fn as_unaligned(&self) -> Self::ULE {
+        BoolULE(*self as u8)
+    }
+
+    #[inline]
+    fn from_unaligned(unaligned: &Self::ULE) -> Self {
+        // The byte of a BoolULE is defined to be 0 or 1.
+        unaligned.0 != 0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let bools = [true, false, false, true];
+        let bool_ules: Vec<BoolULE> = bools.iter().map(bool::as_unaligned).collect();
+        let bool_bytes: &[u8] = BoolULE::as_byte_slice(&bool_ules);
+        assert_eq!(&[1, 0, 0, 1], bool_bytes);
+
+        let parsed_ules: &[BoolULE] = BoolULE::parse_byte_slice(bool_bytes).unwrap();
+        assert_eq!(bool_ules, parsed_ules);
+        let parsed_bools: Vec<bool> = parsed_ules.iter().map(bool::from_unaligned).collect();
+        assert_eq!(&bools, parsed_bools.as_slice());
+
+        let zv = crate::ZeroVec::<bool>::try_from_bytes(bool_bytes).unwrap();
+        assert_eq!(zv.to_vec(), bools);
+        assert_eq!(zv.as_bytes(), bool_bytes);
+    }
+
+    #[test]
+    fn test_failures() {
+        for value in [2u8, 0x10, 0x80, 0xFF].iter().copied() {
+            // The value under test is preceded by two valid bools.
+            let bytes = [1, 0, value, 1];
+            let expected = Err(BoolULEError { offset: 2, value });
+            assert_eq!(
+                BoolULE::parse_byte_slice(&bytes).map(<[BoolULE]>::len),
+                expected,
+                "{:#X}",
+                value
+            );
+            assert_eq!(
+                crate::ZeroVec::<bool>::try_from_bytes(&bytes).map(|zv| zv.len()),
+                expected,
+                "{:#X}",
+                value
+            );
+        }
+        assert_eq!(
+            "Invalid bool value 0xFF at byte offset 2",
+            BoolULEError {
+                offset: 2,
+                value: 0xFF
+            }
+            .to_string()
+        );
+    }
+}
@@ -6,7 +6,8 @@
 //! ULE implementation for the `char` type.
 
 use super::*;
-use std::convert::TryFrom;
+use core::convert::TryFrom;
+use core::fmt;
 
 /// A u8 array of little-endian data corresponding to a Unicode code point.
 ///
@@ -33,27 +34,57 @@ use std::convert::TryFrom;
 /// use zerovec::ule::{ULE, CharULE};
 ///
 /// let bytes: &[u8] = &[0xFF, 0xFF, 0xFF, 0xFF];
-/// CharULE::parse_byte_slice(bytes).expect_err("Invalid bytes");
+/// let err = CharULE::parse_byte_slice(bytes).expect_err("Invalid bytes");
+/// assert_eq!(err.offset, 0);
+/// assert_eq!(err.value, 0xFFFFFFFF);
 /// ```
 #[repr(transparent)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct CharULE([u8; 4]);
 
+/// The error returned by [`CharULE::parse_byte_slice`] if the bytes contain a value that is not a
+/// valid `char`, that is, a surrogate code point or a value above U+10FFFF.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CharULEError {
+    /// The byte offset of the first invalid 4-byte group.
+    pub offset: usize,
+    /// The invalid value, decoded as a little-endian u32.
+    pub value: u32,
+}
+
+impl fmt::Display for CharULEError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid char value {:#X} at byte offset {}",
+            self.value, self.offset
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CharULEError {}
+
 impl ULE for CharULE {
-    type Error = std::char::CharTryFromError;
+    type Error = CharULEError;
 
     #[inline]
     fn parse_byte_slice(bytes: &[u8]) -> Result<&[Self], Self::Error> {
         // Validate the bytes
-        for chunk in bytes.chunks_exact(4) {
+        for (i, chunk) in bytes.chunks_exact(4).enumerate() {
             // TODO: Use slice::as_chunks() when stabilized
             let u = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
-            char::try_from(u)?;
+            if char::try_from(u).is_err() {
+                return Err(CharULEError {
+                    offset: i * 4,
+                    value: u,
+                });
+            }
         }
         let data = bytes.as_ptr();
         let len = bytes.len() / 4;
         // Safe because Self is transparent over [u8; 4]
-        Ok(unsafe { std::slice::from_raw_parts(data as *const Self, len) })
+        Ok(unsafe { core::slice::from_raw_parts(data as *const Self, len) })
     }
 
     #[inline]
@@ -61,7 +92,7 @@ impl ULE for CharULE {
         let data = slice.as_ptr();
         let len = slice.len() * 4;
         // Safe because Self is transparent over [u8; 4]
-        unsafe { std::slice::from_raw_parts(data as *const u8, len) }
+        unsafe { core::slice::from_raw_parts(data as *const u8, len) }
     }
 }
 
@@ -120,7 +151,13 @@ mod test {
         let u32_ules: Vec<PlainOldULE<4>> = u32s.iter().map(<u32 as AsULE>::as_unaligned).collect();
         let u32_bytes: &[u8] = PlainOldULE::<4>::as_byte_slice(&u32_ules);
         let parsed_ules_result = CharULE::parse_byte_slice(&u32_bytes);
-        assert!(matches!(parsed_ules_result, Err(_)));
+        assert_eq!(
+            parsed_ules_result,
+            Err(CharULEError {
+                offset: 4,
+                value: 0xD800
+            })
+        );
 
         // 0x20FFFF is out of range for a char
         let u32s = [0x20FFFF];
@@ -129,4 +166,48 @@ mod test {
         let parsed_ules_result = CharULE::parse_byte_slice(&u32_bytes);
         assert!(matches!(parsed_ules_result, Err(_)));
     }
+
+    #[test]
+    fn test_boundaries() {
+        let cases: [(u32, bool); 6] = [
+            (0xD7FF, true),
+            (0xD800, false),
+            (0xDFFF, false),
+            (0xE000, true),
+            (0x10FFFF, true),
+            (0x110000, false),
+        ];
+        for (value, is_valid) in cases.iter().copied() {
+            // The value under test is preceded by two valid chars.
+            let u32s = [119, 120, value];
+            let u32_ules: Vec<PlainOldULE<4>> =
+                u32s.iter().map(<u32 as AsULE>::as_unaligned).collect();
+            let u32_bytes: &[u8] = PlainOldULE::<4>::as_byte_slice(&u32_ules);
+            let expected = if is_valid {
+                Ok(3)
+            } else {
+                Err(CharULEError { offset: 8, value })
+            };
+            assert_eq!(
+                CharULE::parse_byte_slice(u32_bytes).map(<[CharULE]>::len),
+                expected,
+                "{:#X}",
+                value
+            );
+            assert_eq!(
+                crate::ZeroVec::<char>::try_from_bytes(u32_bytes).map(|zv| zv.len()),
+                expected,
+                "{:#X}",
+                value
+            );
+        }
+        assert_eq!(
+            "Invalid char value 0x110000 at byte offset 8",
+            CharULEError {
+                offset: 8,
+                value: 0x110000
+            }
+            .to_string()
+        );
+    }
 }
@@ -5,11 +5,13 @@
 #![allow(clippy::upper_case_acronyms)]
 //! Traits over unaligned little-endian data (ULE, pronounced "yule").
 
+mod bools;
 mod chars;
 mod plain;
 mod string;
 
-pub use chars::CharULE;
+pub use bools::{BoolULE, BoolULEError};
+pub use chars::{CharULE, CharULEError};
 pub use plain::PlainOldULE;
 
 /// Fixed-width, byte-aligned data that can be cast to and from a little-endian byte slice.
@@ -75,6 +77,50 @@ pub trait AsULE {
     /// was first constructed. An implementation may therefore involve an `unsafe{}` block, like
     /// `from_bytes_unchecked()`.
     fn from_unaligned(unaligned: &Self::ULE) -> Self;
+
+    /// Converts a slice of `Self::ULE` to `Self`, writing the results into `dst`.
+    ///
+    /// The default implementation calls [`AsULE::from_unaligned`] on each element. Types whose
+    /// ULE is their little-endian byte representation override it with a bulk copy on
+    /// little-endian targets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` and `dst` have different lengths.
+    #[inline]
+    fn copy_from_unaligned_slice(src: &[Self::ULE], dst: &mut [Self])
+    where
+        Self: Sized,
+    {
+        assert_eq!(
+            src.len(),
+            dst.len(),
+            "source and destination slices have different lengths"
+        );
+        for (aligned, unaligned) in dst.iter_mut().zip(src) {
+            *aligned = Self::from_unaligned(unaligned);
+        }
+    }
+}
+
+/// A trait for [`AsULE`] types whose ULE type can hold values that are not valid for `Self`.
+///
+/// Most ULE types are validated once, in [`ULE::parse_byte_slice`]. A type may instead use a ULE
+/// type that accepts any bytes, such as [`PlainOldULE`], and validate each element when it is
+/// read. This makes parsing cheap and lets callers find out exactly which elements are invalid.
+/// See [`ZeroVec::get_checked`](crate::ZeroVec::get_checked) for an example.
+///
+/// Such a type should panic in [`AsULE::from_unaligned`] if the value is invalid.
+pub trait TryAsULE: AsULE + Sized {
+    /// The error that occurs if a `Self::ULE` is not a valid `Self`.
+    type Error;
+
+    /// Converts from `&Self::ULE` to `Self`, or returns an error if it is not a valid `Self`.
+    ///
+    /// This function may involve byte order swapping (little-endian to native-endian).
+    ///
+    /// For best performance, mark your implementation of this function `#[inline]`.
+    fn try_from_unaligned(unaligned: &Self::ULE) -> Result<Self, Self::Error>;
 }
 
 /// A trait for any type that has a 1:1 mapping with an variable-width unaligned little-endian (VarULE) type.
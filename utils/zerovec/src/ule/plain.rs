@@ -3,7 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 #![allow(clippy::upper_case_acronyms)]
-//! ULE implementation for Plain Old Data types, including all sized integers.
+//! ULE implementation for Plain Old Data types, including all sized integers and floats.
 
 use super::*;
 
@@ -27,20 +27,20 @@ macro_rules! impl_byte_slice_size {
             }
         }
         impl ULE for PlainOldULE<$size> {
-            type Error = std::convert::Infallible;
+            type Error = core::convert::Infallible;
             #[inline]
             fn parse_byte_slice(bytes: &[u8]) -> Result<&[Self], Self::Error> {
                 let data = bytes.as_ptr();
                 let len = bytes.len() / $size;
                 // Safe because Self is transparent over [u8; $size]
-                Ok(unsafe { std::slice::from_raw_parts(data as *const Self, len) })
+                Ok(unsafe { core::slice::from_raw_parts(data as *const Self, len) })
             }
             #[inline]
             fn as_byte_slice(slice: &[Self]) -> &[u8] {
                 let data = slice.as_ptr();
                 let len = slice.len() * $size;
                 // Safe because Self is transparent over [u8; $size]
-                unsafe { std::slice::from_raw_parts(data as *const u8, len) }
+                unsafe { core::slice::from_raw_parts(data as *const u8, len) }
             }
         }
     };
@@ -64,21 +64,53 @@ macro_rules! impl_byte_slice_type {
             fn from_unaligned(unaligned: &Self::ULE) -> Self {
                 <$type>::from_le_bytes(unaligned.0)
             }
+            #[inline]
+            fn copy_from_unaligned_slice(src: &[Self::ULE], dst: &mut [Self]) {
+                assert_eq!(
+                    src.len(),
+                    dst.len(),
+                    "source and destination slices have different lengths"
+                );
+                if cfg!(target_endian = "little") {
+                    let bytes = Self::ULE::as_byte_slice(src);
+                    // Safe because dst is exactly bytes.len() bytes long, a mutable borrow cannot
+                    // overlap src, and the little-endian bytes are the native representation of
+                    // $type on a little-endian target.
+                    unsafe {
+                        core::ptr::copy_nonoverlapping(
+                            bytes.as_ptr(),
+                            dst.as_mut_ptr() as *mut u8,
+                            bytes.len(),
+                        )
+                    }
+                } else {
+                    for (aligned, unaligned) in dst.iter_mut().zip(src) {
+                        *aligned = Self::from_unaligned(unaligned);
+                    }
+                }
+            }
         }
     };
 }
 
+impl_byte_slice_size!(1);
 impl_byte_slice_size!(2);
 impl_byte_slice_size!(4);
 impl_byte_slice_size!(8);
 impl_byte_slice_size!(16);
 
+impl_byte_slice_type!(u8, 1);
 impl_byte_slice_type!(u16, 2);
 impl_byte_slice_type!(u32, 4);
 impl_byte_slice_type!(u64, 8);
 impl_byte_slice_type!(u128, 16);
 
+impl_byte_slice_type!(i8, 1);
 impl_byte_slice_type!(i16, 2);
 impl_byte_slice_type!(i32, 4);
 impl_byte_slice_type!(i64, 8);
 impl_byte_slice_type!(i128, 16);
+
+// Every bit pattern is a valid IEEE 754 float, so these are infallible like the integers.
+impl_byte_slice_type!(f32, 4);
+impl_byte_slice_type!(f64, 8);
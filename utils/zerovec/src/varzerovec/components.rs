@@ -3,9 +3,10 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use super::*;
-use std::convert::TryInto;
-use std::marker::PhantomData;
-use std::{iter, mem};
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use core::marker::PhantomData;
+use core::{iter, mem};
 
 fn usizeify(x: PlainOldULE<4>) -> usize {
     u32::from_unaligned(&x) as usize
@@ -87,6 +88,39 @@ impl<'a, T: AsVarULE> SliceComponents<'a, T> {
         Ok(components)
     }
 
+    /// Construct a new SliceComponents without checking any of the invariants listed on
+    /// [`SliceComponents::try_from_bytes()`].
+    ///
+    /// Safety:
+    /// - `slice` must uphold all of the invariants checked by `try_from_bytes()`, for example
+    ///   by having been produced by a [`LayoutWriter`]
+    #[inline]
+    pub unsafe fn from_bytes_unchecked(slice: &'a [u8]) -> Self {
+        if slice.is_empty() {
+            return SliceComponents {
+                indices: &[],
+                things: &[],
+                entire_slice: slice,
+                marker: PhantomData,
+            };
+        }
+        let len_bytes = slice.get_unchecked(0..4);
+        let len =
+            u32::from_le_bytes([len_bytes[0], len_bytes[1], len_bytes[2], len_bytes[3]]) as usize;
+        let indices_bytes = slice.get_unchecked(4..4 * len + 4);
+        // Safe because PlainOldULE<4> is transparent over [u8; 4]
+        let indices =
+            core::slice::from_raw_parts(indices_bytes.as_ptr() as *const PlainOldULE<4>, len);
+        let things = slice.get_unchecked(4 * len + 4..);
+
+        SliceComponents {
+            indices,
+            things,
+            entire_slice: slice,
+            marker: PhantomData,
+        }
+    }
+
     #[inline]
     pub fn len(self) -> usize {
         self.indices.len()
@@ -234,24 +268,62 @@ where
     }
 }
 
-pub fn get_serializable_bytes<T: AsVarULE>(elements: &[T]) -> Option<Vec<u8>> {
-    let mut vec = Vec::with_capacity(4 + 4 * elements.len());
-    let len_u32: u32 = elements.len().try_into().ok()?;
-    vec.extend(&len_u32.as_unaligned().0);
-    let mut offset: u32 = 0;
-    for element in elements {
-        vec.extend(&offset.as_unaligned().0);
-        let len_u32: u32 = element
-            .as_unaligned()
-            .as_byte_slice()
-            .len()
-            .try_into()
-            .ok()?;
-        offset = offset.checked_add(len_u32)?;
+/// Writes the byte layout of a `VarZeroVec` in a single pass over its elements.
+///
+/// The indices and the element data are accumulated in separate buffers, which are joined
+/// by [`LayoutWriter::finish()`]. The result upholds the invariants checked by
+/// [`SliceComponents::try_from_bytes()`].
+pub struct LayoutWriter {
+    /// The `len` placeholder followed by the indices written so far
+    indices: Vec<u8>,
+    /// The contiguous bytes of the elements written so far
+    things: Vec<u8>,
+    len: u32,
+}
+
+impl LayoutWriter {
+    /// Create a writer with room for the indices of `capacity` elements
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut indices = Vec::with_capacity(4 + 4 * capacity);
+        indices.extend(&0u32.as_unaligned().0);
+        LayoutWriter {
+            indices,
+            things: Vec::new(),
+            len: 0,
+        }
     }
-    vec.reserve(offset as usize);
+
+    /// Append the bytes of one element.
+    ///
+    /// Returns `None` if the number of elements or the total size of the data no
+    /// longer fits in a `u32`, in which case nothing is written.
+    pub fn push(&mut self, bytes: &[u8]) -> Option<()> {
+        let offset: u32 = self.things.len().try_into().ok()?;
+        let size: u32 = bytes.len().try_into().ok()?;
+        offset.checked_add(size)?;
+        self.len = self.len.checked_add(1)?;
+        self.indices.extend(&offset.as_unaligned().0);
+        self.things.extend(bytes);
+        Some(())
+    }
+
+    /// Write the final length and join the indices with the data
+    pub fn finish(self) -> Vec<u8> {
+        let LayoutWriter {
+            mut indices,
+            things,
+            len,
+        } = self;
+        indices[0..4].copy_from_slice(&len.as_unaligned().0);
+        indices.extend(things);
+        indices
+    }
+}
+
+pub fn get_serializable_bytes<T: AsVarULE>(elements: &[T]) -> Option<Vec<u8>> {
+    let mut writer = LayoutWriter::with_capacity(elements.len());
     for element in elements {
-        vec.extend(element.as_unaligned().as_byte_slice())
+        writer.push(element.as_unaligned().as_byte_slice())?;
     }
-    Some(vec)
+    Some(writer.finish())
 }
@@ -3,10 +3,12 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 use crate::ule::*;
-use components::SliceComponents;
+use alloc::vec::Vec;
+use components::{LayoutWriter, SliceComponents};
+use core::fmt::{self, Display};
+use core::iter::FromIterator;
+use core::ops::Index;
 use either::Either;
-use std::fmt::{self, Display};
-use std::ops::Index;
 
 mod components;
 #[cfg(feature = "serde")]
@@ -71,7 +73,7 @@ pub struct VarZeroVec<'a, T>(VarZeroVecInner<'a, T>);
 ///
 /// Should not be made public.
 ///
-/// Safety invariant: Borrowed() must have the appropriate
+/// Safety invariant: Borrowed() and OwnedBytes() must have the appropriate
 /// format:
 ///
 /// The format of the slice is conceptually:
@@ -95,6 +97,9 @@ enum VarZeroVecInner<'a, T> {
     /// a `&'a [T::VarULE]`, but since `T::VarULE` is unsized that type does not actually
     /// exist
     Borrowed(SliceComponents<'a, T>),
+    /// An owned buffer in the same format as the slice behind `Borrowed`, as
+    /// written by [`FromIterator`]
+    OwnedBytes(Vec<u8>),
 }
 
 #[derive(Clone, Debug)]
@@ -136,6 +141,36 @@ impl<'a, T> From<Vec<T>> for VarZeroVec<'a, T> {
     }
 }
 
+/// Collects the elements into an owned `VarZeroVec<T>`, writing the variable-width layout
+/// (the same bytes as [`VarZeroVec::get_serializable_bytes()`]) in a single pass over the
+/// iterator.
+///
+/// # Panics
+///
+/// Panics if the layout does not fit in a list of bytes whose length fits in a `u32`.
+///
+/// # Example
+///
+/// ```
+/// use std::iter::FromIterator;
+/// use zerovec::VarZeroVec;
+///
+/// let vec = VarZeroVec::from_iter(vec!["foo", "bar"].into_iter().map(String::from));
+/// assert_eq!(vec.get(1), Some("bar"));
+/// ```
+impl<'a, T: AsVarULE> FromIterator<T> for VarZeroVec<'a, T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut writer = LayoutWriter::with_capacity(iter.size_hint().0);
+        for element in iter {
+            writer
+                .push(element.as_unaligned().as_byte_slice())
+                .expect("VarZeroVec too large to be represented as bytes");
+        }
+        VarZeroVecInner::OwnedBytes(writer.finish()).into()
+    }
+}
+
 impl<'a, T> From<VarZeroVecInner<'a, T>> for VarZeroVec<'a, T> {
     #[inline]
     fn from(other: VarZeroVecInner<'a, T>) -> Self {
@@ -165,6 +200,7 @@ impl<'a, T: AsVarULE> VarZeroVec<'a, T> {
         match self.0 {
             VarZeroVecInner::Owned(ref vec) => vec.len(),
             VarZeroVecInner::Borrowed(components) => components.len(),
+            VarZeroVecInner::OwnedBytes(ref bytes) => Self::owned_components(bytes).len(),
         }
     }
 
@@ -188,6 +224,7 @@ impl<'a, T: AsVarULE> VarZeroVec<'a, T> {
         match self.0 {
             VarZeroVecInner::Owned(ref vec) => vec.is_empty(),
             VarZeroVecInner::Borrowed(components) => components.is_empty(),
+            VarZeroVecInner::OwnedBytes(ref bytes) => Self::owned_components(bytes).is_empty(),
         }
     }
 
@@ -253,6 +290,9 @@ impl<'a, T: AsVarULE> VarZeroVec<'a, T> {
         match self.0 {
             VarZeroVecInner::Owned(ref vec) => Either::Left(vec.iter().map(|t| t.as_unaligned())),
             VarZeroVecInner::Borrowed(components) => Either::Right(components.iter()),
+            VarZeroVecInner::OwnedBytes(ref bytes) => {
+                Either::Right(Self::owned_components(bytes).iter())
+            }
         }
     }
 
@@ -282,6 +322,7 @@ impl<'a, T: AsVarULE> VarZeroVec<'a, T> {
         match self.0 {
             VarZeroVecInner::Owned(ref vec) => vec.get(idx).map(|t| t.as_unaligned()),
             VarZeroVecInner::Borrowed(components) => components.get(idx),
+            VarZeroVecInner::OwnedBytes(ref bytes) => Self::owned_components(bytes).get(idx),
         }
     }
 
@@ -327,10 +368,45 @@ impl<'a, T: AsVarULE> VarZeroVec<'a, T> {
                 // recursion is limited since we are guaranteed to hit the Owned branch
                 self.make_mut()
             }
+            VarZeroVecInner::OwnedBytes(ref bytes) => {
+                let vec = Self::owned_components(bytes)
+                    .iter()
+                    .map(T::from_unaligned)
+                    .collect();
+                let new_self = VarZeroVecInner::Owned(vec).into();
+                *self = new_self;
+                self.make_mut()
+            }
         }
     }
 
-    /// Converts a borrowed ZeroVec to an owned ZeroVec. No-op if already owned.
+    /// Appends an element to the back of the vector, converting it to an owned vector first if
+    /// necessary.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::str::Utf8Error;
+    /// # use zerovec::VarZeroVecError;
+    /// # use zerovec::VarZeroVec;
+    ///
+    /// let strings = vec!["foo".to_owned(), "bar".to_owned()];
+    /// let bytes = VarZeroVec::get_serializable_bytes(&strings).unwrap();
+    /// let mut vec: VarZeroVec<String> = VarZeroVec::try_from_bytes(&bytes)?;
+    ///
+    /// vec.push("baz".to_owned());
+    /// assert_eq!(vec.len(), 3);
+    /// assert_eq!(&vec[2], "baz");
+    /// # Ok::<(), VarZeroVecError<Utf8Error>>(())
+    /// ```
+    pub fn push(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.make_mut().push(value)
+    }
+
+    /// Converts a borrowed VarZeroVec to an owned VarZeroVec. No-op if already owned.
     ///
     /// # Example
     ///
@@ -353,6 +429,9 @@ impl<'a, T: AsVarULE> VarZeroVec<'a, T> {
     where
         T: Clone,
     {
+        if let VarZeroVecInner::OwnedBytes(bytes) = self.0 {
+            return VarZeroVecInner::OwnedBytes(bytes).into();
+        }
         self.make_mut();
         match self.0 {
             VarZeroVecInner::Owned(vec) => vec.into(),
@@ -373,15 +452,24 @@ impl<'a, T: AsVarULE> VarZeroVec<'a, T> {
         }
     }
 
-    /// If this is borrowed, get the borrowed slice
+    /// If this is backed by a byte buffer (borrowed or owned), get that buffer
     #[cfg(feature = "serde")]
-    pub(crate) fn get_slice_for_borrowed(&self) -> Option<&'a [u8]> {
+    pub(crate) fn get_encoded_slice(&self) -> Option<&[u8]> {
         match self.0 {
             VarZeroVecInner::Owned(..) => None,
             VarZeroVecInner::Borrowed(b) => Some(b.entire_slice()),
+            VarZeroVecInner::OwnedBytes(ref bytes) => Some(bytes),
         }
     }
 
+    /// Get the components of an `OwnedBytes` buffer
+    #[inline]
+    fn owned_components(bytes: &[u8]) -> SliceComponents<'_, T> {
+        // Safe because OwnedBytes is only ever constructed from the output of a
+        // LayoutWriter, which upholds the invariants of SliceComponents
+        unsafe { SliceComponents::from_bytes_unchecked(bytes) }
+    }
+
     /// For a slice of `T`, get a list of bytes that can be passed to
     /// `try_from_bytes` to recoup the same data.
     ///
@@ -442,6 +530,9 @@ where
                 vec.binary_search_by(|probe| probe.as_unaligned().cmp(x))
             }
             VarZeroVecInner::Borrowed(components) => components.binary_search(x),
+            VarZeroVecInner::OwnedBytes(ref bytes) => {
+                Self::owned_components(bytes).binary_search(x)
+            }
         }
     }
 }
@@ -475,3 +566,50 @@ where
         self.iter().eq(other.iter().map(|t| t.as_unaligned()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+
+    #[test]
+    fn test_from_iter() {
+        let strings = vec![
+            String::new(),
+            String::from("foo"),
+            String::from("quux"),
+            String::from("ω文𑄃"),
+        ];
+        let vec: VarZeroVec<String> = strings.iter().cloned().collect();
+        assert!(matches!(vec.0, VarZeroVecInner::OwnedBytes(_)));
+        assert_eq!(vec.len(), 4);
+        assert_eq!(vec.get(3), Some("ω文𑄃"));
+        assert_eq!(vec.get(4), None);
+        assert_eq!(vec, &*strings);
+        assert_eq!(vec.binary_search("foo"), Ok(1));
+        assert_eq!(vec.binary_search("bar"), Err(1));
+
+        let bytes = VarZeroVec::get_serializable_bytes(&strings).unwrap();
+        match vec.0 {
+            VarZeroVecInner::OwnedBytes(ref owned) => assert_eq!(owned, &bytes),
+            _ => unreachable!(),
+        }
+        let borrowed: VarZeroVec<String> = VarZeroVec::try_from_bytes(&bytes).unwrap();
+        assert_eq!(vec, borrowed);
+        assert_eq!(vec.clone().into_owned(), borrowed);
+
+        let mut vec = vec;
+        vec.push(String::from("bar"));
+        assert_eq!(vec.len(), 5);
+        assert_eq!(&vec[4], "bar");
+    }
+
+    #[test]
+    fn test_from_iter_empty() {
+        let vec: VarZeroVec<String> = core::iter::empty().collect();
+        assert!(vec.is_empty());
+        assert_eq!(vec.iter().count(), 0);
+        let empty: &[String] = &[];
+        assert_eq!(vec, empty);
+    }
+}
@@ -4,10 +4,11 @@
 
 use super::VarZeroVec;
 use crate::ule::*;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
 use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
 use serde::ser::{self, Serialize, SerializeSeq, Serializer};
-use std::fmt;
-use std::marker::PhantomData;
 
 struct VarZeroVecVisitor<T> {
     marker: PhantomData<fn() -> T>,
@@ -95,7 +96,7 @@ where
                 seq.serialize_element(&T::from_unaligned(value))?;
             }
             seq.end()
-        } else if let Some(slice) = self.get_slice_for_borrowed() {
+        } else if let Some(slice) = self.get_encoded_slice() {
             serializer.serialize_bytes(&slice)
         } else {
             // This creates an additional Vec allocation to enable code reuse of
@@ -144,7 +145,7 @@ mod test {
         let zerovec_new: VarZeroVec<String> =
             serde_json::from_str(&json_str).expect("deserialize from buffer to VarZeroVec");
         assert_eq!(zerovec_orig.to_vec(), zerovec_new.to_vec());
-        assert!(zerovec_new.get_slice_for_borrowed().is_none());
+        assert!(zerovec_new.get_encoded_slice().is_none());
     }
 
     #[test]
@@ -155,7 +156,7 @@ mod test {
         let zerovec_new: VarZeroVec<String> =
             bincode::deserialize(&bincode_buf).expect("deserialize from buffer to VarZeroVec");
         assert_eq!(zerovec_orig.to_vec(), zerovec_new.to_vec());
-        assert!(zerovec_new.get_slice_for_borrowed().is_some());
+        assert!(zerovec_new.get_encoded_slice().is_some());
     }
 
     #[test]
@@ -6,7 +6,11 @@
 mod serde;
 
 use crate::ule::*;
-use std::fmt;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::FromIterator;
+use core::ops::{Index, Range};
 
 /// A zero-copy vector for fixed-width types.
 ///
@@ -14,7 +18,7 @@ use std::fmt;
 /// desirable to borrow data from an unaligned byte slice, such as zero-copy deserialization.
 ///
 /// `T` must implement [`AsULE`], which is auto-implemented for a number of built-in types,
-/// including all fixed-width multibyte integers.
+/// including all fixed-width integers and floats.
 ///
 /// # How it Works
 ///
@@ -51,7 +55,60 @@ use std::fmt;
 /// assert_eq!(zerovec, nums);
 /// ```
 ///
+/// # Floating-point numbers
+///
+/// `ZeroVec<f32>` and `ZeroVec<f64>` store their elements as little-endian IEEE 754 bytes.
+/// Every bit pattern is a valid float, so [`ZeroVec::try_from_bytes`] cannot fail for them, and
+/// NaN payloads and signed zeros round-trip unchanged.
+///
+/// Floats are not `Ord`, so methods that require it, such as [`ZeroVec::binary_search`], are
+/// not available; `ZeroVec<f32>` is `PartialEq` and `PartialOrd` but not `Eq` or `Ord`, and it
+/// cannot be a key of a [`ZeroMap`]. Use [`ZeroVec::binary_search_by`] with a comparator that
+/// decides how NaN is ordered instead.
+///
+/// ```
+/// use zerovec::ZeroVec;
+///
+/// let coords: &[f64] = &[-122.33, 0.0, 47.61, 151.21];
+/// let zerovec = ZeroVec::from_aligned(coords);
+/// let parsed: ZeroVec<f64> = ZeroVec::try_from_bytes(zerovec.as_bytes()).expect("infallible");
+///
+/// assert_eq!(parsed, coords);
+/// assert_eq!(
+///     parsed.binary_search_by(|probe| probe.partial_cmp(&47.61).expect("not NaN")),
+///     Ok(2)
+/// );
+/// ```
+///
+/// # Serde
+///
+/// With the optional `serde` feature, `ZeroVec<T>` implements `Serialize` and `Deserialize`.
+/// Binary formats, such as Bincode, store it as its byte buffer, which is borrowed and validated
+/// with [`ULE::parse_byte_slice`] on deserialization; invalid bytes, such as a surrogate in a
+/// `ZeroVec<char>`, result in a deserialization error. Human-readable formats, such as JSON,
+/// store it as a sequence of `T`.
+///
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// use zerovec::ZeroVec;
+///
+/// let zerovec = ZeroVec::from_aligned(&['w', 'ω', '文']);
+///
+/// let bincode_buf = bincode::serialize(&zerovec).expect("serialize");
+/// let borrowed: ZeroVec<char> = bincode::deserialize(&bincode_buf).expect("deserialize");
+/// assert!(matches!(borrowed, ZeroVec::Borrowed(_)));
+/// assert_eq!(borrowed, zerovec);
+///
+/// let json_str = serde_json::to_string(&zerovec).expect("serialize");
+/// assert_eq!(json_str, r#"["w","ω","文"]"#);
+/// let owned: ZeroVec<char> = serde_json::from_str(&json_str).expect("deserialize");
+/// assert_eq!(owned, zerovec);
+/// # } // feature = "serde"
+/// ```
+///
 /// [`ule`]: crate::ule
+/// [`ZeroMap`]: crate::ZeroMap
+/// [`ULE::parse_byte_slice`]: crate::ule::ULE::parse_byte_slice
 #[non_exhaustive]
 #[derive(Clone)]
 pub enum ZeroVec<'a, T>
@@ -85,6 +142,44 @@ where
     }
 }
 
+impl<T> Eq for ZeroVec<'_, T> where T: AsULE + Copy + Eq {}
+
+impl<'a, 'b, T> PartialOrd<ZeroVec<'b, T>> for ZeroVec<'a, T>
+where
+    T: AsULE + Copy + PartialOrd,
+{
+    /// Compares the decoded elements lexicographically. This is not the same as comparing the
+    /// little-endian bytes.
+    #[inline]
+    fn partial_cmp(&self, other: &ZeroVec<'b, T>) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T> Ord for ZeroVec<'_, T>
+where
+    T: AsULE + Copy + Ord,
+{
+    /// Compares the decoded elements lexicographically. This is not the same as comparing the
+    /// little-endian bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zerovec::ZeroVec;
+    ///
+    /// // 256 is encoded as [0x00, 0x01], which is less than [0x01, 0x00] bytewise.
+    /// let a = ZeroVec::<u16>::try_from_bytes(&[0x00, 0x01]).expect("infallible");
+    /// let b = ZeroVec::<u16>::try_from_bytes(&[0x01, 0x00]).expect("infallible");
+    ///
+    /// assert!(a > b);
+    /// ```
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
 impl<T> PartialEq<&[T]> for ZeroVec<'_, T>
 where
     T: AsULE + Copy + PartialEq,
@@ -199,6 +294,28 @@ where
     pub fn is_empty(&self) -> bool {
         self.as_slice().is_empty()
     }
+
+    /// Returns a `ZeroVec<T>` borrowing the elements in `range`, or `None` if `range` is out of
+    /// bounds. This is the zero-copy analog of `&vec[range]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zerovec::ZeroVec;
+    ///
+    /// let bytes: &[u8] = &[0xD3, 0x00, 0x19, 0x01, 0xA5, 0x01, 0xCD, 0x01];
+    /// let zerovec: ZeroVec<u16> = ZeroVec::try_from_bytes(bytes).expect("infallible");
+    ///
+    /// let subslice = zerovec.get_subslice(1..3).expect("in bounds");
+    /// assert!(matches!(subslice, ZeroVec::Borrowed(_)));
+    /// assert_eq!(subslice, &[281, 421][..]);
+    ///
+    /// assert!(zerovec.get_subslice(3..5).is_none());
+    /// ```
+    #[inline]
+    pub fn get_subslice(&self, range: Range<usize>) -> Option<ZeroVec<'_, T>> {
+        self.as_slice().get(range).map(ZeroVec::Borrowed)
+    }
 }
 
 impl<T> ZeroVec<'_, T>
@@ -270,6 +387,63 @@ where
         self.as_slice().get(index).map(T::from_unaligned)
     }
 
+    /// Gets the element at the specified index, panicking if out of range. This is the
+    /// by-value analog of `vec[index]`; use [`Self::get`] for the checked version.
+    ///
+    /// The element is returned by value, so `T` must implement `Copy`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zerovec::ZeroVec;
+    ///
+    /// let bytes: &[u8] = &[0xD3, 0x00, 0x19, 0x01, 0xA5, 0x01, 0xCD, 0x01];
+    /// let zerovec: ZeroVec<u16> = ZeroVec::try_from_bytes(bytes).expect("infallible");
+    ///
+    /// assert_eq!(zerovec.at(2), 421);
+    /// ```
+    #[inline]
+    pub fn at(&self, index: usize) -> T {
+        match self.as_slice().get(index) {
+            Some(ule) => T::from_unaligned(ule),
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.len(),
+                index
+            ),
+        }
+    }
+
+    /// Copies the elements in `range` into `dst`. This is the bulk analog of calling
+    /// [`Self::get`] for each index in `range`.
+    ///
+    /// For the built-in integer types, this is a single memory copy on little-endian targets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `range` is out of bounds, or if `dst` is not the same length as `range`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zerovec::ZeroVec;
+    ///
+    /// let bytes: &[u8] = &[0xD3, 0x00, 0x19, 0x01, 0xA5, 0x01, 0xCD, 0x01];
+    /// let zerovec: ZeroVec<u16> = ZeroVec::try_from_bytes(bytes).expect("infallible");
+    ///
+    /// let mut buffer = [0u16; 2];
+    /// zerovec.copy_into_aligned(1..3, &mut buffer);
+    /// assert_eq!(buffer, [281, 421]);
+    /// ```
+    #[inline]
+    pub fn copy_into_aligned(&self, range: Range<usize>, dst: &mut [T]) {
+        T::copy_from_unaligned_slice(&self.as_slice()[range], dst)
+    }
+
     pub(crate) fn get_ule_ref(&self, index: usize) -> Option<&T::ULE> {
         self.as_slice().get(index)
     }
@@ -360,6 +534,31 @@ where
         }
     }
 
+    /// Binary searches a sorted `ZeroVec<T>` with a comparator function. Each probed element is
+    /// converted to `T` before being passed to the comparator. For more information, see the
+    /// primitive function [`binary_search_by`].
+    ///
+    /// # Example
+    ///
+    /// Search packed `key << 8 | value` entries by key:
+    ///
+    /// ```
+    /// use zerovec::ZeroVec;
+    ///
+    /// let entries: &[u32] = &[0x0101, 0x0203, 0x0502, 0x0907];
+    /// let zerovec = ZeroVec::from_aligned(entries);
+    ///
+    /// assert_eq!(zerovec.binary_search_by(|probe| (probe >> 8).cmp(&5)), Ok(2));
+    /// assert_eq!(zerovec.binary_search_by(|probe| (probe >> 8).cmp(&4)), Err(2));
+    /// ```
+    ///
+    /// [`binary_search_by`]: https://doc.rust-lang.org/std/primitive.slice.html#method.binary_search_by
+    #[inline]
+    pub fn binary_search_by(&self, mut f: impl FnMut(T) -> Ordering) -> Result<usize, usize> {
+        self.as_slice()
+            .binary_search_by(|probe| f(T::from_unaligned(probe)))
+    }
+
     /// Allows the ZeroVec to be mutated by converting it to an owned variant
     ///
     /// # Example
@@ -379,17 +578,156 @@ where
     // This function is crate-public for now since we don't yet want to stabilize
     // the internal implementation details
     pub(crate) fn make_mut(&mut self) -> &mut Vec<T::ULE> {
+        self.make_mut_with_additional(0)
+    }
+
+    /// Like [`Self::make_mut`], but a `Borrowed` instance is converted to a `Vec` with room for
+    /// `additional` more elements, so that adding them does not allocate a second time.
+    fn make_mut_with_additional(&mut self, additional: usize) -> &mut Vec<T::ULE> {
         match self {
             ZeroVec::Owned(ref mut vec) => vec,
             ZeroVec::Borrowed(_) => {
-                let vec: Vec<T::ULE> = self.iter().map(|ule| T::as_unaligned(&ule)).collect();
+                let mut vec: Vec<T::ULE> = Vec::with_capacity(self.len() + additional);
+                vec.extend(self.iter().map(|ule| T::as_unaligned(&ule)));
                 let new_self = ZeroVec::Owned(vec);
                 *self = new_self;
                 // recursion is limited since we are guaranteed to hit the Owned branch
-                self.make_mut()
+                self.make_mut_with_additional(additional)
             }
         }
     }
+
+    /// Appends an element to the end of the `ZeroVec<T>`. A `Borrowed` instance is converted
+    /// to `Owned` first, with a single allocation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zerovec::ZeroVec;
+    ///
+    /// let bytes: &[u8] = &[0xD3, 0x00, 0x19, 0x01];
+    /// let mut zerovec: ZeroVec<u16> = ZeroVec::try_from_bytes(bytes).expect("infallible");
+    /// assert!(matches!(zerovec, ZeroVec::Borrowed(_)));
+    ///
+    /// zerovec.push(421);
+    /// assert!(matches!(zerovec, ZeroVec::Owned(_)));
+    /// assert_eq!(zerovec, &[211, 281, 421][..]);
+    /// ```
+    pub fn push(&mut self, value: T) {
+        self.make_mut_with_additional(1).push(value.as_unaligned());
+    }
+
+    /// Inserts an element at position `index`, shifting all elements after it to the right. A
+    /// `Borrowed` instance is converted to `Owned` first, with a single allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zerovec::ZeroVec;
+    ///
+    /// let bytes: &[u8] = &[0xD3, 0x00, 0xA5, 0x01];
+    /// let mut zerovec: ZeroVec<u16> = ZeroVec::try_from_bytes(bytes).expect("infallible");
+    ///
+    /// zerovec.insert(1, 281);
+    /// assert!(matches!(zerovec, ZeroVec::Owned(_)));
+    /// assert_eq!(zerovec, &[211, 281, 421][..]);
+    /// ```
+    pub fn insert(&mut self, index: usize, value: T) {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {}) should be <= len (is {})",
+            index,
+            len
+        );
+        self.make_mut_with_additional(1)
+            .insert(index, value.as_unaligned());
+    }
+
+    /// Removes and returns the element at position `index`, shifting all elements after it to
+    /// the left. A `Borrowed` instance is converted to `Owned` first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zerovec::ZeroVec;
+    ///
+    /// let bytes: &[u8] = &[0xD3, 0x00, 0x19, 0x01, 0xA5, 0x01];
+    /// let mut zerovec: ZeroVec<u16> = ZeroVec::try_from_bytes(bytes).expect("infallible");
+    ///
+    /// assert_eq!(zerovec.remove(1), 281);
+    /// assert!(matches!(zerovec, ZeroVec::Owned(_)));
+    /// assert_eq!(zerovec, &[211, 421][..]);
+    /// ```
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        assert!(
+            index < len,
+            "removal index (is {}) should be < len (is {})",
+            index,
+            len
+        );
+        T::from_unaligned(&self.make_mut().remove(index))
+    }
+}
+
+impl<T> ZeroVec<'_, T>
+where
+    T: TryAsULE,
+{
+    /// Gets the element at the specified index, validating it with [`TryAsULE`]. Returns None if
+    /// out of range, and the validation error if the element is invalid.
+    ///
+    /// # Example
+    ///
+    /// Store even numbers without validating them on parse:
+    ///
+    /// ```
+    /// use zerovec::ule::{AsULE, PlainOldULE, TryAsULE};
+    /// use zerovec::ZeroVec;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Even(u32);
+    ///
+    /// impl AsULE for Even {
+    ///     type ULE = PlainOldULE<4>;
+    ///     fn as_unaligned(&self) -> Self::ULE {
+    ///         self.0.as_unaligned()
+    ///     }
+    ///     fn from_unaligned(unaligned: &Self::ULE) -> Self {
+    ///         Self::try_from_unaligned(unaligned).expect("Odd number")
+    ///     }
+    /// }
+    ///
+    /// impl TryAsULE for Even {
+    ///     type Error = u32;
+    ///     fn try_from_unaligned(unaligned: &Self::ULE) -> Result<Self, u32> {
+    ///         match u32::from_unaligned(unaligned) {
+    ///             n if n % 2 == 0 => Ok(Even(n)),
+    ///             n => Err(n),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let bytes: &[u8] = &[0x02, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00];
+    /// let zerovec: ZeroVec<Even> = ZeroVec::try_from_bytes(bytes).expect("infallible");
+    ///
+    /// assert_eq!(zerovec.get_checked(0), Some(Ok(Even(2))));
+    /// assert_eq!(zerovec.get_checked(1), Some(Err(3)));
+    /// assert_eq!(zerovec.get_checked(2), None);
+    /// ```
+    #[inline]
+    pub fn get_checked(&self, index: usize) -> Option<Result<T, T::Error>> {
+        self.as_slice().get(index).map(T::try_from_unaligned)
+    }
 }
 
 impl<T> ZeroVec<'_, T>
@@ -419,6 +757,89 @@ where
     }
 }
 
+impl<T> FromIterator<T> for ZeroVec<'_, T>
+where
+    T: AsULE,
+{
+    /// Creates an `Owned` [`ZeroVec`] from an iterator of values, converting each one to its
+    /// unaligned representation without collecting into an intermediate `Vec<T>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zerovec::ZeroVec;
+    ///
+    /// let zerovec: ZeroVec<u32> = (0..1000u32).collect();
+    ///
+    /// assert!(matches!(zerovec, ZeroVec::Owned(_)));
+    /// assert_eq!(zerovec.len(), 1000);
+    /// assert_eq!(zerovec.get(999), Some(999));
+    /// ```
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::Owned(iter.into_iter().map(|t| t.as_unaligned()).collect())
+    }
+}
+
+impl<T> Extend<T> for ZeroVec<'_, T>
+where
+    T: AsULE + Copy,
+{
+    /// Appends the values of an iterator to the [`ZeroVec`]. A `Borrowed` instance is
+    /// converted to `Owned` first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use zerovec::ZeroVec;
+    ///
+    /// let bytes: &[u8] = &[0xD3, 0x00, 0x19, 0x01];
+    /// let mut zerovec: ZeroVec<u16> = ZeroVec::try_from_bytes(bytes).expect("infallible");
+    /// assert!(matches!(zerovec, ZeroVec::Borrowed(_)));
+    ///
+    /// zerovec.extend(vec![421, 461]);
+    /// assert!(matches!(zerovec, ZeroVec::Owned(_)));
+    /// assert_eq!(zerovec, &[211, 281, 421, 461][..]);
+    /// ```
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        self.make_mut()
+            .extend(iter.into_iter().map(|t| t.as_unaligned()));
+    }
+}
+
+/// Indexes into the unaligned elements, like [`ZeroVec::as_slice`]. Use [`ZeroVec::at`] to get
+/// the element by value.
+impl<T> Index<usize> for ZeroVec<'_, T>
+where
+    T: AsULE,
+{
+    type Output = T::ULE;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T::ULE {
+        &self.as_slice()[index]
+    }
+}
+
+/// Indexes into the unaligned elements, like [`ZeroVec::as_slice`]. Use
+/// [`ZeroVec::get_subslice`] to get a borrowed `ZeroVec<T>` instead.
+impl<T> Index<Range<usize>> for ZeroVec<'_, T>
+where
+    T: AsULE,
+{
+    type Output = [T::ULE];
+
+    #[inline]
+    fn index(&self, range: Range<usize>) -> &[T::ULE] {
+        &self.as_slice()[range]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -440,6 +861,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_at() {
+        let zerovec = ZeroVec::<u32>::try_from_bytes(&TEST_BUFFER_LE).unwrap();
+        for (i, x) in TEST_SLICE.iter().enumerate() {
+            assert_eq!(zerovec.at(i), *x);
+            assert_eq!(u32::from_unaligned(&zerovec[i]), *x);
+        }
+        assert_eq!(
+            zerovec[1..3]
+                .iter()
+                .map(u32::from_unaligned)
+                .collect::<Vec<_>>(),
+            &TEST_SLICE[1..3]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 20 but the index is 20")]
+    fn test_at_out_of_bounds() {
+        let zerovec = ZeroVec::from_aligned(TEST_SLICE);
+        zerovec.at(TEST_SLICE.len());
+    }
+
     #[test]
     fn test_binary_search() {
         {
@@ -454,6 +898,163 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_binary_search_by() {
+        {
+            let zerovec = ZeroVec::from_aligned(TEST_SLICE);
+            assert_eq!(Ok(3), zerovec.binary_search_by(|x| (x >> 16).cmp(&0x0e)));
+            assert_eq!(Err(3), zerovec.binary_search_by(|x| (x >> 16).cmp(&0x0c)));
+        }
+        {
+            let zerovec = ZeroVec::<u32>::try_from_bytes(&TEST_BUFFER_LE).unwrap();
+            assert_eq!(Ok(3), zerovec.binary_search_by(|x| (x >> 16).cmp(&0x0e)));
+            assert_eq!(Err(3), zerovec.binary_search_by(|x| (x >> 16).cmp(&0x0c)));
+        }
+    }
+
+    #[test]
+    fn test_from_iter_and_extend() {
+        let zerovec: ZeroVec<u32> = TEST_SLICE.iter().copied().collect();
+        assert!(matches!(zerovec, ZeroVec::Owned(_)));
+        assert_eq!(zerovec, TEST_SLICE);
+
+        let mut zerovec = ZeroVec::<u32>::try_from_bytes(&TEST_BUFFER_LE[..8]).unwrap();
+        assert!(matches!(zerovec, ZeroVec::Borrowed(_)));
+        zerovec.extend(TEST_SLICE[2..].iter().copied());
+        assert!(matches!(zerovec, ZeroVec::Owned(_)));
+        assert_eq!(zerovec, TEST_SLICE);
+    }
+
+    #[test]
+    fn test_push_insert_remove() {
+        let mut zerovec = ZeroVec::<u32>::try_from_bytes(&TEST_BUFFER_LE[..8]).unwrap();
+        assert!(matches!(zerovec, ZeroVec::Borrowed(_)));
+        zerovec.push(TEST_SLICE[3]);
+        assert!(matches!(zerovec, ZeroVec::Owned(_)));
+        assert_eq!(zerovec, &[TEST_SLICE[0], TEST_SLICE[1], TEST_SLICE[3]][..]);
+
+        zerovec.insert(2, TEST_SLICE[2]);
+        zerovec.insert(4, TEST_SLICE[4]);
+        assert_eq!(zerovec, &TEST_SLICE[..5]);
+
+        assert_eq!(zerovec.remove(0), TEST_SLICE[0]);
+        assert_eq!(zerovec.remove(3), TEST_SLICE[4]);
+        assert_eq!(zerovec, &TEST_SLICE[1..4]);
+
+        // Mutating a borrowed ZeroVec does not affect the buffer it was borrowed from.
+        let mut zerovec = ZeroVec::<u32>::try_from_bytes(&TEST_BUFFER_LE).unwrap();
+        zerovec.remove(0);
+        assert_eq!(zerovec, &TEST_SLICE[1..]);
+        assert_eq!(
+            ZeroVec::<u32>::try_from_bytes(&TEST_BUFFER_LE).unwrap(),
+            TEST_SLICE
+        );
+    }
+
+    #[test]
+    fn test_push_allocates_once() {
+        let mut zerovec = ZeroVec::<u32>::try_from_bytes(&TEST_BUFFER_LE).unwrap();
+        zerovec.push(0);
+        match zerovec {
+            ZeroVec::Owned(vec) => {
+                assert_eq!(vec.len(), TEST_SLICE.len() + 1);
+                assert_eq!(vec.capacity(), TEST_SLICE.len() + 1);
+            }
+            ZeroVec::Borrowed(_) => panic!("push should convert to Owned"),
+        }
+    }
+
+    #[test]
+    fn test_insert_at_end() {
+        let mut zerovec = ZeroVec::<u32>::try_from_bytes(&TEST_BUFFER_LE[..8]).unwrap();
+        zerovec.insert(2, TEST_SLICE[2]);
+        assert_eq!(zerovec, &TEST_SLICE[..3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 21) should be <= len (is 20)")]
+    fn test_insert_out_of_bounds() {
+        let mut zerovec = ZeroVec::<u32>::try_from_bytes(&TEST_BUFFER_LE).unwrap();
+        zerovec.insert(TEST_SLICE.len() + 1, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "removal index (is 20) should be < len (is 20)")]
+    fn test_remove_out_of_bounds() {
+        let mut zerovec = ZeroVec::<u32>::try_from_bytes(&TEST_BUFFER_LE).unwrap();
+        zerovec.remove(TEST_SLICE.len());
+    }
+
+    #[test]
+    fn test_get_subslice() {
+        let owned = ZeroVec::Owned(TEST_SLICE.iter().map(u32::as_unaligned).collect());
+        let borrowed = ZeroVec::<u32>::try_from_bytes(&TEST_BUFFER_LE).unwrap();
+        for zerovec in &[owned, borrowed] {
+            let subslice = zerovec.get_subslice(2..5).unwrap();
+            assert!(matches!(subslice, ZeroVec::Borrowed(_)));
+            assert_eq!(subslice, &TEST_SLICE[2..5]);
+            assert_eq!(zerovec.get_subslice(0..0).unwrap().len(), 0);
+            assert_eq!(zerovec.get_subslice(18..21), None);
+        }
+    }
+
+    #[test]
+    fn test_copy_into_aligned() {
+        let zerovec = ZeroVec::<u32>::try_from_bytes(&TEST_BUFFER_LE).unwrap();
+        let mut buffer = [0u32; 3];
+        zerovec.copy_into_aligned(2..5, &mut buffer);
+        assert_eq!(buffer, TEST_SLICE[2..5]);
+
+        let zerovec = ZeroVec::<u32>::try_from_bytes(&TEST_BUFFER_LE[1..]).unwrap();
+        let mut buffer = vec![0u32; zerovec.len()];
+        zerovec.copy_into_aligned(0..zerovec.len(), &mut buffer);
+        assert_eq!(buffer, zerovec.to_vec());
+
+        let zerovec = ZeroVec::from_aligned(&['a', 'ß', '\u{1F600}']);
+        let mut buffer = ['\0'; 2];
+        zerovec.copy_into_aligned(1..3, &mut buffer);
+        assert_eq!(buffer, ['ß', '\u{1F600}']);
+    }
+
+    #[test]
+    #[should_panic(expected = "source and destination slices have different lengths")]
+    fn test_copy_into_aligned_wrong_length() {
+        let zerovec = ZeroVec::from_aligned(TEST_SLICE);
+        zerovec.copy_into_aligned(0..2, &mut [0u32; 3]);
+    }
+
+    #[test]
+    fn test_ord() {
+        let one = ZeroVec::<u16>::try_from_bytes(&[0x01, 0x00]).unwrap();
+        let two_fifty_six = ZeroVec::<u16>::try_from_bytes(&[0x00, 0x01]).unwrap();
+        let empty = ZeroVec::<u16>::try_from_bytes(&[]).unwrap();
+        let one_one = ZeroVec::from_aligned(&[1u16, 1]);
+
+        let mut vecs = vec![
+            two_fifty_six.clone(),
+            one_one.clone(),
+            empty.clone(),
+            one.clone(),
+        ];
+        vecs.sort();
+        assert_eq!(vecs, vec![empty, one, one_one, two_fifty_six]);
+
+        assert_eq!(
+            ZeroVec::from_aligned(&[-1i32]).cmp(&ZeroVec::from_aligned(&[1i32])),
+            Ordering::Less
+        );
+
+        let mut keys: Vec<ZeroVec<u8>> = vec![
+            ZeroVec::try_from_bytes(b"zz").unwrap(),
+            ZeroVec::try_from_bytes(b"a").unwrap(),
+            ZeroVec::try_from_bytes(b"ab").unwrap(),
+        ];
+        keys.sort();
+        assert_eq!(keys[0].as_bytes(), b"a");
+        assert_eq!(keys[1].as_bytes(), b"ab");
+        assert_eq!(keys[2].as_bytes(), b"zz");
+    }
+
     #[test]
     fn test_odd_alignment() {
         assert_eq!(
@@ -529,4 +1130,76 @@ mod tests {
                 .get(19)
         );
     }
+
+    #[test]
+    fn test_signed_round_trip() {
+        let nums: [i32; 4] = [-1, i32::MIN, 0x01020304, i32::MAX];
+        let zv = ZeroVec::from_aligned(&nums);
+        let bytes = zv.as_bytes();
+        assert_eq!(
+            bytes,
+            &[
+                0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x80, 0x04, 0x03, 0x02, 0x01, 0xFF, 0xFF,
+                0xFF, 0x7F
+            ]
+        );
+        let parsed = ZeroVec::<i32>::try_from_bytes(bytes).unwrap();
+        assert_eq!(parsed, &nums[..]);
+        assert_eq!(parsed.to_vec(), nums);
+
+        let nums: [i16; 3] = [-2, i16::MIN, 300];
+        let zv = ZeroVec::<i16>::try_from_bytes(ZeroVec::from_aligned(&nums).as_bytes())
+            .unwrap()
+            .into_owned();
+        assert_eq!(zv.to_vec(), nums);
+
+        let nums: [i64; 2] = [-3, i64::MAX];
+        let zv = ZeroVec::<i64>::try_from_bytes(ZeroVec::from_aligned(&nums).as_bytes())
+            .unwrap()
+            .into_owned();
+        assert_eq!(zv.to_vec(), nums);
+    }
+
+    #[test]
+    fn test_float_round_trip() {
+        let nums: [f32; 6] = [
+            -1.5,
+            -0.0,
+            f32::MIN_POSITIVE,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::NAN,
+        ];
+        let zv = ZeroVec::from_aligned(&nums);
+        assert_eq!(&zv.as_bytes()[0..4], &[0x00, 0x00, 0xC0, 0xBF]);
+        assert_eq!(&zv.as_bytes()[12..16], &[0x00, 0x00, 0x80, 0x7F]);
+        let parsed = ZeroVec::<f32>::try_from_bytes(zv.as_bytes()).unwrap();
+        // NaN is not equal to itself, so compare the bits.
+        let bits: Vec<u32> = parsed.iter().map(f32::to_bits).collect();
+        let expected: Vec<u32> = nums.iter().copied().map(f32::to_bits).collect();
+        assert_eq!(bits, expected);
+        assert!(parsed.get(5).unwrap().is_nan());
+
+        let nums: [f64; 5] = [47.61, f64::MAX, f64::INFINITY, f64::NEG_INFINITY, -f64::NAN];
+        let zv = ZeroVec::from_aligned(&nums);
+        let parsed = ZeroVec::<f64>::try_from_bytes(zv.as_bytes()).unwrap();
+        let bits: Vec<u64> = parsed.to_vec().into_iter().map(f64::to_bits).collect();
+        let expected: Vec<u64> = nums.iter().copied().map(f64::to_bits).collect();
+        assert_eq!(bits, expected);
+        assert_eq!(parsed.get(2), Some(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_float_from_arbitrary_bytes() {
+        // Every bit pattern is a valid float, including signaling NaNs.
+        let zv = ZeroVec::<f32>::try_from_bytes(&TEST_BUFFER_LE[1..]).unwrap();
+        assert_eq!(zv.len(), (TEST_BUFFER_LE.len() - 1) / 4);
+        let zv = ZeroVec::<f64>::try_from_bytes(&[0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x7F])
+            .unwrap();
+        assert!(zv.get(0).unwrap().is_nan());
+        assert_eq!(
+            zv.as_bytes(),
+            &[0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0xF0, 0x7F]
+        );
+    }
 }
@@ -4,10 +4,11 @@
 
 use super::ZeroVec;
 use crate::ule::*;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
 use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
 use serde::ser::{Serialize, SerializeSeq, Serializer};
-use std::fmt;
-use std::marker::PhantomData;
 
 struct ZeroVecVisitor<T> {
     marker: PhantomData<fn() -> T>,
@@ -56,6 +57,10 @@ where
 }
 
 /// This impl can be made available by enabling the optional `serde` feature of the `zerovec` crate
+///
+/// Human-readable formats, such as JSON, store a [`ZeroVec`] as a sequence of its elements, which
+/// are deserialized into an owned vector. Other formats store it as a byte buffer, which is
+/// borrowed when the deserializer supports it.
 impl<'de, 'a, T> Deserialize<'de> for ZeroVec<'a, T>
 where
     T: 'de + Deserialize<'de> + AsULE,
@@ -119,6 +124,20 @@ mod test {
         assert!(matches!(zerovec_new, ZeroVec::Owned(_)));
     }
 
+    #[test]
+    fn test_serde_json_handwritten() {
+        let json_str = "[ 211, 281,\n  421 ]";
+        let zerovec: ZeroVec<u16> = serde_json::from_str(json_str).expect("deserialize");
+        assert_eq!(zerovec, ZeroVec::from_aligned(&[211u16, 281, 421]));
+        assert_eq!(
+            "[211,281,421]",
+            serde_json::to_string(&zerovec).expect("serialize")
+        );
+        // Elements are checked against the element type.
+        serde_json::from_str::<ZeroVec<u16>>("[211, 70000]").expect_err("out of range for u16");
+        serde_json::from_str::<ZeroVec<char>>("[\"a\", 98]").expect_err("not a char");
+    }
+
     #[test]
     fn test_serde_bincode() {
         let zerovec_orig = ZeroVec::from_aligned(TEST_SLICE);
@@ -143,6 +162,17 @@ mod test {
         assert!(matches!(zerovec_new, ZeroVec::Borrowed(_)));
     }
 
+    #[test]
+    fn test_chars_json() {
+        let zerovec_orig = ZeroVec::from_aligned(&['w', 'ω', '文', '𑄃']);
+        let json_str = serde_json::to_string(&zerovec_orig).expect("serialize");
+        assert_eq!(r#"["w","ω","文","𑄃"]"#, json_str);
+        let zerovec_new: ZeroVec<char> =
+            serde_json::from_str(&json_str).expect("deserialize from JSON to ZeroVec");
+        assert_eq!(zerovec_orig, zerovec_new);
+        assert!(matches!(zerovec_new, ZeroVec::Owned(_)));
+    }
+
     #[test]
     fn test_chars_invalid() {
         // 119 and 120 are valid, but not 0xD800 (high surrogate)
@@ -150,5 +180,10 @@ mod test {
         let bincode_buf = bincode::serialize(&zerovec_orig).expect("serialize");
         let zerovec_result = bincode::deserialize::<ZeroVec<char>>(&bincode_buf);
         assert!(matches!(zerovec_result, Err(_)));
+        // The error of the ULE is surfaced as a Serde error.
+        assert_eq!(
+            zerovec_result.unwrap_err().to_string(),
+            "Invalid char value 0xD800 at byte offset 4"
+        );
     }
 }